@@ -0,0 +1,216 @@
+//! Compile the checked-in DuckDB function snapshot into a static catalog
+//!
+//! Reads `data/duckdb_functions.tsv` (produced by the ff-db
+//! `dump_duckdb_functions` example) and writes `duckdb_function_catalog.rs`
+//! into `OUT_DIR`, where `datafusion_bridge::catalog` includes it. Each
+//! overload's return type is reduced to a `ReturnRule` here so that
+//! planning never has to reason about DuckDB's generic type names.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+const SNAPSHOT: &str = "data/duckdb_functions.tsv";
+
+/// Type variables and placeholders DuckDB uses in signatures
+const TYPE_VARIABLES: &[&str] = &["ANY", "T", "K", "V", "LAMBDA"];
+
+/// Type families that stand for any member when written without parameters
+const TYPE_FAMILIES: &[&str] = &["DECIMAL", "LIST", "ARRAY", "STRUCT", "MAP", "UNION", "ENUM"];
+
+struct Overload {
+    params: Vec<String>,
+    varargs: Option<String>,
+    returns: String,
+    stability: String,
+}
+
+fn main() {
+    println!("cargo:rerun-if-changed={SNAPSHOT}");
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let text =
+        fs::read_to_string(SNAPSHOT).unwrap_or_else(|e| panic!("failed to read {SNAPSHOT}: {e}"));
+
+    let mut version = String::from("unknown");
+    let mut functions: BTreeMap<(String, String), Vec<Overload>> = BTreeMap::new();
+    for (line_no, line) in text.lines().enumerate() {
+        if let Some(comment) = line.strip_prefix('#') {
+            if let Some(v) = comment.trim().strip_prefix("duckdb_version") {
+                version = v.trim().to_string();
+            }
+            continue;
+        }
+        if line.trim().is_empty() || line.starts_with("function_name\t") {
+            continue;
+        }
+        let fields: Vec<&str> = line.split('\t').collect();
+        let &[name, kind, params, varargs, returns, stability] = fields.as_slice() else {
+            panic!(
+                "{SNAPSHOT}:{}: expected 6 tab-separated fields, found {}",
+                line_no + 1,
+                fields.len()
+            );
+        };
+        let params = if params.is_empty() {
+            Vec::new()
+        } else {
+            params.split('|').map(str::to_string).collect()
+        };
+        functions
+            .entry((name.to_string(), kind.to_string()))
+            .or_default()
+            .push(Overload {
+                params,
+                varargs: (!varargs.is_empty()).then(|| varargs.to_string()),
+                returns: returns.to_string(),
+                stability: stability.to_string(),
+            });
+    }
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "/// DuckDB version the catalog snapshot was taken from"
+    )
+    .unwrap();
+    writeln!(out, "pub(crate) const DUCKDB_VERSION: &str = {version:?};").unwrap();
+    writeln!(out).unwrap();
+    writeln!(
+        out,
+        "/// Every DuckDB function in the snapshot, sorted by name"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "pub(crate) static DUCKDB_FUNCTIONS: &[CatalogFunction] = &["
+    )
+    .unwrap();
+    for ((name, kind), overloads) in &functions {
+        let kind = match kind.as_str() {
+            "scalar" => "Scalar",
+            "aggregate" => "Aggregate",
+            "window" => "Window",
+            "macro" => "Macro",
+            other => panic!("{SNAPSHOT}: unsupported function_type '{other}' for '{name}'"),
+        };
+        writeln!(
+            out,
+            "    CatalogFunction {{ name: {name:?}, kind: FunctionKind::{kind}, volatility: Volatility::{}, overloads: &[",
+            volatility(overloads)
+        )
+        .unwrap();
+        for overload in overloads {
+            let params: Vec<String> = overload.params.iter().map(|p| param(p)).collect();
+            let varargs = match &overload.varargs {
+                Some(v) => format!("Some({})", param(v)),
+                None => "None".to_string(),
+            };
+            writeln!(
+                out,
+                "        CatalogOverload {{ params: &[{}], varargs: {varargs}, returns: {} }},",
+                params.join(", "),
+                return_rule(overload)
+            )
+            .unwrap();
+        }
+        writeln!(out, "    ] }},").unwrap();
+    }
+    writeln!(out, "];").unwrap();
+
+    let out_dir = env::var("OUT_DIR").expect("OUT_DIR is set by cargo");
+    fs::write(Path::new(&out_dir).join("duckdb_function_catalog.rs"), out)
+        .expect("failed to write generated DuckDB function catalog");
+}
+
+/// The most volatile stability across a function's overloads
+fn volatility(overloads: &[Overload]) -> &'static str {
+    let stability = |s: &str| overloads.iter().any(|o| o.stability == s);
+    if stability("VOLATILE") {
+        "Volatile"
+    } else if stability("CONSISTENT_WITHIN_QUERY") {
+        "Stable"
+    } else {
+        "Immutable"
+    }
+}
+
+fn param(ty: &str) -> String {
+    if is_generic(ty) {
+        "ParamType::Generic".to_string()
+    } else {
+        format!("ParamType::Exact({ty:?})")
+    }
+}
+
+/// Whether a DuckDB type name stands for a family of types rather than one type
+///
+/// `DECIMAL` and `MAP` are generic, `DECIMAL(18,3)` and `MAP(VARCHAR, BIGINT)`
+/// are not, and anything mentioning a type variable (`T[]`, `MAP(K, V)`) is.
+fn is_generic(ty: &str) -> bool {
+    let mut rest = ty;
+    while !rest.is_empty() {
+        let end = rest
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+            .unwrap_or(rest.len());
+        let (token, tail) = rest.split_at(end);
+        if TYPE_VARIABLES.contains(&token)
+            || (TYPE_FAMILIES.contains(&token) && !tail.starts_with('('))
+        {
+            return true;
+        }
+        rest = tail.get(1..).unwrap_or("");
+    }
+    false
+}
+
+/// Reduce an overload's return type to a rule over its argument types.
+///
+/// Varargs count as the argument after the fixed parameters. A list
+/// argument of the return type wins over an argument of the same type, so
+/// `list_aggregate(ANY[], VARCHAR, ANY...) -> ANY` follows the list.
+fn return_rule(overload: &Overload) -> String {
+    let ret = overload.returns.as_str();
+    if ret.is_empty() {
+        return "ReturnRule::Unknown".to_string();
+    }
+    if !is_generic(ret) {
+        return format!("ReturnRule::Fixed({ret:?})");
+    }
+
+    let args: Vec<&str> = overload
+        .params
+        .iter()
+        .chain(&overload.varargs)
+        .map(String::as_str)
+        .collect();
+    let position = |pred: &dyn Fn(&str) -> bool| args.iter().position(|a| pred(a));
+
+    if let Some(i) = position(&|a| a.strip_suffix("[]") == Some(ret)) {
+        return format!("ReturnRule::Element({i})");
+    }
+    if let Some(i) = position(&|a| a == ret) {
+        return format!("ReturnRule::Arg({i})");
+    }
+    if let Some(element) = ret.strip_suffix("[]") {
+        if let Some(i) = position(&|a| a == element) {
+            return format!("ReturnRule::ListOf({i})");
+        }
+    }
+    if let Some(i) = position(&|a| a.starts_with("MAP(")) {
+        match ret {
+            "K[]" => return format!("ReturnRule::MapKeys({i})"),
+            "V[]" => return format!("ReturnRule::MapValues({i})"),
+            "V" => return format!("ReturnRule::MapValue({i})"),
+            _ => {}
+        }
+    }
+    if ret == "MAP(K, V)" {
+        if let (Some(k), Some(v)) = (position(&|a| a == "K[]"), position(&|a| a == "V[]")) {
+            return format!("ReturnRule::MapOf({k}, {v})");
+        }
+    }
+    "ReturnRule::Unknown".to_string()
+}
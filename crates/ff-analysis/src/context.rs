@@ -2,10 +2,11 @@
 
 use crate::schema::RelSchema;
 use ff_core::dag::ModelDag;
+use ff_core::model::{parse_test_definition, SchemaColumnDef, TestDefinition, TestType};
 use ff_core::ModelName;
 use ff_core::Project;
 use ff_sql::ProjectLineage;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::sync::Arc;
 
 /// Context object passed to analysis passes, providing access to project metadata
@@ -20,6 +21,10 @@ pub struct AnalysisContext {
     pub(crate) lineage: ProjectLineage,
     /// Set of known model names
     pub(crate) known_models: HashSet<ModelName>,
+    /// Declared unique column sets per lowercase model/source table name
+    pub(crate) unique_keys: HashMap<String, Vec<BTreeSet<String>>>,
+    /// Parsed schema tests per lowercase model/source table, then lowercase column
+    pub(crate) column_tests: HashMap<String, HashMap<String, Vec<TestType>>>,
 }
 
 impl AnalysisContext {
@@ -31,12 +36,16 @@ impl AnalysisContext {
         lineage: ProjectLineage,
     ) -> Self {
         let known_models = project.models.keys().cloned().collect();
+        let unique_keys = build_unique_keys(&project);
+        let column_tests = build_column_tests(&project);
         Self {
             project,
             dag,
             yaml_schemas,
             lineage,
             known_models,
+            unique_keys,
+            column_tests,
         }
     }
 
@@ -69,4 +78,121 @@ impl AnalysisContext {
     pub fn yaml_schemas(&self) -> &HashMap<ModelName, Arc<RelSchema>> {
         &self.yaml_schemas
     }
+
+    /// Column sets declared unique for a model or source table (lowercase
+    /// names): `primary_key` columns, `unique`-tested columns, and the
+    /// incremental `unique_key`. Empty when nothing is declared.
+    pub fn unique_keys(&self, table: &str) -> &[BTreeSet<String>] {
+        self.unique_keys
+            .get(&table.to_lowercase())
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Schema tests declared on a model or source table column
+    /// (case-insensitive). Empty when the column has no parseable tests.
+    pub fn column_tests(&self, table: &str, column: &str) -> &[TestType] {
+        self.column_tests
+            .get(&table.to_lowercase())
+            .and_then(|columns| columns.get(&column.to_lowercase()))
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+}
+
+/// Collect parsed schema tests for every model and source table column.
+fn build_column_tests(project: &Project) -> HashMap<String, HashMap<String, Vec<TestType>>> {
+    fn parse_all(tests: &[TestDefinition]) -> Vec<TestType> {
+        tests.iter().filter_map(parse_test_definition).collect()
+    }
+
+    let mut all: HashMap<String, HashMap<String, Vec<TestType>>> = HashMap::new();
+    for (name, model) in &project.models {
+        let Some(schema) = &model.schema else {
+            continue;
+        };
+        let columns: HashMap<String, Vec<TestType>> = schema
+            .columns
+            .iter()
+            .map(|c| (c.name.to_lowercase(), parse_all(&c.tests)))
+            .filter(|(_, tests)| !tests.is_empty())
+            .collect();
+        if !columns.is_empty() {
+            all.insert(name.to_lowercase(), columns);
+        }
+    }
+    for source in &project.sources {
+        for table in &source.tables {
+            let columns: HashMap<String, Vec<TestType>> = table
+                .columns
+                .iter()
+                .map(|c| (c.name.to_lowercase(), parse_all(&c.tests)))
+                .filter(|(_, tests)| !tests.is_empty())
+                .collect();
+            if !columns.is_empty() {
+                all.entry(table.name.to_lowercase()).or_insert(columns);
+            }
+        }
+    }
+    all
+}
+
+/// Collect declared unique column sets for every model and source table.
+fn build_unique_keys(project: &Project) -> HashMap<String, Vec<BTreeSet<String>>> {
+    let mut keys: HashMap<String, Vec<BTreeSet<String>>> = HashMap::new();
+
+    for (name, model) in &project.models {
+        let mut model_keys = model
+            .schema
+            .as_ref()
+            .map(|s| column_keys(&s.columns))
+            .unwrap_or_default();
+        if let Some(unique_key) = model.unique_key().filter(|k| !k.is_empty()) {
+            model_keys.push(unique_key.iter().map(|c| c.to_lowercase()).collect());
+        }
+        if !model_keys.is_empty() {
+            keys.insert(name.to_lowercase(), model_keys);
+        }
+    }
+
+    for source in &project.sources {
+        for table in &source.tables {
+            let table_keys: Vec<BTreeSet<String>> = table
+                .columns
+                .iter()
+                .filter(|c| has_unique_test(&c.tests))
+                .map(|c| BTreeSet::from([c.name.to_lowercase()]))
+                .collect();
+            if !table_keys.is_empty() {
+                keys.insert(table.name.to_lowercase(), table_keys);
+            }
+        }
+    }
+
+    keys
+}
+
+/// Unique-tested columns as single-column keys, plus the (possibly composite)
+/// primary key.
+fn column_keys(columns: &[SchemaColumnDef]) -> Vec<BTreeSet<String>> {
+    let mut keys: Vec<BTreeSet<String>> = columns
+        .iter()
+        .filter(|c| has_unique_test(&c.tests))
+        .map(|c| BTreeSet::from([c.name.to_lowercase()]))
+        .collect();
+    let primary_key: BTreeSet<String> = columns
+        .iter()
+        .filter(|c| c.primary_key)
+        .map(|c| c.name.to_lowercase())
+        .collect();
+    if !primary_key.is_empty() {
+        keys.push(primary_key);
+    }
+    keys
+}
+
+fn has_unique_test(tests: &[TestDefinition]) -> bool {
+    tests
+        .iter()
+        .any(|t| matches!(parse_test_definition(t), Some(TestType::Unique)))
 }
@@ -0,0 +1,227 @@
+//! Generated DuckDB function catalog
+//!
+//! `build.rs` compiles `data/duckdb_functions.tsv`, a snapshot of DuckDB's
+//! `duckdb_functions()`, into [`DUCKDB_FUNCTIONS`]. This module picks the
+//! overload that best fits a call's argument types and applies its return
+//! rule. Regenerate the snapshot with `make duckdb-functions` after upgrading
+//! DuckDB.
+
+use arrow::datatypes::{DataType, Field, Fields};
+use datafusion_expr::Volatility;
+
+use crate::datafusion_bridge::types::sql_type_to_arrow;
+use crate::types::parse_sql_type;
+
+/// Which DataFusion registry a catalog function belongs in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum FunctionKind {
+    Scalar,
+    Aggregate,
+    Window,
+    /// Built-in SQL macro; parameters are untyped and the return type unknown
+    Macro,
+}
+
+/// A declared parameter type
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum ParamType {
+    /// A type variable (`ANY`, `T[]`, `MAP(K, V)`) or unparameterized family (`DECIMAL`)
+    Generic,
+    /// A concrete DuckDB type name
+    Exact(&'static str),
+}
+
+/// How an overload's return type follows from its argument types.
+///
+/// Argument indices count varargs as the argument after the fixed parameters.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub(crate) enum ReturnRule {
+    /// A concrete DuckDB type name
+    Fixed(&'static str),
+    /// The type of argument `i`
+    Arg(usize),
+    /// The element type of list argument `i`
+    Element(usize),
+    /// A list of argument `i`'s type
+    ListOf(usize),
+    /// A list of map argument `i`'s key type
+    MapKeys(usize),
+    /// A list of map argument `i`'s value type
+    MapValues(usize),
+    /// Map argument `i`'s value type
+    MapValue(usize),
+    /// A map from list argument `k`'s element type to list argument `v`'s
+    MapOf(usize, usize),
+    /// Not derivable from the arguments (struct construction, JSON transforms, macros)
+    Unknown,
+}
+
+/// One overload of a catalog function
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(crate) struct CatalogOverload {
+    pub(crate) params: &'static [ParamType],
+    pub(crate) varargs: Option<ParamType>,
+    pub(crate) returns: ReturnRule,
+}
+
+/// A DuckDB function and all of its overloads
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub(crate) struct CatalogFunction {
+    pub(crate) name: &'static str,
+    pub(crate) kind: FunctionKind,
+    pub(crate) volatility: Volatility,
+    pub(crate) overloads: &'static [CatalogOverload],
+}
+
+include!(concat!(env!("OUT_DIR"), "/duckdb_function_catalog.rs"));
+
+impl CatalogFunction {
+    /// Return type of a call with the given argument types.
+    ///
+    /// Calls no overload accepts, and overloads whose rule cannot be applied
+    /// to the arguments, return `DataType::Null`, which static analysis
+    /// reports as an unknown type.
+    pub(crate) fn return_type(&self, args: &[DataType]) -> DataType {
+        self.resolve(args)
+            .and_then(|overload| overload.returns.apply(args))
+            .unwrap_or(DataType::Null)
+    }
+
+    /// The overload with the best-matching parameter types, first one on ties
+    pub(crate) fn resolve(&self, args: &[DataType]) -> Option<&'static CatalogOverload> {
+        self.overloads
+            .iter()
+            .filter(|overload| overload.accepts(args.len()))
+            .rev()
+            .max_by_key(|overload| overload.score(args))
+    }
+
+    /// Whether the function can be called without arguments
+    pub(crate) fn accepts_no_args(&self) -> bool {
+        self.overloads.iter().any(|overload| overload.accepts(0))
+    }
+
+    /// Whether every overload takes no arguments
+    pub(crate) fn is_nullary(&self) -> bool {
+        self.overloads
+            .iter()
+            .all(|overload| overload.params.is_empty() && overload.varargs.is_none())
+    }
+}
+
+impl CatalogOverload {
+    fn accepts(&self, arg_count: usize) -> bool {
+        arg_count == self.params.len() || (self.varargs.is_some() && arg_count > self.params.len())
+    }
+
+    /// Two points per argument of the declared type family, one per generic
+    /// parameter or NULL argument
+    fn score(&self, args: &[DataType]) -> usize {
+        let params = self.params.iter().copied().chain(std::iter::repeat_n(
+            self.varargs.unwrap_or(ParamType::Generic),
+            args.len().saturating_sub(self.params.len()),
+        ));
+        params
+            .zip(args)
+            .map(|(param, arg)| match param {
+                ParamType::Generic => 1,
+                _ if matches!(arg, DataType::Null) => 1,
+                ParamType::Exact(ty) => {
+                    if std::mem::discriminant(&duckdb_type_to_arrow(ty))
+                        == std::mem::discriminant(arg)
+                    {
+                        2
+                    } else {
+                        0
+                    }
+                }
+            })
+            .sum()
+    }
+}
+
+impl ReturnRule {
+    fn apply(self, args: &[DataType]) -> Option<DataType> {
+        match self {
+            ReturnRule::Fixed(ty) => Some(duckdb_type_to_arrow(ty)),
+            ReturnRule::Arg(i) => args.get(i).cloned(),
+            ReturnRule::Element(i) => list_element(args.get(i)?),
+            ReturnRule::ListOf(i) => Some(list_of(args.get(i)?.clone())),
+            ReturnRule::MapKeys(i) => map_entry(args.get(i)?).map(|(key, _)| list_of(key)),
+            ReturnRule::MapValues(i) => map_entry(args.get(i)?).map(|(_, value)| list_of(value)),
+            ReturnRule::MapValue(i) => map_entry(args.get(i)?).map(|(_, value)| value),
+            ReturnRule::MapOf(k, v) => {
+                let key = list_element(args.get(k)?)?;
+                let value = list_element(args.get(v)?)?;
+                Some(map_of(key, value))
+            }
+            ReturnRule::Unknown => None,
+        }
+    }
+}
+
+/// Convert a DuckDB type name from `duckdb_functions()` to Arrow.
+///
+/// Handles the names `parse_sql_type` does not know (unsigned integers,
+/// timestamp precisions) before deferring to it.
+pub(crate) fn duckdb_type_to_arrow(ty: &str) -> DataType {
+    if let Some(element) = ty.strip_suffix("[]") {
+        return list_of(duckdb_type_to_arrow(element));
+    }
+    match ty.trim().to_uppercase().as_str() {
+        "UTINYINT" => DataType::UInt8,
+        "USMALLINT" => DataType::UInt16,
+        "UINTEGER" => DataType::UInt32,
+        "UBIGINT" => DataType::UInt64,
+        "UHUGEINT" => DataType::Decimal128(38, 0),
+        "TIMESTAMP_S" | "TIMESTAMP_MS" | "TIMESTAMP_NS" => {
+            DataType::Timestamp(arrow::datatypes::TimeUnit::Microsecond, None)
+        }
+        "TIME WITH TIME ZONE" | "TIMETZ" => {
+            DataType::Time64(arrow::datatypes::TimeUnit::Microsecond)
+        }
+        _ => sql_type_to_arrow(&parse_sql_type(ty)),
+    }
+}
+
+fn list_of(element: DataType) -> DataType {
+    DataType::List(Field::new("item", element, true).into())
+}
+
+fn list_element(list: &DataType) -> Option<DataType> {
+    match list {
+        DataType::List(field) | DataType::LargeList(field) | DataType::FixedSizeList(field, _) => {
+            Some(field.data_type().clone())
+        }
+        _ => None,
+    }
+}
+
+fn map_of(key: DataType, value: DataType) -> DataType {
+    let entries = Field::new(
+        "entries",
+        DataType::Struct(Fields::from(vec![
+            Field::new("key", key, false),
+            Field::new("value", value, true),
+        ])),
+        false,
+    );
+    DataType::Map(entries.into(), false)
+}
+
+fn map_entry(map: &DataType) -> Option<(DataType, DataType)> {
+    let DataType::Map(entries, _) = map else {
+        return None;
+    };
+    let DataType::Struct(fields) = entries.data_type() else {
+        return None;
+    };
+    match fields.iter().collect::<Vec<_>>().as_slice() {
+        [key, value] => Some((key.data_type().clone(), value.data_type().clone())),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "catalog_test.rs"]
+mod tests;
@@ -0,0 +1,99 @@
+use super::*;
+
+fn function(name: &str, kind: FunctionKind) -> &'static CatalogFunction {
+    DUCKDB_FUNCTIONS
+        .iter()
+        .find(|f| f.name == name && f.kind == kind)
+        .unwrap_or_else(|| panic!("{name} missing from the DuckDB catalog"))
+}
+
+fn list(element: DataType) -> DataType {
+    DataType::List(Field::new("item", element, true).into())
+}
+
+#[test]
+fn test_catalog_is_sorted_and_unique() {
+    assert!(!DUCKDB_VERSION.is_empty());
+    assert!(DUCKDB_FUNCTIONS.len() > 300);
+    assert!(DUCKDB_FUNCTIONS.windows(2).all(|w| w[0].name <= w[1].name));
+
+    let mut seen = std::collections::HashSet::new();
+    for f in DUCKDB_FUNCTIONS {
+        assert!(seen.insert((f.name, f.kind)), "{} listed twice", f.name);
+        assert!(!f.overloads.is_empty(), "{} has no overloads", f.name);
+    }
+}
+
+#[test]
+fn test_overload_picked_by_argument_type() {
+    let abs = function("abs", FunctionKind::Scalar);
+    assert_eq!(abs.return_type(&[DataType::Int32]), DataType::Int32);
+    assert_eq!(abs.return_type(&[DataType::Float64]), DataType::Float64);
+    assert_eq!(
+        abs.return_type(&[DataType::Decimal128(10, 2)]),
+        DataType::Decimal128(10, 2)
+    );
+
+    let hash = function("hash", FunctionKind::Scalar);
+    assert_eq!(
+        hash.return_type(&[DataType::Utf8, DataType::Int64]),
+        DataType::UInt64
+    );
+}
+
+#[test]
+fn test_list_rules() {
+    let extract = function("list_extract", FunctionKind::Scalar);
+    assert_eq!(
+        extract.return_type(&[list(DataType::Date32), DataType::Int64]),
+        DataType::Date32
+    );
+    assert_eq!(
+        extract.return_type(&[DataType::Utf8, DataType::Int64]),
+        DataType::Utf8
+    );
+
+    let agg = function("list", FunctionKind::Aggregate);
+    assert_eq!(agg.return_type(&[DataType::Int64]), list(DataType::Int64));
+
+    let flatten = function("flatten", FunctionKind::Scalar);
+    assert_eq!(
+        flatten.return_type(&[list(list(DataType::Utf8))]),
+        list(DataType::Utf8)
+    );
+}
+
+#[test]
+fn test_map_rules() {
+    let map = function("map", FunctionKind::Scalar);
+    let built = map.return_type(&[list(DataType::Utf8), list(DataType::Int32)]);
+    assert_eq!(map_entry(&built), Some((DataType::Utf8, DataType::Int32)));
+
+    let keys = function("map_keys", FunctionKind::Scalar);
+    assert_eq!(keys.return_type(&[built.clone()]), list(DataType::Utf8));
+    let value = function("map_extract_value", FunctionKind::Scalar);
+    assert_eq!(value.return_type(&[built, DataType::Utf8]), DataType::Int32);
+}
+
+#[test]
+fn test_unresolvable_calls_are_null() {
+    let json = function("json_transform", FunctionKind::Scalar);
+    assert_eq!(
+        json.return_type(&[DataType::Utf8, DataType::Utf8]),
+        DataType::Null
+    );
+
+    let extract = function("list_extract", FunctionKind::Scalar);
+    assert_eq!(extract.return_type(&[DataType::Int64]), DataType::Null);
+}
+
+#[test]
+fn test_duckdb_type_names() {
+    assert_eq!(duckdb_type_to_arrow("UBIGINT"), DataType::UInt64);
+    assert_eq!(duckdb_type_to_arrow("VARCHAR[]"), list(DataType::Utf8));
+    assert_eq!(
+        duckdb_type_to_arrow("TIMESTAMP WITH TIME ZONE"),
+        DataType::Timestamp(arrow::datatypes::TimeUnit::Microsecond, None)
+    );
+    assert_eq!(duckdb_type_to_arrow("JSON"), DataType::Utf8);
+}
@@ -3,17 +3,24 @@
 //! Registers stub UDFs with correct signatures so that DataFusion's SqlToRel
 //! can plan queries containing DuckDB-specific functions. These stubs are
 //! never executed — they only provide type information for static analysis.
+//!
+//! Every function in the generated [`catalog`](super::catalog) gets a stub.
+//! The hand-written stubs below take precedence over it where the snapshot's
+//! return rules are too coarse (e.g. COALESCE, SUM) or where planning relies
+//! on a fixed signature.
 
 use std::sync::Arc;
 
-use arrow::datatypes::DataType;
+use arrow::datatypes::{DataType, Field, FieldRef};
 use datafusion_common::{DataFusionError, Result as DFResult};
-use datafusion_expr::function::AccumulatorArgs;
+use datafusion_expr::function::{AccumulatorArgs, PartitionEvaluatorArgs, WindowUDFFieldArgs};
 use datafusion_expr::{
-    Accumulator, AggregateUDF, ColumnarValue, ScalarUDF, ScalarUDFImpl, Signature, TypeSignature,
-    Volatility,
+    Accumulator, AggregateUDF, ColumnarValue, PartitionEvaluator, ScalarUDF, ScalarUDFImpl,
+    Signature, TypeSignature, Volatility, WindowUDF, WindowUDFImpl,
 };
 
+use crate::datafusion_bridge::catalog::{CatalogFunction, FunctionKind, DUCKDB_FUNCTIONS};
+
 /// Register all DuckDB scalar UDFs
 pub(crate) fn duckdb_scalar_udfs() -> Vec<Arc<ScalarUDF>> {
     let ts = DataType::Timestamp(arrow::datatypes::TimeUnit::Microsecond, None);
@@ -147,11 +154,6 @@ pub(crate) fn duckdb_scalar_udfs() -> Vec<Arc<ScalarUDF>> {
             vec![DataType::Utf8, DataType::Utf8],
             DataType::Utf8,
         ),
-        make_scalar(
-            "string_split",
-            vec![DataType::Utf8, DataType::Utf8],
-            DataType::Utf8,
-        ),
         make_scalar("lower", vec![DataType::Utf8], DataType::Utf8),
         make_scalar("upper", vec![DataType::Utf8], DataType::Utf8),
         make_scalar("trim", vec![DataType::Utf8], DataType::Utf8),
@@ -220,6 +222,23 @@ pub(crate) fn duckdb_scalar_udfs() -> Vec<Arc<ScalarUDF>> {
             vec![DataType::Utf8, DataType::Utf8, DataType::Utf8],
             DataType::Utf8,
         ),
+        // Current time: stable within a query, different on every run
+        make_nullary("now", ts.clone(), Volatility::Stable),
+        make_nullary("current_timestamp", ts.clone(), Volatility::Stable),
+        make_nullary("get_current_timestamp", ts.clone(), Volatility::Stable),
+        make_nullary("transaction_timestamp", ts.clone(), Volatility::Stable),
+        make_nullary("localtimestamp", ts, Volatility::Stable),
+        make_nullary("current_date", date.clone(), Volatility::Stable),
+        make_nullary("today", date, Volatility::Stable),
+        make_nullary(
+            "current_time",
+            DataType::Time64(arrow::datatypes::TimeUnit::Microsecond),
+            Volatility::Stable,
+        ),
+        // Random values: different on every call
+        make_nullary("random", DataType::Float64, Volatility::Volatile),
+        make_nullary("uuid", DataType::Utf8, Volatility::Volatile),
+        make_nullary("gen_random_uuid", DataType::Utf8, Volatility::Volatile),
     ]
 }
 
@@ -251,6 +270,104 @@ pub(crate) fn duckdb_aggregate_udfs() -> Vec<Arc<AggregateUDF>> {
     ]
 }
 
+/// Register DuckDB window function UDFs
+///
+/// Aggregates used with OVER resolve through the aggregate registry; these
+/// cover the ranking and value window functions.
+pub(crate) fn duckdb_window_udfs() -> Vec<Arc<WindowUDF>> {
+    let mut udfs: Vec<Arc<WindowUDF>> = ["row_number", "rank", "dense_rank", "ntile"]
+        .into_iter()
+        .map(|name| make_window(name, Some(DataType::Int64)))
+        .collect();
+    udfs.extend(
+        ["percent_rank", "cume_dist"]
+            .into_iter()
+            .map(|name| make_window(name, Some(DataType::Float64))),
+    );
+    udfs.extend(
+        ["lag", "lead", "first_value", "last_value", "nth_value"]
+            .into_iter()
+            .map(|name| make_window(name, None)),
+    );
+    udfs
+}
+
+/// Stubs for every scalar function and macro in the DuckDB catalog
+pub(crate) fn catalog_scalar_udfs() -> Vec<Arc<ScalarUDF>> {
+    catalog_functions(&[FunctionKind::Scalar, FunctionKind::Macro])
+        .map(|function| {
+            Arc::new(ScalarUDF::from(CatalogScalarUDF {
+                function,
+                signature: catalog_signature(function),
+            }))
+        })
+        .collect()
+}
+
+/// Stubs for every aggregate function in the DuckDB catalog
+pub(crate) fn catalog_aggregate_udfs() -> Vec<Arc<AggregateUDF>> {
+    catalog_functions(&[FunctionKind::Aggregate])
+        .map(|function| {
+            Arc::new(AggregateUDF::from(CatalogAggregateUDF {
+                function,
+                signature: catalog_signature(function),
+            }))
+        })
+        .collect()
+}
+
+/// Stubs for every window function in the DuckDB catalog
+pub(crate) fn catalog_window_udfs() -> Vec<Arc<WindowUDF>> {
+    catalog_functions(&[FunctionKind::Window])
+        .map(|function| {
+            Arc::new(WindowUDF::from(CatalogWindowUDF {
+                function,
+                signature: catalog_signature(function),
+            }))
+        })
+        .collect()
+}
+
+fn catalog_functions(
+    kinds: &[FunctionKind],
+) -> impl Iterator<Item = &'static CatalogFunction> + '_ {
+    DUCKDB_FUNCTIONS
+        .iter()
+        .filter(move |function| kinds.contains(&function.kind))
+}
+
+/// Accept any arguments; overloads are resolved in `return_type`
+fn catalog_signature(function: &CatalogFunction) -> Signature {
+    if function.is_nullary() {
+        Signature::nullary(function.volatility)
+    } else if function.accepts_no_args() {
+        Signature::one_of(
+            vec![TypeSignature::Nullary, TypeSignature::VariadicAny],
+            function.volatility,
+        )
+    } else {
+        Signature::variadic_any(function.volatility)
+    }
+}
+
+/// Create a zero-argument scalar UDF with the given volatility
+fn make_nullary(name: &str, ret: DataType, volatility: Volatility) -> Arc<ScalarUDF> {
+    Arc::new(ScalarUDF::from(StubScalarUDF {
+        name: name.to_string(),
+        signature: Signature::nullary(volatility),
+        return_type: ret,
+    }))
+}
+
+/// Create a window UDF; `None` returns the type of the first argument
+fn make_window(name: &str, ret: Option<DataType>) -> Arc<WindowUDF> {
+    Arc::new(WindowUDF::from(StubWindowUDF {
+        name: name.to_string(),
+        signature: Signature::variadic_any(Volatility::Immutable),
+        return_type: ret,
+    }))
+}
+
 /// Create a scalar UDF with exact signature
 fn make_scalar(name: &str, args: Vec<DataType>, ret: DataType) -> Arc<ScalarUDF> {
     Arc::new(ScalarUDF::from(StubScalarUDF {
@@ -410,6 +527,120 @@ impl datafusion_expr::AggregateUDFImpl for StubAggregateUDF {
     }
 }
 
+/// A scalar UDF stub backed by a DuckDB catalog entry.
+///
+/// The return type comes from the catalog overload that best matches the
+/// call's argument types.
+#[derive(Debug, Hash, PartialEq, Eq)]
+struct CatalogScalarUDF {
+    function: &'static CatalogFunction,
+    signature: Signature,
+}
+
+impl ScalarUDFImpl for CatalogScalarUDF {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        self.function.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, args: &[DataType]) -> DFResult<DataType> {
+        Ok(self.function.return_type(args))
+    }
+
+    fn invoke_with_args(
+        &self,
+        _args: datafusion_expr::ScalarFunctionArgs,
+    ) -> DFResult<ColumnarValue> {
+        Err(DataFusionError::Internal(format!(
+            "Catalog stub scalar UDF '{}' should not be executed during static analysis",
+            self.function.name
+        )))
+    }
+}
+
+/// An aggregate UDF stub backed by a DuckDB catalog entry.
+#[derive(Debug, Hash, PartialEq, Eq)]
+struct CatalogAggregateUDF {
+    function: &'static CatalogFunction,
+    signature: Signature,
+}
+
+impl datafusion_expr::AggregateUDFImpl for CatalogAggregateUDF {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        self.function.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn return_type(&self, args: &[DataType]) -> DFResult<DataType> {
+        Ok(self.function.return_type(args))
+    }
+
+    fn accumulator(&self, _args: AccumulatorArgs) -> DFResult<Box<dyn Accumulator>> {
+        Err(DataFusionError::Internal(format!(
+            "Catalog stub aggregate UDF '{}' should not be executed during static analysis",
+            self.function.name
+        )))
+    }
+}
+
+/// A window UDF stub backed by a DuckDB catalog entry.
+#[derive(Debug, Hash, PartialEq, Eq)]
+struct CatalogWindowUDF {
+    function: &'static CatalogFunction,
+    signature: Signature,
+}
+
+impl WindowUDFImpl for CatalogWindowUDF {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        self.function.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn partition_evaluator(
+        &self,
+        _args: PartitionEvaluatorArgs,
+    ) -> DFResult<Box<dyn PartitionEvaluator>> {
+        Err(DataFusionError::Internal(format!(
+            "Catalog stub window UDF '{}' should not be executed during static analysis",
+            self.function.name
+        )))
+    }
+
+    fn field(&self, field_args: WindowUDFFieldArgs) -> DFResult<FieldRef> {
+        let args: Vec<DataType> = field_args
+            .input_fields()
+            .iter()
+            .map(|f| f.data_type().clone())
+            .collect();
+        Ok(Arc::new(Field::new(
+            field_args.name(),
+            self.function.return_type(&args),
+            true,
+        )))
+    }
+}
+
 /// A scalar UDF that returns the type of its first non-Null argument.
 ///
 /// Used for COALESCE, IFNULL, NULLIF where the output type should match the input.
@@ -484,3 +715,50 @@ impl datafusion_expr::AggregateUDFImpl for TypePreservingAggregateUDF {
         )))
     }
 }
+
+/// A no-op window UDF stub that provides type information for DataFusion planning.
+///
+/// These stubs are never executed; they exist solely so that DataFusion's
+/// `SqlToRel` planner can resolve window functions such as `row_number()`.
+#[derive(Debug, Hash, PartialEq, Eq)]
+struct StubWindowUDF {
+    name: String,
+    signature: Signature,
+    return_type: Option<DataType>,
+}
+
+impl WindowUDFImpl for StubWindowUDF {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    fn partition_evaluator(
+        &self,
+        _args: PartitionEvaluatorArgs,
+    ) -> DFResult<Box<dyn PartitionEvaluator>> {
+        Err(DataFusionError::Internal(format!(
+            "Stub window UDF '{}' should not be executed during static analysis",
+            self.name
+        )))
+    }
+
+    fn field(&self, field_args: WindowUDFFieldArgs) -> DFResult<FieldRef> {
+        let data_type = match &self.return_type {
+            Some(dt) => dt.clone(),
+            None => field_args
+                .input_fields()
+                .first()
+                .map(|f| f.data_type().clone())
+                .unwrap_or(DataType::Utf8),
+        };
+        Ok(Arc::new(Field::new(field_args.name(), data_type, true)))
+    }
+}
@@ -1,5 +1,6 @@
 //! Bridge between Feather-Flow's type system and DataFusion/Arrow types
 
+pub(crate) mod catalog;
 pub(crate) mod functions;
 pub(crate) mod lineage;
 pub(crate) mod planner;
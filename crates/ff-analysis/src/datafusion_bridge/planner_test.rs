@@ -2,6 +2,7 @@ use super::*;
 use crate::datafusion_bridge::provider::FunctionRegistry;
 use crate::schema::{RelSchema, SchemaCatalog};
 use crate::types::{IntBitWidth, Nullability, SqlType, TypedColumn};
+use arrow::datatypes::DataType;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -161,6 +162,25 @@ fn test_order_by_limit() {
     assert_eq!(schema.fields().len(), 2);
 }
 
+#[test]
+fn test_window_function_plan() {
+    let plan = plan_sql(
+        "SELECT id, row_number() OVER (PARTITION BY customer_id ORDER BY id) AS rn, \
+         lag(amount) OVER (ORDER BY id) AS prev_amount FROM orders",
+    )
+    .unwrap();
+    let schema = plan.schema();
+    assert_eq!(schema.fields().len(), 3);
+    assert_eq!(schema.fields()[1].data_type(), &DataType::Int64);
+    assert_eq!(schema.fields()[2].name(), "prev_amount");
+}
+
+#[test]
+fn test_current_time_functions_plan() {
+    let plan = plan_sql("SELECT id, now() AS loaded_at, random() AS r FROM orders").unwrap();
+    assert_eq!(plan.schema().fields().len(), 3);
+}
+
 #[test]
 fn test_case_expression_plan() {
     let plan =
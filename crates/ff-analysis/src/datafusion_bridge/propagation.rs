@@ -3,10 +3,15 @@
 //! Walks models in topological order, plans each one via DataFusion,
 //! extracts inferred output schemas, cross-checks against YAML declarations,
 //! and feeds schemas forward for downstream models.
+//!
+//! Models DataFusion cannot plan (DuckDB-only syntax such as PIVOT or
+//! `SELECT * EXCLUDE`) can fall back to a [`SchemaFallback`], which asks the
+//! execution engine for the output schema so downstream models still plan.
 
 use std::collections::HashMap;
 use std::sync::Arc;
 
+use async_trait::async_trait;
 use datafusion_expr::LogicalPlan;
 use ff_core::ModelName;
 
@@ -23,6 +28,8 @@ use crate::types::{Nullability, TypedColumn};
 /// Contains the logical plan, the inferred output schema, and any
 /// mismatches detected when cross-checking against the YAML declaration.
 pub struct ModelPlanResult {
+    /// The rendered SQL the plan was built from
+    pub sql: String,
     /// The DataFusion LogicalPlan for this model
     pub plan: LogicalPlan,
     /// The inferred output schema (from the plan), shared with the catalog
@@ -66,6 +73,26 @@ impl SchemaMismatch {
         !self.is_error()
     }
 
+    /// The column the mismatch is about.
+    pub fn column(&self) -> &str {
+        match self {
+            SchemaMismatch::ExtraInSql { column }
+            | SchemaMismatch::MissingFromSql { column }
+            | SchemaMismatch::TypeMismatch { column, .. }
+            | SchemaMismatch::NullabilityMismatch { column, .. } => column,
+        }
+    }
+
+    /// Stable name of the mismatch kind, independent of the column and types
+    pub fn kind(&self) -> &'static str {
+        match self {
+            SchemaMismatch::ExtraInSql { .. } => "extra_in_sql",
+            SchemaMismatch::MissingFromSql { .. } => "missing_from_sql",
+            SchemaMismatch::TypeMismatch { .. } => "type_mismatch",
+            SchemaMismatch::NullabilityMismatch { .. } => "nullability_mismatch",
+        }
+    }
+
     /// Diagnostic code string: `"SA01"` for errors, `"SA02"` for warnings.
     pub fn code(&self) -> &'static str {
         if self.is_error() {
@@ -115,6 +142,37 @@ impl std::fmt::Display for SchemaMismatch {
     }
 }
 
+/// Result for a model DataFusion could not plan, whose output schema was
+/// inferred by the execution engine instead.
+///
+/// There is no LogicalPlan, so plan-based passes skip these models; the
+/// schema is still cross-checked against YAML and fed to downstream models.
+pub struct EngineInferredResult {
+    /// The rendered SQL the engine described
+    pub sql: String,
+    /// The DataFusion planning error that triggered the fallback
+    pub planning_error: String,
+    /// The output schema reported by the engine, shared with the catalog
+    pub inferred_schema: Arc<RelSchema>,
+    /// Schema mismatches between YAML declaration and the engine's schema
+    pub mismatches: Vec<SchemaMismatch>,
+}
+
+/// Engine-backed schema inference for models DataFusion cannot plan.
+///
+/// Propagation calls [`register`](Self::register) for every model it infers,
+/// in topological order, so the engine can resolve references to them, and
+/// [`describe`](Self::describe) only when planning fails. Both are async so
+/// implementations can query the engine without blocking the runtime.
+#[async_trait]
+pub trait SchemaFallback: Send {
+    /// Record an inferred model schema for later `describe` calls
+    async fn register(&mut self, model: &ModelName, schema: &RelSchema);
+
+    /// Describe a model's output schema with the engine, or return an error message
+    async fn describe(&mut self, model: &ModelName, sql: &str) -> Result<RelSchema, String>;
+}
+
 /// Propagation results for the entire DAG.
 ///
 /// Aggregates per-model planning outcomes, the final schema catalog with all
@@ -122,12 +180,28 @@ impl std::fmt::Display for SchemaMismatch {
 pub struct PropagationResult {
     /// Per-model planning results (model name → result)
     pub model_plans: HashMap<ModelName, ModelPlanResult>,
+    /// Models whose schema came from the [`SchemaFallback`] (model name → result)
+    pub engine_inferred: HashMap<ModelName, EngineInferredResult>,
     /// Final schema catalog after propagation
     pub final_catalog: SchemaCatalog,
     /// Models that failed to plan (model name → error message)
     pub failures: HashMap<ModelName, String>,
 }
 
+impl PropagationResult {
+    /// Schema mismatches per analyzed model, planned or engine-inferred
+    pub fn all_mismatches(&self) -> impl Iterator<Item = (&ModelName, &[SchemaMismatch])> {
+        self.model_plans
+            .iter()
+            .map(|(name, r)| (name, r.mismatches.as_slice()))
+            .chain(
+                self.engine_inferred
+                    .iter()
+                    .map(|(name, r)| (name, r.mismatches.as_slice())),
+            )
+    }
+}
+
 /// Propagate schemas through the DAG in topological order
 ///
 /// For each model, renders its SQL, plans it via DataFusion, extracts the
@@ -141,57 +215,184 @@ pub fn propagate_schemas(
     user_functions: &[UserFunctionStub],
     user_table_functions: &[UserTableFunctionStub],
 ) -> PropagationResult {
-    let mut catalog = initial_catalog;
-    let mut model_plans: HashMap<ModelName, ModelPlanResult> =
-        HashMap::with_capacity(topo_order.len());
-    let mut failures: HashMap<ModelName, String> = HashMap::new();
-
     let registry = FunctionRegistry::with_user_functions(user_functions, user_table_functions);
-    let mut provider = FeatherFlowProvider::new(&catalog, &registry);
+    let mut state = PropagationState::new(initial_catalog, yaml_schemas, &registry);
 
     for model_name in topo_order {
-        let sql = match sql_sources.get(model_name) {
-            Some(s) => s,
-            None => {
-                failures.insert(model_name.clone(), "No rendered SQL available".to_string());
-                continue;
-            }
+        let Some(sql) = state.source(model_name, sql_sources) else {
+            continue;
         };
+        if let Err(planning_error) = state.plan(model_name, sql) {
+            state.failures.insert(model_name.clone(), planning_error);
+        }
+    }
+
+    state.finish()
+}
+
+/// Like [`propagate_schemas`], but models DataFusion cannot plan are
+/// described by `fallback` and recorded in
+/// [`PropagationResult::engine_inferred`] instead of failing.
+pub async fn propagate_schemas_with_fallback(
+    topo_order: &[ModelName],
+    sql_sources: &HashMap<ModelName, String>,
+    yaml_schemas: &HashMap<ModelName, Arc<RelSchema>>,
+    initial_catalog: SchemaCatalog,
+    user_functions: &[UserFunctionStub],
+    user_table_functions: &[UserTableFunctionStub],
+    fallback: &mut dyn SchemaFallback,
+) -> PropagationResult {
+    let registry = FunctionRegistry::with_user_functions(user_functions, user_table_functions);
+    let mut state = PropagationState::new(initial_catalog, yaml_schemas, &registry);
 
-        let plan = match sql_to_plan(sql, &provider) {
-            Ok(p) => p,
-            Err(e) => {
-                failures.insert(model_name.clone(), format!("model '{}': {}", model_name, e));
+    for model_name in topo_order {
+        let Some(sql) = state.source(model_name, sql_sources) else {
+            continue;
+        };
+        let planning_error = match state.plan(model_name, sql) {
+            Ok(schema) => {
+                fallback.register(model_name, &schema).await;
                 continue;
             }
+            Err(e) => e,
         };
+        match fallback.describe(model_name, sql).await {
+            Ok(described) => {
+                state.record_engine_inferred(model_name, sql, planning_error, described)
+            }
+            Err(engine_error) => {
+                state.failures.insert(
+                    model_name.clone(),
+                    format!("{planning_error} (engine fallback: {engine_error})"),
+                );
+            }
+        }
+    }
 
-        let inferred_schema = Arc::new(extract_schema_from_plan(&plan));
+    state.finish()
+}
 
-        let mismatches = if let Some(yaml_schema) = yaml_schemas.get(model_name.as_str()) {
-            compare_schemas(yaml_schema, &inferred_schema)
-        } else {
-            vec![]
-        };
+/// Catalog and results threaded through one propagation walk.
+struct PropagationState<'a> {
+    catalog: SchemaCatalog,
+    provider: FeatherFlowProvider<'a>,
+    yaml_schemas: &'a HashMap<ModelName, Arc<RelSchema>>,
+    model_plans: HashMap<ModelName, ModelPlanResult>,
+    engine_inferred: HashMap<ModelName, EngineInferredResult>,
+    failures: HashMap<ModelName, String>,
+}
+
+impl<'a> PropagationState<'a> {
+    fn new(
+        catalog: SchemaCatalog,
+        yaml_schemas: &'a HashMap<ModelName, Arc<RelSchema>>,
+        registry: &'a FunctionRegistry,
+    ) -> Self {
+        let provider = FeatherFlowProvider::new(&catalog, registry);
+        Self {
+            catalog,
+            provider,
+            yaml_schemas,
+            model_plans: HashMap::new(),
+            engine_inferred: HashMap::new(),
+            failures: HashMap::new(),
+        }
+    }
+
+    /// The model's rendered SQL, recording a failure when there is none
+    fn source<'s>(
+        &mut self,
+        model_name: &ModelName,
+        sql_sources: &'s HashMap<ModelName, String>,
+    ) -> Option<&'s String> {
+        let sql = sql_sources.get(model_name);
+        if sql.is_none() {
+            self.failures
+                .insert(model_name.clone(), "No rendered SQL available".to_string());
+        }
+        sql
+    }
 
-        catalog.insert(model_name.to_string(), Arc::clone(&inferred_schema));
-        provider.insert_schema(model_name.to_string(), &inferred_schema);
+    /// Plan a model and register its schema, or return the planning error
+    fn plan(&mut self, model_name: &ModelName, sql: &str) -> Result<Arc<RelSchema>, String> {
+        let plan = sql_to_plan(sql, &self.provider)
+            .map_err(|e| format!("model '{}': {}", model_name, e))?;
+        let inferred_schema = Arc::new(extract_schema_from_plan(&plan));
+        let mismatches = self.compare(model_name, &inferred_schema);
+        self.register(model_name, &inferred_schema);
 
-        model_plans.insert(
+        self.model_plans.insert(
             model_name.clone(),
             ModelPlanResult {
+                sql: sql.to_string(),
                 plan,
+                inferred_schema: Arc::clone(&inferred_schema),
+                mismatches,
+            },
+        );
+        Ok(inferred_schema)
+    }
+
+    /// Register a schema the fallback described for a model that did not plan
+    fn record_engine_inferred(
+        &mut self,
+        model_name: &ModelName,
+        sql: &str,
+        planning_error: String,
+        described: RelSchema,
+    ) {
+        let yaml_schema = self.yaml_schemas.get(model_name);
+        let inferred_schema = Arc::new(adopt_declared_nullability(described, yaml_schema));
+        let mismatches = self.compare(model_name, &inferred_schema);
+        self.register(model_name, &inferred_schema);
+
+        self.engine_inferred.insert(
+            model_name.clone(),
+            EngineInferredResult {
+                sql: sql.to_string(),
+                planning_error,
                 inferred_schema,
                 mismatches,
             },
         );
     }
 
-    PropagationResult {
-        model_plans,
-        final_catalog: catalog,
-        failures,
+    fn compare(&self, model_name: &ModelName, inferred: &RelSchema) -> Vec<SchemaMismatch> {
+        self.yaml_schemas
+            .get(model_name)
+            .map(|yaml| compare_schemas(yaml, inferred))
+            .unwrap_or_default()
+    }
+
+    fn register(&mut self, model_name: &ModelName, schema: &Arc<RelSchema>) {
+        self.catalog
+            .insert(model_name.to_string(), Arc::clone(schema));
+        self.provider.insert_schema(model_name.to_string(), schema);
+    }
+
+    fn finish(self) -> PropagationResult {
+        PropagationResult {
+            model_plans: self.model_plans,
+            engine_inferred: self.engine_inferred,
+            final_catalog: self.catalog,
+            failures: self.failures,
+        }
+    }
+}
+
+/// Engines rarely track nullability through a query, so columns the engine
+/// reports as nullable take their nullability from the YAML declaration.
+fn adopt_declared_nullability(mut schema: RelSchema, yaml: Option<&Arc<RelSchema>>) -> RelSchema {
+    for col in &mut schema.columns {
+        if col.nullability == Nullability::NotNull {
+            continue;
+        }
+        col.nullability = yaml
+            .and_then(|y| y.find_column(&col.name))
+            .map(|declared| declared.nullability)
+            .unwrap_or(Nullability::Unknown);
     }
+    schema
 }
 
 /// Extract a RelSchema from a DataFusion LogicalPlan's output schema
@@ -592,3 +592,103 @@ fn test_coalesce_type_preservation() {
         val_col.sql_type.display_name()
     );
 }
+
+/// Fallback that answers every describe with a fixed schema
+struct FixedFallback {
+    registered: Vec<String>,
+    described: Vec<String>,
+}
+
+#[async_trait::async_trait]
+impl SchemaFallback for FixedFallback {
+    async fn register(&mut self, model: &ModelName, _schema: &RelSchema) {
+        self.registered.push(model.to_string());
+    }
+
+    async fn describe(&mut self, model: &ModelName, _sql: &str) -> Result<RelSchema, String> {
+        self.described.push(model.to_string());
+        Ok(RelSchema::new(vec![
+            make_col("id", int32(), Nullability::Nullable),
+            make_col("completed", int64(), Nullability::Nullable),
+        ]))
+    }
+}
+
+#[tokio::test]
+async fn test_engine_fallback_for_unplannable_model() {
+    let mut initial_catalog: SchemaCatalog = HashMap::new();
+    initial_catalog.insert(
+        "raw_orders".to_string(),
+        Arc::new(RelSchema::new(vec![
+            make_col("id", int32(), Nullability::NotNull),
+            make_col("amount", int32(), Nullability::Nullable),
+            make_col("status", varchar(), Nullability::Nullable),
+        ])),
+    );
+    let topo_order = mn(vec![
+        "stg_orders".to_string(),
+        "pivoted".to_string(),
+        "mart".to_string(),
+    ]);
+    let mut sql_sources = HashMap::new();
+    sql_sources.insert(
+        "stg_orders".to_string(),
+        "SELECT id, amount, status FROM raw_orders".to_string(),
+    );
+    sql_sources.insert(
+        "pivoted".to_string(),
+        "PIVOT stg_orders ON status USING sum(amount) GROUP BY id".to_string(),
+    );
+    sql_sources.insert(
+        "mart".to_string(),
+        "SELECT id, completed * 2 AS doubled FROM pivoted".to_string(),
+    );
+    let mut yaml = HashMap::new();
+    yaml.insert(
+        "pivoted".to_string(),
+        Arc::new(RelSchema::new(vec![
+            make_col("id", int32(), Nullability::NotNull),
+            make_col("completed", int64(), Nullability::Unknown),
+        ])),
+    );
+    let sql_sources = ms(sql_sources);
+    let yaml = my(yaml);
+
+    let without = propagate_schemas(
+        &topo_order,
+        &sql_sources,
+        &yaml,
+        initial_catalog.clone(),
+        &[],
+        &[],
+    );
+    assert!(without.failures.contains_key("pivoted"));
+    assert!(without.failures.contains_key("mart"));
+
+    let mut fallback = FixedFallback {
+        registered: vec![],
+        described: vec![],
+    };
+    let result = propagate_schemas_with_fallback(
+        &topo_order,
+        &sql_sources,
+        &yaml,
+        initial_catalog,
+        &[],
+        &[],
+        &mut fallback,
+    )
+    .await;
+    assert!(result.failures.is_empty(), "{:?}", result.failures);
+    assert_eq!(fallback.described, vec!["pivoted"]);
+    assert_eq!(fallback.registered, vec!["stg_orders", "mart"]);
+
+    let pivoted = &result.engine_inferred["pivoted"];
+    assert!(pivoted.mismatches.is_empty());
+    assert_eq!(
+        pivoted.inferred_schema.columns[0].nullability,
+        Nullability::NotNull
+    );
+    assert!(result.model_plans.contains_key("mart"));
+    assert_eq!(result.all_mismatches().count(), 3);
+}
@@ -3,7 +3,7 @@
 //! Implements the `ContextProvider` trait so that `SqlToRel` can resolve
 //! table names, UDF signatures, and aggregate functions during planning.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use arrow::datatypes::{Field, Schema, SchemaRef};
@@ -14,8 +14,8 @@ use datafusion_expr::{AggregateUDF, Expr, ScalarUDF, TableSource, WindowUDF};
 use datafusion_sql::planner::ContextProvider;
 use datafusion_sql::TableReference;
 
-use crate::datafusion_bridge::functions;
 use crate::datafusion_bridge::types::sql_type_to_arrow;
+use crate::datafusion_bridge::{catalog, functions};
 use crate::schema::{RelSchema, SchemaCatalog};
 use crate::types::Nullability;
 
@@ -102,12 +102,14 @@ impl UserTableFunctionStub {
 
 /// Pre-built function registries for reuse across multiple provider instances.
 ///
-/// Building DuckDB scalar/aggregate stubs is expensive (47+ entries). This struct
+/// Building DuckDB scalar/aggregate stubs is expensive (hundreds of catalog
+/// entries on top of the hand-written ones). This struct
 /// captures the result so that [`FeatherFlowProvider`] instances sharing the same
 /// function set can borrow it instead of rebuilding on every construction.
 pub struct FunctionRegistry {
     scalar_functions: HashMap<String, Arc<ScalarUDF>>,
     aggregate_functions: HashMap<String, Arc<AggregateUDF>>,
+    window_functions: HashMap<String, Arc<WindowUDF>>,
     table_functions: HashMap<String, Arc<dyn TableSource>>,
 }
 
@@ -126,11 +128,43 @@ impl FunctionRegistry {
             .into_iter()
             .map(|f| (f.name().to_uppercase(), f))
             .collect();
-        let aggregate_functions: HashMap<String, Arc<AggregateUDF>> =
+        let mut aggregate_functions: HashMap<String, Arc<AggregateUDF>> =
             functions::duckdb_aggregate_udfs()
                 .into_iter()
                 .map(|f| (f.name().to_uppercase(), f))
                 .collect();
+        let mut window_functions: HashMap<String, Arc<WindowUDF>> = functions::duckdb_window_udfs()
+            .into_iter()
+            .map(|f| (f.name().to_uppercase(), f))
+            .collect();
+
+        // Catalog stubs fill in everything the hand-written stubs don't cover
+        log::debug!(
+            "Registering DuckDB {} function catalog",
+            catalog::DUCKDB_VERSION
+        );
+        let hand_written: HashSet<String> = scalar_functions
+            .keys()
+            .chain(aggregate_functions.keys())
+            .chain(window_functions.keys())
+            .cloned()
+            .collect();
+        let uncovered = |name: &str| !hand_written.contains(&name.to_uppercase());
+        for f in functions::catalog_scalar_udfs() {
+            if uncovered(f.name()) {
+                scalar_functions.insert(f.name().to_uppercase(), f);
+            }
+        }
+        for f in functions::catalog_aggregate_udfs() {
+            if uncovered(f.name()) {
+                aggregate_functions.insert(f.name().to_uppercase(), f);
+            }
+        }
+        for f in functions::catalog_window_udfs() {
+            if uncovered(f.name()) {
+                window_functions.insert(f.name().to_uppercase(), f);
+            }
+        }
 
         for uf in user_functions {
             let udf = functions::make_user_scalar_udf(uf.name(), uf.arg_types(), uf.return_type());
@@ -147,6 +181,7 @@ impl FunctionRegistry {
         Self {
             scalar_functions,
             aggregate_functions,
+            window_functions,
             table_functions,
         }
     }
@@ -201,10 +236,14 @@ impl<'a> FeatherFlowProvider<'a> {
             .iter()
             .map(|(k, v)| (k.to_lowercase(), Arc::clone(v)))
             .collect();
+        // Record source spans on column references so passes can point
+        // diagnostics at the SQL that produced them.
+        let mut config = ConfigOptions::default();
+        config.sql_parser.collect_spans = true;
         Self {
             arrow_schemas,
             lowercase_schemas,
-            config: ConfigOptions::default(),
+            config,
             registry,
         }
     }
@@ -290,8 +329,11 @@ impl ContextProvider for FeatherFlowProvider<'_> {
             .cloned()
     }
 
-    fn get_window_meta(&self, _name: &str) -> Option<Arc<WindowUDF>> {
-        None
+    fn get_window_meta(&self, name: &str) -> Option<Arc<WindowUDF>> {
+        self.registry
+            .window_functions
+            .get(&name.to_uppercase())
+            .cloned()
     }
 
     fn get_variable_type(&self, _variable_names: &[String]) -> Option<arrow::datatypes::DataType> {
@@ -311,7 +353,7 @@ impl ContextProvider for FeatherFlowProvider<'_> {
     }
 
     fn udwf_names(&self) -> Vec<String> {
-        vec![]
+        self.registry.window_functions.keys().cloned().collect()
     }
 
     fn get_expr_planners(&self) -> &[Arc<dyn ExprPlanner>] {
@@ -16,10 +16,15 @@ pub mod test_utils;
 
 pub use context::AnalysisContext;
 pub use error::{AnalysisError, AnalysisResult};
+pub use pass::baseline::{
+    Baseline, BaselineComparison, BaselineEntry, Baselined, BASELINE_VERSION,
+};
+pub use pass::engine_verify::{engine_describe_failure, verify_engine_schema, ENGINE_VERIFY_PASS};
+pub use pass::location::column_span;
 pub use pass::plan_pass::{DagPlanPass, PlanPass, PlanPassManager};
 pub use pass::{
     apply_severity_overrides, Diagnostic, DiagnosticCode, OverriddenSeverity, Severity,
-    SeverityOverrides,
+    SeverityOverrides, SourceSpan,
 };
 pub use schema::{RelSchema, SchemaCatalog};
 pub use types::{parse_sql_type, FloatBitWidth, IntBitWidth, Nullability, SqlType, TypedColumn};
@@ -30,7 +35,8 @@ pub use datafusion_bridge::lineage::{
 };
 pub use datafusion_bridge::planner::sql_to_plan;
 pub use datafusion_bridge::propagation::{
-    propagate_schemas, ModelPlanResult, PropagationResult, SchemaMismatch,
+    propagate_schemas, propagate_schemas_with_fallback, EngineInferredResult, ModelPlanResult,
+    PropagationResult, SchemaFallback, SchemaMismatch,
 };
 pub use datafusion_bridge::provider::{
     build_user_function_stubs, FeatherFlowProvider, FunctionRegistry, UserFunctionStub,
@@ -0,0 +1,249 @@
+//! Diagnostics baseline
+//!
+//! A baseline records the diagnostics a project already has so that later
+//! runs fail only on new ones. Entries are matched by a fingerprint of the
+//! code, model, and location: the column plus the start of the source span
+//! for diagnostics, or the kind of mismatch for schema mismatches. Message
+//! text is left out because some messages embed engine error text that
+//! changes between versions. Identical diagnostics are matched as a
+//! multiset: two recorded findings absorb at most two current ones.
+//!
+//! Both analysis diagnostics and schema mismatches (SA01/SA02) can be
+//! recorded, so `ff dt analyze` and the `ff dt compile`/`ff dt run` schema
+//! checks share one baseline file.
+
+use std::collections::{HashMap, HashSet};
+
+use ff_core::ModelName;
+use serde::{Deserialize, Serialize};
+
+use super::{Diagnostic, DiagnosticCode, Severity};
+use crate::datafusion_bridge::propagation::SchemaMismatch;
+
+/// Baseline file format version written by [`Baseline::record`]
+pub const BASELINE_VERSION: u32 = 1;
+
+/// Diagnostics accepted as known, usually committed next to the project.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    /// File format version
+    pub version: u32,
+    /// Recorded diagnostics, sorted by model, code, and column
+    pub entries: Vec<BaselineEntry>,
+}
+
+/// One recorded diagnostic
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    /// Diagnostic code (e.g. A020, SA02)
+    pub code: String,
+    /// Model the diagnostic was reported on
+    pub model: ModelName,
+    /// Column the diagnostic was reported on, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub column: Option<String>,
+    /// Stable hash identifying the diagnostic
+    pub fingerprint: String,
+}
+
+impl BaselineEntry {
+    fn new(code: String, model: &ModelName, column: Option<&str>, location: &str) -> Self {
+        let fingerprint = fingerprint(&code, model, column, location);
+        Self {
+            code,
+            model: model.clone(),
+            column: column.map(String::from),
+            fingerprint,
+        }
+    }
+
+    fn is_schema_mismatch(&self) -> bool {
+        self.code.starts_with("SA")
+    }
+}
+
+/// A finding that can be recorded in a [`Baseline`].
+pub trait Baselined {
+    /// Whether this is a schema mismatch rather than an analysis diagnostic
+    const SCHEMA_MISMATCH: bool;
+
+    /// The entry recording this finding
+    fn baseline_entry(&self) -> BaselineEntry;
+}
+
+impl Baselined for Diagnostic {
+    const SCHEMA_MISMATCH: bool = false;
+
+    fn baseline_entry(&self) -> BaselineEntry {
+        BaselineEntry::new(
+            self.code.to_string(),
+            &self.model,
+            self.column.as_deref(),
+            // Only the start, so reformatting the flagged expression keeps it
+            &self
+                .span
+                .map_or_else(String::new, |s| format!("{}:{}", s.line, s.column)),
+        )
+    }
+}
+
+/// A schema mismatch and the model it was found in
+impl Baselined for (&ModelName, &SchemaMismatch) {
+    const SCHEMA_MISMATCH: bool = true;
+
+    fn baseline_entry(&self) -> BaselineEntry {
+        let (model, mismatch) = self;
+        BaselineEntry::new(
+            mismatch.code().to_string(),
+            model,
+            Some(mismatch.column()),
+            mismatch.kind(),
+        )
+    }
+}
+
+/// Current findings split against a baseline
+#[derive(Debug, Clone)]
+pub struct BaselineComparison<T = Diagnostic> {
+    /// Findings not covered by the baseline
+    pub new: Vec<T>,
+    /// Number of findings the baseline covered
+    pub baselined: usize,
+    /// Entries of the same kind, for analyzed models, that no longer match
+    /// any finding
+    pub fixed: Vec<BaselineEntry>,
+}
+
+impl Baseline {
+    /// Record `diagnostics` and schema `mismatches` as the new baseline.
+    ///
+    /// Entries of `previous` for models outside `analyzed` are kept, so
+    /// recording a baseline for a node selection does not drop the rest.
+    pub fn record(
+        diagnostics: &[Diagnostic],
+        mismatches: &[(&ModelName, &SchemaMismatch)],
+        previous: Option<&Baseline>,
+        analyzed: &[ModelName],
+    ) -> Self {
+        let analyzed: HashSet<&ModelName> = analyzed.iter().collect();
+        let kept = previous
+            .into_iter()
+            .flat_map(|b| &b.entries)
+            .filter(|e| !analyzed.contains(&e.model))
+            .cloned();
+        let mut entries: Vec<BaselineEntry> = diagnostics
+            .iter()
+            .filter(|d| d.code != DiagnosticCode::A062)
+            .map(Baselined::baseline_entry)
+            .chain(mismatches.iter().map(Baselined::baseline_entry))
+            .chain(kept)
+            .collect();
+        entries.sort_by(|a, b| {
+            (&a.model, &a.code, &a.column, &a.fingerprint).cmp(&(
+                &b.model,
+                &b.code,
+                &b.column,
+                &b.fingerprint,
+            ))
+        });
+        Self {
+            version: BASELINE_VERSION,
+            entries,
+        }
+    }
+
+    /// Split `findings` into those the baseline covers and new ones.
+    ///
+    /// Entries of the same kind (diagnostics or schema mismatches) for models
+    /// in `analyzed` that matched nothing are returned as fixed. Pass
+    /// `check_fixed = false` when only some passes ran, since the missing
+    /// diagnostics may simply not have been looked for.
+    pub fn compare<T: Baselined>(
+        &self,
+        findings: Vec<T>,
+        analyzed: &[ModelName],
+        check_fixed: bool,
+    ) -> BaselineComparison<T> {
+        let mut remaining: HashMap<&str, usize> = HashMap::new();
+        for entry in &self.entries {
+            *remaining.entry(entry.fingerprint.as_str()).or_default() += 1;
+        }
+
+        let mut new = Vec::new();
+        let mut baselined = 0;
+        for finding in findings {
+            let key = finding.baseline_entry().fingerprint;
+            match remaining.get_mut(key.as_str()) {
+                Some(count) if *count > 0 => {
+                    *count -= 1;
+                    baselined += 1;
+                }
+                _ => new.push(finding),
+            }
+        }
+
+        let analyzed: HashSet<&ModelName> = analyzed.iter().collect();
+        let mut fixed = Vec::new();
+        if check_fixed {
+            for entry in &self.entries {
+                if entry.is_schema_mismatch() != T::SCHEMA_MISMATCH {
+                    continue;
+                }
+                let Some(count) = remaining.get_mut(entry.fingerprint.as_str()) else {
+                    continue;
+                };
+                if *count > 0 && analyzed.contains(&entry.model) {
+                    *count -= 1;
+                    fixed.push(entry.clone());
+                }
+            }
+        }
+
+        BaselineComparison {
+            new,
+            baselined,
+            fixed,
+        }
+    }
+}
+
+impl<T> BaselineComparison<T> {
+    /// Report each fixed entry as an A062 diagnostic so it can be pruned.
+    pub fn fixed_diagnostics(&self) -> Vec<Diagnostic> {
+        self.fixed
+            .iter()
+            .map(|entry| Diagnostic {
+                code: DiagnosticCode::A062,
+                severity: Severity::Info,
+                message: match &entry.column {
+                    Some(column) => format!(
+                        "Baselined {} on column '{}' is no longer reported",
+                        entry.code, column
+                    ),
+                    None => format!("Baselined {} is no longer reported", entry.code),
+                },
+                model: entry.model.clone(),
+                column: entry.column.clone(),
+                hint: Some("Run `ff dt analyze --write-baseline` to prune the entry".to_string()),
+                pass_name: "baseline".into(),
+                span: None,
+            })
+            .collect()
+    }
+}
+
+/// Hash of the parts of a finding that identify it across runs
+fn fingerprint(code: &str, model: &str, column: Option<&str>, location: &str) -> String {
+    let key = format!(
+        "{}\0{}\0{}\0{}",
+        code,
+        model,
+        column.unwrap_or(""),
+        location
+    );
+    ff_core::compute_checksum(&key)[..16].to_string()
+}
+
+#[cfg(test)]
+#[path = "baseline_test.rs"]
+mod tests;
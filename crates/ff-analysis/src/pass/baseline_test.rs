@@ -0,0 +1,228 @@
+use super::*;
+use crate::SourceSpan;
+
+fn diag(code: DiagnosticCode, model: &str, column: Option<&str>, message: &str) -> Diagnostic {
+    Diagnostic {
+        code,
+        severity: Severity::Warning,
+        message: message.to_string(),
+        model: ModelName::new(model),
+        column: column.map(str::to_string),
+        hint: None,
+        pass_name: "test_pass".into(),
+        span: None,
+    }
+}
+
+fn models(names: &[&str]) -> Vec<ModelName> {
+    names.iter().map(|n| ModelName::new(*n)).collect()
+}
+
+#[test]
+fn test_recorded_diagnostics_are_baselined() {
+    let diagnostics = vec![
+        diag(DiagnosticCode::A020, "orders", Some("legacy_id"), "unused"),
+        diag(DiagnosticCode::A032, "customers", None, "cross join"),
+    ];
+    let baseline = Baseline::record(&diagnostics, &[], None, &models(&["orders", "customers"]));
+    assert_eq!(baseline.version, BASELINE_VERSION);
+    let recorded: Vec<&str> = baseline.entries.iter().map(|e| e.model.as_str()).collect();
+    assert_eq!(recorded, vec!["customers", "orders"]);
+
+    let comparison = baseline.compare(diagnostics, &models(&["orders", "customers"]), true);
+    assert!(comparison.new.is_empty());
+    assert_eq!(comparison.baselined, 2);
+    assert!(comparison.fixed.is_empty());
+}
+
+#[test]
+fn test_new_diagnostic_is_reported() {
+    let old = vec![diag(DiagnosticCode::A020, "orders", Some("a"), "unused")];
+    let baseline = Baseline::record(&old, &[], None, &models(&["orders"]));
+
+    let current = vec![
+        diag(DiagnosticCode::A020, "orders", Some("a"), "unused"),
+        diag(DiagnosticCode::A020, "orders", Some("b"), "unused"),
+    ];
+    let comparison = baseline.compare(current, &models(&["orders"]), true);
+    assert_eq!(comparison.new.len(), 1);
+    assert_eq!(comparison.new[0].column.as_deref(), Some("b"));
+    assert_eq!(comparison.baselined, 1);
+}
+
+#[test]
+fn test_duplicates_match_as_multiset() {
+    let old = vec![diag(DiagnosticCode::A033, "orders", None, "non-equi join")];
+    let baseline = Baseline::record(&old, &[], None, &models(&["orders"]));
+
+    let current = vec![
+        diag(DiagnosticCode::A033, "orders", None, "non-equi join"),
+        diag(DiagnosticCode::A033, "orders", None, "non-equi join"),
+    ];
+    let comparison = baseline.compare(current, &models(&["orders"]), true);
+    assert_eq!(comparison.new.len(), 1);
+    assert_eq!(comparison.baselined, 1);
+}
+
+fn at(mut d: Diagnostic, line: usize, column: usize) -> Diagnostic {
+    d.span = Some(SourceSpan {
+        line,
+        column,
+        end_line: line,
+        end_column: column + 5,
+    });
+    d
+}
+
+#[test]
+fn test_fingerprint_ignores_message_text() {
+    let recorded = diag(
+        DiagnosticCode::A042,
+        "pivoted",
+        None,
+        "DataFusion could not plan the model: Unsupported SQL statement: PIVOT",
+    );
+    let baseline = Baseline::record(&[recorded], &[], None, &models(&["pivoted"]));
+
+    let reworded = diag(
+        DiagnosticCode::A042,
+        "pivoted",
+        None,
+        "DataFusion could not plan the model: This feature is not implemented: PIVOT",
+    );
+    let comparison = baseline.compare(vec![reworded], &models(&["pivoted"]), true);
+    assert!(comparison.new.is_empty());
+    assert_eq!(comparison.baselined, 1);
+}
+
+#[test]
+fn test_fingerprint_includes_span_start() {
+    let recorded = at(
+        diag(DiagnosticCode::A014, "orders", Some("status"), "a <> b"),
+        9,
+        3,
+    );
+    let baseline = Baseline::record(&[recorded], &[], None, &models(&["orders"]));
+
+    let mut reformatted = at(
+        diag(DiagnosticCode::A014, "orders", Some("status"), "a  <>\n b"),
+        9,
+        3,
+    );
+    reformatted.span.as_mut().unwrap().end_line = 10;
+    let moved = at(
+        diag(DiagnosticCode::A014, "orders", Some("status"), "a <> b"),
+        12,
+        3,
+    );
+    let comparison = baseline.compare(vec![reformatted, moved], &models(&["orders"]), true);
+    assert_eq!(comparison.baselined, 1);
+    assert_eq!(comparison.new.len(), 1);
+    assert_eq!(comparison.new[0].span.unwrap().line, 12);
+}
+
+#[test]
+fn test_fixed_entries_only_for_analyzed_models() {
+    let old = vec![
+        diag(DiagnosticCode::A020, "orders", Some("a"), "unused"),
+        diag(DiagnosticCode::A020, "customers", Some("b"), "unused"),
+    ];
+    let baseline = Baseline::record(&old, &[], None, &models(&["orders", "customers"]));
+
+    let comparison = baseline.compare(vec![], &models(&["orders"]), true);
+    assert_eq!(comparison.fixed.len(), 1);
+    assert_eq!(comparison.fixed[0].model.as_str(), "orders");
+
+    let fixed = comparison.fixed_diagnostics();
+    assert_eq!(fixed[0].code, DiagnosticCode::A062);
+    assert_eq!(fixed[0].severity, Severity::Info);
+    assert_eq!(fixed[0].column.as_deref(), Some("a"));
+
+    let partial = baseline.compare(vec![], &models(&["orders"]), false);
+    assert!(partial.fixed.is_empty());
+}
+
+#[test]
+fn test_record_keeps_entries_for_unselected_models() {
+    let old = vec![
+        diag(DiagnosticCode::A020, "orders", Some("a"), "unused"),
+        diag(DiagnosticCode::A020, "customers", Some("b"), "unused"),
+    ];
+    let previous = Baseline::record(&old, &[], None, &models(&["orders", "customers"]));
+
+    let rerecorded = Baseline::record(&[], &[], Some(&previous), &models(&["orders"]));
+    let kept: Vec<&str> = rerecorded
+        .entries
+        .iter()
+        .map(|e| e.model.as_str())
+        .collect();
+    assert_eq!(kept, vec!["customers"]);
+}
+
+#[test]
+fn test_schema_mismatches_share_the_baseline() {
+    let orders = ModelName::new("orders");
+    let extra = SchemaMismatch::ExtraInSql {
+        column: "debug_flag".to_string(),
+    };
+    let missing = SchemaMismatch::MissingFromSql {
+        column: "email".to_string(),
+    };
+    let diagnostics = vec![diag(DiagnosticCode::A020, "orders", Some("a"), "unused")];
+    let baseline = Baseline::record(
+        &diagnostics,
+        &[(&orders, &extra)],
+        None,
+        &models(&["orders"]),
+    );
+    assert_eq!(baseline.entries.len(), 2);
+    assert!(baseline.entries.iter().any(|e| e.code == "SA02"));
+
+    let comparison = baseline.compare(
+        vec![(&orders, &extra), (&orders, &missing)],
+        &models(&["orders"]),
+        true,
+    );
+    assert_eq!(comparison.baselined, 1);
+    assert_eq!(comparison.new.len(), 1);
+    assert_eq!(comparison.new[0].1.code(), "SA01");
+    // The A020 entry is not reported as fixed by a mismatch comparison
+    assert!(comparison.fixed.is_empty());
+
+    let comparison = baseline.compare(Vec::<Diagnostic>::new(), &models(&["orders"]), true);
+    let fixed: Vec<&str> = comparison.fixed.iter().map(|e| e.code.as_str()).collect();
+    assert_eq!(fixed, vec!["A020"]);
+}
+
+#[test]
+fn test_mismatch_fingerprint_distinguishes_kinds() {
+    let orders = ModelName::new("orders");
+    let type_mismatch = SchemaMismatch::TypeMismatch {
+        column: "amount".to_string(),
+        yaml_type: "INTEGER".to_string(),
+        inferred_type: "VARCHAR".to_string(),
+    };
+    let retyped = SchemaMismatch::TypeMismatch {
+        column: "amount".to_string(),
+        yaml_type: "INTEGER".to_string(),
+        inferred_type: "DOUBLE".to_string(),
+    };
+    let extra = SchemaMismatch::ExtraInSql {
+        column: "amount".to_string(),
+    };
+    let baseline = Baseline::record(
+        &[],
+        &[(&orders, &type_mismatch)],
+        None,
+        &models(&["orders"]),
+    );
+
+    let comparison = baseline.compare(
+        vec![(&orders, &retyped), (&orders, &extra)],
+        &models(&["orders"]),
+        true,
+    );
+    assert_eq!(comparison.baselined, 1);
+    assert_eq!(comparison.new.len(), 1);
+    assert_eq!(comparison.new[0].1.kind(), "extra_in_sql");
+}
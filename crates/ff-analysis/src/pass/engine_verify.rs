@@ -0,0 +1,189 @@
+//! Engine verification — compare inferred schemas against DuckDB's own DESCRIBE (A009)
+//!
+//! The DataFusion bridge approximates DuckDB semantics with function stubs
+//! and type mappings, so an inferred schema can drift from what DuckDB will
+//! actually produce. `ff dt analyze --verify-engine` describes every model in
+//! an in-memory DuckDB built from empty source and seed tables, and this
+//! module turns the differences into diagnostics.
+//!
+//! DuckDB reports almost every query column as nullable, so nullability is
+//! only compared when the engine guarantees NOT NULL.
+
+use ff_core::ModelName;
+
+use crate::schema::RelSchema;
+use crate::types::{parse_sql_type, Nullability, SqlType, TypedColumn};
+
+use super::{Diagnostic, DiagnosticCode, Severity};
+
+/// Pass name reported on engine verification diagnostics
+pub const ENGINE_VERIFY_PASS: &str = "engine_verify";
+
+/// Compare a model's inferred schema against the columns DuckDB reports.
+///
+/// `engine_columns` are `(name, type, nullable)` triples as returned by
+/// `DatabaseSchema::describe_query_columns`. Columns are paired by position
+/// when both sides have the same width, and by name otherwise.
+pub fn verify_engine_schema(
+    model: &ModelName,
+    inferred: &RelSchema,
+    engine_columns: &[(String, String, bool)],
+) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    if inferred.len() != engine_columns.len() {
+        diagnostics.push(diagnostic(
+            model,
+            None,
+            Severity::Warning,
+            format!(
+                "DuckDB reports {} output columns but {} were inferred",
+                engine_columns.len(),
+                inferred.len()
+            ),
+        ));
+        for (name, engine_type, nullable) in engine_columns {
+            if let Some(col) = inferred.find_column(name) {
+                compare_column(model, col, engine_type, *nullable, &mut diagnostics);
+            }
+        }
+        return diagnostics;
+    }
+
+    for (col, (name, engine_type, nullable)) in inferred.columns.iter().zip(engine_columns) {
+        if !col.name.eq_ignore_ascii_case(name) {
+            diagnostics.push(diagnostic(
+                model,
+                Some(&col.name),
+                Severity::Info,
+                format!(
+                    "Column '{}' is named '{}' by DuckDB; downstream references by name will differ",
+                    col.name, name
+                ),
+            ));
+        }
+        compare_column(model, col, engine_type, *nullable, &mut diagnostics);
+    }
+
+    diagnostics
+}
+
+/// Report a model DuckDB could not describe, so its schema went unverified.
+pub fn engine_describe_failure(model: &ModelName, error: &str) -> Diagnostic {
+    let mut diagnostic = diagnostic(
+        model,
+        None,
+        Severity::Warning,
+        format!("DuckDB could not describe the model, so its schema was not verified: {error}"),
+    );
+    diagnostic.hint = Some(
+        "Check that the SQL runs in DuckDB; upstream sources and seeds are stubbed as empty tables"
+            .to_string(),
+    );
+    diagnostic
+}
+
+/// Compare one inferred column against DuckDB's type and nullability
+fn compare_column(
+    model: &ModelName,
+    col: &TypedColumn,
+    engine_type: &str,
+    engine_nullable: bool,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let engine = parse_sql_type(engine_type);
+    if !same_type(&col.sql_type, &engine) {
+        let severity = if col.sql_type.is_compatible_with(&engine) {
+            Severity::Info
+        } else {
+            Severity::Warning
+        };
+        diagnostics.push(diagnostic(
+            model,
+            Some(&col.name),
+            severity,
+            format!(
+                "Column '{}' inferred as {} but DuckDB reports {}",
+                col.name,
+                col.sql_type.display_name(),
+                engine_type
+            ),
+        ));
+    }
+
+    if !engine_nullable && col.nullability == Nullability::Nullable {
+        diagnostics.push(diagnostic(
+            model,
+            Some(&col.name),
+            Severity::Info,
+            format!(
+                "Column '{}' inferred as nullable but DuckDB reports NOT NULL",
+                col.name
+            ),
+        ));
+    }
+}
+
+/// Whether two types are the same for engine verification purposes.
+///
+/// String lengths are ignored (DuckDB has a single VARCHAR), as are decimal
+/// parameters that only one side knows, and anything involving an unknown type.
+fn same_type(inferred: &SqlType, engine: &SqlType) -> bool {
+    match (inferred, engine) {
+        (SqlType::Unknown(_), _) | (_, SqlType::Unknown(_)) => true,
+        (SqlType::String { .. }, SqlType::String { .. }) => true,
+        (
+            SqlType::Decimal {
+                precision: p1,
+                scale: s1,
+            },
+            SqlType::Decimal {
+                precision: p2,
+                scale: s2,
+            },
+        ) => agrees(*p1, *p2) && agrees(*s1, *s2),
+        (SqlType::Array(a), SqlType::Array(b)) => same_type(a, b),
+        (SqlType::Struct(a), SqlType::Struct(b)) => {
+            a.len() == b.len()
+                && a.iter()
+                    .zip(b)
+                    .all(|((n1, t1), (n2, t2))| n1.eq_ignore_ascii_case(n2) && same_type(t1, t2))
+        }
+        (SqlType::Map { key: k1, value: v1 }, SqlType::Map { key: k2, value: v2 }) => {
+            same_type(k1, k2) && same_type(v1, v2)
+        }
+        _ => inferred == engine,
+    }
+}
+
+/// Optional parameters agree unless both are known and differ
+fn agrees(a: Option<u16>, b: Option<u16>) -> bool {
+    match (a, b) {
+        (Some(a), Some(b)) => a == b,
+        _ => true,
+    }
+}
+
+fn diagnostic(
+    model: &ModelName,
+    column: Option<&str>,
+    severity: Severity,
+    message: String,
+) -> Diagnostic {
+    Diagnostic {
+        code: DiagnosticCode::A009,
+        severity,
+        message,
+        model: model.clone(),
+        column: column.map(str::to_string),
+        hint: Some(
+            "The DataFusion stubs disagree with DuckDB here; declare the column type in YAML or add an explicit CAST".to_string(),
+        ),
+        pass_name: ENGINE_VERIFY_PASS.into(),
+        span: None,
+    }
+}
+
+#[cfg(test)]
+#[path = "engine_verify_test.rs"]
+mod tests;
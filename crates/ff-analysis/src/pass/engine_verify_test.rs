@@ -0,0 +1,108 @@
+use super::*;
+use crate::test_utils::*;
+
+fn engine(columns: &[(&str, &str, bool)]) -> Vec<(String, String, bool)> {
+    columns
+        .iter()
+        .map(|(name, ty, nullable)| (name.to_string(), ty.to_string(), *nullable))
+        .collect()
+}
+
+fn inferred() -> RelSchema {
+    RelSchema::new(vec![
+        make_col("id", int32(), Nullability::NotNull),
+        make_col("name", varchar(), Nullability::Nullable),
+        make_col(
+            "amount",
+            SqlType::Decimal {
+                precision: Some(10),
+                scale: Some(2),
+            },
+            Nullability::Nullable,
+        ),
+    ])
+}
+
+#[test]
+fn test_matching_schema_is_silent() {
+    let diags = verify_engine_schema(
+        &ModelName::new("m"),
+        &inferred(),
+        &engine(&[
+            ("id", "INTEGER", true),
+            ("name", "VARCHAR", true),
+            ("amount", "DECIMAL(10,2)", true),
+        ]),
+    );
+    assert!(diags.is_empty(), "{:?}", diags);
+}
+
+#[test]
+fn test_type_drift_shows_both_types() {
+    let diags = verify_engine_schema(
+        &ModelName::new("m"),
+        &inferred(),
+        &engine(&[
+            ("id", "BIGINT", true),
+            ("name", "DATE", true),
+            ("amount", "DOUBLE", true),
+        ]),
+    );
+    assert_eq!(diags.len(), 3, "{:?}", diags);
+    assert!(diags.iter().all(|d| d.code == DiagnosticCode::A009));
+    assert_eq!(diags[0].severity, Severity::Info);
+    assert!(diags[0]
+        .message
+        .contains("inferred as INTEGER but DuckDB reports BIGINT"));
+    assert_eq!(diags[1].severity, Severity::Warning);
+    assert_eq!(diags[1].column.as_deref(), Some("name"));
+}
+
+#[test]
+fn test_nullability_compared_only_when_engine_knows() {
+    let diags = verify_engine_schema(
+        &ModelName::new("m"),
+        &inferred(),
+        &engine(&[
+            ("id", "INTEGER", true),
+            ("name", "VARCHAR", false),
+            ("amount", "DECIMAL(10,2)", true),
+        ]),
+    );
+    assert_eq!(diags.len(), 1, "{:?}", diags);
+    assert_eq!(diags[0].column.as_deref(), Some("name"));
+    assert!(diags[0].message.contains("NOT NULL"));
+}
+
+#[test]
+fn test_column_count_and_name_drift() {
+    let diags = verify_engine_schema(
+        &ModelName::new("m"),
+        &inferred(),
+        &engine(&[("id", "INTEGER", true), ("name", "INTEGER", true)]),
+    );
+    assert_eq!(diags.len(), 2, "{:?}", diags);
+    assert!(diags[0].column.is_none());
+    assert_eq!(diags[1].column.as_deref(), Some("name"));
+
+    let diags = verify_engine_schema(
+        &ModelName::new("m"),
+        &inferred(),
+        &engine(&[
+            ("id", "INTEGER", true),
+            ("name", "VARCHAR", true),
+            ("sum(amount)", "DECIMAL(10,2)", true),
+        ]),
+    );
+    assert_eq!(diags.len(), 1, "{:?}", diags);
+    assert!(diags[0].message.contains("'sum(amount)'"));
+}
+
+#[test]
+fn test_describe_failure_is_a_warning() {
+    let diag = engine_describe_failure(&ModelName::new("m"), "Catalog Error: no such table");
+    assert_eq!(diag.code, DiagnosticCode::A009);
+    assert_eq!(diag.severity, Severity::Warning);
+    assert_eq!(diag.pass_name, ENGINE_VERIFY_PASS);
+    assert!(diag.message.ends_with("Catalog Error: no such table"));
+}
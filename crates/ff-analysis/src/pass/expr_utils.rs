@@ -1,8 +1,9 @@
 //! Shared expression-tree utilities for analysis passes.
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
-use datafusion_expr::Expr;
+use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
+use datafusion_expr::{Expr, LogicalPlan};
 
 /// Walk a DataFusion expression tree, invoking `collector` for every
 /// [`datafusion_common::Column`] encountered.
@@ -139,3 +140,44 @@ pub(crate) fn expr_display_name(expr: &Expr) -> String {
         _ => expr.schema_name().to_string(),
     }
 }
+
+/// Map every relation qualifier in the plan to the table it scans
+pub(crate) fn relation_tables(plan: &LogicalPlan) -> HashMap<String, String> {
+    let mut relations = HashMap::new();
+    let _ = plan.apply_with_subqueries(|node| {
+        match node {
+            LogicalPlan::TableScan(scan) => {
+                let table = scan.table_name.table().to_string();
+                relations.insert(table.to_lowercase(), table);
+            }
+            LogicalPlan::SubqueryAlias(alias) => {
+                if let LogicalPlan::TableScan(scan) = alias.input.as_ref() {
+                    relations.insert(
+                        alias.alias.table().to_lowercase(),
+                        scan.table_name.table().to_string(),
+                    );
+                }
+            }
+            _ => {}
+        }
+        Ok(TreeNodeRecursion::Continue)
+    });
+    relations
+}
+
+/// Unwrap any aliases around an expression
+pub(crate) fn strip_alias(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Alias(alias) => strip_alias(&alias.expr),
+        _ => expr,
+    }
+}
+
+/// Unwrap any casts (`CAST` and `TRY_CAST`) around an expression
+pub(crate) fn strip_cast(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Cast(cast) => strip_cast(&cast.expr),
+        Expr::TryCast(cast) => strip_cast(&cast.expr),
+        _ => expr,
+    }
+}
@@ -0,0 +1,72 @@
+//! Source spans for diagnostics
+//!
+//! Passes that hold a DataFusion expression take its span from the column
+//! references DataFusion recorded while planning. Diagnostics that only name a
+//! column are located afterwards by finding that column in the model's SQL.
+
+use std::collections::HashMap;
+
+use datafusion_common::Column;
+use datafusion_expr::Expr;
+use ff_core::ModelName;
+use ff_sql::{locate_column, SqlParser, Statement};
+
+use super::expr_utils::walk_expr_columns;
+use super::{Diagnostic, SourceSpan};
+use crate::datafusion_bridge::propagation::ModelPlanResult;
+
+/// Span covering every column reference in `expr`.
+///
+/// `None` when no referenced column carries a span, e.g. for expressions
+/// DataFusion synthesized rather than parsed.
+pub(crate) fn expr_span(expr: &Expr) -> Option<SourceSpan> {
+    let mut span: Option<SourceSpan> = None;
+    walk_expr_columns(expr, &mut |col| {
+        if let Some(s) = column_ref_span(col) {
+            span = Some(span.map_or(s, |acc| acc.union(s)));
+        }
+    });
+    span
+}
+
+/// Span of a column reference where it first appears in the SQL
+pub(crate) fn column_ref_span(col: &Column) -> Option<SourceSpan> {
+    col.spans()
+        .first()
+        .and_then(|span| SourceSpan::from_datafusion(&span))
+}
+
+/// Locate `column` in `sql`, preferring the outermost SELECT item producing it.
+///
+/// Returns `None` when the SQL does not parse or never mentions the column.
+pub fn column_span(parser: &SqlParser, sql: &str, column: &str) -> Option<SourceSpan> {
+    let stmt = parser.parse(sql).ok()?.into_iter().next()?;
+    locate_column(&stmt, column).and_then(SourceSpan::from_sqlparser)
+}
+
+/// Give column diagnostics without a span the location of their column.
+pub(crate) fn locate_column_diagnostics(
+    diagnostics: &mut [Diagnostic],
+    models: &HashMap<ModelName, ModelPlanResult>,
+    parser: &SqlParser,
+) {
+    let mut parsed: HashMap<ModelName, Option<Statement>> = HashMap::new();
+    for diagnostic in diagnostics.iter_mut().filter(|d| d.span.is_none()) {
+        let Some(column) = diagnostic.column.as_deref() else {
+            continue;
+        };
+        let Some(result) = models.get(&diagnostic.model) else {
+            continue;
+        };
+        let stmt = parsed.entry(diagnostic.model.clone()).or_insert_with(|| {
+            parser
+                .parse(&result.sql)
+                .ok()
+                .and_then(|stmts| stmts.into_iter().next())
+        });
+        diagnostic.span = stmt
+            .as_ref()
+            .and_then(|stmt| locate_column(stmt, column))
+            .and_then(SourceSpan::from_sqlparser);
+    }
+}
@@ -1,19 +1,32 @@
 //! Pass infrastructure — composable analysis passes over DataFusion LogicalPlans
 
+pub(crate) mod baseline;
+pub(crate) mod engine_verify;
 pub(crate) mod expr_utils;
+pub(crate) mod location;
 pub(crate) mod plan_cross_model;
 pub(crate) mod plan_description_drift;
+pub(crate) mod plan_determinism;
+pub(crate) mod plan_join_fanout;
 pub(crate) mod plan_join_keys;
+pub(crate) mod plan_null_semantics;
 pub(crate) mod plan_nullability;
+pub(crate) mod plan_numeric_precision;
 pub mod plan_pass;
+pub(crate) mod plan_predicates;
+pub(crate) mod plan_sql_style;
 pub(crate) mod plan_type_inference;
 pub(crate) mod plan_unused_columns;
+pub(crate) mod suppression;
 
 use ff_core::config::ConfigSeverity;
-use ff_core::ModelName;
+use ff_core::{ModelName, Project};
 use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+use crate::datafusion_bridge::propagation::PropagationResult;
+use suppression::NodeRules;
 
 /// Strongly-typed diagnostic codes emitted by analysis passes.
 ///
@@ -31,12 +44,28 @@ pub enum DiagnosticCode {
     A004,
     /// A005: Lossy cast
     A005,
+    /// A006: Inferred numeric precision or scale exceeds the declared type
+    A006,
+    /// A007: Integer division drops the fractional part
+    A007,
+    /// A008: SUM of integer may overflow a narrower declared type
+    A008,
+    /// A009: Inferred type or nullability disagrees with DuckDB's DESCRIBE
+    A009,
     /// A010: Nullable from JOIN without guard
     A010,
     /// A011: YAML NOT NULL vs JOIN nullable
     A011,
     /// A012: Redundant IS NULL check
     A012,
+    /// A013: NOT IN subquery over a nullable column
+    A013,
+    /// A014: `<>`/`=` filter on nullable columns that silently drops rows
+    A014,
+    /// A015: COUNT(col) on a nullable column
+    A015,
+    /// A016: String concatenation with a nullable column
+    A016,
     /// A020: Unused column
     A020,
     // A021: Reserved/retired — SELECT * is now allowed; DataFusion expands wildcards transparently.
@@ -47,16 +76,58 @@ pub enum DiagnosticCode {
     A032,
     /// A033: Non-equi join
     A033,
+    /// A034: Many-to-many join — neither side is unique on the join keys
+    A034,
+    /// A035: Aggregate over rows repeated by a join — probable double counting
+    A035,
+    /// A036: Declared unique key not guaranteed by the output grain
+    A036,
     /// A040: Cross-model schema mismatch (extra/missing/type)
     A040,
     /// A041: Cross-model nullability mismatch
     A041,
+    /// A042: Schema inferred by the execution engine because DataFusion could not plan the model
+    A042,
     /// A050: Copy/Rename column with missing description — suggest inheriting from upstream
     A050,
     /// A051: Copy/Rename column with modified description — potential documentation drift
     A051,
     /// A052: Transform column with missing description — needs new documentation
     A052,
+    /// A060: Node-level suppression without a reason
+    A060,
+    /// A061: Node-level suppression that suppresses nothing
+    A061,
+    /// A062: Baseline entry that no longer matches a diagnostic
+    A062,
+    /// A070: Non-deterministic function (current time, random values)
+    A070,
+    /// A071: Order-sensitive window function without ORDER BY
+    A071,
+    /// A072: LIMIT without ORDER BY
+    A072,
+    /// A073: Incremental unique_key column missing from the output
+    A073,
+    /// A074: Incremental unique_key column not declared NOT NULL
+    A074,
+    /// A080: Predicate is always false
+    A080,
+    /// A081: Predicate is always true given the column's tests
+    A081,
+    /// A082: Predicate is implied by the other conditions
+    A082,
+    /// L001: `SELECT *` in a mart model
+    L001,
+    /// L002: Implicit comma join in FROM
+    L002,
+    /// L003: GROUP BY / ORDER BY by ordinal position
+    L003,
+    /// L004: Unqualified column reference in a multi-table query
+    L004,
+    /// L005: `UNION` without `ALL` or `DISTINCT`
+    L005,
+    /// L006: Node name does not follow its layer's naming convention
+    L006,
 }
 
 impl std::fmt::Display for DiagnosticCode {
@@ -75,18 +146,47 @@ impl std::str::FromStr for DiagnosticCode {
             "A003" => Ok(DiagnosticCode::A003),
             "A004" => Ok(DiagnosticCode::A004),
             "A005" => Ok(DiagnosticCode::A005),
+            "A006" => Ok(DiagnosticCode::A006),
+            "A007" => Ok(DiagnosticCode::A007),
+            "A008" => Ok(DiagnosticCode::A008),
+            "A009" => Ok(DiagnosticCode::A009),
             "A010" => Ok(DiagnosticCode::A010),
             "A011" => Ok(DiagnosticCode::A011),
             "A012" => Ok(DiagnosticCode::A012),
+            "A013" => Ok(DiagnosticCode::A013),
+            "A014" => Ok(DiagnosticCode::A014),
+            "A015" => Ok(DiagnosticCode::A015),
+            "A016" => Ok(DiagnosticCode::A016),
             "A020" => Ok(DiagnosticCode::A020),
             "A030" => Ok(DiagnosticCode::A030),
             "A032" => Ok(DiagnosticCode::A032),
             "A033" => Ok(DiagnosticCode::A033),
+            "A034" => Ok(DiagnosticCode::A034),
+            "A035" => Ok(DiagnosticCode::A035),
+            "A036" => Ok(DiagnosticCode::A036),
             "A040" => Ok(DiagnosticCode::A040),
             "A041" => Ok(DiagnosticCode::A041),
+            "A042" => Ok(DiagnosticCode::A042),
             "A050" => Ok(DiagnosticCode::A050),
             "A051" => Ok(DiagnosticCode::A051),
             "A052" => Ok(DiagnosticCode::A052),
+            "A060" => Ok(DiagnosticCode::A060),
+            "A061" => Ok(DiagnosticCode::A061),
+            "A062" => Ok(DiagnosticCode::A062),
+            "A070" => Ok(DiagnosticCode::A070),
+            "A071" => Ok(DiagnosticCode::A071),
+            "A072" => Ok(DiagnosticCode::A072),
+            "A073" => Ok(DiagnosticCode::A073),
+            "A074" => Ok(DiagnosticCode::A074),
+            "A080" => Ok(DiagnosticCode::A080),
+            "A081" => Ok(DiagnosticCode::A081),
+            "A082" => Ok(DiagnosticCode::A082),
+            "L001" => Ok(DiagnosticCode::L001),
+            "L002" => Ok(DiagnosticCode::L002),
+            "L003" => Ok(DiagnosticCode::L003),
+            "L004" => Ok(DiagnosticCode::L004),
+            "L005" => Ok(DiagnosticCode::L005),
+            "L006" => Ok(DiagnosticCode::L006),
             _ => Err(format!("unknown diagnostic code: {s}")),
         }
     }
@@ -136,6 +236,62 @@ pub struct Diagnostic {
     /// borrowed at zero cost via `"name".into()`. If a future extension
     /// needs a dynamic pass name, use `Cow::Owned(name)`.
     pub pass_name: Cow<'static, str>,
+    /// Where in the model's SQL the diagnostic points, when known.
+    ///
+    /// Passes report positions in the rendered SQL the plan was built from;
+    /// the CLI maps them back to the model's `.sql` template before printing.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub span: Option<SourceSpan>,
+}
+
+/// A range of source text, as 1-based line and character column positions.
+///
+/// The end position is exclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SourceSpan {
+    /// Start line (1-based)
+    pub line: usize,
+    /// Start column (1-based, in characters)
+    pub column: usize,
+    /// End line (1-based)
+    pub end_line: usize,
+    /// End column (1-based, exclusive)
+    pub end_column: usize,
+}
+
+impl SourceSpan {
+    /// Convert a sqlparser span; `None` for the empty span of synthesized nodes
+    pub(crate) fn from_sqlparser(span: sqlparser::tokenizer::Span) -> Option<Self> {
+        (span.start.line > 0).then(|| SourceSpan {
+            line: span.start.line as usize,
+            column: span.start.column as usize,
+            end_line: span.end.line as usize,
+            end_column: span.end.column as usize,
+        })
+    }
+
+    /// Convert a span DataFusion recorded while planning
+    pub(crate) fn from_datafusion(span: &datafusion_common::Span) -> Option<Self> {
+        (span.start.line > 0).then(|| SourceSpan {
+            line: span.start.line as usize,
+            column: span.start.column as usize,
+            end_line: span.end.line as usize,
+            end_column: span.end.column as usize,
+        })
+    }
+
+    /// The smallest span covering both `self` and `other`
+    pub fn union(self, other: SourceSpan) -> SourceSpan {
+        let (line, column) = (self.line, self.column).min((other.line, other.column));
+        let (end_line, end_column) =
+            (self.end_line, self.end_column).max((other.end_line, other.end_column));
+        SourceSpan {
+            line,
+            column,
+            end_line,
+            end_column,
+        }
+    }
 }
 
 /// Check if a pass should run given an optional filter list
@@ -155,16 +311,29 @@ pub enum OverriddenSeverity {
     Off,
 }
 
+impl From<ConfigSeverity> for OverriddenSeverity {
+    fn from(sev: ConfigSeverity) -> Self {
+        match sev {
+            ConfigSeverity::Info => OverriddenSeverity::Level(Severity::Info),
+            ConfigSeverity::Warning => OverriddenSeverity::Level(Severity::Warning),
+            ConfigSeverity::Error => OverriddenSeverity::Level(Severity::Error),
+            ConfigSeverity::Off => OverriddenSeverity::Off,
+        }
+    }
+}
+
 /// User-configured severity overrides for diagnostic codes.
 ///
 /// Built from `Config.analysis.severity_overrides` and used to remap or
 /// suppress diagnostics after passes have run. Uses separate maps for
 /// typed `DiagnosticCode` keys (direct lookup, no formatting) and
-/// string SA-codes.
+/// string SA-codes. Per-node suppressions and overrides, added with
+/// [`SeverityOverrides::with_node_rules`], take precedence over both.
 #[derive(Debug, Clone, Default)]
 pub struct SeverityOverrides {
     code_overrides: HashMap<DiagnosticCode, OverriddenSeverity>,
     sa_overrides: HashMap<String, OverriddenSeverity>,
+    node_rules: HashMap<ModelName, NodeRules>,
 }
 
 impl SeverityOverrides {
@@ -178,12 +347,7 @@ impl SeverityOverrides {
         let mut sa_overrides = HashMap::new();
 
         for (key, sev) in config_map {
-            let resolved = match sev {
-                ConfigSeverity::Info => OverriddenSeverity::Level(Severity::Info),
-                ConfigSeverity::Warning => OverriddenSeverity::Level(Severity::Warning),
-                ConfigSeverity::Error => OverriddenSeverity::Level(Severity::Error),
-                ConfigSeverity::Off => OverriddenSeverity::Off,
-            };
+            let resolved = OverriddenSeverity::from(*sev);
             if let Some(dc) = parse_diagnostic_code(key) {
                 code_overrides.insert(dc, resolved);
             } else {
@@ -194,7 +358,29 @@ impl SeverityOverrides {
         Self {
             code_overrides,
             sa_overrides,
+            node_rules: HashMap::new(),
+        }
+    }
+
+    /// Add per-node rules from each model's YAML `analysis:` block and
+    /// inline `-- ff:ignore` comments in its SQL.
+    ///
+    /// Inline comments are read from the rendered SQL in `sql_sources`, the
+    /// text diagnostic spans refer to, falling back to the raw template.
+    pub fn with_node_rules(
+        mut self,
+        project: &Project,
+        sql_sources: &HashMap<ModelName, String>,
+    ) -> Self {
+        for (name, model) in &project.models {
+            let yaml = model.schema.as_ref().map(|s| &s.analysis);
+            let sql = sql_sources.get(name).unwrap_or(&model.raw_sql);
+            let rules = NodeRules::new(yaml, sql);
+            if !rules.is_empty() {
+                self.node_rules.insert(name.clone(), rules);
+            }
         }
+        self
     }
 
     /// Look up an override for a `DiagnosticCode` (e.g. `A020`).
@@ -206,6 +392,132 @@ impl SeverityOverrides {
     pub fn get_for_sa(&self, code: &str) -> Option<OverriddenSeverity> {
         self.sa_overrides.get(code).copied()
     }
+
+    /// Look up an SA-code override for one column of a node, honouring the
+    /// node's suppressions and overrides before the project-wide ones.
+    pub fn get_for_node_sa(
+        &self,
+        model: &str,
+        code: &str,
+        column: &str,
+    ) -> Option<OverriddenSeverity> {
+        self.node_rules
+            .get(model)
+            .and_then(|rules| rules.resolve(code, Some(column), None))
+            .or_else(|| self.get_for_sa(code))
+    }
+
+    /// Resolve the override for a diagnostic: node rules first, then project-wide.
+    fn resolve(&self, diagnostic: &Diagnostic) -> Option<OverriddenSeverity> {
+        self.node_rules
+            .get(&diagnostic.model)
+            .and_then(|rules| {
+                rules.resolve(
+                    &diagnostic.code.to_string(),
+                    diagnostic.column.as_deref(),
+                    diagnostic.span.as_ref(),
+                )
+            })
+            .or_else(|| self.get_for_code(diagnostic.code))
+    }
+
+    /// Report node-level suppressions that lack a reason (A060) or that match
+    /// nothing (A061), including `ff:ignore` comments that name no code or an
+    /// unknown one.
+    ///
+    /// Pass-diagnostic suppressions are checked against the `raw` diagnostics
+    /// produced before overrides, and schema-mismatch (`SA`) suppressions
+    /// against the mismatches in `propagation`. Only nodes in `analyzed` are
+    /// checked. Pass `check_unused = false` when some passes were skipped,
+    /// since their suppressions would look unused; `SA` suppressions are
+    /// still checked because propagation always runs.
+    pub fn audit_suppressions(
+        &self,
+        raw: &[Diagnostic],
+        propagation: &PropagationResult,
+        analyzed: &[ModelName],
+        check_unused: bool,
+    ) -> Vec<Diagnostic> {
+        let mismatches: HashMap<&ModelName, _> = propagation.all_mismatches().collect();
+        let mut diagnostics = Vec::new();
+        let mut seen = HashSet::new();
+
+        for model in analyzed {
+            if !seen.insert(model) {
+                continue;
+            }
+            let Some(rules) = self.node_rules.get(model) else {
+                continue;
+            };
+            diagnostics.extend(rules.invalid.iter().map(|invalid| Diagnostic {
+                code: DiagnosticCode::A061,
+                severity: Severity::Warning,
+                message: format!("{}, so it suppresses nothing", invalid.message),
+                model: model.clone(),
+                column: None,
+                hint: Some("Name a diagnostic code, e.g. `-- ff:ignore A020`".to_string()),
+                pass_name: "suppressions".into(),
+                span: invalid.span,
+            }));
+            for suppression in &rules.suppressions {
+                if suppression.reason.is_none() {
+                    diagnostics.push(Diagnostic {
+                        code: DiagnosticCode::A060,
+                        severity: Severity::Warning,
+                        message: format!(
+                            "{} suppression of {} has no reason",
+                            suppression.source, suppression.code
+                        ),
+                        model: model.clone(),
+                        column: None,
+                        hint: Some(match suppression.source {
+                            suppression::SuppressionSource::Inline => {
+                                "Add reason=\"...\" to the ff:ignore comment".to_string()
+                            }
+                            suppression::SuppressionSource::Yaml => {
+                                "Add a `reason:` to the analysis.ignore entry".to_string()
+                            }
+                        }),
+                        pass_name: "suppressions".into(),
+                        span: None,
+                    });
+                }
+
+                let used = if suppression.code.starts_with("SA") {
+                    mismatches.get(model).is_some_and(|mismatches| {
+                        mismatches
+                            .iter()
+                            .any(|m| suppression.matches(m.code(), Some(m.column())))
+                    })
+                } else if check_unused {
+                    raw.iter().any(|d| {
+                        &d.model == model
+                            && suppression.matches(&d.code.to_string(), d.column.as_deref())
+                            && suppression.covers(d.span.as_ref())
+                    })
+                } else {
+                    continue;
+                };
+                if !used {
+                    diagnostics.push(Diagnostic {
+                        code: DiagnosticCode::A061,
+                        severity: Severity::Warning,
+                        message: format!(
+                            "{} suppression of {} does not suppress any diagnostic",
+                            suppression.source, suppression.code
+                        ),
+                        model: model.clone(),
+                        column: None,
+                        hint: Some("Remove the stale suppression".to_string()),
+                        pass_name: "suppressions".into(),
+                        span: None,
+                    });
+                }
+            }
+        }
+
+        diagnostics
+    }
 }
 
 /// Try to parse a string key into a DiagnosticCode.
@@ -215,6 +527,7 @@ fn parse_diagnostic_code(s: &str) -> Option<DiagnosticCode> {
 
 /// Apply severity overrides to a list of diagnostics.
 ///
+/// - Node-level suppressions and overrides are checked before project-wide ones.
 /// - `Off` overrides remove the diagnostic from the output.
 /// - `Level(s)` overrides change the diagnostic's severity to `s`.
 /// - Diagnostics without overrides are passed through unchanged.
@@ -224,7 +537,7 @@ pub fn apply_severity_overrides(
 ) -> Vec<Diagnostic> {
     diagnostics
         .into_iter()
-        .filter_map(|mut d| match overrides.get_for_code(d.code) {
+        .filter_map(|mut d| match overrides.resolve(&d) {
             Some(OverriddenSeverity::Off) => None,
             Some(OverriddenSeverity::Level(s)) => {
                 d.severity = s;
@@ -30,6 +30,7 @@ fn mismatch_to_diagnostic(
                 "Add '{column}' to the YAML schema or remove it from SELECT"
             )),
             pass_name: pass_name.into(),
+            span: None,
         },
         SchemaMismatch::MissingFromSql { column } => Diagnostic {
             code: DiagnosticCode::A040,
@@ -39,6 +40,7 @@ fn mismatch_to_diagnostic(
             column: Some(column.clone()),
             hint: Some(format!("Add '{column}' to SELECT or remove it from YAML")),
             pass_name: pass_name.into(),
+            span: None,
         },
         SchemaMismatch::TypeMismatch {
             column,
@@ -56,6 +58,7 @@ fn mismatch_to_diagnostic(
                 "Update YAML type to '{inferred_type}' or add explicit CAST"
             )),
             pass_name: pass_name.into(),
+            span: None,
         },
         SchemaMismatch::NullabilityMismatch {
             column,
@@ -77,6 +80,7 @@ fn mismatch_to_diagnostic(
                 column: Some(column.clone()),
                 hint: Some(hint.to_string()),
                 pass_name: pass_name.into(),
+                span: None,
             }
         }
     }
@@ -67,6 +67,7 @@ impl DagPlanPass for PlanDescriptionDrift {
                             edge.target_column, edge.source_model, edge.source_column
                         )),
                         pass_name: "description_drift".into(),
+                        span: None,
                     });
                 }
                 (true, Some(src), Some(tgt)) if src != tgt => {
@@ -81,6 +82,7 @@ impl DagPlanPass for PlanDescriptionDrift {
                         column: Some(edge.target_column.clone()),
                         hint: None,
                         pass_name: "description_drift".into(),
+                        span: None,
                     });
                 }
                 (false, _, None) => {
@@ -98,6 +100,7 @@ impl DagPlanPass for PlanDescriptionDrift {
                             edge.target_column
                         )),
                         pass_name: "description_drift".into(),
+                        span: None,
                     });
                 }
                 _ => {}
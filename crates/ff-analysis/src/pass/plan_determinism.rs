@@ -0,0 +1,268 @@
+//! Determinism and incremental-safety pass on DataFusion LogicalPlans (A070-A074)
+//!
+//! Flags expressions whose result changes between runs (current time, random
+//! values), order-sensitive window functions without ORDER BY, and LIMIT
+//! without ORDER BY. Incremental models keep rows from earlier runs, so these
+//! are reported as warnings there and as info elsewhere.
+//!
+//! For merge and delete+insert incremental models it also checks that every
+//! `unique_key` column is in the output and declared NOT NULL in YAML.
+
+use std::collections::BTreeSet;
+
+use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
+use datafusion_expr::{Expr, LogicalPlan, Volatility};
+use ff_core::config::IncrementalStrategy;
+use ff_core::model::Model;
+use ff_core::ModelName;
+
+use crate::context::AnalysisContext;
+use crate::types::Nullability;
+
+use super::plan_pass::PlanPass;
+use super::{Diagnostic, DiagnosticCode, Severity};
+
+/// Functions whose result depends on when the query runs
+const TIME_FUNCTIONS: &[&str] = &[
+    "now",
+    "current_timestamp",
+    "get_current_timestamp",
+    "transaction_timestamp",
+    "localtimestamp",
+    "current_date",
+    "current_time",
+    "today",
+];
+
+/// Window functions whose result depends on row order within the partition
+const ORDER_SENSITIVE_WINDOWS: &[&str] = &[
+    "row_number",
+    "ntile",
+    "lag",
+    "lead",
+    "first_value",
+    "last_value",
+    "nth_value",
+];
+
+/// Determinism and incremental-safety pass (DataFusion LogicalPlan)
+pub(crate) struct PlanDeterminism;
+
+impl PlanPass for PlanDeterminism {
+    fn name(&self) -> &'static str {
+        "plan_determinism"
+    }
+
+    fn description(&self) -> &'static str {
+        "Flags non-deterministic expressions and unsafe incremental unique keys"
+    }
+
+    fn run_model(
+        &self,
+        model_name: &str,
+        plan: &LogicalPlan,
+        ctx: &AnalysisContext,
+    ) -> Vec<Diagnostic> {
+        let model = ctx.project().get_model(model_name);
+        let incremental =
+            model.is_some_and(|m| m.is_incremental_model(ctx.project().config.materialization));
+
+        let mut findings = Findings::default();
+        let _ = plan.apply_with_subqueries(|node| {
+            findings.visit_node(node);
+            Ok(TreeNodeRecursion::Continue)
+        });
+
+        let mut diagnostics = findings.into_diagnostics(model_name, incremental);
+        if let Some(model) = model.filter(|_| incremental) {
+            check_unique_key(model_name, model, plan, ctx, &mut diagnostics);
+        }
+        diagnostics
+    }
+}
+
+/// Non-deterministic constructs found in one model, deduplicated by function
+#[derive(Default)]
+struct Findings {
+    functions: BTreeSet<String>,
+    unordered_windows: BTreeSet<String>,
+    unordered_limits: usize,
+}
+
+impl Findings {
+    fn visit_node(&mut self, node: &LogicalPlan) {
+        if let LogicalPlan::Limit(limit) = node {
+            if !is_ordered(&limit.input) {
+                self.unordered_limits += 1;
+            }
+        }
+        let _ = node.apply_expressions(|expr| {
+            expr.apply(|e| {
+                self.visit_expr(e);
+                Ok(TreeNodeRecursion::Continue)
+            })
+        });
+    }
+
+    fn visit_expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::ScalarFunction(func) => {
+                let name = func.func.name().to_lowercase();
+                if TIME_FUNCTIONS.contains(&name.as_str())
+                    || func.func.signature().volatility == Volatility::Volatile
+                {
+                    self.functions.insert(name);
+                }
+            }
+            Expr::WindowFunction(wf) => {
+                let name = wf.fun.name().to_lowercase();
+                if wf.params.order_by.is_empty() && ORDER_SENSITIVE_WINDOWS.contains(&name.as_str())
+                {
+                    self.unordered_windows.insert(name);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn into_diagnostics(self, model: &str, incremental: bool) -> Vec<Diagnostic> {
+        let (severity, consequence) = if incremental {
+            (
+                Severity::Warning,
+                "rows kept from earlier incremental runs will not match a full refresh",
+            )
+        } else {
+            (Severity::Info, "results differ between runs")
+        };
+
+        let mut diagnostics = Vec::new();
+        for name in self.functions {
+            let hint = if TIME_FUNCTIONS.contains(&name.as_str()) {
+                "Pass the run time in with a variable, or derive it from a column in the data"
+            } else {
+                "Derive the value from row data (e.g. a hash of key columns) instead"
+            };
+            diagnostics.push(Diagnostic {
+                code: DiagnosticCode::A070,
+                severity,
+                message: format!("Non-deterministic function {}(): {}", name, consequence),
+                model: ModelName::new(model),
+                column: None,
+                hint: Some(hint.to_string()),
+                pass_name: "plan_determinism".into(),
+                span: None,
+            });
+        }
+        for name in self.unordered_windows {
+            diagnostics.push(Diagnostic {
+                code: DiagnosticCode::A071,
+                severity,
+                message: format!(
+                    "{}() OVER without ORDER BY depends on row order: {}",
+                    name, consequence
+                ),
+                model: ModelName::new(model),
+                column: None,
+                hint: Some("Add an ORDER BY on unique columns to the window".to_string()),
+                pass_name: "plan_determinism".into(),
+                span: None,
+            });
+        }
+        if self.unordered_limits > 0 {
+            diagnostics.push(Diagnostic {
+                code: DiagnosticCode::A072,
+                severity,
+                message: format!(
+                    "LIMIT without ORDER BY returns an arbitrary subset of rows: {}",
+                    consequence
+                ),
+                model: ModelName::new(model),
+                column: None,
+                hint: Some("Add an ORDER BY on unique columns before the LIMIT".to_string()),
+                pass_name: "plan_determinism".into(),
+                span: None,
+            });
+        }
+        diagnostics
+    }
+}
+
+/// Whether a LIMIT input is sorted (looking through projections and aliases)
+fn is_ordered(plan: &LogicalPlan) -> bool {
+    match plan {
+        LogicalPlan::Sort(_) => true,
+        LogicalPlan::Projection(proj) => is_ordered(&proj.input),
+        LogicalPlan::SubqueryAlias(alias) => is_ordered(&alias.input),
+        // A global aggregate produces a single row
+        LogicalPlan::Aggregate(agg) => agg.group_expr.is_empty(),
+        _ => false,
+    }
+}
+
+/// Check the unique_key of merge and delete+insert models (A073, A074)
+fn check_unique_key(
+    model_name: &str,
+    model: &Model,
+    plan: &LogicalPlan,
+    ctx: &AnalysisContext,
+    diags: &mut Vec<Diagnostic>,
+) {
+    let strategy = model.incremental_strategy();
+    if !matches!(
+        strategy,
+        IncrementalStrategy::Merge | IncrementalStrategy::DeleteInsert
+    ) {
+        return;
+    }
+    let Some(unique_key) = model.unique_key() else {
+        return;
+    };
+
+    let output = plan.schema();
+    let yaml = ctx.yaml_schemas().get(model_name);
+    for column in &unique_key {
+        let in_output = output
+            .fields()
+            .iter()
+            .any(|f| f.name().eq_ignore_ascii_case(column));
+        if !in_output {
+            diags.push(Diagnostic {
+                code: DiagnosticCode::A073,
+                severity: Severity::Error,
+                message: format!(
+                    "unique_key column '{}' is not in the model output; the {} cannot match existing rows",
+                    column, strategy
+                ),
+                model: ModelName::new(model_name),
+                column: Some(column.clone()),
+                hint: Some("Select the key column or fix unique_key in the node YAML".to_string()),
+                pass_name: "plan_determinism".into(),
+                span: None,
+            });
+            continue;
+        }
+
+        let declared_not_null = yaml
+            .and_then(|schema| schema.find_column(column))
+            .is_some_and(|c| c.nullability == Nullability::NotNull);
+        if !declared_not_null {
+            diags.push(Diagnostic {
+                code: DiagnosticCode::A074,
+                severity: Severity::Warning,
+                message: format!(
+                    "unique_key column '{}' is not declared NOT NULL; rows with a NULL key never match and are duplicated by every {}",
+                    column, strategy
+                ),
+                model: ModelName::new(model_name),
+                column: Some(column.clone()),
+                hint: Some("Add a not_null test to the column in the node YAML".to_string()),
+                pass_name: "plan_determinism".into(),
+                span: None,
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "plan_determinism_test.rs"]
+mod tests;
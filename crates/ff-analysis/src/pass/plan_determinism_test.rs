@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::*;
+use crate::datafusion_bridge::propagation::propagate_schemas;
+use crate::schema::{RelSchema, SchemaCatalog};
+use crate::test_utils::*;
+use ff_core::config::Materialization;
+use ff_core::model::{ModelConfig, ModelKind};
+
+fn plan(sql: &str) -> LogicalPlan {
+    let mut catalog: SchemaCatalog = HashMap::new();
+    catalog.insert(
+        "events".to_string(),
+        Arc::new(RelSchema::new(vec![
+            make_col("id", int32(), Nullability::NotNull),
+            make_col("user_id", int32(), Nullability::Nullable),
+            make_col("amount", int32(), Nullability::Nullable),
+        ])),
+    );
+    let mut sql_sources = HashMap::new();
+    sql_sources.insert(ModelName::new("m"), sql.to_string());
+    let result = propagate_schemas(
+        &[ModelName::new("m")],
+        &sql_sources,
+        &HashMap::new(),
+        catalog,
+        &[],
+        &[],
+    );
+    result
+        .model_plans
+        .get("m")
+        .expect("model should plan")
+        .plan
+        .clone()
+}
+
+/// Context with `m` registered as a merge incremental model keyed on `id`
+fn incremental_ctx(id_not_null: bool) -> AnalysisContext {
+    let mut ctx = make_ctx();
+    let config = ModelConfig {
+        materialized: Some(Materialization::Incremental),
+        incremental_strategy: Some(IncrementalStrategy::Merge),
+        unique_key: Some("id".to_string()),
+        ..Default::default()
+    };
+    ctx.project.models.insert(
+        ModelName::new("m"),
+        Model {
+            name: ModelName::new("m"),
+            path: std::path::PathBuf::from("nodes/m/m.sql"),
+            raw_sql: String::new(),
+            compiled_sql: None,
+            config,
+            depends_on: Default::default(),
+            external_deps: Default::default(),
+            schema: None,
+            base_name: None,
+            version: None,
+            kind: ModelKind::default(),
+        },
+    );
+    let nullability = if id_not_null {
+        Nullability::NotNull
+    } else {
+        Nullability::Unknown
+    };
+    ctx.yaml_schemas.insert(
+        ModelName::new("m"),
+        Arc::new(RelSchema::new(vec![make_col("id", int32(), nullability)])),
+    );
+    ctx
+}
+
+fn run(sql: &str, ctx: &AnalysisContext) -> Vec<Diagnostic> {
+    PlanDeterminism.run_model("m", &plan(sql), ctx)
+}
+
+#[test]
+fn test_a070_time_and_random_functions() {
+    let diags = run(
+        "SELECT id, now() AS loaded_at, current_date AS d, now() AS again, random() AS r FROM events",
+        &make_ctx(),
+    );
+    let a070: Vec<_> = diags
+        .iter()
+        .filter(|d| d.code == DiagnosticCode::A070)
+        .collect();
+    assert_eq!(a070.len(), 3, "{:?}", diags);
+    assert!(a070.iter().all(|d| d.severity == Severity::Info));
+}
+
+#[test]
+fn test_incremental_models_get_warnings() {
+    let diags = run(
+        "SELECT id, now() AS loaded_at FROM events",
+        &incremental_ctx(true),
+    );
+    assert_eq!(diags.len(), 1, "{:?}", diags);
+    assert_eq!(diags[0].code, DiagnosticCode::A070);
+    assert_eq!(diags[0].severity, Severity::Warning);
+}
+
+#[test]
+fn test_a071_unordered_row_number() {
+    let diags = run(
+        "SELECT id, row_number() OVER (PARTITION BY user_id) AS rn FROM events",
+        &make_ctx(),
+    );
+    assert_has_diagnostic(&diags, DiagnosticCode::A071);
+
+    let diags = run(
+        "SELECT id, row_number() OVER (PARTITION BY user_id ORDER BY id) AS rn, \
+         rank() OVER (PARTITION BY user_id) AS rk FROM events",
+        &make_ctx(),
+    );
+    assert_no_diagnostic(&diags, DiagnosticCode::A071);
+}
+
+#[test]
+fn test_a072_limit_without_order_by() {
+    let diags = run("SELECT id FROM events LIMIT 10", &make_ctx());
+    assert_has_diagnostic(&diags, DiagnosticCode::A072);
+
+    let diags = run("SELECT id FROM events ORDER BY id LIMIT 10", &make_ctx());
+    assert_no_diagnostic(&diags, DiagnosticCode::A072);
+
+    let diags = run(
+        "SELECT id FROM events WHERE amount > (SELECT max(amount) FROM events LIMIT 1)",
+        &make_ctx(),
+    );
+    assert_no_diagnostic(&diags, DiagnosticCode::A072);
+}
+
+#[test]
+fn test_a073_unique_key_missing_from_output() {
+    let diags = run("SELECT user_id, amount FROM events", &incremental_ctx(true));
+    assert_has_diagnostic(&diags, DiagnosticCode::A073);
+    let a073 = diags
+        .iter()
+        .find(|d| d.code == DiagnosticCode::A073)
+        .unwrap();
+    assert_eq!(a073.severity, Severity::Error);
+    assert_eq!(a073.column.as_deref(), Some("id"));
+}
+
+#[test]
+fn test_a074_unique_key_not_declared_not_null() {
+    let diags = run("SELECT id, amount FROM events", &incremental_ctx(false));
+    assert_has_diagnostic(&diags, DiagnosticCode::A074);
+
+    let diags = run("SELECT id, amount FROM events", &incremental_ctx(true));
+    assert!(diags.is_empty(), "{:?}", diags);
+}
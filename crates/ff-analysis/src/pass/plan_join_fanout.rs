@@ -0,0 +1,456 @@
+//! Join fan-out analysis pass on DataFusion LogicalPlans (A034-A036)
+//!
+//! Uses the keys declared in node and source YAML (`primary_key` columns,
+//! `unique` tests, incremental `unique_key`) to decide whether each side of a
+//! join is unique on its join columns. The row grain of every plan node is
+//! tracked so that aggregates over rows repeated by a join, and declared keys
+//! the output can no longer guarantee, are reported.
+//!
+//! Tables without declared keys have an unknown grain and never trigger
+//! diagnostics.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+
+use datafusion_common::DFSchema;
+use datafusion_expr::logical_plan::{Aggregate, Distinct, Join, JoinType};
+use datafusion_expr::utils::split_conjunction;
+use datafusion_expr::{Expr, LogicalPlan, Operator};
+use ff_core::ModelName;
+
+use crate::context::AnalysisContext;
+
+use super::expr_utils::{strip_alias, walk_expr_columns};
+use super::location::expr_span;
+use super::plan_pass::PlanPass;
+use super::{Diagnostic, DiagnosticCode, Severity, SourceSpan};
+
+/// Join fan-out analysis pass (DataFusion LogicalPlan)
+pub(crate) struct PlanJoinFanout;
+
+impl PlanPass for PlanJoinFanout {
+    fn name(&self) -> &'static str {
+        "plan_join_fanout"
+    }
+
+    fn description(&self) -> &'static str {
+        "Detects joins that multiply rows using declared unique keys"
+    }
+
+    fn run_model(
+        &self,
+        model_name: &str,
+        plan: &LogicalPlan,
+        ctx: &AnalysisContext,
+    ) -> Vec<Diagnostic> {
+        let mut walker = FanoutWalker {
+            model: model_name,
+            ctx,
+            diagnostics: Vec::new(),
+        };
+        let grain = walker.grain(plan);
+        walker.check_declared_keys(&grain, plan.schema());
+        walker.diagnostics
+    }
+}
+
+/// Row grain of a plan node, expressed as indices into its output schema
+#[derive(Debug, Clone, Default)]
+struct Grain {
+    /// Column sets known to be unique, or `None` when the grain is unknown.
+    /// An empty set means the node produces at most one row.
+    keys: Option<Vec<BTreeSet<usize>>>,
+    /// Columns whose rows may have been repeated by a join
+    fanned: HashSet<usize>,
+}
+
+impl Grain {
+    fn unknown() -> Self {
+        Self::default()
+    }
+
+    fn keyed(keys: Vec<BTreeSet<usize>>) -> Self {
+        Self {
+            keys: Some(keys),
+            fanned: HashSet::new(),
+        }
+    }
+
+    /// Whether every combination of `columns` occurs at most once (`None` if unknown)
+    fn is_unique_on(&self, columns: &BTreeSet<usize>) -> Option<bool> {
+        self.keys
+            .as_ref()
+            .map(|keys| keys.iter().any(|key| key.is_subset(columns)))
+    }
+}
+
+struct FanoutWalker<'a> {
+    model: &'a str,
+    ctx: &'a AnalysisContext,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl FanoutWalker<'_> {
+    /// Compute the grain of a plan node, reporting fan-out issues on the way
+    fn grain(&mut self, plan: &LogicalPlan) -> Grain {
+        match plan {
+            LogicalPlan::TableScan(scan) => {
+                self.scan_grain(scan.table_name.table(), &scan.projected_schema)
+            }
+            LogicalPlan::EmptyRelation(empty) if empty.produce_one_row => {
+                Grain::keyed(vec![BTreeSet::new()])
+            }
+            LogicalPlan::SubqueryAlias(alias) => self.grain(&alias.input),
+            LogicalPlan::Projection(proj) => {
+                let input = self.grain(&proj.input);
+                project_grain(&input, &proj.expr, proj.input.schema())
+            }
+            // Equalities in a WHERE directly above a join (comma joins) act as join keys
+            LogicalPlan::Filter(filter) => match filter.input.as_ref() {
+                LogicalPlan::Join(join) => self.join_grain(join, Some(&filter.predicate)),
+                input => self.grain(input),
+            },
+            LogicalPlan::Sort(sort) => self.grain(&sort.input),
+            LogicalPlan::Limit(limit) => self.grain(&limit.input),
+            LogicalPlan::Window(window) => self.grain(&window.input),
+            LogicalPlan::Distinct(Distinct::All(input)) => {
+                self.grain(input);
+                Grain::keyed(vec![(0..input.schema().fields().len()).collect()])
+            }
+            LogicalPlan::Aggregate(agg) => self.aggregate_grain(agg),
+            LogicalPlan::Join(join) => self.join_grain(join, None),
+            other => {
+                for input in other.inputs() {
+                    self.grain(input);
+                }
+                Grain::unknown()
+            }
+        }
+    }
+
+    /// Grain of a model or source table from its declared keys
+    fn scan_grain(&self, table: &str, schema: &DFSchema) -> Grain {
+        let declared = self.ctx.unique_keys(table);
+        if declared.is_empty() {
+            return Grain::unknown();
+        }
+        Grain::keyed(
+            declared
+                .iter()
+                .filter_map(|key| resolve_key(key, schema))
+                .collect(),
+        )
+    }
+
+    /// Check a join for many-to-many keys (A034) and compute its output grain
+    fn join_grain(&mut self, join: &Join, predicate: Option<&Expr>) -> Grain {
+        let left = self.grain(&join.left);
+        let right = self.grain(&join.right);
+        match join.join_type {
+            JoinType::LeftSemi | JoinType::LeftAnti | JoinType::LeftMark => return left,
+            JoinType::RightSemi | JoinType::RightAnti => return right,
+            _ => {}
+        }
+
+        let left_schema = join.left.schema();
+        let right_schema = join.right.schema();
+        let offset = left_schema.fields().len();
+
+        let mut pairs: Vec<(usize, usize)> = join
+            .on
+            .iter()
+            .filter_map(|(l, r)| key_pair(l, r, left_schema, right_schema))
+            .collect();
+        for expr in join.filter.iter().chain(predicate) {
+            for conjunct in split_conjunction(expr) {
+                if let Expr::BinaryExpr(bin) = conjunct {
+                    if bin.op == Operator::Eq {
+                        pairs.extend(key_pair(&bin.left, &bin.right, left_schema, right_schema));
+                    }
+                }
+            }
+        }
+
+        let left_cols: BTreeSet<usize> = pairs.iter().map(|(l, _)| *l).collect();
+        let right_cols: BTreeSet<usize> = pairs.iter().map(|(_, r)| *r).collect();
+        let left_unique = left.is_unique_on(&left_cols);
+        let right_unique = right.is_unique_on(&right_cols);
+
+        if !pairs.is_empty() && left_unique == Some(false) && right_unique == Some(false) {
+            let condition = pairs
+                .iter()
+                .map(|(l, r)| {
+                    format!(
+                        "{} = {}",
+                        column_label(left_schema, *l),
+                        column_label(right_schema, *r)
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(" AND ");
+            let span = join
+                .on
+                .iter()
+                .flat_map(|(l, r)| [l, r])
+                .chain(&join.filter)
+                .filter_map(expr_span)
+                .reduce(SourceSpan::union);
+            self.diagnostics.push(Diagnostic {
+                code: DiagnosticCode::A034,
+                severity: Severity::Warning,
+                message: format!(
+                    "Many-to-many join on {}: neither side is unique on the join keys",
+                    condition
+                ),
+                model: ModelName::new(self.model),
+                column: None,
+                hint: Some(
+                    "Aggregate one side to the join grain first, or join on a declared unique key"
+                        .to_string(),
+                ),
+                pass_name: "plan_join_fanout".into(),
+                span,
+            });
+        }
+
+        // A side's rows are repeated when the other side may match several times
+        let right_len = right_schema.fields().len();
+        let mut fanned: HashSet<usize> = left
+            .fanned
+            .iter()
+            .copied()
+            .chain(right.fanned.iter().map(|i| i + offset))
+            .collect();
+        if right_unique == Some(false) {
+            fanned.extend(0..offset);
+        }
+        if left_unique == Some(false) {
+            fanned.extend(offset..offset + right_len);
+        }
+
+        Grain {
+            keys: join_keys(
+                &left,
+                &right,
+                left_unique,
+                right_unique,
+                offset,
+                join.join_type,
+            ),
+            fanned,
+        }
+    }
+
+    /// Check aggregates for double counting (A035); the output grain is the GROUP BY
+    fn aggregate_grain(&mut self, agg: &Aggregate) -> Grain {
+        let input = self.grain(&agg.input);
+        let input_schema = agg.input.schema();
+        let group_len = agg.group_expr.len();
+
+        for (i, expr) in agg.aggr_expr.iter().enumerate() {
+            let output = agg.schema.field(group_len + i).name();
+            self.check_double_counting(expr, &input, input_schema, output);
+        }
+
+        if agg
+            .group_expr
+            .iter()
+            .any(|e| matches!(e, Expr::GroupingSet(_)))
+        {
+            return Grain::unknown();
+        }
+        Grain::keyed(vec![(0..group_len).collect()])
+    }
+
+    /// Flag SUM/AVG/COUNT over columns whose rows a join has repeated (A035)
+    fn check_double_counting(
+        &mut self,
+        expr: &Expr,
+        input: &Grain,
+        input_schema: &DFSchema,
+        output: &str,
+    ) {
+        let Expr::AggregateFunction(func) = strip_alias(expr) else {
+            return;
+        };
+        let func_name = func.func.name().to_uppercase();
+        if !matches!(func_name.as_str(), "SUM" | "AVG" | "COUNT") || func.params.distinct {
+            return;
+        }
+
+        let mut fanned_cols = BTreeSet::new();
+        for arg in &func.params.args {
+            walk_expr_columns(arg, &mut |col| {
+                if let Ok(idx) = input_schema.index_of_column(col) {
+                    if input.fanned.contains(&idx) {
+                        fanned_cols.insert(column_label(input_schema, idx));
+                    }
+                }
+            });
+        }
+        if fanned_cols.is_empty() {
+            return;
+        }
+
+        self.diagnostics.push(Diagnostic {
+            code: DiagnosticCode::A035,
+            severity: Severity::Warning,
+            message: format!(
+                "{} over {} after a join that repeats its rows: '{}' is probably double counted",
+                func_name,
+                fanned_cols.into_iter().collect::<Vec<_>>().join(", "),
+                output
+            ),
+            model: ModelName::new(self.model),
+            column: Some(output.to_string()),
+            hint: Some(
+                "Aggregate before joining, or use a join key that is unique on the other side"
+                    .to_string(),
+            ),
+            pass_name: "plan_join_fanout".into(),
+            span: expr_span(expr),
+        });
+    }
+
+    /// Check that the model's declared keys survive its joins (A036)
+    fn check_declared_keys(&mut self, grain: &Grain, schema: &DFSchema) {
+        if grain.fanned.is_empty() {
+            return;
+        }
+        let Some(keys) = &grain.keys else {
+            return;
+        };
+        for declared in self.ctx.unique_keys(self.model) {
+            let Some(columns) = resolve_key(declared, schema) else {
+                continue;
+            };
+            if keys.iter().any(|key| key.is_subset(&columns)) {
+                continue;
+            }
+            let names: Vec<&str> = declared.iter().map(String::as_str).collect();
+            self.diagnostics.push(Diagnostic {
+                code: DiagnosticCode::A036,
+                severity: Severity::Warning,
+                message: format!(
+                    "Declared unique key ({}) is not guaranteed: a join can repeat output rows",
+                    names.join(", ")
+                ),
+                model: ModelName::new(self.model),
+                column: (names.len() == 1).then(|| names[0].to_string()),
+                hint: Some(
+                    "Join only on keys that are unique on the other side, or aggregate to the declared grain"
+                        .to_string(),
+                ),
+                pass_name: "plan_join_fanout".into(),
+                span: None,
+            });
+        }
+    }
+}
+
+/// Carry keys and fanned columns through a projection
+fn project_grain(input: &Grain, exprs: &[Expr], input_schema: &DFSchema) -> Grain {
+    let mut passthrough: HashMap<usize, usize> = HashMap::new();
+    let mut fanned = HashSet::new();
+    for (out, expr) in exprs.iter().enumerate() {
+        if let Expr::Column(col) = strip_alias(expr) {
+            if let Ok(idx) = input_schema.index_of_column(col) {
+                passthrough.entry(idx).or_insert(out);
+            }
+        }
+        let mut repeated = false;
+        walk_expr_columns(expr, &mut |col| {
+            repeated |= input_schema
+                .index_of_column(col)
+                .is_ok_and(|idx| input.fanned.contains(&idx));
+        });
+        if repeated {
+            fanned.insert(out);
+        }
+    }
+
+    let keys = input.keys.as_ref().map(|keys| {
+        keys.iter()
+            .filter_map(|key| {
+                key.iter()
+                    .map(|idx| passthrough.get(idx).copied())
+                    .collect::<Option<BTreeSet<_>>>()
+            })
+            .collect()
+    });
+    Grain { keys, fanned }
+}
+
+/// Output keys of a join, given whether each side is unique on its join columns
+fn join_keys(
+    left: &Grain,
+    right: &Grain,
+    left_unique: Option<bool>,
+    right_unique: Option<bool>,
+    offset: usize,
+    join_type: JoinType,
+) -> Option<Vec<BTreeSet<usize>>> {
+    let shift = |key: &BTreeSet<usize>| key.iter().map(|i| i + offset).collect::<BTreeSet<_>>();
+
+    // Each row of a preserved side matches at most once when the other side is unique
+    let mut keys = Vec::new();
+    if matches!(join_type, JoinType::Inner | JoinType::Left) && right_unique == Some(true) {
+        keys.extend(left.keys.iter().flatten().cloned());
+    }
+    if matches!(join_type, JoinType::Inner | JoinType::Right) && left_unique == Some(true) {
+        keys.extend(right.keys.iter().flatten().map(shift));
+    }
+    if !keys.is_empty() {
+        return Some(keys);
+    }
+
+    // Otherwise a key from each side together identifies an output row
+    let (left_keys, right_keys) = (left.keys.as_ref()?, right.keys.as_ref()?);
+    Some(
+        left_keys
+            .iter()
+            .flat_map(|l| {
+                right_keys
+                    .iter()
+                    .map(move |r| l.union(&shift(r)).copied().collect())
+            })
+            .collect(),
+    )
+}
+
+/// Resolve a `left = right` column pair to (left index, right index)
+fn key_pair(a: &Expr, b: &Expr, left: &DFSchema, right: &DFSchema) -> Option<(usize, usize)> {
+    let (Expr::Column(a), Expr::Column(b)) = (strip_alias(a), strip_alias(b)) else {
+        return None;
+    };
+    match (left.index_of_column(a), right.index_of_column(b)) {
+        (Ok(l), Ok(r)) => Some((l, r)),
+        _ => Some((
+            left.index_of_column(b).ok()?,
+            right.index_of_column(a).ok()?,
+        )),
+    }
+}
+
+/// Map declared key column names to output indices (`None` if any is missing)
+fn resolve_key(key: &BTreeSet<String>, schema: &DFSchema) -> Option<BTreeSet<usize>> {
+    key.iter()
+        .map(|name| {
+            schema
+                .fields()
+                .iter()
+                .position(|f| f.name().eq_ignore_ascii_case(name))
+        })
+        .collect()
+}
+
+fn column_label(schema: &DFSchema, idx: usize) -> String {
+    let (qualifier, field) = schema.qualified_field(idx);
+    match qualifier {
+        Some(q) => format!("{}.{}", q, field.name()),
+        None => field.name().to_string(),
+    }
+}
+
+#[cfg(test)]
+#[path = "plan_join_fanout_test.rs"]
+mod tests;
@@ -0,0 +1,160 @@
+use std::sync::Arc;
+
+use super::*;
+use crate::datafusion_bridge::propagation::propagate_schemas;
+use crate::schema::{RelSchema, SchemaCatalog};
+use crate::test_utils::*;
+use crate::types::Nullability;
+
+fn catalog() -> SchemaCatalog {
+    let mut catalog: SchemaCatalog = HashMap::new();
+    catalog.insert(
+        "orders".to_string(),
+        Arc::new(RelSchema::new(vec![
+            make_col("id", int32(), Nullability::NotNull),
+            make_col("customer_id", int32(), Nullability::NotNull),
+            make_col("shipping", int32(), Nullability::Nullable),
+        ])),
+    );
+    catalog.insert(
+        "items".to_string(),
+        Arc::new(RelSchema::new(vec![
+            make_col("id", int32(), Nullability::NotNull),
+            make_col("order_id", int32(), Nullability::NotNull),
+            make_col("customer_id", int32(), Nullability::NotNull),
+            make_col("qty", int32(), Nullability::Nullable),
+        ])),
+    );
+    catalog.insert(
+        "customers".to_string(),
+        Arc::new(RelSchema::new(vec![
+            make_col("id", int32(), Nullability::NotNull),
+            make_col("name", varchar(), Nullability::Nullable),
+        ])),
+    );
+    catalog
+}
+
+fn key(columns: &[&str]) -> BTreeSet<String> {
+    columns.iter().map(|c| c.to_string()).collect()
+}
+
+/// Context where orders, items and customers are unique on `id`
+fn keyed_ctx() -> AnalysisContext {
+    let mut ctx = make_ctx();
+    for table in ["orders", "items", "customers"] {
+        ctx.unique_keys
+            .insert(table.to_string(), vec![key(&["id"])]);
+    }
+    ctx
+}
+
+fn run(sql: &str, ctx: &AnalysisContext) -> Vec<Diagnostic> {
+    let mut sql_sources = HashMap::new();
+    sql_sources.insert(ModelName::new("m"), sql.to_string());
+    let result = propagate_schemas(
+        &[ModelName::new("m")],
+        &sql_sources,
+        &HashMap::new(),
+        catalog(),
+        &[],
+        &[],
+    );
+    let plan = &result.model_plans.get("m").expect("model should plan").plan;
+    PlanJoinFanout.run_model("m", plan, ctx)
+}
+
+#[test]
+fn test_a034_many_to_many_join() {
+    let diags = run(
+        "SELECT o.id, i.id AS item_id FROM orders o JOIN items i ON o.customer_id = i.customer_id",
+        &keyed_ctx(),
+    );
+    assert_has_diagnostic(&diags, DiagnosticCode::A034);
+
+    let diags = run(
+        "SELECT o.id, i.qty FROM orders o JOIN items i ON o.id = i.order_id",
+        &keyed_ctx(),
+    );
+    assert_no_diagnostic(&diags, DiagnosticCode::A034);
+}
+
+#[test]
+fn test_a035_sum_over_fanned_out_side() {
+    let diags = run(
+        "SELECT o.id, SUM(o.shipping) AS shipping, SUM(i.qty) AS qty \
+         FROM orders o JOIN items i ON o.id = i.order_id GROUP BY o.id",
+        &keyed_ctx(),
+    );
+    let a035: Vec<_> = diags
+        .iter()
+        .filter(|d| d.code == DiagnosticCode::A035)
+        .collect();
+    assert_eq!(a035.len(), 1, "{:?}", diags);
+    assert_eq!(a035[0].column.as_deref(), Some("shipping"));
+}
+
+#[test]
+fn test_a035_ignores_distinct_min_max_and_count_star() {
+    let diags = run(
+        "SELECT o.id, COUNT(DISTINCT o.shipping) AS d, MAX(o.shipping) AS mx, COUNT(*) AS n \
+         FROM orders o JOIN items i ON o.id = i.order_id GROUP BY o.id",
+        &keyed_ctx(),
+    );
+    assert_no_diagnostic(&diags, DiagnosticCode::A035);
+}
+
+#[test]
+fn test_pre_aggregated_side_is_unique() {
+    let diags = run(
+        "WITH per_order AS (SELECT order_id, SUM(qty) AS qty FROM items GROUP BY order_id) \
+         SELECT o.customer_id, SUM(o.shipping) AS shipping, SUM(p.qty) AS qty \
+         FROM orders o JOIN per_order p ON o.id = p.order_id GROUP BY o.customer_id",
+        &keyed_ctx(),
+    );
+    assert!(diags.is_empty(), "{:?}", diags);
+}
+
+#[test]
+fn test_comma_join_uses_where_equalities() {
+    let diags = run(
+        "SELECT o.id, SUM(o.shipping) AS shipping FROM orders o, items i \
+         WHERE o.id = i.order_id GROUP BY o.id",
+        &keyed_ctx(),
+    );
+    assert_has_diagnostic(&diags, DiagnosticCode::A035);
+}
+
+#[test]
+fn test_undeclared_keys_are_unknown() {
+    let diags = run(
+        "SELECT o.id, SUM(o.shipping) AS shipping FROM orders o \
+         JOIN items i ON o.customer_id = i.customer_id GROUP BY o.id",
+        &make_ctx(),
+    );
+    assert!(diags.is_empty(), "{:?}", diags);
+}
+
+#[test]
+fn test_a036_declared_key_not_guaranteed() {
+    let mut ctx = keyed_ctx();
+    ctx.unique_keys.insert("m".to_string(), vec![key(&["id"])]);
+
+    let diags = run(
+        "SELECT o.id, i.qty FROM orders o JOIN items i ON o.id = i.order_id",
+        &ctx,
+    );
+    let a036: Vec<_> = diags
+        .iter()
+        .filter(|d| d.code == DiagnosticCode::A036)
+        .collect();
+    assert_eq!(a036.len(), 1, "{:?}", diags);
+    assert_eq!(a036[0].column.as_deref(), Some("id"));
+
+    // Joining to a side that is unique on the join key keeps the grain
+    let diags = run(
+        "SELECT o.id, c.name FROM orders o JOIN customers c ON o.customer_id = c.id",
+        &ctx,
+    );
+    assert!(diags.is_empty(), "{:?}", diags);
+}
@@ -10,8 +10,9 @@ use crate::context::AnalysisContext;
 use crate::datafusion_bridge::types::arrow_to_sql_type;
 
 use super::expr_utils::expr_display_name;
+use super::location::expr_span;
 use super::plan_pass::PlanPass;
-use super::{Diagnostic, DiagnosticCode, Severity};
+use super::{Diagnostic, DiagnosticCode, Severity, SourceSpan};
 
 /// Join key analysis pass (DataFusion LogicalPlan)
 pub(crate) struct PlanJoinKeys;
@@ -53,6 +54,7 @@ fn walk_for_joins(model: &str, plan: &LogicalPlan, diags: &mut Vec<Diagnostic>)
                     column: None,
                     hint: Some("This may produce a Cartesian product".to_string()),
                     pass_name: "plan_join_keys".into(),
+                    span: None,
                 });
                 return;
             }
@@ -106,6 +108,10 @@ fn check_equi_join_types(
             column: None,
             hint: Some("Add explicit CASTs to ensure matching types".to_string()),
             pass_name: "plan_join_keys".into(),
+            span: expr_span(left_key)
+                .into_iter()
+                .chain(expr_span(right_key))
+                .reduce(SourceSpan::union),
         });
     }
 }
@@ -131,6 +137,7 @@ fn check_non_equi_condition(model: &str, expr: &Expr, diags: &mut Vec<Diagnostic
                 column: None,
                 hint: Some("Non-equi joins may have performance implications".to_string()),
                 pass_name: "plan_join_keys".into(),
+                span: expr_span(expr),
             });
         }
     }
@@ -0,0 +1,333 @@
+//! NULL-semantics trap pass on DataFusion LogicalPlans (A013-A016)
+//!
+//! Flags SQL constructs whose behaviour with NULLs is easy to get wrong:
+//! `NOT IN` over a nullable subquery column, `<>`/`=` filters that silently
+//! drop NULL rows, `COUNT(col)` on a nullable column, and `||` concatenation
+//! of nullable values.
+//!
+//! A column counts as nullable when its propagated plan type is nullable and
+//! the model or source it is read from does not declare it `not_null`.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
+use datafusion_common::{Column, DFSchema};
+use datafusion_expr::{Expr, LogicalPlan, Operator};
+use ff_core::model::{parse_test_definition, TestType};
+use ff_core::ModelName;
+
+use crate::context::AnalysisContext;
+use crate::types::Nullability;
+
+use super::expr_utils::{relation_tables, strip_alias, strip_cast};
+use super::location::expr_span;
+use super::plan_pass::PlanPass;
+use super::{Diagnostic, DiagnosticCode, Severity, SourceSpan};
+
+/// NULL-semantics trap detection pass (DataFusion LogicalPlan)
+pub(crate) struct PlanNullSemantics;
+
+impl PlanPass for PlanNullSemantics {
+    fn name(&self) -> &'static str {
+        "plan_null_semantics"
+    }
+
+    fn description(&self) -> &'static str {
+        "Detects NOT IN, comparison, COUNT and concatenation traps on nullable columns"
+    }
+
+    fn run_model(
+        &self,
+        model_name: &str,
+        plan: &LogicalPlan,
+        ctx: &AnalysisContext,
+    ) -> Vec<Diagnostic> {
+        let mut checker = NullChecker {
+            model: model_name,
+            ctx,
+            relations: relation_tables(plan),
+            diagnostics: Vec::new(),
+        };
+        let _ = plan.apply_with_subqueries(|node| {
+            checker.check_node(node);
+            Ok(TreeNodeRecursion::Continue)
+        });
+        checker.diagnostics
+    }
+}
+
+struct NullChecker<'a> {
+    model: &'a str,
+    ctx: &'a AnalysisContext,
+    relations: HashMap<String, String>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl NullChecker<'_> {
+    fn check_node(&mut self, node: &LogicalPlan) {
+        let inputs = node.inputs();
+        let schema = match inputs.as_slice() {
+            [input] => Arc::clone(input.schema()),
+            _ => Arc::clone(node.schema()),
+        };
+
+        if let LogicalPlan::Filter(filter) = node {
+            let joins_inputs = matches!(filter.input.as_ref(), LogicalPlan::Join(_));
+            self.check_filter_comparisons(&filter.predicate, &schema, joins_inputs);
+        }
+        if let LogicalPlan::Aggregate(agg) = node {
+            for expr in &agg.aggr_expr {
+                self.check_count(expr, &schema);
+            }
+        }
+
+        let _ = node.apply_expressions(|expr| {
+            expr.apply(|e| {
+                match e {
+                    Expr::InSubquery(in_sub) if in_sub.negated => {
+                        self.check_not_in(&in_sub.subquery.subquery, expr_span(&in_sub.expr));
+                    }
+                    Expr::BinaryExpr(bin) if bin.op == Operator::StringConcat => {
+                        self.check_concat(&bin.left, &bin.right, &schema);
+                    }
+                    _ => {}
+                }
+                Ok(TreeNodeRecursion::Continue)
+            })
+        });
+    }
+
+    /// `x NOT IN (SELECT col ...)` returns no rows once `col` contains a NULL (A013)
+    fn check_not_in(&mut self, subquery: &LogicalPlan, span: Option<SourceSpan>) {
+        let schema = subquery.schema();
+        if schema.fields().is_empty() {
+            return;
+        }
+        let (qualifier, field) = schema.qualified_field(0);
+        let col = Column::new(qualifier.cloned(), field.name());
+        if !self.is_nullable(&col, schema) || filters_out_nulls(subquery, field.name()) {
+            return;
+        }
+        self.push(
+            DiagnosticCode::A013,
+            Severity::Warning,
+            format!(
+                "NOT IN subquery over nullable column '{}': if it contains a NULL, no rows match",
+                field.name()
+            ),
+            field.name(),
+            "Use NOT EXISTS, or add WHERE ... IS NOT NULL to the subquery",
+            span,
+        );
+    }
+
+    /// `<>` drops NULL rows; `=` between two nullable columns never matches NULLs (A014)
+    fn check_filter_comparisons(&mut self, predicate: &Expr, schema: &DFSchema, joins: bool) {
+        let mut guarded: HashSet<String> = HashSet::new();
+        let _ = predicate.apply(|e| {
+            if let Expr::IsNotNull(inner) = e {
+                if let Expr::Column(col) = strip_cast(inner) {
+                    guarded.insert(col.name.to_lowercase());
+                }
+            }
+            Ok(TreeNodeRecursion::Continue)
+        });
+
+        let mut findings: Vec<(String, &'static str, Option<SourceSpan>)> = Vec::new();
+        let _ = predicate.apply(|e| {
+            let Expr::BinaryExpr(bin) = e else {
+                return Ok(TreeNodeRecursion::Continue);
+            };
+            let nullable = |side: &Expr| match strip_cast(side) {
+                Expr::Column(col) if !guarded.contains(&col.name.to_lowercase()) => {
+                    self.is_nullable(col, schema).then(|| col.name.clone())
+                }
+                _ => None,
+            };
+            match bin.op {
+                Operator::NotEq => {
+                    for col in [nullable(&bin.left), nullable(&bin.right)]
+                        .into_iter()
+                        .flatten()
+                    {
+                        findings.push((col, "<>", expr_span(e)));
+                    }
+                }
+                // Equalities directly above a join are join keys
+                Operator::Eq if !joins => {
+                    if let (Some(col), Some(_)) = (nullable(&bin.left), nullable(&bin.right)) {
+                        findings.push((col, "=", expr_span(e)));
+                    }
+                }
+                _ => {}
+            }
+            Ok(TreeNodeRecursion::Continue)
+        });
+
+        for (col, op, span) in findings {
+            let (message, hint) = if op == "<>" {
+                (
+                    format!(
+                        "Filter '{} <> ...' on nullable column silently drops rows where '{}' is NULL",
+                        col, col
+                    ),
+                    "Use IS DISTINCT FROM, or handle NULL explicitly",
+                )
+            } else {
+                (
+                    format!(
+                        "Filter '{} = ...' compares two nullable columns; rows where both are NULL never match",
+                        col
+                    ),
+                    "Use IS NOT DISTINCT FROM if NULLs should match",
+                )
+            };
+            self.push(
+                DiagnosticCode::A014,
+                Severity::Info,
+                message,
+                &col,
+                hint,
+                span,
+            );
+        }
+    }
+
+    /// `COUNT(col)` skips NULLs, which differs from `COUNT(*)` (A015)
+    fn check_count(&mut self, expr: &Expr, schema: &DFSchema) {
+        let Expr::AggregateFunction(func) = strip_alias(expr) else {
+            return;
+        };
+        if !func.func.name().eq_ignore_ascii_case("count") || func.params.distinct {
+            return;
+        }
+        let [arg] = func.params.args.as_slice() else {
+            return;
+        };
+        let Expr::Column(col) = strip_cast(arg) else {
+            return;
+        };
+        if !self.is_nullable(col, schema) {
+            return;
+        }
+        self.push(
+            DiagnosticCode::A015,
+            Severity::Info,
+            format!(
+                "COUNT({}) skips rows where '{}' is NULL and can differ from COUNT(*)",
+                col.name, col.name
+            ),
+            &col.name,
+            "Use COUNT(*) to count rows, or declare the column not_null",
+            expr_span(arg),
+        );
+    }
+
+    /// `a || b` is NULL when either operand is NULL (A016)
+    fn check_concat(&mut self, left: &Expr, right: &Expr, schema: &DFSchema) {
+        for side in [left, right] {
+            let Expr::Column(col) = strip_cast(side) else {
+                continue;
+            };
+            if !self.is_nullable(col, schema) {
+                continue;
+            }
+            self.push(
+                DiagnosticCode::A016,
+                Severity::Warning,
+                format!(
+                    "String concatenation with nullable column '{}': the whole result is NULL when it is NULL",
+                    col.name
+                ),
+                &col.name,
+                "Use concat(), which skips NULLs, or wrap the column in COALESCE",
+                expr_span(side),
+            );
+        }
+    }
+
+    /// Nullable in the plan and not declared `not_null` where it is read from
+    fn is_nullable(&self, col: &Column, schema: &DFSchema) -> bool {
+        let Ok((qualifier, field)) = schema.qualified_field_from_column(col) else {
+            return false;
+        };
+        if !field.is_nullable() {
+            return false;
+        }
+        let Some(table) = qualifier.and_then(|q| self.relations.get(&q.table().to_lowercase()))
+        else {
+            return true;
+        };
+        !self.declared_not_null(table, field.name())
+    }
+
+    fn declared_not_null(&self, table: &str, column: &str) -> bool {
+        if let Some(schema) = self.ctx.model_schema(table) {
+            return schema
+                .find_column(column)
+                .is_some_and(|c| c.nullability == Nullability::NotNull);
+        }
+        self.ctx
+            .project()
+            .sources
+            .iter()
+            .flat_map(|s| &s.tables)
+            .filter(|t| t.name.eq_ignore_ascii_case(table))
+            .flat_map(|t| &t.columns)
+            .filter(|c| c.name.eq_ignore_ascii_case(column))
+            .flat_map(|c| &c.tests)
+            .any(|t| matches!(parse_test_definition(t), Some(TestType::NotNull)))
+    }
+
+    fn push(
+        &mut self,
+        code: DiagnosticCode,
+        severity: Severity,
+        message: String,
+        column: &str,
+        hint: &str,
+        span: Option<SourceSpan>,
+    ) {
+        let duplicate = self
+            .diagnostics
+            .iter()
+            .any(|d| d.code == code && d.message == message);
+        if duplicate {
+            return;
+        }
+        self.diagnostics.push(Diagnostic {
+            code,
+            severity,
+            message,
+            model: ModelName::new(self.model),
+            column: Some(column.to_string()),
+            hint: Some(hint.to_string()),
+            pass_name: "plan_null_semantics".into(),
+            span,
+        });
+    }
+}
+
+/// Whether a subquery filters `column` with IS NOT NULL
+fn filters_out_nulls(plan: &LogicalPlan, column: &str) -> bool {
+    let mut found = false;
+    let _ = plan.apply(|node| {
+        if let LogicalPlan::Filter(filter) = node {
+            let _ = filter.predicate.apply(|e| {
+                if let Expr::IsNotNull(inner) = e {
+                    if let Expr::Column(col) = strip_cast(inner) {
+                        found |= col.name.eq_ignore_ascii_case(column);
+                    }
+                }
+                Ok(TreeNodeRecursion::Continue)
+            });
+        }
+        Ok(TreeNodeRecursion::Continue)
+    });
+    found
+}
+
+#[cfg(test)]
+#[path = "plan_null_semantics_test.rs"]
+mod tests;
@@ -0,0 +1,131 @@
+use super::*;
+use crate::datafusion_bridge::propagation::propagate_schemas;
+use crate::schema::{RelSchema, SchemaCatalog};
+use crate::test_utils::*;
+
+fn run(sql: &str, ctx: &AnalysisContext) -> Vec<Diagnostic> {
+    let mut catalog: SchemaCatalog = HashMap::new();
+    catalog.insert(
+        "orders".to_string(),
+        Arc::new(RelSchema::new(vec![
+            make_col("id", int32(), Nullability::NotNull),
+            make_col("customer_id", int32(), Nullability::Nullable),
+            make_col("status", varchar(), Nullability::Nullable),
+        ])),
+    );
+    catalog.insert(
+        "customers".to_string(),
+        Arc::new(RelSchema::new(vec![
+            make_col("id", int32(), Nullability::Nullable),
+            make_col("first_name", varchar(), Nullability::Nullable),
+            make_col("last_name", varchar(), Nullability::NotNull),
+        ])),
+    );
+    let mut sql_sources = HashMap::new();
+    sql_sources.insert(ModelName::new("m"), sql.to_string());
+    let result = propagate_schemas(
+        &[ModelName::new("m")],
+        &sql_sources,
+        &HashMap::new(),
+        catalog,
+        &[],
+        &[],
+    );
+    let plan = &result.model_plans.get("m").expect("model should plan").plan;
+    PlanNullSemantics.run_model("m", plan, ctx)
+}
+
+#[test]
+fn test_a013_not_in_nullable_subquery() {
+    let ctx = make_ctx();
+    let diags = run(
+        "SELECT id FROM orders WHERE id NOT IN (SELECT id FROM customers)",
+        &ctx,
+    );
+    assert_has_diagnostic(&diags, DiagnosticCode::A013);
+
+    let diags = run(
+        "SELECT id FROM orders WHERE id NOT IN (SELECT id FROM customers WHERE id IS NOT NULL)",
+        &ctx,
+    );
+    assert_no_diagnostic(&diags, DiagnosticCode::A013);
+
+    let diags = run(
+        "SELECT id FROM orders WHERE customer_id IN (SELECT id FROM customers)",
+        &ctx,
+    );
+    assert_no_diagnostic(&diags, DiagnosticCode::A013);
+}
+
+#[test]
+fn test_yaml_not_null_declaration_is_respected() {
+    let mut ctx = make_ctx();
+    ctx.yaml_schemas.insert(
+        ModelName::new("customers"),
+        Arc::new(RelSchema::new(vec![make_col(
+            "id",
+            int32(),
+            Nullability::NotNull,
+        )])),
+    );
+    let diags = run(
+        "SELECT id FROM orders WHERE id NOT IN (SELECT c.id FROM customers c)",
+        &ctx,
+    );
+    assert_no_diagnostic(&diags, DiagnosticCode::A013);
+}
+
+#[test]
+fn test_a014_not_equal_on_nullable_column() {
+    let ctx = make_ctx();
+    let diags = run("SELECT id FROM orders WHERE status <> 'cancelled'", &ctx);
+    assert_has_diagnostic(&diags, DiagnosticCode::A014);
+    assert_eq!(diags[0].column.as_deref(), Some("status"));
+
+    let diags = run(
+        "SELECT id FROM orders WHERE status IS NOT NULL AND status <> 'cancelled'",
+        &ctx,
+    );
+    assert_no_diagnostic(&diags, DiagnosticCode::A014);
+
+    let diags = run("SELECT id FROM orders WHERE id <> 5", &ctx);
+    assert_no_diagnostic(&diags, DiagnosticCode::A014);
+}
+
+#[test]
+fn test_a015_count_nullable_column() {
+    let ctx = make_ctx();
+    let diags = run(
+        "SELECT status, COUNT(customer_id) AS n FROM orders GROUP BY status",
+        &ctx,
+    );
+    assert_has_diagnostic(&diags, DiagnosticCode::A015);
+
+    let diags = run(
+        "SELECT status, COUNT(*) AS n, COUNT(id) AS ids, COUNT(DISTINCT customer_id) AS c \
+         FROM orders GROUP BY status",
+        &ctx,
+    );
+    assert_no_diagnostic(&diags, DiagnosticCode::A015);
+}
+
+#[test]
+fn test_a016_concat_nullable_column() {
+    let ctx = make_ctx();
+    let diags = run(
+        "SELECT first_name || ' ' || last_name AS full_name FROM customers",
+        &ctx,
+    );
+    let columns: Vec<_> = diags
+        .iter()
+        .filter(|d| d.code == DiagnosticCode::A016)
+        .filter_map(|d| d.column.as_deref())
+        .collect();
+    assert_eq!(columns, vec!["first_name"]);
+
+    let diags = run(
+        "SELECT coalesce(first_name, '') || ' ' || last_name AS full_name FROM customers",
+        &ctx,
+    );
+    assert_no_diagnostic(&diags, DiagnosticCode::A016);
+}
@@ -67,6 +67,7 @@ impl PlanPass for PlanNullability {
                         "Wrap with COALESCE() or add an IS NOT NULL filter".to_string(),
                     ),
                     pass_name: "plan_nullability".into(),
+                    span: None,
                 }),
         );
 
@@ -103,6 +104,7 @@ fn check_yaml_nullability_conflicts(
             column: Some(col.name.clone()),
             hint: Some("Add a COALESCE or filter to ensure NOT NULL".to_string()),
             pass_name: "plan_nullability".into(),
+            span: None,
         });
     }
 }
@@ -306,5 +308,6 @@ fn emit_redundant_null_check(
         column: Some(col.name.clone()),
         hint: Some("This check is redundant and can be removed".to_string()),
         pass_name: "plan_nullability".into(),
+        span: None,
     });
 }
@@ -0,0 +1,497 @@
+//! Numeric precision pass on DataFusion LogicalPlans (A006-A008)
+//!
+//! Tracks the precision and scale of exact numeric columns through
+//! `+ - * /`, SUM and CAST, and compares each output column against the type
+//! declared in the model YAML. Integer arithmetic keeps the wider operand
+//! type; decimal arithmetic follows the usual SQL widening rules. SUM of a
+//! decimal adds ten integer digits of headroom and SUM of an integer
+//! produces a BIGINT, as the planner does.
+//!
+//! Only widening that can actually overflow the declared type is reported.
+//! Columns read from another model take that model's declared type, which
+//! its own values are held to, and SUM headroom is not counted: declaring a
+//! total with the precision of the summed column is the common convention.
+//!
+//! Also flags integer division (`//` in DuckDB, `DIV` in MySQL-style SQL),
+//! which drops the fractional part. Plain `/` is not flagged: DuckDB returns
+//! a DOUBLE for `/` even when both operands are integers.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+use arrow::datatypes::DataType as ArrowDataType;
+use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
+use datafusion_common::{DFSchema, ScalarValue};
+use datafusion_expr::{Expr, ExprSchemable, LogicalPlan, Operator};
+use ff_core::ModelName;
+
+use crate::context::AnalysisContext;
+use crate::schema::RelSchema;
+use crate::types::SqlType;
+
+use super::expr_utils::expr_display_name;
+use super::plan_pass::PlanPass;
+use super::{Diagnostic, DiagnosticCode, Severity};
+
+/// Largest decimal precision supported by the planner and DuckDB
+const MAX_PRECISION: u16 = 38;
+
+/// Integer digits SUM adds to a decimal argument's precision
+const SUM_HEADROOM_DIGITS: u16 = 10;
+
+/// SUM of an integer column produces at least a BIGINT
+const BIGINT_DIGITS: u16 = 19;
+
+/// Numeric precision and overflow pass (DataFusion LogicalPlan)
+pub(crate) struct PlanNumericPrecision;
+
+impl PlanPass for PlanNumericPrecision {
+    fn name(&self) -> &'static str {
+        "plan_numeric_precision"
+    }
+
+    fn description(&self) -> &'static str {
+        "Checks decimal precision, integer division, and SUM overflow against declared types"
+    }
+
+    fn run_model(
+        &self,
+        model_name: &str,
+        plan: &LogicalPlan,
+        ctx: &AnalysisContext,
+    ) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        check_integer_division(model_name, plan, &mut diagnostics);
+        if let Some(declared) = ctx.yaml_schemas().get(model_name) {
+            let shapes = plan_shapes(plan, ctx.yaml_schemas());
+            for (field, shape) in plan.schema().fields().iter().zip(shapes) {
+                let Some(inferred) = shape else {
+                    continue;
+                };
+                let Some(column) = declared.find_column(field.name()) else {
+                    continue;
+                };
+                if let Some(diag) =
+                    check_declared(model_name, &column.sql_type, field.name(), inferred)
+                {
+                    diagnostics.push(diag);
+                }
+            }
+        }
+        diagnostics
+    }
+}
+
+/// Precision and scale of an exact numeric value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Shape {
+    precision: u16,
+    scale: u16,
+    /// An integer type rather than a decimal
+    integer: bool,
+    /// Derived from SUM of an integer, which widens the result
+    summed: bool,
+    /// Integer digits added by SUM, which the summed values need not use
+    headroom: u16,
+}
+
+impl Shape {
+    fn integer(digits: u16) -> Self {
+        Self {
+            precision: digits,
+            scale: 0,
+            integer: true,
+            summed: false,
+            headroom: 0,
+        }
+    }
+
+    fn decimal(precision: u16, scale: u16) -> Self {
+        let precision = precision.min(MAX_PRECISION);
+        Self {
+            precision,
+            scale: scale.min(precision),
+            integer: false,
+            summed: false,
+            headroom: 0,
+        }
+    }
+
+    fn integer_digits(self) -> u16 {
+        self.precision - self.scale
+    }
+
+    /// Integer digits the values need, not counting SUM headroom
+    fn required_digits(self) -> u16 {
+        self.integer_digits().saturating_sub(self.headroom)
+    }
+
+    fn from_arrow(data_type: &ArrowDataType) -> Option<Self> {
+        let digits = match data_type {
+            ArrowDataType::Int8 | ArrowDataType::UInt8 => 3,
+            ArrowDataType::Int16 | ArrowDataType::UInt16 => 5,
+            ArrowDataType::Int32 | ArrowDataType::UInt32 => 10,
+            ArrowDataType::Int64 => BIGINT_DIGITS,
+            ArrowDataType::UInt64 => 20,
+            ArrowDataType::Decimal128(p, s) | ArrowDataType::Decimal256(p, s) => {
+                return Some(Self::decimal(u16::from(*p), (*s).max(0) as u16));
+            }
+            _ => return None,
+        };
+        Some(Self::integer(digits))
+    }
+
+    /// Capacity of a declared YAML type; `None` for non-exact or unsized types
+    fn from_declared(sql_type: &SqlType) -> Option<Self> {
+        match sql_type {
+            SqlType::Integer { bits } => Some(Self::integer(bits.max_digits())),
+            SqlType::HugeInt => Some(Self::integer(MAX_PRECISION + 1)),
+            SqlType::Decimal {
+                precision: Some(p),
+                scale,
+            } => Some(Self::decimal(*p, scale.unwrap_or(0))),
+            _ => None,
+        }
+    }
+
+    fn add(self, other: Self) -> Self {
+        if self.integer && other.integer {
+            return self.widest(other);
+        }
+        let scale = self.scale.max(other.scale);
+        let digits = self.integer_digits().max(other.integer_digits()) + 1;
+        self.derived(other, Self::decimal(digits + scale, scale))
+    }
+
+    fn multiply(self, other: Self) -> Self {
+        if self.integer && other.integer {
+            return self.widest(other);
+        }
+        let scale = (self.scale + other.scale).min(MAX_PRECISION);
+        self.derived(
+            other,
+            Self::decimal(self.precision + other.precision, scale),
+        )
+    }
+
+    fn sum(self) -> Self {
+        if self.integer {
+            return Self {
+                summed: true,
+                ..Self::integer(self.precision.max(BIGINT_DIGITS))
+            };
+        }
+        let widened = Self::decimal(self.precision + SUM_HEADROOM_DIGITS, self.scale);
+        Self {
+            headroom: self.headroom + (widened.precision - self.precision),
+            ..widened
+        }
+    }
+
+    /// Shape holding values of either input, as for UNION or COALESCE
+    fn merge(self, other: Self) -> Self {
+        if self.integer && other.integer {
+            return self.widest(other);
+        }
+        let scale = self.scale.max(other.scale);
+        let digits = self.integer_digits().max(other.integer_digits());
+        self.derived(other, Self::decimal(digits + scale, scale))
+    }
+
+    fn widest(self, other: Self) -> Self {
+        let mut widest = if self.precision >= other.precision {
+            self
+        } else {
+            other
+        };
+        widest.summed = self.summed || other.summed;
+        widest.headroom = self.headroom.max(other.headroom);
+        widest
+    }
+
+    fn derived(self, other: Self, mut result: Self) -> Self {
+        result.summed = self.summed || other.summed;
+        result.headroom = self.headroom.max(other.headroom);
+        result
+    }
+}
+
+/// Compute the numeric shape of every output column of a plan node
+///
+/// `declared` holds the YAML schemas of the models the plan may scan.
+fn plan_shapes(
+    plan: &LogicalPlan,
+    declared: &HashMap<ModelName, Arc<RelSchema>>,
+) -> Vec<Option<Shape>> {
+    let shapes = match plan {
+        LogicalPlan::TableScan(scan) => {
+            let schema = declared.get(scan.table_name.table());
+            scan.projected_schema
+                .fields()
+                .iter()
+                .map(|field| {
+                    schema
+                        .and_then(|s| s.find_column(field.name()))
+                        .and_then(|column| Shape::from_declared(&column.sql_type))
+                        .or_else(|| Shape::from_arrow(field.data_type()))
+                })
+                .collect()
+        }
+        LogicalPlan::Projection(proj) => {
+            let input = plan_shapes(&proj.input, declared);
+            proj.expr
+                .iter()
+                .map(|e| expr_shape(e, proj.input.schema(), &input))
+                .collect()
+        }
+        LogicalPlan::Aggregate(agg) => {
+            let input = plan_shapes(&agg.input, declared);
+            agg.group_expr
+                .iter()
+                .chain(&agg.aggr_expr)
+                .map(|e| expr_shape(e, agg.input.schema(), &input))
+                .collect()
+        }
+        LogicalPlan::Window(window) => {
+            let mut shapes = plan_shapes(&window.input, declared);
+            let input_shapes = shapes.clone();
+            shapes.extend(
+                window
+                    .window_expr
+                    .iter()
+                    .map(|e| expr_shape(e, window.input.schema(), &input_shapes)),
+            );
+            shapes
+        }
+        LogicalPlan::Join(join) => {
+            let mut shapes = plan_shapes(&join.left, declared);
+            shapes.extend(plan_shapes(&join.right, declared));
+            shapes
+        }
+        LogicalPlan::Union(union) => {
+            let mut branches = union
+                .inputs
+                .iter()
+                .map(|input| plan_shapes(input, declared));
+            let first = branches.next().unwrap_or_default();
+            branches.fold(first, |acc, branch| {
+                acc.into_iter()
+                    .zip(branch)
+                    .map(|(a, b)| Some(a?.merge(b?)))
+                    .collect()
+            })
+        }
+        LogicalPlan::Filter(filter) => plan_shapes(&filter.input, declared),
+        LogicalPlan::Sort(sort) => plan_shapes(&sort.input, declared),
+        LogicalPlan::Limit(limit) => plan_shapes(&limit.input, declared),
+        LogicalPlan::SubqueryAlias(alias) => plan_shapes(&alias.input, declared),
+        _ => schema_shapes(plan.schema()),
+    };
+    // Nodes that add or drop columns (semi joins, grouping sets) fall back
+    // to the planner's types
+    if shapes.len() == plan.schema().fields().len() {
+        shapes
+    } else {
+        schema_shapes(plan.schema())
+    }
+}
+
+fn schema_shapes(schema: &DFSchema) -> Vec<Option<Shape>> {
+    schema
+        .fields()
+        .iter()
+        .map(|f| Shape::from_arrow(f.data_type()))
+        .collect()
+}
+
+/// Numeric shape of an expression evaluated over `schema`, whose columns
+/// have the shapes in `input`
+fn expr_shape(expr: &Expr, schema: &DFSchema, input: &[Option<Shape>]) -> Option<Shape> {
+    match expr {
+        Expr::Alias(alias) => expr_shape(&alias.expr, schema, input),
+        Expr::Column(col) => schema
+            .index_of_column(col)
+            .ok()
+            .and_then(|i| input.get(i).copied().flatten()),
+        Expr::Literal(value, _) => literal_shape(value),
+        Expr::Cast(cast) => Shape::from_arrow(&cast.data_type),
+        Expr::TryCast(cast) => Shape::from_arrow(&cast.data_type),
+        Expr::Negative(inner) => expr_shape(inner, schema, input),
+        Expr::BinaryExpr(bin)
+            if matches!(
+                bin.op,
+                Operator::Plus | Operator::Minus | Operator::Multiply | Operator::Divide
+            ) =>
+        {
+            let left = expr_shape(&bin.left, schema, input)?;
+            let right = expr_shape(&bin.right, schema, input)?;
+            match bin.op {
+                Operator::Plus | Operator::Minus => Some(left.add(right)),
+                Operator::Multiply => Some(left.multiply(right)),
+                // Integer division keeps the dividend's type; decimal
+                // division produces a floating-point result
+                _ if left.integer && right.integer => Some(left.widest(right)),
+                _ => None,
+            }
+        }
+        Expr::ScalarFunction(func)
+            if matches!(
+                func.func.name().to_lowercase().as_str(),
+                "coalesce" | "nullif" | "ifnull" | "nvl"
+            ) =>
+        {
+            let mut shapes = func.args.iter().map(|a| expr_shape(a, schema, input));
+            let first = shapes.next()??;
+            shapes.try_fold(first, |acc, shape| Some(acc.merge(shape?)))
+        }
+        Expr::AggregateFunction(func) => {
+            let name = func.func.name().to_lowercase();
+            let arg = || {
+                func.params
+                    .args
+                    .first()
+                    .and_then(|a| expr_shape(a, schema, input))
+            };
+            match name.as_str() {
+                "sum" => arg().map(Shape::sum),
+                "min" | "max" | "first_value" | "last_value" | "any_value" => arg(),
+                // Bounded by the number of rows, not by the column type
+                "count" => None,
+                _ => fallback_shape(expr, schema),
+            }
+        }
+        _ => fallback_shape(expr, schema),
+    }
+}
+
+/// Shape of the type the planner assigned to an expression
+fn fallback_shape(expr: &Expr, schema: &DFSchema) -> Option<Shape> {
+    expr.get_type(schema)
+        .ok()
+        .and_then(|dt| Shape::from_arrow(&dt))
+}
+
+/// Integer literals only need as many digits as their value has
+fn literal_shape(value: &ScalarValue) -> Option<Shape> {
+    let magnitude = match value {
+        ScalarValue::Int8(Some(v)) => i64::from(*v).unsigned_abs(),
+        ScalarValue::Int16(Some(v)) => i64::from(*v).unsigned_abs(),
+        ScalarValue::Int32(Some(v)) => i64::from(*v).unsigned_abs(),
+        ScalarValue::Int64(Some(v)) => v.unsigned_abs(),
+        ScalarValue::UInt8(Some(v)) => u64::from(*v),
+        ScalarValue::UInt16(Some(v)) => u64::from(*v),
+        ScalarValue::UInt32(Some(v)) => u64::from(*v),
+        ScalarValue::UInt64(Some(v)) => *v,
+        ScalarValue::Decimal128(Some(_), p, s) => {
+            return Some(Shape::decimal(u16::from(*p), (*s).max(0) as u16));
+        }
+        _ => return None,
+    };
+    Some(Shape::integer(magnitude.to_string().len() as u16))
+}
+
+/// Compare an inferred shape with the column's declared YAML type (A006, A008)
+fn check_declared(
+    model: &str,
+    declared_type: &SqlType,
+    column: &str,
+    inferred: Shape,
+) -> Option<Diagnostic> {
+    let declared = Shape::from_declared(declared_type)?;
+    let declared_name = declared_type.display_name();
+    let inferred_name = if inferred.integer {
+        format!("{}-digit integer", inferred.precision)
+    } else {
+        format!("DECIMAL({},{})", inferred.precision, inferred.scale)
+    };
+
+    // SUM headroom alone does not count as overflow
+    let (code, severity, message, hint) = if inferred.required_digits() > declared.integer_digits()
+    {
+        if declared.integer && inferred.summed {
+            (
+                DiagnosticCode::A008,
+                Severity::Warning,
+                format!(
+                    "SUM in column '{}' produces a {} that may overflow declared type {}",
+                    column, inferred_name, declared_name
+                ),
+                "Declare the column as BIGINT or HUGEINT",
+            )
+        } else if declared.integer && inferred.integer {
+            // Integer columns narrower than the planner's BIGINT are common
+            // (counts, ids); only widened SUM results are reported
+            return None;
+        } else {
+            (
+                DiagnosticCode::A006,
+                Severity::Warning,
+                format!(
+                    "Column '{}' is inferred as {}, which may overflow declared type {}",
+                    column, inferred_name, declared_name
+                ),
+                "Increase the declared precision, or CAST the expression to the declared type",
+            )
+        }
+    } else if inferred.scale > declared.scale {
+        (
+            DiagnosticCode::A006,
+            Severity::Info,
+            format!(
+                "Column '{}' is inferred as {}, which is rounded to {} decimal places by declared type {}",
+                column, inferred_name, declared.scale, declared_name
+            ),
+            "Increase the declared scale, or ROUND the expression explicitly",
+        )
+    } else {
+        return None;
+    };
+
+    Some(Diagnostic {
+        code,
+        severity,
+        message,
+        model: ModelName::new(model),
+        column: Some(column.to_string()),
+        hint: Some(hint.to_string()),
+        pass_name: "plan_numeric_precision".into(),
+        span: None,
+    })
+}
+
+/// Flag `a // b` and `a DIV b` (A007)
+fn check_integer_division(model: &str, plan: &LogicalPlan, diags: &mut Vec<Diagnostic>) {
+    let mut seen: HashSet<String> = HashSet::new();
+    let _ = plan.apply_with_subqueries(|node| {
+        let _ = node.apply_expressions(|expr| {
+            expr.apply(|e| {
+                if let Expr::BinaryExpr(bin) = e {
+                    if bin.op == Operator::IntegerDivide {
+                        let text = expr_display_name(e);
+                        if seen.insert(text.clone()) {
+                            diags.push(Diagnostic {
+                                code: DiagnosticCode::A007,
+                                severity: Severity::Info,
+                                message: format!(
+                                    "Integer division '{}' drops the fractional part",
+                                    text
+                                ),
+                                model: ModelName::new(model),
+                                column: None,
+                                hint: Some("Use `/` if the fraction should be kept".to_string()),
+                                pass_name: "plan_numeric_precision".into(),
+                                span: None,
+                            });
+                        }
+                    }
+                }
+                Ok(TreeNodeRecursion::Continue)
+            })
+        });
+        Ok(TreeNodeRecursion::Continue)
+    });
+}
+
+#[cfg(test)]
+#[path = "plan_numeric_precision_test.rs"]
+mod tests;
@@ -0,0 +1,177 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use super::*;
+use crate::datafusion_bridge::propagation::propagate_schemas;
+use crate::schema::{RelSchema, SchemaCatalog};
+use crate::test_utils::*;
+use crate::types::{IntBitWidth, Nullability};
+
+fn decimal(precision: u16, scale: u16) -> SqlType {
+    SqlType::Decimal {
+        precision: Some(precision),
+        scale: Some(scale),
+    }
+}
+
+fn run(sql: &str, declared: Vec<(&str, SqlType)>) -> Vec<Diagnostic> {
+    let mut catalog: SchemaCatalog = HashMap::new();
+    catalog.insert(
+        "orders".to_string(),
+        Arc::new(RelSchema::new(vec![
+            make_col("id", int32(), Nullability::NotNull),
+            make_col("qty", int32(), Nullability::NotNull),
+            make_col("price", decimal(10, 2), Nullability::NotNull),
+            make_col("rate", decimal(6, 4), Nullability::NotNull),
+        ])),
+    );
+    // An upstream model whose planned type is wider than its declaration
+    catalog.insert(
+        "totals".to_string(),
+        Arc::new(RelSchema::new(vec![make_col(
+            "amount",
+            decimal(20, 2),
+            Nullability::Unknown,
+        )])),
+    );
+    let mut sql_sources = HashMap::new();
+    sql_sources.insert(ModelName::new("m"), sql.to_string());
+    let result = propagate_schemas(
+        &[ModelName::new("m")],
+        &sql_sources,
+        &HashMap::new(),
+        catalog,
+        &[],
+        &[],
+    );
+    let plan = &result.model_plans.get("m").expect("model should plan").plan;
+
+    let mut ctx = make_ctx();
+    let columns = declared
+        .into_iter()
+        .map(|(name, ty)| make_col(name, ty, Nullability::Unknown))
+        .collect();
+    ctx.yaml_schemas
+        .insert(ModelName::new("m"), Arc::new(RelSchema::new(columns)));
+    ctx.yaml_schemas.insert(
+        ModelName::new("totals"),
+        Arc::new(RelSchema::new(vec![make_col(
+            "amount",
+            decimal(10, 2),
+            Nullability::Unknown,
+        )])),
+    );
+    PlanNumericPrecision.run_model("m", plan, &ctx)
+}
+
+#[test]
+fn test_a006_decimal_arithmetic_overflows_declared_type() {
+    let diags = run(
+        "SELECT price * qty AS line_total FROM orders",
+        vec![("line_total", decimal(10, 2))],
+    );
+    assert_has_diagnostic(&diags, DiagnosticCode::A006);
+    assert_eq!(diags[0].severity, Severity::Warning);
+    assert_eq!(diags[0].column.as_deref(), Some("line_total"));
+
+    let diags = run(
+        "SELECT price * qty AS line_total FROM orders",
+        vec![("line_total", decimal(20, 2))],
+    );
+    assert!(diags.is_empty(), "{:?}", diags);
+}
+
+#[test]
+fn test_a006_scale_rounded_by_declared_type() {
+    let diags = run(
+        "SELECT price * rate AS fee FROM orders",
+        vec![("fee", decimal(18, 2))],
+    );
+    assert_has_diagnostic(&diags, DiagnosticCode::A006);
+    assert_eq!(diags[0].severity, Severity::Info);
+}
+
+#[test]
+fn test_sum_and_cast_are_tracked() {
+    let diags = run(
+        "SELECT id, SUM(price) AS total FROM orders GROUP BY id",
+        vec![("total", decimal(8, 2))],
+    );
+    assert_has_diagnostic(&diags, DiagnosticCode::A006);
+
+    let diags = run(
+        "SELECT id, CAST(SUM(price) AS DECIMAL(10,2)) AS total, price + 1 AS bumped \
+         FROM orders GROUP BY id, price",
+        vec![("total", decimal(10, 2)), ("bumped", decimal(11, 2))],
+    );
+    assert!(diags.is_empty(), "{:?}", diags);
+}
+
+#[test]
+fn test_sum_headroom_and_declared_upstream_types_do_not_overflow() {
+    let diags = run(
+        "SELECT id, SUM(price) AS total, COALESCE(SUM(price), 0) AS total_or_zero \
+         FROM orders GROUP BY id",
+        vec![("total", decimal(10, 2)), ("total_or_zero", decimal(10, 2))],
+    );
+    assert!(diags.is_empty(), "{:?}", diags);
+
+    let diags = run(
+        "SELECT amount, COALESCE(amount, 0) AS amount_or_zero FROM totals",
+        vec![
+            ("amount", decimal(10, 2)),
+            ("amount_or_zero", decimal(10, 2)),
+        ],
+    );
+    assert!(diags.is_empty(), "{:?}", diags);
+
+    let diags = run(
+        "SELECT amount * 100 AS cents FROM totals",
+        vec![("cents", decimal(10, 2))],
+    );
+    assert_has_diagnostic(&diags, DiagnosticCode::A006);
+}
+
+#[test]
+fn test_a007_integer_division() {
+    let diags = run("SELECT qty // 2 AS half FROM orders", vec![]);
+    assert_has_diagnostic(&diags, DiagnosticCode::A007);
+
+    let diags = run(
+        "SELECT CAST(qty AS DOUBLE) / 2 AS half, price / qty AS unit FROM orders",
+        vec![],
+    );
+    assert_no_diagnostic(&diags, DiagnosticCode::A007);
+}
+
+#[test]
+fn test_a007_ignores_slash_on_integers() {
+    // DuckDB's `/` returns a DOUBLE even when both operands are integers
+    let diags = run(
+        "SELECT qty / 2 AS half, qty / id AS ratio FROM orders",
+        vec![],
+    );
+    assert_no_diagnostic(&diags, DiagnosticCode::A007);
+}
+
+#[test]
+fn test_a008_sum_of_integer_into_narrow_type() {
+    let integer = SqlType::Integer {
+        bits: IntBitWidth::I32,
+    };
+    let bigint = SqlType::Integer {
+        bits: IntBitWidth::I64,
+    };
+    let diags = run(
+        "SELECT id, SUM(qty) AS total_qty, COUNT(*) AS n FROM orders GROUP BY id",
+        vec![("total_qty", integer.clone()), ("n", integer)],
+    );
+    assert_eq!(diags.len(), 1, "{:?}", diags);
+    assert_eq!(diags[0].code, DiagnosticCode::A008);
+
+    let diags = run(
+        "SELECT id, SUM(qty) AS total_qty FROM orders GROUP BY id",
+        vec![("total_qty", bigint)],
+    );
+    assert!(diags.is_empty(), "{:?}", diags);
+}
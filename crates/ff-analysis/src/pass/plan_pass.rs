@@ -7,9 +7,10 @@ use std::collections::HashMap;
 
 use datafusion_expr::LogicalPlan;
 use ff_core::ModelName;
+use ff_sql::SqlParser;
 
 use crate::context::AnalysisContext;
-use crate::datafusion_bridge::propagation::ModelPlanResult;
+use crate::datafusion_bridge::propagation::{EngineInferredResult, ModelPlanResult};
 
 use super::Diagnostic;
 
@@ -57,13 +58,19 @@ impl PlanPassManager {
         Self {
             model_passes: vec![
                 Box::new(super::plan_type_inference::PlanTypeInference),
+                Box::new(super::plan_numeric_precision::PlanNumericPrecision),
                 Box::new(super::plan_nullability::PlanNullability),
+                Box::new(super::plan_null_semantics::PlanNullSemantics),
                 Box::new(super::plan_join_keys::PlanJoinKeys),
+                Box::new(super::plan_join_fanout::PlanJoinFanout),
+                Box::new(super::plan_determinism::PlanDeterminism),
+                Box::new(super::plan_predicates::PlanPredicates),
             ],
             dag_passes: vec![
                 Box::new(super::plan_unused_columns::PlanUnusedColumns),
                 Box::new(super::plan_cross_model::CrossModelConsistency),
                 Box::new(super::plan_description_drift::PlanDescriptionDrift),
+                Box::new(super::plan_sql_style::PlanSqlStyle),
             ],
         }
     }
@@ -71,6 +78,8 @@ impl PlanPassManager {
     /// Run all passes, returning collected diagnostics
     ///
     /// Models are processed in the order provided (should be topological).
+    /// Column diagnostics that a pass did not locate are given the span of
+    /// their column in the model's SQL.
     pub fn run(
         &self,
         model_order: &[ModelName],
@@ -94,9 +103,31 @@ impl PlanPassManager {
             diagnostics.extend(pass.run_project(models, ctx));
         }
 
+        let dialect = ctx.project().config.dialect.to_string();
+        if let Ok(parser) = SqlParser::from_dialect_name(&dialect) {
+            super::location::locate_column_diagnostics(&mut diagnostics, models, &parser);
+        }
+
         diagnostics
     }
 
+    /// Run the passes that need no plan on engine-inferred models.
+    ///
+    /// Only the SQL style rules qualify; every other pass reads the plan
+    /// these models lack.
+    pub fn run_engine_inferred(
+        &self,
+        models: &HashMap<ModelName, EngineInferredResult>,
+        ctx: &AnalysisContext,
+        pass_filter: Option<&[String]>,
+    ) -> Vec<Diagnostic> {
+        let style = super::plan_sql_style::PlanSqlStyle;
+        if !super::should_run_pass(style.name(), pass_filter) {
+            return Vec::new();
+        }
+        super::plan_sql_style::lint_engine_inferred(models, ctx)
+    }
+
     /// Run all per-model passes on a single model's plan, appending diagnostics.
     fn run_model_passes(
         &self,
@@ -0,0 +1,769 @@
+//! Predicate satisfiability pass on DataFusion LogicalPlans (A080-A082)
+//!
+//! Splits every Filter and join filter into conjuncts, turns each conjunct
+//! that compares a column with literals into a constraint, and intersects
+//! the constraints per column. The `accepted_values`, `min_value`,
+//! `max_value`, `positive` and `non_negative` tests declared on the column's
+//! model or source are folded in as known facts.
+//!
+//! A column whose constraints admit no value makes the predicate always
+//! false (A080); in the ON clause of an outer join it means the join never
+//! matches. A conjunct the column's tests already guarantee is always true
+//! (A081). A conjunct implied by the other conjuncts is redundant (A082).
+//!
+//! Date and timestamp literals compare as instants, so `DATE '2024-01-01'`
+//! and `TIMESTAMP '2024-01-01 00:00:00'` are equal.
+
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use arrow::compute::kernels::cast_utils::string_to_timestamp_nanos;
+use arrow::datatypes::DataType as ArrowDataType;
+use datafusion_common::tree_node::{TreeNode, TreeNodeRecursion};
+use datafusion_common::{Column, ScalarValue};
+use datafusion_expr::logical_plan::JoinType;
+use datafusion_expr::utils::split_conjunction;
+use datafusion_expr::{Expr, LogicalPlan, Operator};
+use ff_core::model::TestType;
+use ff_core::ModelName;
+
+use crate::context::AnalysisContext;
+
+use super::expr_utils::{relation_tables, strip_cast};
+use super::location::column_ref_span;
+use super::plan_pass::PlanPass;
+use super::{Diagnostic, DiagnosticCode, Severity, SourceSpan};
+
+/// Contradictory, always-true and redundant predicate pass (DataFusion LogicalPlan)
+pub(crate) struct PlanPredicates;
+
+impl PlanPass for PlanPredicates {
+    fn name(&self) -> &'static str {
+        "plan_predicates"
+    }
+
+    fn description(&self) -> &'static str {
+        "Detects contradictory, always-true and redundant filter predicates"
+    }
+
+    fn run_model(
+        &self,
+        model_name: &str,
+        plan: &LogicalPlan,
+        ctx: &AnalysisContext,
+    ) -> Vec<Diagnostic> {
+        let mut checker = PredicateChecker {
+            model: model_name,
+            ctx,
+            relations: relation_tables(plan),
+            outer_join: false,
+            diagnostics: Vec::new(),
+        };
+        let _ = plan.apply_with_subqueries(|node| {
+            match node {
+                LogicalPlan::Filter(filter) => checker.check_predicate(&filter.predicate),
+                LogicalPlan::Join(join) => {
+                    if let Some(filter) = &join.filter {
+                        // A condition that never holds only empties the
+                        // result of joins that drop unmatched rows
+                        checker.outer_join = !matches!(
+                            join.join_type,
+                            JoinType::Inner | JoinType::LeftSemi | JoinType::RightSemi
+                        );
+                        checker.check_predicate(filter);
+                        checker.outer_join = false;
+                    }
+                }
+                _ => {}
+            }
+            Ok(TreeNodeRecursion::Continue)
+        });
+        checker.diagnostics
+    }
+}
+
+/// Nanoseconds in a day
+const NANOS_PER_DAY: i128 = 86_400_000_000_000;
+
+/// A literal value a column is compared against
+#[derive(Debug, Clone)]
+enum Value {
+    Number(f64),
+    Text(String),
+    Bool(bool),
+    /// A date or timestamp as nanoseconds since the Unix epoch, with its
+    /// literal text for messages
+    Instant(i128, String),
+}
+
+impl Value {
+    fn from_scalar(value: &ScalarValue) -> Option<Self> {
+        let value = match value {
+            ScalarValue::Boolean(Some(b)) => Value::Bool(*b),
+            ScalarValue::Utf8(Some(s))
+            | ScalarValue::LargeUtf8(Some(s))
+            | ScalarValue::Utf8View(Some(s)) => Value::Text(s.clone()),
+            ScalarValue::Int8(Some(v)) => Value::Number(f64::from(*v)),
+            ScalarValue::Int16(Some(v)) => Value::Number(f64::from(*v)),
+            ScalarValue::Int32(Some(v)) => Value::Number(f64::from(*v)),
+            ScalarValue::Int64(Some(v)) => Value::Number(*v as f64),
+            ScalarValue::UInt8(Some(v)) => Value::Number(f64::from(*v)),
+            ScalarValue::UInt16(Some(v)) => Value::Number(f64::from(*v)),
+            ScalarValue::UInt32(Some(v)) => Value::Number(f64::from(*v)),
+            ScalarValue::UInt64(Some(v)) => Value::Number(*v as f64),
+            ScalarValue::Float32(Some(v)) => Value::Number(f64::from(*v)),
+            ScalarValue::Float64(Some(v)) => Value::Number(*v),
+            ScalarValue::Decimal128(Some(v), _, scale) => {
+                Value::Number(*v as f64 / 10f64.powi(i32::from(*scale)))
+            }
+            ScalarValue::Date32(Some(days)) => {
+                Value::Instant(i128::from(*days) * NANOS_PER_DAY, value.to_string())
+            }
+            ScalarValue::Date64(Some(ms)) => {
+                Value::Instant(i128::from(*ms) * 1_000_000, value.to_string())
+            }
+            ScalarValue::TimestampSecond(Some(v), _) => {
+                Value::Instant(i128::from(*v) * 1_000_000_000, value.to_string())
+            }
+            ScalarValue::TimestampMillisecond(Some(v), _) => {
+                Value::Instant(i128::from(*v) * 1_000_000, value.to_string())
+            }
+            ScalarValue::TimestampMicrosecond(Some(v), _) => {
+                Value::Instant(i128::from(*v) * 1_000, value.to_string())
+            }
+            ScalarValue::TimestampNanosecond(Some(v), _) => {
+                Value::Instant(i128::from(*v), value.to_string())
+            }
+            _ => return None,
+        };
+        Some(value)
+    }
+
+    /// Parse a date or timestamp string such as `2024-01-01` or
+    /// `2024-01-01 12:00:00`
+    fn parse_instant(text: &str) -> Option<Self> {
+        let nanos = string_to_timestamp_nanos(text.trim()).ok()?;
+        Some(Value::Instant(i128::from(nanos), text.to_string()))
+    }
+
+    /// Values from `accepted_values` are strings in YAML; numeric ones compare as numbers
+    fn from_test_value(value: &str) -> Self {
+        value
+            .trim()
+            .parse::<f64>()
+            .map(Value::Number)
+            .unwrap_or_else(|_| Value::Text(value.to_string()))
+    }
+
+    /// Order two values; `None` when they cannot be compared
+    fn compare(&self, other: &Value) -> Option<Ordering> {
+        match (self, other) {
+            (Value::Number(a), Value::Number(b)) => a.partial_cmp(b),
+            (Value::Text(a), Value::Text(b)) => Some(a.cmp(b)),
+            (Value::Bool(a), Value::Bool(b)) => Some(a.cmp(b)),
+            (Value::Number(a), Value::Text(b)) => {
+                b.trim().parse::<f64>().ok().and_then(|b| a.partial_cmp(&b))
+            }
+            (Value::Text(_), Value::Number(_)) => other.compare(self).map(Ordering::reverse),
+            (Value::Bool(a), Value::Text(b)) => b
+                .trim()
+                .to_lowercase()
+                .parse::<bool>()
+                .ok()
+                .map(|b| a.cmp(&b)),
+            (Value::Text(_), Value::Bool(_)) => other.compare(self).map(Ordering::reverse),
+            (Value::Instant(a, _), Value::Instant(b, _)) => Some(a.cmp(b)),
+            (Value::Instant(..), Value::Text(b)) => {
+                Value::parse_instant(b).and_then(|b| self.compare(&b))
+            }
+            (Value::Text(_), Value::Instant(..)) => other.compare(self).map(Ordering::reverse),
+            _ => None,
+        }
+    }
+
+    fn equals(&self, other: &Value) -> bool {
+        self.compare(other) == Some(Ordering::Equal)
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Number(n) => write!(f, "{}", n),
+            Value::Text(s) => write!(f, "'{}'", s),
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Instant(_, text) => write!(f, "'{}'", text),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Bound {
+    value: Value,
+    inclusive: bool,
+}
+
+/// One restriction on the values of a column
+#[derive(Debug, Clone)]
+enum Constraint {
+    Lower(Bound),
+    Upper(Bound),
+    In(Vec<Value>),
+    NotIn(Vec<Value>),
+}
+
+impl Constraint {
+    fn describe(&self, column: &str) -> String {
+        let list = |values: &[Value]| {
+            values
+                .iter()
+                .map(|v| v.to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        match self {
+            Constraint::Lower(b) => {
+                let op = if b.inclusive { ">=" } else { ">" };
+                format!("{} {} {}", column, op, b.value)
+            }
+            Constraint::Upper(b) => {
+                let op = if b.inclusive { "<=" } else { "<" };
+                format!("{} {} {}", column, op, b.value)
+            }
+            Constraint::In(values) if values.len() == 1 => {
+                format!("{} = {}", column, values[0])
+            }
+            Constraint::In(values) => format!("{} IN ({})", column, list(values)),
+            Constraint::NotIn(values) if values.len() == 1 => {
+                format!("{} <> {}", column, values[0])
+            }
+            Constraint::NotIn(values) => format!("{} NOT IN ({})", column, list(values)),
+        }
+    }
+}
+
+/// The set of values a column may take, as the intersection of constraints
+#[derive(Debug, Clone, Default)]
+struct Domain {
+    lower: Option<Bound>,
+    upper: Option<Bound>,
+    allowed: Option<Vec<Value>>,
+    excluded: Vec<Value>,
+}
+
+impl Domain {
+    fn from_constraints<'a>(constraints: impl IntoIterator<Item = &'a Constraint>) -> Self {
+        let mut domain = Domain::default();
+        for constraint in constraints {
+            domain.restrict(constraint);
+        }
+        domain
+    }
+
+    fn restrict(&mut self, constraint: &Constraint) {
+        match constraint {
+            Constraint::Lower(b) => {
+                let tighter =
+                    self.lower
+                        .as_ref()
+                        .is_none_or(|cur| match b.value.compare(&cur.value) {
+                            Some(Ordering::Greater) => true,
+                            Some(Ordering::Equal) => !b.inclusive,
+                            _ => false,
+                        });
+                if tighter {
+                    self.lower = Some(b.clone());
+                }
+            }
+            Constraint::Upper(b) => {
+                let tighter =
+                    self.upper
+                        .as_ref()
+                        .is_none_or(|cur| match b.value.compare(&cur.value) {
+                            Some(Ordering::Less) => true,
+                            Some(Ordering::Equal) => !b.inclusive,
+                            _ => false,
+                        });
+                if tighter {
+                    self.upper = Some(b.clone());
+                }
+            }
+            Constraint::In(values) => {
+                let allowed = match self.allowed.take() {
+                    None => values.clone(),
+                    Some(current) => current
+                        .into_iter()
+                        .filter(|v| values.iter().any(|w| w.equals(v)))
+                        .collect(),
+                };
+                self.allowed = Some(allowed);
+            }
+            Constraint::NotIn(values) => self.excluded.extend(values.iter().cloned()),
+        }
+    }
+
+    /// Whether a value satisfies the bounds and exclusions.
+    /// Values that cannot be compared are assumed to pass.
+    fn admits(&self, value: &Value) -> bool {
+        let above_lower = self.lower.as_ref().is_none_or(|b| {
+            !matches!(
+                (value.compare(&b.value), b.inclusive),
+                (Some(Ordering::Less), _) | (Some(Ordering::Equal), false)
+            )
+        });
+        let below_upper = self.upper.as_ref().is_none_or(|b| {
+            !matches!(
+                (value.compare(&b.value), b.inclusive),
+                (Some(Ordering::Greater), _) | (Some(Ordering::Equal), false)
+            )
+        });
+        above_lower && below_upper && !self.excluded.iter().any(|e| e.equals(value))
+    }
+
+    fn is_empty(&self) -> bool {
+        if let Some(allowed) = &self.allowed {
+            return !allowed.iter().any(|v| self.admits(v));
+        }
+        let (Some(lower), Some(upper)) = (&self.lower, &self.upper) else {
+            return false;
+        };
+        match lower.value.compare(&upper.value) {
+            Some(Ordering::Greater) => true,
+            Some(Ordering::Equal) => {
+                !(lower.inclusive && upper.inclusive) || !self.admits(&lower.value)
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether every value in this domain satisfies `constraint`
+    fn implies(&self, constraint: &Constraint) -> bool {
+        if let Some(allowed) = &self.allowed {
+            let single = Domain::from_constraints([constraint]);
+            return allowed
+                .iter()
+                .filter(|v| self.admits(v))
+                .all(|v| single.admits_known(v));
+        }
+        match constraint {
+            Constraint::Lower(b) => {
+                self.lower
+                    .as_ref()
+                    .is_some_and(|cur| match cur.value.compare(&b.value) {
+                        Some(Ordering::Greater) => true,
+                        Some(Ordering::Equal) => b.inclusive || !cur.inclusive,
+                        _ => false,
+                    })
+            }
+            Constraint::Upper(b) => {
+                self.upper
+                    .as_ref()
+                    .is_some_and(|cur| match cur.value.compare(&b.value) {
+                        Some(Ordering::Less) => true,
+                        Some(Ordering::Equal) => b.inclusive || !cur.inclusive,
+                        _ => false,
+                    })
+            }
+            Constraint::In(_) => false,
+            Constraint::NotIn(values) => values
+                .iter()
+                .all(|v| self.excluded.iter().any(|e| e.equals(v)) || !self.admits_known(v)),
+        }
+    }
+
+    /// Like `admits`, but values that cannot be compared fail
+    fn admits_known(&self, value: &Value) -> bool {
+        let comparable =
+            |b: &Option<Bound>| b.as_ref().is_none_or(|b| value.compare(&b.value).is_some());
+        let in_allowed = self
+            .allowed
+            .as_ref()
+            .is_none_or(|allowed| allowed.iter().any(|a| a.equals(value)));
+        comparable(&self.lower) && comparable(&self.upper) && in_allowed && self.admits(value)
+    }
+}
+
+/// A conjunct that restricts a single column
+struct Restriction {
+    column: Column,
+    constraints: Vec<Constraint>,
+}
+
+impl Restriction {
+    fn describe(&self) -> String {
+        self.constraints
+            .iter()
+            .map(|c| c.describe(&self.column.name))
+            .collect::<Vec<_>>()
+            .join(" AND ")
+    }
+}
+
+struct PredicateChecker<'a> {
+    model: &'a str,
+    ctx: &'a AnalysisContext,
+    relations: HashMap<String, String>,
+    /// Whether the predicate being checked is the ON clause of an outer join
+    outer_join: bool,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl PredicateChecker<'_> {
+    /// How an unsatisfiable predicate is described
+    fn never_holds(&self) -> &'static str {
+        if self.outer_join {
+            "Outer join condition never matches, so no rows are joined"
+        } else {
+            "Predicate is always false"
+        }
+    }
+
+    fn check_predicate(&mut self, predicate: &Expr) {
+        let mut by_column: Vec<Vec<Restriction>> = Vec::new();
+        for conjunct in split_conjunction(predicate) {
+            if let Expr::Literal(ScalarValue::Boolean(Some(value)), _) = conjunct {
+                self.report_constant(*value);
+                continue;
+            }
+            let Some(restriction) = restriction(conjunct) else {
+                continue;
+            };
+            match by_column
+                .iter_mut()
+                .find(|group| group[0].column == restriction.column)
+            {
+                Some(group) => group.push(restriction),
+                None => by_column.push(vec![restriction]),
+            }
+        }
+        for group in by_column {
+            self.check_column(&group);
+        }
+    }
+
+    fn check_column(&mut self, restrictions: &[Restriction]) {
+        let column = restrictions[0].column.clone();
+        let (fact_names, facts) = self.column_facts(&column);
+        let fact_domain = Domain::from_constraints(&facts);
+        let all_constraints = restrictions.iter().flat_map(|r| &r.constraints);
+        let span = restrictions
+            .iter()
+            .filter_map(|r| column_ref_span(&r.column))
+            .reduce(SourceSpan::union);
+
+        // Always false (A080)
+        let predicate_domain = Domain::from_constraints(all_constraints.clone());
+        if predicate_domain.is_empty() {
+            let parts: Vec<String> = restrictions.iter().map(Restriction::describe).collect();
+            self.push(
+                DiagnosticCode::A080,
+                Severity::Warning,
+                format!(
+                    "{}: {} cannot all hold",
+                    self.never_holds(),
+                    parts.join(" AND ")
+                ),
+                &column.name,
+                "Fix or remove the contradictory conditions",
+                span,
+            );
+            return;
+        }
+        let full = Domain::from_constraints(facts.iter().chain(all_constraints));
+        if full.is_empty() {
+            let parts: Vec<String> = restrictions.iter().map(Restriction::describe).collect();
+            self.push(
+                DiagnosticCode::A080,
+                Severity::Warning,
+                format!(
+                    "{}: {} excludes every value allowed by the column's {} test",
+                    self.never_holds(),
+                    parts.join(" AND "),
+                    fact_names.join("/")
+                ),
+                &column.name,
+                "Fix the filter, or update the column's tests if the accepted values changed",
+                span,
+            );
+            return;
+        }
+
+        // Always true (A081) and redundant (A082)
+        let mut redundant: HashSet<usize> = HashSet::new();
+        for (i, restriction) in restrictions.iter().enumerate() {
+            if !facts.is_empty()
+                && restriction
+                    .constraints
+                    .iter()
+                    .all(|c| fact_domain.implies(c))
+            {
+                self.push(
+                    DiagnosticCode::A081,
+                    Severity::Info,
+                    format!(
+                        "Predicate {} is always true given the column's {} test; it only filters out NULLs",
+                        restriction.describe(),
+                        fact_names.join("/")
+                    ),
+                    &column.name,
+                    "Remove the condition, or use IS NOT NULL if that is the intent",
+                    column_ref_span(&restriction.column),
+                );
+                redundant.insert(i);
+                continue;
+            }
+            let others = Domain::from_constraints(
+                restrictions
+                    .iter()
+                    .enumerate()
+                    .filter(|(j, _)| *j != i && !redundant.contains(j))
+                    .flat_map(|(_, r)| &r.constraints),
+            );
+            let has_others = restrictions.len() - redundant.len() > 1;
+            if has_others && restriction.constraints.iter().all(|c| others.implies(c)) {
+                self.push(
+                    DiagnosticCode::A082,
+                    Severity::Info,
+                    format!(
+                        "Predicate {} is redundant: the other conditions on '{}' already imply it",
+                        restriction.describe(),
+                        column.name
+                    ),
+                    &column.name,
+                    "Remove the redundant condition",
+                    column_ref_span(&restriction.column),
+                );
+                redundant.insert(i);
+            }
+        }
+    }
+
+    /// Constraints implied by the tests declared on the column's table
+    fn column_facts(&self, column: &Column) -> (Vec<String>, Vec<Constraint>) {
+        let table = column
+            .relation
+            .as_ref()
+            .and_then(|r| self.relations.get(&r.table().to_lowercase()));
+        let Some(table) = table else {
+            return (Vec::new(), Vec::new());
+        };
+        let mut names = Vec::new();
+        let mut facts = Vec::new();
+        for test in self.ctx.column_tests(table, &column.name) {
+            let fact = match test {
+                TestType::AcceptedValues { values, .. } => {
+                    Constraint::In(values.iter().map(|v| Value::from_test_value(v)).collect())
+                }
+                TestType::MinValue { value } => Constraint::Lower(Bound {
+                    value: Value::Number(*value),
+                    inclusive: true,
+                }),
+                TestType::MaxValue { value } => Constraint::Upper(Bound {
+                    value: Value::Number(*value),
+                    inclusive: true,
+                }),
+                TestType::Positive => Constraint::Lower(Bound {
+                    value: Value::Number(0.0),
+                    inclusive: false,
+                }),
+                TestType::NonNegative => Constraint::Lower(Bound {
+                    value: Value::Number(0.0),
+                    inclusive: true,
+                }),
+                _ => continue,
+            };
+            names.push(test.to_string());
+            facts.push(fact);
+        }
+        (names, facts)
+    }
+
+    fn report_constant(&mut self, value: bool) {
+        let (code, severity, message, hint) = if value {
+            (
+                DiagnosticCode::A081,
+                Severity::Info,
+                "Predicate contains a condition that is always TRUE".to_string(),
+                "Remove the constant condition",
+            )
+        } else {
+            (
+                DiagnosticCode::A080,
+                Severity::Warning,
+                format!("{}: it contains a FALSE condition", self.never_holds()),
+                "Remove the constant condition, or the whole filter if no rows are wanted",
+            )
+        };
+        self.diagnostics.push(Diagnostic {
+            code,
+            severity,
+            message,
+            model: ModelName::new(self.model),
+            column: None,
+            hint: Some(hint.to_string()),
+            pass_name: "plan_predicates".into(),
+            span: None,
+        });
+    }
+
+    fn push(
+        &mut self,
+        code: DiagnosticCode,
+        severity: Severity,
+        message: String,
+        column: &str,
+        hint: &str,
+        span: Option<SourceSpan>,
+    ) {
+        self.diagnostics.push(Diagnostic {
+            code,
+            severity,
+            message,
+            model: ModelName::new(self.model),
+            column: Some(column.to_string()),
+            hint: Some(hint.to_string()),
+            pass_name: "plan_predicates".into(),
+            span,
+        });
+    }
+}
+
+/// Interpret a conjunct as constraints on one column compared with literals
+fn restriction(expr: &Expr) -> Option<Restriction> {
+    let (column, constraints) = match expr {
+        Expr::BinaryExpr(bin) => {
+            if bin.op == Operator::Or {
+                return disjunction(expr);
+            }
+            let (column, op, value) = match (strip_cast(&bin.left), strip_cast(&bin.right)) {
+                (Expr::Column(col), Expr::Literal(..)) => (col, bin.op, literal(&bin.right)?),
+                (Expr::Literal(..), Expr::Column(col)) => {
+                    (col, bin.op.swap()?, literal(&bin.left)?)
+                }
+                _ => return None,
+            };
+            let bound = |inclusive| Bound {
+                value: value.clone(),
+                inclusive,
+            };
+            let constraint = match op {
+                Operator::Eq => Constraint::In(vec![value.clone()]),
+                Operator::NotEq => Constraint::NotIn(vec![value.clone()]),
+                Operator::Gt => Constraint::Lower(bound(false)),
+                Operator::GtEq => Constraint::Lower(bound(true)),
+                Operator::Lt => Constraint::Upper(bound(false)),
+                Operator::LtEq => Constraint::Upper(bound(true)),
+                _ => return None,
+            };
+            (column, vec![constraint])
+        }
+        Expr::InList(in_list) => {
+            let Expr::Column(column) = strip_cast(&in_list.expr) else {
+                return None;
+            };
+            let values = in_list
+                .list
+                .iter()
+                .map(literal)
+                .collect::<Option<Vec<_>>>()?;
+            let constraint = if in_list.negated {
+                Constraint::NotIn(values)
+            } else {
+                Constraint::In(values)
+            };
+            (column, vec![constraint])
+        }
+        Expr::Between(between) if !between.negated => {
+            let Expr::Column(column) = strip_cast(&between.expr) else {
+                return None;
+            };
+            let bound = |expr| {
+                Some(Bound {
+                    value: literal(expr)?,
+                    inclusive: true,
+                })
+            };
+            (
+                column,
+                vec![
+                    Constraint::Lower(bound(&between.low)?),
+                    Constraint::Upper(bound(&between.high)?),
+                ],
+            )
+        }
+        _ => return None,
+    };
+    Some(Restriction {
+        column: column.clone(),
+        constraints,
+    })
+}
+
+/// The value of a literal, looking through casts.
+///
+/// A string cast to a date or timestamp type, as `DATE '2024-01-01'` is
+/// planned, becomes an instant.
+fn literal(expr: &Expr) -> Option<Value> {
+    if let Expr::Cast(datafusion_expr::Cast {
+        expr: inner,
+        data_type,
+    })
+    | Expr::TryCast(datafusion_expr::TryCast {
+        expr: inner,
+        data_type,
+    }) = expr
+    {
+        if matches!(
+            data_type,
+            ArrowDataType::Date32 | ArrowDataType::Date64 | ArrowDataType::Timestamp(..)
+        ) {
+            if let Expr::Literal(
+                ScalarValue::Utf8(Some(text))
+                | ScalarValue::LargeUtf8(Some(text))
+                | ScalarValue::Utf8View(Some(text)),
+                _,
+            ) = strip_cast(inner)
+            {
+                return Value::parse_instant(text);
+            }
+        }
+    }
+    match strip_cast(expr) {
+        Expr::Literal(value, _) => Value::from_scalar(value),
+        _ => None,
+    }
+}
+
+/// `col = a OR col = b OR col IN (c)` on a single column is an IN list
+fn disjunction(expr: &Expr) -> Option<Restriction> {
+    let mut column: Option<Column> = None;
+    let mut values = Vec::new();
+    let mut pending = vec![expr];
+    while let Some(e) = pending.pop() {
+        if let Expr::BinaryExpr(bin) = e {
+            if bin.op == Operator::Or {
+                pending.push(&bin.left);
+                pending.push(&bin.right);
+                continue;
+            }
+        }
+        let branch = restriction(e)?;
+        if column.as_ref().is_some_and(|c| *c != branch.column) {
+            return None;
+        }
+        let [Constraint::In(branch_values)] = branch.constraints.as_slice() else {
+            return None;
+        };
+        values.extend(branch_values.iter().cloned());
+        column = Some(branch.column);
+    }
+    Some(Restriction {
+        column: column?,
+        constraints: vec![Constraint::In(values)],
+    })
+}
+
+#[cfg(test)]
+#[path = "plan_predicates_test.rs"]
+mod tests;
@@ -0,0 +1,198 @@
+use std::sync::Arc;
+
+use super::*;
+use crate::datafusion_bridge::propagation::propagate_schemas;
+use crate::schema::{RelSchema, SchemaCatalog};
+use crate::test_utils::*;
+use crate::types::Nullability;
+
+fn run(sql: &str, ctx: &AnalysisContext) -> Vec<Diagnostic> {
+    let mut catalog: SchemaCatalog = HashMap::new();
+    catalog.insert(
+        "orders".to_string(),
+        Arc::new(RelSchema::new(vec![
+            make_col("id", int32(), Nullability::NotNull),
+            make_col("amount", int32(), Nullability::Nullable),
+            make_col("status", varchar(), Nullability::Nullable),
+            make_col("order_date", varchar(), Nullability::Nullable),
+            make_col("ship_date", date(), Nullability::Nullable),
+        ])),
+    );
+    let mut sql_sources = HashMap::new();
+    sql_sources.insert(ModelName::new("m"), sql.to_string());
+    let result = propagate_schemas(
+        &[ModelName::new("m")],
+        &sql_sources,
+        &HashMap::new(),
+        catalog,
+        &[],
+        &[],
+    );
+    let plan = &result.model_plans.get("m").expect("model should plan").plan;
+    PlanPredicates.run_model("m", plan, ctx)
+}
+
+/// Context where orders.status has accepted_values and orders.amount is non_negative
+fn tested_ctx() -> AnalysisContext {
+    let mut ctx = make_ctx();
+    let mut columns = HashMap::new();
+    columns.insert(
+        "status".to_string(),
+        vec![TestType::AcceptedValues {
+            values: vec!["pending".to_string(), "completed".to_string()],
+            quote: true,
+        }],
+    );
+    columns.insert("amount".to_string(), vec![TestType::NonNegative]);
+    ctx.column_tests.insert("orders".to_string(), columns);
+    ctx
+}
+
+fn codes(diags: &[Diagnostic]) -> Vec<DiagnosticCode> {
+    diags.iter().map(|d| d.code).collect()
+}
+
+#[test]
+fn test_a080_contradictory_equalities() {
+    let diags = run(
+        "SELECT id FROM orders WHERE status = 'a' AND status = 'b'",
+        &make_ctx(),
+    );
+    assert_eq!(codes(&diags), vec![DiagnosticCode::A080], "{:?}", diags);
+    assert_eq!(diags[0].column.as_deref(), Some("status"));
+}
+
+#[test]
+fn test_a080_empty_range() {
+    let diags = run(
+        "SELECT id FROM orders WHERE order_date > '2025-01-01' AND order_date < '2024-01-01'",
+        &make_ctx(),
+    );
+    assert_has_diagnostic(&diags, DiagnosticCode::A080);
+
+    let diags = run(
+        "SELECT id FROM orders WHERE order_date >= '2024-01-01' AND order_date < '2025-01-01'",
+        &make_ctx(),
+    );
+    assert!(diags.is_empty(), "{:?}", diags);
+}
+
+#[test]
+fn test_a080_excludes_all_accepted_values() {
+    let diags = run(
+        "SELECT id FROM orders WHERE status NOT IN ('pending', 'completed')",
+        &tested_ctx(),
+    );
+    assert_has_diagnostic(&diags, DiagnosticCode::A080);
+
+    let diags = run("SELECT id FROM orders WHERE amount < 0", &tested_ctx());
+    assert_has_diagnostic(&diags, DiagnosticCode::A080);
+
+    let diags = run(
+        "SELECT id FROM orders WHERE status = 'pending' OR status = 'shipped'",
+        &tested_ctx(),
+    );
+    assert!(diags.is_empty(), "{:?}", diags);
+}
+
+#[test]
+fn test_a081_always_true_given_tests() {
+    let diags = run(
+        "SELECT id FROM orders WHERE status IN ('pending', 'completed', 'cancelled')",
+        &tested_ctx(),
+    );
+    assert_eq!(codes(&diags), vec![DiagnosticCode::A081], "{:?}", diags);
+
+    let diags = run("SELECT id FROM orders WHERE amount >= 0", &tested_ctx());
+    assert_has_diagnostic(&diags, DiagnosticCode::A081);
+
+    let diags = run("SELECT id FROM orders WHERE amount > 0", &tested_ctx());
+    assert!(diags.is_empty(), "{:?}", diags);
+}
+
+#[test]
+fn test_a082_redundant_conjunct() {
+    let diags = run(
+        "SELECT id FROM orders WHERE amount > 100 AND amount > 10",
+        &make_ctx(),
+    );
+    assert_eq!(codes(&diags), vec![DiagnosticCode::A082], "{:?}", diags);
+    assert!(diags[0].message.contains("amount > 10 is redundant"));
+
+    let diags = run(
+        "SELECT id FROM orders WHERE status = 'a' AND status = 'a'",
+        &make_ctx(),
+    );
+    assert_eq!(codes(&diags), vec![DiagnosticCode::A082], "{:?}", diags);
+}
+
+#[test]
+fn test_column_comparisons_are_ignored() {
+    let diags = run(
+        "SELECT o.id FROM orders o JOIN orders p ON o.id = p.id AND o.amount > p.amount \
+         WHERE o.amount BETWEEN p.amount AND p.amount",
+        &tested_ctx(),
+    );
+    assert!(diags.is_empty(), "{:?}", diags);
+}
+
+#[test]
+fn test_date_and_timestamp_scalars_compare_as_instants() {
+    let date = Value::from_scalar(&ScalarValue::Date32(Some(19723))).unwrap();
+    let midnight = Value::from_scalar(&ScalarValue::TimestampMicrosecond(
+        Some(1_704_067_200_000_000),
+        None,
+    ))
+    .unwrap();
+    let noon =
+        Value::from_scalar(&ScalarValue::TimestampSecond(Some(1_704_110_400), None)).unwrap();
+    assert!(date.equals(&midnight));
+    assert_eq!(date.compare(&noon), Some(Ordering::Less));
+    assert_eq!(
+        date.compare(&Value::Text("2024-01-02".to_string())),
+        Some(Ordering::Less)
+    );
+    assert_eq!(date.to_string(), "'2024-01-01'");
+}
+
+#[test]
+fn test_a080_on_date_column() {
+    let diags = run(
+        "SELECT id FROM orders WHERE ship_date > DATE '2025-01-01' AND ship_date < DATE '2024-01-01'",
+        &make_ctx(),
+    );
+    assert_eq!(codes(&diags), vec![DiagnosticCode::A080], "{:?}", diags);
+    assert_eq!(diags[0].column.as_deref(), Some("ship_date"));
+
+    // Midnight of a day equals the date, although the literals differ as text
+    let diags = run(
+        "SELECT id FROM orders \
+         WHERE ship_date >= TIMESTAMP '2024-01-01 00:00:00' AND ship_date <= DATE '2024-01-01'",
+        &make_ctx(),
+    );
+    assert!(diags.is_empty(), "{:?}", diags);
+}
+
+#[test]
+fn test_a080_outer_join_condition_never_matches() {
+    let diags = run(
+        "SELECT o.id FROM orders o LEFT JOIN orders p \
+         ON o.id = p.id AND p.amount > 10 AND p.amount < 5",
+        &make_ctx(),
+    );
+    assert_eq!(codes(&diags), vec![DiagnosticCode::A080], "{:?}", diags);
+    assert!(
+        diags[0]
+            .message
+            .starts_with("Outer join condition never matches"),
+        "{}",
+        diags[0].message
+    );
+
+    let diags = run(
+        "SELECT o.id FROM orders o JOIN orders p \
+         ON o.id = p.id AND p.amount > 10 AND p.amount < 5",
+        &make_ctx(),
+    );
+    assert!(diags[0].message.starts_with("Predicate is always false"));
+}
@@ -0,0 +1,485 @@
+//! SQL style lint pass (L001-L006)
+//!
+//! Opt-in style rules configured under `analysis.lint`. The DataFusion plan
+//! has already expanded wildcards and resolved ordinals, so most rules walk
+//! the sqlparser AST of the rendered SQL instead; the plan supplies the
+//! expanded output schema reported by L001.
+//!
+//! - L001: `SELECT *` in a model tagged as a mart
+//! - L002: Implicit comma join (`FROM a, b`)
+//! - L003: GROUP BY / ORDER BY by ordinal position
+//! - L004: Unqualified column reference in a multi-table query
+//! - L005: `UNION` without `ALL` or `DISTINCT`
+//! - L006: Node name does not use its layer's prefix
+//!
+//! Each rule's default severity can be replaced per rule through
+//! `analysis.lint.rules`.
+
+use std::collections::{BTreeSet, HashMap, HashSet};
+use std::ops::ControlFlow;
+
+use ff_core::config::LintConfig;
+use ff_core::ModelName;
+use ff_sql::SqlParser;
+use sqlparser::ast::{
+    Expr, GroupByExpr, JoinConstraint, JoinOperator, OrderByKind, Query, Select, SelectItem,
+    SetExpr, SetOperator, SetQuantifier, Statement, TableFactor, TableWithJoins, Visit, Visitor,
+};
+
+use crate::context::AnalysisContext;
+use crate::datafusion_bridge::propagation::{EngineInferredResult, ModelPlanResult};
+
+use super::plan_pass::DagPlanPass;
+use super::{apply_severity_overrides, Diagnostic, DiagnosticCode, Severity, SeverityOverrides};
+
+/// DAG-level pass running the configurable SQL style rules.
+pub(crate) struct PlanSqlStyle;
+
+impl DagPlanPass for PlanSqlStyle {
+    fn name(&self) -> &'static str {
+        "sql_style"
+    }
+
+    fn description(&self) -> &'static str {
+        "Configurable SQL style rules (SELECT * in marts, comma joins, ordinals, naming)"
+    }
+
+    fn run_project(
+        &self,
+        models: &HashMap<ModelName, ModelPlanResult>,
+        ctx: &AnalysisContext,
+    ) -> Vec<Diagnostic> {
+        lint_models(
+            models
+                .iter()
+                .map(|(name, r)| (name.as_str(), r.sql.as_str(), r.inferred_schema.len())),
+            ctx,
+        )
+    }
+}
+
+/// Run the lint rules on models whose schema was engine-inferred.
+///
+/// The rules read the SQL rather than the plan, so these models are linted
+/// like planned ones.
+pub(crate) fn lint_engine_inferred(
+    models: &HashMap<ModelName, EngineInferredResult>,
+    ctx: &AnalysisContext,
+) -> Vec<Diagnostic> {
+    lint_models(
+        models
+            .iter()
+            .map(|(name, r)| (name.as_str(), r.sql.as_str(), r.inferred_schema.len())),
+        ctx,
+    )
+}
+
+/// Lint `(model, rendered SQL, output column count)` entries in name order.
+fn lint_models<'a>(
+    models: impl Iterator<Item = (&'a str, &'a str, usize)>,
+    ctx: &AnalysisContext,
+) -> Vec<Diagnostic> {
+    let project = ctx.project();
+    let config = &project.config.analysis.lint;
+    if !config.enabled {
+        return Vec::new();
+    }
+    let Ok(parser) = SqlParser::from_dialect_name(&project.config.dialect.to_string()) else {
+        return Vec::new();
+    };
+
+    let mut models: Vec<_> = models.collect();
+    models.sort_unstable_by_key(|(name, _, _)| *name);
+
+    let mut diagnostics = Vec::new();
+    for (name, sql, output_columns) in models {
+        let target = project
+            .get_model(name)
+            .map(|m| LintTarget {
+                tags: &m.config.tags,
+                schema: m.config.schema.as_deref(),
+            })
+            .unwrap_or_default();
+
+        diagnostics.extend(check_naming(name, &target, config));
+
+        let Ok(stmts) = parser.parse(sql) else {
+            continue;
+        };
+        for stmt in &stmts {
+            diagnostics.extend(lint_statement(name, stmt, &target, config, output_columns));
+        }
+    }
+
+    apply_severity_overrides(diagnostics, &SeverityOverrides::from_config(&config.rules))
+}
+
+/// The parts of a model's configuration the lint rules depend on.
+#[derive(Debug, Default)]
+pub(crate) struct LintTarget<'a> {
+    /// Model tags
+    pub tags: &'a [String],
+    /// Target schema, if configured
+    pub schema: Option<&'a str>,
+}
+
+impl LintTarget<'_> {
+    /// A model belongs to a group when it has a tag or targets a schema of that name.
+    fn in_group(&self, group: &str) -> bool {
+        self.tags.iter().any(|t| t == group) || self.schema == Some(group)
+    }
+}
+
+/// Run the AST-based rules (L001-L005) on one statement.
+///
+/// `output_columns` is the width of the model's planned output schema, used
+/// to report how many columns a mart's `SELECT *` expands to.
+pub(crate) fn lint_statement(
+    model: &str,
+    stmt: &Statement,
+    target: &LintTarget,
+    config: &LintConfig,
+    output_columns: usize,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+
+    if let Statement::Query(query) = stmt {
+        let is_mart = config.mart_tags.iter().any(|t| target.in_group(t));
+        if is_mart && has_wildcard(&query.body) {
+            diagnostics.push(diagnostic(
+                DiagnosticCode::L001,
+                Severity::Warning,
+                model,
+                format!(
+                    "Mart model selects * ({} output columns) instead of listing its columns",
+                    output_columns
+                ),
+                None,
+                "List the output columns explicitly so upstream changes cannot silently alter the mart",
+            ));
+        }
+    }
+
+    let mut linter = QueryLinter {
+        model,
+        diagnostics: &mut diagnostics,
+    };
+    let _ = stmt.visit(&mut linter);
+
+    diagnostics
+}
+
+/// Check the node name against the prefixes required for its layer (L006).
+pub(crate) fn check_naming(
+    model: &str,
+    target: &LintTarget,
+    config: &LintConfig,
+) -> Option<Diagnostic> {
+    let mut layers: Vec<(&String, &Vec<String>)> = config.naming.iter().collect();
+    layers.sort();
+    let (layer, prefixes) = layers
+        .into_iter()
+        .find(|(layer, prefixes)| !prefixes.is_empty() && target.in_group(layer))?;
+
+    if prefixes.iter().any(|p| model.starts_with(p.as_str())) {
+        return None;
+    }
+
+    let expected = prefixes
+        .iter()
+        .map(|p| format!("'{p}'"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    Some(diagnostic(
+        DiagnosticCode::L006,
+        Severity::Warning,
+        model,
+        format!("Node is in layer '{layer}' but its name does not start with {expected}"),
+        None,
+        "Rename the node directory and its files to use the layer prefix",
+    ))
+}
+
+/// Visits every query in a statement (CTEs, derived tables and subqueries
+/// included) and applies the per-query rules.
+struct QueryLinter<'a> {
+    model: &'a str,
+    diagnostics: &'a mut Vec<Diagnostic>,
+}
+
+impl Visitor for QueryLinter<'_> {
+    type Break = ();
+
+    fn pre_visit_query(&mut self, query: &Query) -> ControlFlow<()> {
+        check_unions(self.model, &query.body, self.diagnostics);
+
+        if let Some(order_by) = &query.order_by {
+            if let OrderByKind::Expressions(exprs) = &order_by.kind {
+                let ordinals: Vec<String> = exprs
+                    .iter()
+                    .map(|e| &e.expr)
+                    .filter(|e| is_ordinal(e))
+                    .map(|e| e.to_string())
+                    .collect();
+                if !ordinals.is_empty() {
+                    self.diagnostics
+                        .push(ordinal_diagnostic(self.model, "ORDER BY", &ordinals));
+                }
+            }
+        }
+
+        for_each_select(&query.body, &mut |select| {
+            check_select(self.model, select, self.diagnostics)
+        });
+
+        ControlFlow::Continue(())
+    }
+}
+
+/// Call `f` on each SELECT of a set expression. Parenthesized subqueries are
+/// skipped because the visitor reaches them as queries of their own.
+fn for_each_select(body: &SetExpr, f: &mut impl FnMut(&Select)) {
+    match body {
+        SetExpr::Select(select) => f(select),
+        SetExpr::SetOperation { left, right, .. } => {
+            for_each_select(left, f);
+            for_each_select(right, f);
+        }
+        _ => {}
+    }
+}
+
+/// Whether any top-level SELECT of the body projects `*` or `alias.*`.
+fn has_wildcard(body: &SetExpr) -> bool {
+    match body {
+        SetExpr::Select(select) => select.projection.iter().any(|item| {
+            matches!(
+                item,
+                SelectItem::Wildcard(_) | SelectItem::QualifiedWildcard(..)
+            )
+        }),
+        SetExpr::SetOperation { left, right, .. } => has_wildcard(left) || has_wildcard(right),
+        SetExpr::Query(query) => has_wildcard(&query.body),
+        _ => false,
+    }
+}
+
+/// Flag bare `UNION`, which silently deduplicates rows (L005).
+fn check_unions(model: &str, body: &SetExpr, diags: &mut Vec<Diagnostic>) {
+    let SetExpr::SetOperation {
+        op,
+        set_quantifier,
+        left,
+        right,
+    } = body
+    else {
+        return;
+    };
+
+    if matches!(op, SetOperator::Union) && matches!(set_quantifier, SetQuantifier::None) {
+        diags.push(diagnostic(
+            DiagnosticCode::L005,
+            Severity::Info,
+            model,
+            "UNION removes duplicate rows, which is rarely intended when combining model inputs"
+                .to_string(),
+            None,
+            "Use UNION ALL, or UNION DISTINCT if deduplication is intended",
+        ));
+    }
+
+    check_unions(model, left, diags);
+    check_unions(model, right, diags);
+}
+
+/// Rules that apply to a single SELECT (L002-L004).
+fn check_select(model: &str, select: &Select, diags: &mut Vec<Diagnostic>) {
+    if select.from.len() > 1 {
+        let relations: Vec<String> = select.from.iter().map(|t| t.relation.to_string()).collect();
+        diags.push(diagnostic(
+            DiagnosticCode::L002,
+            Severity::Warning,
+            model,
+            format!("Implicit comma join between {}", relations.join(", ")),
+            None,
+            "Use an explicit JOIN ... ON so each join condition sits next to its relation",
+        ));
+    }
+
+    if let GroupByExpr::Expressions(exprs, _) = &select.group_by {
+        let ordinals: Vec<String> = exprs
+            .iter()
+            .filter(|e| is_ordinal(e))
+            .map(|e| e.to_string())
+            .collect();
+        if !ordinals.is_empty() {
+            diags.push(ordinal_diagnostic(model, "GROUP BY", &ordinals));
+        }
+    }
+
+    let relations: usize = select.from.iter().map(count_relations).sum();
+    if relations > 1 {
+        for column in unqualified_columns(select) {
+            diags.push(diagnostic(
+                DiagnosticCode::L004,
+                Severity::Warning,
+                model,
+                format!(
+                    "Column '{}' is not qualified in a query over {} relations",
+                    column, relations
+                ),
+                Some(column),
+                "Prefix the column with its table alias so the query keeps working when another relation gains a column of the same name",
+            ));
+        }
+    }
+}
+
+/// Number of relations in a FROM item, looking through nested joins.
+fn count_relations(table: &TableWithJoins) -> usize {
+    let factor_count = |factor: &TableFactor| match factor {
+        TableFactor::NestedJoin {
+            table_with_joins, ..
+        } => count_relations(table_with_joins),
+        _ => 1,
+    };
+    factor_count(&table.relation)
+        + table
+            .joins
+            .iter()
+            .map(|j| factor_count(&j.relation))
+            .sum::<usize>()
+}
+
+/// Bare column identifiers referenced directly by a SELECT (not by its
+/// subqueries), excluding output aliases and `USING` join columns.
+fn unqualified_columns(select: &Select) -> BTreeSet<String> {
+    let mut excluded: HashSet<String> = select
+        .projection
+        .iter()
+        .filter_map(|item| match item {
+            SelectItem::ExprWithAlias { alias, .. } => Some(alias.value.to_lowercase()),
+            _ => None,
+        })
+        .collect();
+
+    let mut collector = UnqualifiedColumns::default();
+    let _ = select.projection.visit(&mut collector);
+    let _ = select.selection.visit(&mut collector);
+    let _ = select.group_by.visit(&mut collector);
+    let _ = select.having.visit(&mut collector);
+    for join in select.from.iter().flat_map(|t| t.joins.iter()) {
+        match join_constraint(&join.join_operator) {
+            Some(JoinConstraint::On(expr)) => {
+                let _ = expr.visit(&mut collector);
+            }
+            Some(JoinConstraint::Using(columns)) => {
+                excluded.extend(columns.iter().map(|c| c.to_string().to_lowercase()));
+            }
+            _ => {}
+        }
+    }
+
+    collector
+        .names
+        .into_iter()
+        .filter(|name| !excluded.contains(&name.to_lowercase()))
+        .collect()
+}
+
+/// The constraint of a join operator that carries one.
+fn join_constraint(op: &JoinOperator) -> Option<&JoinConstraint> {
+    match op {
+        JoinOperator::Join(c)
+        | JoinOperator::Inner(c)
+        | JoinOperator::Left(c)
+        | JoinOperator::LeftOuter(c)
+        | JoinOperator::Right(c)
+        | JoinOperator::RightOuter(c)
+        | JoinOperator::FullOuter(c) => Some(c),
+        _ => None,
+    }
+}
+
+/// Collects `Expr::Identifier` names, skipping nested queries and lambda bodies.
+#[derive(Default)]
+struct UnqualifiedColumns {
+    depth: usize,
+    names: BTreeSet<String>,
+}
+
+impl Visitor for UnqualifiedColumns {
+    type Break = ();
+
+    fn pre_visit_query(&mut self, _query: &Query) -> ControlFlow<()> {
+        self.depth += 1;
+        ControlFlow::Continue(())
+    }
+
+    fn post_visit_query(&mut self, _query: &Query) -> ControlFlow<()> {
+        self.depth -= 1;
+        ControlFlow::Continue(())
+    }
+
+    fn pre_visit_expr(&mut self, expr: &Expr) -> ControlFlow<()> {
+        match expr {
+            Expr::Lambda(_) => self.depth += 1,
+            Expr::Identifier(ident) if self.depth == 0 => {
+                self.names.insert(ident.value.clone());
+            }
+            _ => {}
+        }
+        ControlFlow::Continue(())
+    }
+
+    fn post_visit_expr(&mut self, expr: &Expr) -> ControlFlow<()> {
+        if matches!(expr, Expr::Lambda(_)) {
+            self.depth -= 1;
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+/// Whether an expression is a bare positional reference such as `1`.
+fn is_ordinal(expr: &Expr) -> bool {
+    matches!(expr, Expr::Value(_)) && expr.to_string().parse::<u64>().is_ok()
+}
+
+fn ordinal_diagnostic(model: &str, clause: &str, ordinals: &[String]) -> Diagnostic {
+    diagnostic(
+        DiagnosticCode::L003,
+        Severity::Warning,
+        model,
+        format!(
+            "{} uses ordinal position(s) {}",
+            clause,
+            ordinals.join(", ")
+        ),
+        None,
+        "Reference columns by name; ordinals silently change meaning when the SELECT list is reordered",
+    )
+}
+
+fn diagnostic(
+    code: DiagnosticCode,
+    severity: Severity,
+    model: &str,
+    message: String,
+    column: Option<String>,
+    hint: &str,
+) -> Diagnostic {
+    Diagnostic {
+        code,
+        severity,
+        message,
+        model: ModelName::new(model),
+        column,
+        hint: Some(hint.to_string()),
+        pass_name: "sql_style".into(),
+        span: None,
+    }
+}
+
+#[cfg(test)]
+#[path = "plan_sql_style_test.rs"]
+mod tests;
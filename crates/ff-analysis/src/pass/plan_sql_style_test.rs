@@ -0,0 +1,228 @@
+use super::*;
+use crate::datafusion_bridge::propagation::propagate_schemas;
+use crate::schema::{RelSchema, SchemaCatalog};
+use crate::test_utils::*;
+use crate::types::Nullability;
+use std::sync::Arc;
+
+fn lint(sql: &str, target: &LintTarget) -> Vec<Diagnostic> {
+    let stmt = SqlParser::duckdb().parse_single(sql).unwrap();
+    lint_statement("m", &stmt, target, &LintConfig::default(), 0)
+}
+
+fn codes(diags: &[Diagnostic]) -> Vec<DiagnosticCode> {
+    diags.iter().map(|d| d.code).collect()
+}
+
+#[test]
+fn test_l001_select_star_only_in_marts() {
+    let mart_tags = vec!["mart".to_string()];
+    let mart = LintTarget {
+        tags: &mart_tags,
+        schema: None,
+    };
+    let sql = "WITH o AS (SELECT * FROM orders) SELECT * FROM o";
+    assert_has_diagnostic(&lint(sql, &mart), DiagnosticCode::L001);
+    assert_no_diagnostic(&lint(sql, &LintTarget::default()), DiagnosticCode::L001);
+
+    // Import CTEs may still select *, only the model output is checked
+    let explicit = "WITH o AS (SELECT * FROM orders) SELECT id, amount FROM o";
+    assert_no_diagnostic(&lint(explicit, &mart), DiagnosticCode::L001);
+}
+
+#[test]
+fn test_l002_comma_join() {
+    let diags = lint(
+        "SELECT a.id FROM orders a, customers c WHERE a.customer_id = c.id",
+        &LintTarget::default(),
+    );
+    assert_has_diagnostic(&diags, DiagnosticCode::L002);
+
+    let diags = lint(
+        "SELECT a.id FROM orders a JOIN customers c ON a.customer_id = c.id",
+        &LintTarget::default(),
+    );
+    assert_no_diagnostic(&diags, DiagnosticCode::L002);
+}
+
+#[test]
+fn test_l003_ordinals_in_group_and_order_by() {
+    let diags = lint(
+        "SELECT customer_id, count(*) FROM orders GROUP BY 1 ORDER BY 2 DESC",
+        &LintTarget::default(),
+    );
+    let l003: Vec<_> = diags
+        .iter()
+        .filter(|d| d.code == DiagnosticCode::L003)
+        .collect();
+    assert_eq!(l003.len(), 2);
+    assert!(l003.iter().any(|d| d.message.starts_with("GROUP BY")));
+    assert!(l003.iter().any(|d| d.message.starts_with("ORDER BY")));
+
+    let diags = lint(
+        "SELECT customer_id, count(*) AS n FROM orders GROUP BY customer_id ORDER BY n",
+        &LintTarget::default(),
+    );
+    assert_no_diagnostic(&diags, DiagnosticCode::L003);
+}
+
+#[test]
+fn test_l004_unqualified_columns_in_joins() {
+    let diags = lint(
+        "SELECT o.id, amount AS total, c.name FROM orders o JOIN customers c ON o.customer_id = c.id WHERE status = 'paid'",
+        &LintTarget::default(),
+    );
+    let columns: Vec<_> = diags
+        .iter()
+        .filter(|d| d.code == DiagnosticCode::L004)
+        .filter_map(|d| d.column.as_deref())
+        .collect();
+    assert_eq!(columns, vec!["amount", "status"]);
+}
+
+#[test]
+fn test_l004_ignores_single_table_using_and_subqueries() {
+    let target = LintTarget::default();
+    assert_no_diagnostic(
+        &lint(
+            "SELECT id, amount FROM orders WHERE status = 'paid'",
+            &target,
+        ),
+        DiagnosticCode::L004,
+    );
+    assert_no_diagnostic(
+        &lint(
+            "SELECT o.amount FROM orders o JOIN payments p USING (order_id) WHERE order_id > 0",
+            &target,
+        ),
+        DiagnosticCode::L004,
+    );
+    assert_no_diagnostic(
+        &lint(
+            "SELECT o.id FROM orders o JOIN customers c ON o.customer_id = c.id \
+             WHERE o.id IN (SELECT order_id FROM refunds)",
+            &target,
+        ),
+        DiagnosticCode::L004,
+    );
+}
+
+#[test]
+fn test_l005_bare_union() {
+    let target = LintTarget::default();
+    assert_eq!(
+        codes(&lint("SELECT id FROM a UNION SELECT id FROM b", &target)),
+        vec![DiagnosticCode::L005]
+    );
+    assert!(lint("SELECT id FROM a UNION ALL SELECT id FROM b", &target).is_empty());
+    assert!(lint("SELECT id FROM a UNION DISTINCT SELECT id FROM b", &target).is_empty());
+}
+
+#[test]
+fn test_l006_naming_by_layer() {
+    let mut config = LintConfig::default();
+    config
+        .naming
+        .insert("staging".to_string(), vec!["stg_".to_string()]);
+    config.naming.insert(
+        "marts".to_string(),
+        vec!["fct_".to_string(), "dim_".to_string()],
+    );
+
+    let staging = LintTarget {
+        tags: &[],
+        schema: Some("staging"),
+    };
+    assert!(check_naming("stg_orders", &staging, &config).is_none());
+    let diag = check_naming("orders_clean", &staging, &config).unwrap();
+    assert_eq!(diag.code, DiagnosticCode::L006);
+    assert!(diag.message.contains("'stg_'"));
+
+    let marts_tags = vec!["marts".to_string()];
+    let marts = LintTarget {
+        tags: &marts_tags,
+        schema: None,
+    };
+    assert!(check_naming("dim_customers", &marts, &config).is_none());
+    assert!(check_naming("stg_customers", &marts, &config).is_some());
+
+    // Nodes outside every configured layer are not checked
+    assert!(check_naming("anything", &LintTarget::default(), &config).is_none());
+}
+
+fn plan_single(sql: &str) -> HashMap<ModelName, ModelPlanResult> {
+    let mut catalog: SchemaCatalog = HashMap::new();
+    catalog.insert(
+        "orders".to_string(),
+        Arc::new(RelSchema::new(vec![
+            make_col("id", int32(), Nullability::NotNull),
+            make_col("amount", int32(), Nullability::Nullable),
+        ])),
+    );
+    let mut sql_sources = HashMap::new();
+    sql_sources.insert(ModelName::new("m"), sql.to_string());
+    propagate_schemas(
+        &[ModelName::new("m")],
+        &sql_sources,
+        &HashMap::new(),
+        catalog,
+        &[],
+        &[],
+    )
+    .model_plans
+}
+
+#[test]
+fn test_pass_is_opt_in_and_applies_rule_severities() {
+    let models = plan_single("SELECT amount, count(*) AS n FROM orders GROUP BY 1");
+
+    let mut ctx = make_ctx();
+    assert!(PlanSqlStyle.run_project(&models, &ctx).is_empty());
+
+    ctx.project.config.analysis.lint.enabled = true;
+    let diags = PlanSqlStyle.run_project(&models, &ctx);
+    assert_eq!(codes(&diags), vec![DiagnosticCode::L003]);
+    assert_eq!(diags[0].severity, Severity::Warning);
+
+    ctx.project
+        .config
+        .analysis
+        .lint
+        .rules
+        .insert("L003".to_string(), ff_core::config::ConfigSeverity::Error);
+    let diags = PlanSqlStyle.run_project(&models, &ctx);
+    assert_eq!(diags[0].severity, Severity::Error);
+
+    ctx.project
+        .config
+        .analysis
+        .lint
+        .rules
+        .insert("L003".to_string(), ff_core::config::ConfigSeverity::Off);
+    assert!(PlanSqlStyle.run_project(&models, &ctx).is_empty());
+}
+
+#[test]
+fn test_engine_inferred_models_are_linted() {
+    let mut models = HashMap::new();
+    models.insert(
+        ModelName::new("m"),
+        EngineInferredResult {
+            sql: "SELECT id FROM a UNION SELECT id FROM b".to_string(),
+            planning_error: "unsupported QUALIFY".to_string(),
+            inferred_schema: Arc::new(RelSchema::new(vec![make_col(
+                "id",
+                int32(),
+                Nullability::NotNull,
+            )])),
+            mismatches: Vec::new(),
+        },
+    );
+
+    let mut ctx = make_ctx();
+    ctx.project.config.analysis.lint.enabled = true;
+    assert_eq!(
+        codes(&lint_engine_inferred(&models, &ctx)),
+        vec![DiagnosticCode::L005]
+    );
+}
@@ -116,6 +116,7 @@ fn check_union_column_types(
             column: None,
             hint: None,
             pass_name: "plan_type_inference".into(),
+            span: None,
         });
         return;
     }
@@ -141,6 +142,7 @@ fn check_union_column_types(
                 column: Some(l.name().clone()),
                 hint: Some("Add explicit CASTs to ensure matching types".to_string()),
                 pass_name: "plan_type_inference".into(),
+                span: None,
             });
         }
     }
@@ -183,6 +185,7 @@ fn check_aggregate_type(
             column: Some(col_name),
             hint: Some("Ensure the column is numeric, or add a CAST".to_string()),
             pass_name: "plan_type_inference".into(),
+            span: None,
         });
     }
 }
@@ -217,6 +220,7 @@ fn check_cast_expr(
                     column: Some(context),
                     hint: Some("Consider using TRY_CAST for safer conversion".to_string()),
                     pass_name: "plan_type_inference".into(),
+                    span: None,
                 });
             }
             check_cast_expr(model, &cast.expr, input_plan, diags);
@@ -248,7 +252,32 @@ fn is_string_type(dt: &ArrowDataType) -> bool {
 }
 
 /// Check if a cast is potentially lossy
+///
+/// Besides cross-family casts, this covers casts to a narrower integer and
+/// to a decimal with fewer integer digits or a smaller scale.
 fn is_lossy_cast(source: &SqlType, target: &SqlType) -> bool {
+    match (source, target) {
+        (SqlType::Integer { bits: from }, SqlType::Integer { bits: to }) => return to < from,
+        (SqlType::HugeInt, SqlType::Integer { .. }) => return true,
+        (
+            SqlType::Integer { bits },
+            SqlType::Decimal {
+                precision: Some(p),
+                scale: Some(s),
+            },
+        ) => return p.saturating_sub(*s) < bits.max_digits(),
+        (
+            SqlType::Decimal {
+                precision: Some(p1),
+                scale: Some(s1),
+            },
+            SqlType::Decimal {
+                precision: Some(p2),
+                scale: Some(s2),
+            },
+        ) => return p2.saturating_sub(*s2) < p1.saturating_sub(*s1) || s2 < s1,
+        _ => {}
+    }
     matches!(
         (source, target),
         (SqlType::Float { .. }, SqlType::Integer { .. })
@@ -85,6 +85,7 @@ fn check_model_unused_columns(
                 column: Some(col_name.clone()),
                 hint: Some("Consider removing this column to simplify the model".to_string()),
                 pass_name: "plan_unused_columns".into(),
+                span: None,
             }),
     );
 }
@@ -1,4 +1,5 @@
 use super::*;
+use crate::datafusion_bridge::propagation::SchemaMismatch;
 use ff_core::ModelName;
 use std::collections::HashMap;
 
@@ -11,6 +12,7 @@ fn make_diagnostic(code: DiagnosticCode, severity: Severity) -> Diagnostic {
         column: None,
         hint: None,
         pass_name: "test_pass".into(),
+        span: None,
     }
 }
 
@@ -118,3 +120,245 @@ fn mixed_overrides() {
     assert_eq!(result[2].code, DiagnosticCode::A010);
     assert_eq!(result[2].severity, Severity::Warning);
 }
+
+fn project_with_model(raw_sql: &str, schema_yaml: &str) -> Project {
+    use ff_core::model::{Model, ModelConfig, ModelKind, ModelSchema};
+
+    let model = Model {
+        name: ModelName::new("test_model"),
+        path: std::path::PathBuf::from("nodes/test_model/test_model.sql"),
+        raw_sql: raw_sql.to_string(),
+        compiled_sql: None,
+        config: ModelConfig::default(),
+        depends_on: Default::default(),
+        external_deps: Default::default(),
+        schema: Some(serde_yaml::from_str::<ModelSchema>(schema_yaml).unwrap()),
+        base_name: None,
+        version: None,
+        kind: ModelKind::default(),
+    };
+    let config: ff_core::config::Config = serde_yaml::from_str("name: test_project").unwrap();
+    Project::new(ff_core::project::ProjectParts {
+        root: std::path::PathBuf::from("/tmp/test"),
+        config,
+        models: HashMap::from([(ModelName::new("test_model"), model)]),
+        seeds: vec![],
+        tests: vec![],
+        singular_tests: vec![],
+        sources: vec![],
+        functions: vec![],
+        packages: vec![],
+    })
+}
+
+fn with_column(mut d: Diagnostic, column: &str) -> Diagnostic {
+    d.column = Some(column.to_string());
+    d
+}
+
+#[test]
+fn node_rules_take_precedence_over_project_overrides() {
+    let yaml = r#"
+version: 1
+analysis:
+  ignore:
+    - code: A020
+      columns: [legacy_id]
+      reason: "kept for BI"
+  overrides:
+    A010: error
+"#;
+    let project = project_with_model("SELECT 1 AS legacy_id -- ff:ignore A032", yaml);
+    let mut map = HashMap::new();
+    map.insert("A010".to_string(), ConfigSeverity::Info);
+    let overrides = SeverityOverrides::from_config(&map).with_node_rules(&project, &HashMap::new());
+
+    let diags = vec![
+        with_column(
+            make_diagnostic(DiagnosticCode::A020, Severity::Info),
+            "legacy_id",
+        ),
+        with_column(make_diagnostic(DiagnosticCode::A020, Severity::Info), "id"),
+        make_diagnostic(DiagnosticCode::A010, Severity::Warning),
+        make_diagnostic(DiagnosticCode::A032, Severity::Warning),
+    ];
+    let result = apply_severity_overrides(diags, &overrides);
+    assert_eq!(result.len(), 2);
+    assert_eq!(result[0].code, DiagnosticCode::A020);
+    assert_eq!(result[0].column.as_deref(), Some("id"));
+    assert_eq!(result[1].code, DiagnosticCode::A010);
+    assert_eq!(result[1].severity, Severity::Error);
+}
+
+#[test]
+fn node_rules_apply_to_schema_mismatch_codes() {
+    let yaml = r#"
+version: 1
+analysis:
+  ignore:
+    - code: SA02
+      columns: [debug_flag]
+      reason: "not part of the contract"
+"#;
+    let project = project_with_model("SELECT 1", yaml);
+    let overrides = SeverityOverrides::default().with_node_rules(&project, &HashMap::new());
+
+    assert_eq!(
+        overrides.get_for_node_sa("test_model", "SA02", "debug_flag"),
+        Some(OverriddenSeverity::Off)
+    );
+    assert_eq!(overrides.get_for_node_sa("test_model", "SA02", "id"), None);
+    assert_eq!(
+        overrides.get_for_node_sa("other", "SA02", "debug_flag"),
+        None
+    );
+}
+
+fn propagation_with_mismatches(mismatches: Vec<SchemaMismatch>) -> PropagationResult {
+    use crate::datafusion_bridge::propagation::EngineInferredResult;
+    use crate::schema::RelSchema;
+    use std::sync::Arc;
+
+    PropagationResult {
+        model_plans: HashMap::new(),
+        engine_inferred: HashMap::from([(
+            ModelName::new("test_model"),
+            EngineInferredResult {
+                sql: String::new(),
+                planning_error: String::new(),
+                inferred_schema: Arc::new(RelSchema::empty()),
+                mismatches,
+            },
+        )]),
+        final_catalog: HashMap::new(),
+        failures: HashMap::new(),
+    }
+}
+
+#[test]
+fn inline_suppressions_are_scoped_to_their_statement() {
+    let sql = "SELECT 1 AS legacy_id; -- ff:ignore A020 reason=\"kept\"\nSELECT 2 AS legacy_id";
+    let project = project_with_model("SELECT 1", "version: 1\n");
+    let rendered = HashMap::from([(ModelName::new("test_model"), sql.to_string())]);
+    let overrides = SeverityOverrides::default().with_node_rules(&project, &rendered);
+
+    let at_line = |line| {
+        let mut d = make_diagnostic(DiagnosticCode::A020, Severity::Info);
+        d.span = Some(SourceSpan {
+            line,
+            column: 8,
+            end_line: line,
+            end_column: 9,
+        });
+        d
+    };
+    let diags = vec![
+        at_line(1),
+        at_line(2),
+        make_diagnostic(DiagnosticCode::A020, Severity::Info),
+    ];
+    let result = apply_severity_overrides(diags, &overrides);
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].span.map(|s| s.line), Some(2));
+}
+
+#[test]
+fn audit_reports_missing_reasons_and_unused_suppressions() {
+    let yaml = r#"
+version: 1
+analysis:
+  ignore:
+    - A033
+    - code: SA01
+      columns: [email]
+      reason: "dropped upstream"
+"#;
+    let sql = "SELECT 1 -- ff:ignore A020 reason=\"kept for BI\"\n-- ff:ignore SA02";
+    let project = project_with_model(sql, yaml);
+    let overrides = SeverityOverrides::default().with_node_rules(&project, &HashMap::new());
+    let analyzed = vec![ModelName::new("test_model")];
+    let propagation = propagation_with_mismatches(vec![SchemaMismatch::ExtraInSql {
+        column: "debug_flag".to_string(),
+    }]);
+
+    let raw = vec![make_diagnostic(DiagnosticCode::A020, Severity::Info)];
+    let issues = overrides.audit_suppressions(&raw, &propagation, &analyzed, true);
+    let summary: Vec<(DiagnosticCode, &str)> = issues
+        .iter()
+        .map(|d| (d.code, d.message.as_str()))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (
+                DiagnosticCode::A060,
+                "YAML suppression of A033 has no reason"
+            ),
+            (
+                DiagnosticCode::A061,
+                "YAML suppression of A033 does not suppress any diagnostic"
+            ),
+            (
+                DiagnosticCode::A061,
+                "YAML suppression of SA01 does not suppress any diagnostic"
+            ),
+            (
+                DiagnosticCode::A060,
+                "inline suppression of SA02 has no reason"
+            ),
+        ]
+    );
+
+    // Unused pass suppressions are not reported when not every pass ran,
+    // but schema-mismatch suppressions still are
+    let issues = overrides.audit_suppressions(&[], &propagation, &analyzed, false);
+    let unused: Vec<&str> = issues
+        .iter()
+        .filter(|d| d.code == DiagnosticCode::A061)
+        .map(|d| d.message.as_str())
+        .collect();
+    assert_eq!(
+        unused,
+        vec!["YAML suppression of SA01 does not suppress any diagnostic"]
+    );
+
+    // Nodes that were not analyzed are not audited
+    assert!(overrides
+        .audit_suppressions(&[], &propagation, &[], true)
+        .is_empty());
+}
+
+#[test]
+fn audit_reports_ff_ignore_without_a_known_code() {
+    let sql = "SELECT 1 -- ff:ignore\n-- ff:ignore A999 reason=\"typo\"";
+    let project = project_with_model(sql, "version: 1\n");
+    let overrides = SeverityOverrides::default().with_node_rules(&project, &HashMap::new());
+    let analyzed = vec![ModelName::new("test_model")];
+    let propagation = propagation_with_mismatches(vec![]);
+
+    // Reported even when unused suppressions are not checked
+    let issues = overrides.audit_suppressions(&[], &propagation, &analyzed, false);
+    let summary: Vec<(DiagnosticCode, &str, Option<usize>)> = issues
+        .iter()
+        .map(|d| (d.code, d.message.as_str(), d.span.map(|s| s.line)))
+        .collect();
+    assert_eq!(
+        summary,
+        vec![
+            (
+                DiagnosticCode::A061,
+                "ff:ignore comment names no diagnostic code, so it suppresses nothing",
+                Some(1)
+            ),
+            (
+                DiagnosticCode::A061,
+                "ff:ignore names unknown diagnostic code 'A999', so it suppresses nothing",
+                Some(2)
+            ),
+        ]
+    );
+
+    // A bare ff:ignore does not silence every diagnostic
+    let diags = vec![make_diagnostic(DiagnosticCode::A020, Severity::Info)];
+    assert_eq!(apply_severity_overrides(diags, &overrides).len(), 1);
+}
@@ -0,0 +1,284 @@
+//! Per-node diagnostic suppressions
+//!
+//! Suppressions come from two places:
+//! - the node YAML `analysis:` block (`ignore` entries, optionally limited to
+//!   columns, and per-node `overrides`)
+//! - inline SQL comments such as `-- ff:ignore A020 reason="kept for BI"`,
+//!   which apply to the statement they appear in
+//!
+//! They are folded into [`super::SeverityOverrides`] and take precedence over
+//! the project-wide `analysis.severity_overrides`.
+
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+use ff_core::model::NodeAnalysisConfig;
+use sqlparser::dialect::GenericDialect;
+use sqlparser::tokenizer::{Token, Tokenizer, Whitespace};
+
+use super::{DiagnosticCode, OverriddenSeverity, SourceSpan};
+
+/// Where a suppression was declared.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SuppressionSource {
+    /// `analysis.ignore` in the node YAML
+    Yaml,
+    /// `-- ff:ignore` comment in the node SQL
+    Inline,
+}
+
+impl std::fmt::Display for SuppressionSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SuppressionSource::Yaml => write!(f, "YAML"),
+            SuppressionSource::Inline => write!(f, "inline"),
+        }
+    }
+}
+
+/// A diagnostic code suppressed for one node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suppression {
+    /// Suppressed code (e.g. `A020`, `SA02`), uppercase
+    pub code: String,
+    /// Columns the suppression is limited to; empty means the whole node
+    pub columns: Vec<String>,
+    /// Why the diagnostic is suppressed
+    pub reason: Option<String>,
+    /// Where the suppression was declared
+    pub source: SuppressionSource,
+    /// Lines of the statement an inline suppression appears in; `None`
+    /// covers the whole node
+    pub statement: Option<RangeInclusive<usize>>,
+}
+
+impl Suppression {
+    /// Whether this suppression covers a diagnostic with the given code and column.
+    pub fn matches(&self, code: &str, column: Option<&str>) -> bool {
+        if !self.code.eq_ignore_ascii_case(code) {
+            return false;
+        }
+        self.columns.is_empty()
+            || column.is_some_and(|c| self.columns.iter().any(|s| s.eq_ignore_ascii_case(c)))
+    }
+
+    /// Whether a diagnostic at `span` falls within this suppression's statement.
+    ///
+    /// Diagnostics without a position are covered, as they concern the node
+    /// as a whole.
+    pub fn covers(&self, span: Option<&SourceSpan>) -> bool {
+        match (&self.statement, span) {
+            (Some(lines), Some(span)) => lines.contains(&span.line),
+            _ => true,
+        }
+    }
+}
+
+/// An inline `ff:ignore` comment that suppresses nothing because it names
+/// no code, or a code that does not exist.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidSuppression {
+    /// Position of the comment in the SQL
+    pub span: Option<SourceSpan>,
+    /// What is wrong with the comment
+    pub message: String,
+}
+
+/// Suppressions and severity overrides that apply to a single node.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct NodeRules {
+    pub(crate) suppressions: Vec<Suppression>,
+    pub(crate) invalid: Vec<InvalidSuppression>,
+    overrides: HashMap<String, OverriddenSeverity>,
+}
+
+impl NodeRules {
+    /// Build the rules for a node from its YAML block and rendered SQL.
+    pub(crate) fn new(yaml: Option<&NodeAnalysisConfig>, sql: &str) -> Self {
+        let mut rules = NodeRules::default();
+        if let Some(yaml) = yaml {
+            rules
+                .suppressions
+                .extend(yaml.ignore.iter().map(|entry| Suppression {
+                    code: entry.code().to_uppercase(),
+                    columns: entry.columns().to_vec(),
+                    reason: non_empty(entry.reason()),
+                    source: SuppressionSource::Yaml,
+                    statement: None,
+                }));
+            rules.overrides = yaml
+                .overrides
+                .iter()
+                .map(|(code, sev)| (code.to_uppercase(), OverriddenSeverity::from(*sev)))
+                .collect();
+        }
+        let (inline, invalid) = parse_inline(sql);
+        rules.suppressions.extend(inline);
+        rules.invalid = invalid;
+        rules
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.suppressions.is_empty() && self.invalid.is_empty() && self.overrides.is_empty()
+    }
+
+    /// Resolve a diagnostic against this node: suppressions first, then overrides.
+    pub(crate) fn resolve(
+        &self,
+        code: &str,
+        column: Option<&str>,
+        span: Option<&SourceSpan>,
+    ) -> Option<OverriddenSeverity> {
+        if self
+            .suppressions
+            .iter()
+            .any(|s| s.matches(code, column) && s.covers(span))
+        {
+            return Some(OverriddenSeverity::Off);
+        }
+        self.overrides.get(code).copied()
+    }
+}
+
+/// Parse `-- ff:ignore CODE[,CODE...] [reason="..."]` comments from SQL.
+///
+/// Comments are found with the SQL tokenizer, so `--` inside string literals
+/// is not mistaken for one. Each suppression is scoped to the lines of the
+/// statement it appears in; a comment on the line of a statement's closing
+/// `;`, or after the last statement, belongs to the preceding statement.
+/// SQL that cannot be tokenized yields no suppressions. Comments naming no
+/// code or an unknown code are skipped; see [`NodeRules`] for how they are
+/// reported.
+pub fn parse_inline_suppressions(sql: &str) -> Vec<Suppression> {
+    parse_inline(sql).0
+}
+
+/// Parse inline suppressions, also returning the `ff:ignore` comments that
+/// name no code or an unknown code.
+fn parse_inline(sql: &str) -> (Vec<Suppression>, Vec<InvalidSuppression>) {
+    let Ok(tokens) = Tokenizer::new(&GenericDialect {}, sql).tokenize_with_location() else {
+        return (Vec::new(), Vec::new());
+    };
+
+    // (first line, last line) of each statement, and the suppressions of
+    // each ff:ignore comment with the index of the statement it belongs to
+    let mut statements: Vec<(usize, usize)> = Vec::new();
+    let mut current: Option<(usize, usize)> = None;
+    let mut parsed: Vec<(usize, Vec<Suppression>)> = Vec::new();
+    let mut invalid: Vec<InvalidSuppression> = Vec::new();
+
+    for token in &tokens {
+        let line = token.span.start.line as usize;
+        match &token.token {
+            Token::Whitespace(Whitespace::SingleLineComment { comment, prefix })
+                if prefix == "--" =>
+            {
+                let Some(mut suppressions) = parse_comment(comment) else {
+                    continue;
+                };
+                if suppressions.is_empty() {
+                    invalid.push(InvalidSuppression {
+                        span: SourceSpan::from_sqlparser(token.span),
+                        message: "ff:ignore comment names no diagnostic code".to_string(),
+                    });
+                    continue;
+                }
+                suppressions.retain(|s| {
+                    let known = is_known_code(&s.code);
+                    if !known {
+                        invalid.push(InvalidSuppression {
+                            span: SourceSpan::from_sqlparser(token.span),
+                            message: format!(
+                                "ff:ignore names unknown diagnostic code '{}'",
+                                s.code
+                            ),
+                        });
+                    }
+                    known
+                });
+                let index = match (current, statements.last()) {
+                    (None, Some(&(_, end))) if end == line => statements.len() - 1,
+                    _ => statements.len(),
+                };
+                parsed.push((index, suppressions));
+            }
+            Token::Whitespace(_) => {}
+            Token::SemiColon => {
+                let (start, _) = current.take().unwrap_or((line, line));
+                statements.push((start, line));
+            }
+            _ => {
+                let end = token.span.end.line as usize;
+                current = Some(current.map_or((line, end), |(start, _)| (start, end)));
+            }
+        }
+    }
+    statements.extend(current);
+
+    let suppressions = parsed
+        .into_iter()
+        .flat_map(|(index, suppressions)| {
+            let statement = statements
+                .get(index)
+                .or_else(|| statements.last())
+                .map(|&(start, end)| start..=end);
+            suppressions.into_iter().map(move |s| Suppression {
+                statement: statement.clone(),
+                ..s
+            })
+        })
+        .collect();
+    (suppressions, invalid)
+}
+
+/// Whether `code` (uppercase) is a pass diagnostic or a schema mismatch code.
+fn is_known_code(code: &str) -> bool {
+    matches!(code, "SA01" | "SA02") || code.parse::<DiagnosticCode>().is_ok()
+}
+
+/// Parse the text of one `--` comment, if it is an `ff:ignore` directive.
+fn parse_comment(comment: &str) -> Option<Vec<Suppression>> {
+    let rest = comment.trim().strip_prefix("ff:ignore")?;
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+
+    let (codes, reason) = match rest.find("reason=") {
+        Some(pos) => (&rest[..pos], parse_reason(&rest[pos + "reason=".len()..])),
+        None => (rest, None),
+    };
+    Some(
+        codes
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|code| !code.is_empty())
+            .map(|code| Suppression {
+                code: code.to_uppercase(),
+                columns: Vec::new(),
+                reason: reason.clone(),
+                source: SuppressionSource::Inline,
+                statement: None,
+            })
+            .collect(),
+    )
+}
+
+/// Read a `reason=` value, quoted or bare to the end of the line.
+fn parse_reason(value: &str) -> Option<String> {
+    let value = value.trim();
+    let reason = match value.strip_prefix('"') {
+        Some(quoted) => quoted.split('"').next().unwrap_or_default(),
+        None => value,
+    };
+    non_empty(Some(reason))
+}
+
+fn non_empty(reason: Option<&str>) -> Option<String> {
+    reason
+        .map(str::trim)
+        .filter(|r| !r.is_empty())
+        .map(String::from)
+}
+
+#[cfg(test)]
+#[path = "suppression_test.rs"]
+mod tests;
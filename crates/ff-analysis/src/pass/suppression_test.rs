@@ -0,0 +1,123 @@
+use super::*;
+
+#[test]
+fn test_parse_inline_suppression_with_reason() {
+    let sql = "SELECT id, legacy_id -- ff:ignore A020 reason=\"kept for BI\"\nFROM t";
+    let parsed = parse_inline_suppressions(sql);
+    assert_eq!(
+        parsed,
+        vec![Suppression {
+            code: "A020".to_string(),
+            columns: vec![],
+            reason: Some("kept for BI".to_string()),
+            source: SuppressionSource::Inline,
+            statement: Some(1..=2),
+        }]
+    );
+}
+
+#[test]
+fn test_parse_inline_suppression_multiple_codes() {
+    let parsed = parse_inline_suppressions("-- ff:ignore a010, A032 reason=legacy join");
+    let codes: Vec<&str> = parsed.iter().map(|s| s.code.as_str()).collect();
+    assert_eq!(codes, vec!["A010", "A032"]);
+    assert!(parsed
+        .iter()
+        .all(|s| s.reason.as_deref() == Some("legacy join")));
+}
+
+#[test]
+fn test_parse_inline_suppression_ignores_other_comments() {
+    assert!(parse_inline_suppressions("-- ff:ignored A020\n-- plain comment\nSELECT 1").is_empty());
+    let parsed = parse_inline_suppressions("--ff:ignore A020 reason=\"\"");
+    assert_eq!(parsed.len(), 1);
+    assert_eq!(parsed[0].reason, None);
+}
+
+#[test]
+fn test_parse_inline_rejects_directives_without_a_known_code() {
+    let (suppressions, invalid) =
+        parse_inline("SELECT 1 -- ff:ignore\n-- ff:ignore A999, A020 reason=x");
+    let codes: Vec<&str> = suppressions.iter().map(|s| s.code.as_str()).collect();
+    assert_eq!(codes, vec!["A020"]);
+    let problems: Vec<(Option<usize>, &str)> = invalid
+        .iter()
+        .map(|i| (i.span.map(|s| s.line), i.message.as_str()))
+        .collect();
+    assert_eq!(
+        problems,
+        vec![
+            (Some(1), "ff:ignore comment names no diagnostic code"),
+            (Some(2), "ff:ignore names unknown diagnostic code 'A999'"),
+        ]
+    );
+}
+
+#[test]
+fn test_parse_inline_suppression_skips_string_literals() {
+    let sql = "SELECT '-- ff:ignore A020' AS note, \"a--ff:ignore A032\" FROM t";
+    assert!(parse_inline_suppressions(sql).is_empty());
+}
+
+#[test]
+fn test_parse_inline_suppression_scopes_to_statement() {
+    let sql = "\
+-- ff:ignore A010 reason=\"first\"
+SELECT 1
+FROM a; -- ff:ignore A020 reason=\"first, after the semicolon\"
+
+SELECT 2
+-- ff:ignore A032 reason=\"second\"
+FROM b;
+-- ff:ignore A033 reason=\"trailing\"
+";
+    let parsed = parse_inline_suppressions(sql);
+    let scopes: Vec<(&str, Option<RangeInclusive<usize>>)> = parsed
+        .iter()
+        .map(|s| (s.code.as_str(), s.statement.clone()))
+        .collect();
+    assert_eq!(
+        scopes,
+        vec![
+            ("A010", Some(2..=3)),
+            ("A020", Some(2..=3)),
+            ("A032", Some(5..=7)),
+            ("A033", Some(5..=7)),
+        ]
+    );
+}
+
+#[test]
+fn test_suppression_covers_spans_in_its_statement() {
+    let suppression = Suppression {
+        code: "A020".to_string(),
+        columns: vec![],
+        reason: None,
+        source: SuppressionSource::Inline,
+        statement: Some(2..=3),
+    };
+    let span = |line| SourceSpan {
+        line,
+        column: 1,
+        end_line: line,
+        end_column: 2,
+    };
+    assert!(suppression.covers(Some(&span(3))));
+    assert!(!suppression.covers(Some(&span(5))));
+    assert!(suppression.covers(None));
+}
+
+#[test]
+fn test_suppression_matches_columns_case_insensitively() {
+    let suppression = Suppression {
+        code: "A020".to_string(),
+        columns: vec!["Legacy_Id".to_string()],
+        reason: None,
+        source: SuppressionSource::Yaml,
+        statement: None,
+    };
+    assert!(suppression.matches("A020", Some("legacy_id")));
+    assert!(!suppression.matches("A020", Some("id")));
+    assert!(!suppression.matches("A020", None));
+    assert!(!suppression.matches("A021", Some("legacy_id")));
+}
@@ -178,6 +178,7 @@ pub fn make_ctx_with_schemas(yaml_schemas: HashMap<ModelName, Arc<RelSchema>>) -
         singular_tests: vec![],
         sources: vec![],
         functions: vec![],
+        packages: vec![],
     });
     let dag = ModelDag::build(&HashMap::new()).unwrap();
     AnalysisContext::new(project, dag, yaml_schemas, ProjectLineage::new())
@@ -195,6 +196,7 @@ pub fn make_ctx_with_dag(dep_map: &HashMap<String, Vec<String>>) -> AnalysisCont
         singular_tests: vec![],
         sources: vec![],
         functions: vec![],
+        packages: vec![],
     });
     let dag = ModelDag::build(dep_map).unwrap();
     AnalysisContext::new(project, dag, HashMap::new(), ProjectLineage::new())
@@ -19,6 +19,18 @@ pub enum IntBitWidth {
     I64,
 }
 
+impl IntBitWidth {
+    /// Number of decimal digits needed for the largest value of this width
+    pub fn max_digits(self) -> u16 {
+        match self {
+            IntBitWidth::I8 => 3,
+            IntBitWidth::I16 => 5,
+            IntBitWidth::I32 => 10,
+            IntBitWidth::I64 => 19,
+        }
+    }
+}
+
 impl std::fmt::Display for IntBitWidth {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -357,7 +369,7 @@ pub fn parse_sql_type(s: &str) -> SqlType {
 }
 
 /// Try to parse parameterized type strings like `VARCHAR(255)`, `DECIMAL(10,2)`,
-/// `INTEGER[]`, `STRUCT(name VARCHAR, age INT)`, `MAP(VARCHAR, INTEGER)`
+/// `INTEGER[]`, `LIST(INTEGER)`, `STRUCT(name VARCHAR, age INT)`, `MAP(VARCHAR, INTEGER)`
 fn try_parse_parameterized(s: &str) -> Option<SqlType> {
     let trimmed = s.trim();
     let upper = trimmed.to_uppercase();
@@ -368,6 +380,11 @@ fn try_parse_parameterized(s: &str) -> Option<SqlType> {
         return Some(SqlType::Array(Box::new(inner_type)));
     }
 
+    if upper.starts_with("LIST(") && upper.ends_with(')') {
+        let inner_type = parse_sql_type(&trimmed[5..trimmed.len() - 1]);
+        return Some(SqlType::Array(Box::new(inner_type)));
+    }
+
     if upper.starts_with("STRUCT(") && upper.ends_with(')') {
         let inner = &trimmed[7..trimmed.len() - 1]; // between STRUCT( and )
         return parse_struct_fields(inner);
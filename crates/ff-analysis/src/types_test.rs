@@ -52,6 +52,28 @@ fn test_parse_parameterized_types() {
     );
 }
 
+#[test]
+fn test_parse_nested_types() {
+    let int = SqlType::Integer {
+        bits: IntBitWidth::I32,
+    };
+    assert_eq!(
+        parse_sql_type("INTEGER[]"),
+        SqlType::Array(Box::new(int.clone()))
+    );
+    assert_eq!(
+        parse_sql_type("list(integer)"),
+        SqlType::Array(Box::new(int.clone()))
+    );
+    assert_eq!(
+        parse_sql_type("MAP(VARCHAR, LIST(INTEGER))"),
+        SqlType::Map {
+            key: Box::new(SqlType::String { max_length: None }),
+            value: Box::new(SqlType::Array(Box::new(int))),
+        }
+    );
+}
+
 #[test]
 fn test_parse_unknown_type() {
     assert!(matches!(
@@ -248,6 +248,77 @@ fn test_stub_agg_mode() {
     assert!(result.is_ok(), "mode: {:?}", result);
 }
 
+// ── Function Stub Tests — Generated Catalog ─────────────────────────────
+
+fn output_type(sql: &str, catalog: &SchemaCatalog) -> arrow::datatypes::DataType {
+    let registry = FunctionRegistry::new();
+    let provider = FeatherFlowProvider::new(catalog, &registry);
+    let plan = sql_to_plan(sql, &provider).unwrap_or_else(|e| panic!("{sql}: {e}"));
+    plan.schema().field(0).data_type().clone()
+}
+
+#[test]
+fn test_catalog_scalar_functions_plan() {
+    use arrow::datatypes::DataType;
+
+    let catalog = standard_catalog();
+    assert_eq!(
+        output_type(
+            "SELECT levenshtein(status, name) FROM orders, customers",
+            &catalog
+        ),
+        DataType::Int64
+    );
+    assert_eq!(
+        output_type("SELECT last_day(created_at) FROM orders", &catalog),
+        DataType::Date32
+    );
+    assert_eq!(
+        output_type(
+            "SELECT json_extract_string(status, '$.a') FROM orders",
+            &catalog
+        ),
+        DataType::Utf8
+    );
+}
+
+#[test]
+fn test_catalog_list_functions_follow_arguments() {
+    use arrow::datatypes::DataType;
+
+    let catalog = standard_catalog();
+    assert_eq!(
+        output_type(
+            "SELECT list_extract(string_split(status, ','), 1) FROM orders",
+            &catalog
+        ),
+        DataType::Utf8
+    );
+    assert!(matches!(
+        output_type("SELECT list_distinct(list_value(id, customer_id)) FROM orders", &catalog),
+        DataType::List(f) if f.data_type() == &DataType::Int32
+    ));
+}
+
+#[test]
+fn test_catalog_aggregate_functions_plan() {
+    use arrow::datatypes::DataType;
+
+    let catalog = standard_catalog();
+    assert!(matches!(
+        output_type("SELECT list(created_at) FROM orders", &catalog),
+        DataType::List(_)
+    ));
+    assert_eq!(
+        output_type("SELECT arg_max(status, amount) FROM orders", &catalog),
+        DataType::Utf8
+    );
+    assert_eq!(
+        output_type("SELECT stddev_samp(amount) FROM orders", &catalog),
+        DataType::Float64
+    );
+}
+
 // ── Unstubbed Function — Graceful Failure ───────────────────────────────
 
 #[test]
@@ -31,6 +31,10 @@ pub(crate) struct GlobalArgs {
     /// Select named database connection (from featherflow.yml)
     #[arg(short = 'd', long, global = true)]
     pub database: Option<String>,
+
+    /// Disable compile-time database introspection (run_query, get_columns, get_relation)
+    #[arg(long, global = true)]
+    pub offline: bool,
 }
 
 /// Available subcommands
@@ -95,6 +99,10 @@ pub(crate) struct CompileArgs {
     /// Enable governance checks (data classification completeness)
     #[arg(long)]
     pub governance: bool,
+
+    /// Rewrite node YAML columns to match the schema inferred from SQL (prints a diff first)
+    #[arg(long, conflicts_with = "skip_static_analysis")]
+    pub fix: bool,
 }
 
 /// Arguments for the run command
@@ -355,6 +363,8 @@ pub(crate) struct DocsArgs {
 pub(crate) enum DocsCommands {
     /// Launch interactive documentation server
     Serve(DocsServeArgs),
+    /// Show (or write into YAML) column descriptions inherited through lineage
+    Inherit(DocsInheritArgs),
 }
 
 /// Arguments for the docs serve subcommand
@@ -381,6 +391,18 @@ pub(crate) struct DocsServeArgs {
     pub static_export: Option<String>,
 }
 
+/// Arguments for the docs inherit subcommand
+#[derive(Args, Debug)]
+pub(crate) struct DocsInheritArgs {
+    /// Node selector (names, +node, node+, N+node, node+N, tag:X, path:X)
+    #[arg(short = 'n', long)]
+    pub nodes: Option<String>,
+
+    /// Persist inherited descriptions into node YAML files (default: report only)
+    #[arg(long)]
+    pub write: bool,
+}
+
 /// Documentation output formats
 #[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum DocsFormat {
@@ -485,7 +507,7 @@ pub(crate) struct AnalyzeArgs {
     pub pass: Option<String>,
 
     /// Output format
-    #[arg(short, long, value_enum, default_value = "table")]
+    #[arg(short, long, value_enum, default_value = "pretty")]
     pub output: AnalyzeOutput,
 
     /// Minimum severity to display
@@ -499,6 +521,20 @@ pub(crate) struct AnalyzeArgs {
     /// List discovered rules without executing them (use with --rules)
     #[arg(long, requires = "rules")]
     pub rules_list: bool,
+
+    /// Describe every model in an in-memory DuckDB and report where its
+    /// types disagree with the inferred schema (A009)
+    #[arg(long)]
+    pub verify_engine: bool,
+
+    /// Diagnostics baseline file (default: `analysis.baseline`, else
+    /// analysis-baseline.json); diagnostics recorded in it are not reported
+    #[arg(long)]
+    pub baseline: Option<String>,
+
+    /// Record the current diagnostics to the baseline file instead of reporting them
+    #[arg(long, conflicts_with = "pass")]
+    pub write_baseline: bool,
 }
 
 /// Analyze subcommands (meta database operations)
@@ -517,10 +553,14 @@ pub(crate) enum AnalyzeCommands {
 /// Analyze output formats
 #[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum AnalyzeOutput {
+    /// Code frames quoting each model's `.sql` source
+    Pretty,
     /// Human-readable table
     Table,
     /// JSON output
     Json,
+    /// SARIF 2.1.0 log for code scanning tools
+    Sarif,
 }
 
 /// Arguments for the function command
@@ -706,6 +746,159 @@ pub(crate) enum DtCommands {
 
     /// Analyze SQL models for potential issues
     Analyze(AnalyzeArgs),
+
+    /// Generate node YAML by introspecting the database
+    Generate(GenerateArgs),
+
+    /// Install packages and write featherflow.lock
+    Deps(DepsArgs),
+
+    /// Run macro unit tests from *.test.yml fixtures in the macro directories
+    TestMacros(TestMacrosArgs),
+
+    /// Convert a project from another tool into node layout
+    Import(ImportArgs),
+
+    /// Write project metadata in formats other tools read
+    Export(ExportArgs),
+}
+
+/// Arguments for the import command
+#[derive(Args, Debug)]
+pub(crate) struct ImportArgs {
+    /// Import subcommand
+    #[command(subcommand)]
+    pub command: ImportCommands,
+}
+
+/// Import subcommands
+#[derive(Subcommand, Debug)]
+pub(crate) enum ImportCommands {
+    /// Convert a dbt project's models, sources, seeds, macros, and tests
+    Dbt(ImportDbtArgs),
+}
+
+/// Arguments for the import dbt subcommand
+#[derive(Args, Debug)]
+pub(crate) struct ImportDbtArgs {
+    /// Path to the dbt project (the directory containing dbt_project.yml)
+    pub path: PathBuf,
+
+    /// Directory to write the Featherflow project to (default: --project-dir)
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Skip the compile run after importing
+    #[arg(long)]
+    pub no_compile: bool,
+}
+
+/// Arguments for the export command
+#[derive(Args, Debug)]
+pub(crate) struct ExportArgs {
+    /// Export subcommand
+    #[command(subcommand)]
+    pub command: ExportCommands,
+}
+
+/// Export subcommands
+#[derive(Subcommand, Debug)]
+pub(crate) enum ExportCommands {
+    /// Write dbt-compatible manifest.json and catalog.json
+    DbtArtifacts(ExportDbtArtifactsArgs),
+
+    /// Write node schemas as data contracts (JSON Schema, Avro or ODCS)
+    Contracts(ExportContractsArgs),
+}
+
+/// Arguments for the export dbt-artifacts subcommand
+#[derive(Args, Debug)]
+pub(crate) struct ExportDbtArtifactsArgs {
+    /// Directory to write the artifacts to (default: target/dbt)
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+/// Arguments for the export contracts subcommand
+#[derive(Args, Debug)]
+pub(crate) struct ExportContractsArgs {
+    /// Node selector (names, +node, node+, N+node, node+N, tag:X, path:X)
+    #[arg(short = 'n', long)]
+    pub nodes: Option<String>,
+
+    /// Contract format to write
+    #[arg(short, long, value_enum, default_value = "jsonschema")]
+    pub format: ContractFormat,
+
+    /// Directory to write the contracts to (default: target/contracts)
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+}
+
+/// Data contract formats for `export contracts`
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ContractFormat {
+    /// JSON Schema (draft 2020-12), one `<node>.schema.json` per node
+    Jsonschema,
+    /// Avro record schema, one `<node>.avsc` per node
+    Avro,
+    /// Open Data Contract Standard v3 YAML, one `<node>.odcs.yaml` per node
+    Odcs,
+}
+
+/// Arguments for the test-macros command
+#[derive(Args, Debug)]
+pub(crate) struct TestMacrosArgs {
+    /// Only run tests of these macros (comma-separated)
+    #[arg(short, long)]
+    pub macros: Option<String>,
+
+    /// Stop on first failure
+    #[arg(long)]
+    pub fail_fast: bool,
+}
+
+/// Arguments for the deps command
+#[derive(Args, Debug)]
+pub(crate) struct DepsArgs {
+    /// Check that featherflow.lock matches the packages without changing anything
+    #[arg(long)]
+    pub check: bool,
+}
+
+/// Arguments for the generate command
+#[derive(Args, Debug)]
+pub(crate) struct GenerateArgs {
+    /// Generate subcommand
+    #[command(subcommand)]
+    pub command: GenerateCommands,
+}
+
+/// Generate subcommands
+#[derive(Subcommand, Debug)]
+pub(crate) enum GenerateCommands {
+    /// Write (or merge into) a `kind: source` node from a database schema
+    Sources(GenerateSourcesArgs),
+}
+
+/// Arguments for the generate sources subcommand
+#[derive(Args, Debug)]
+pub(crate) struct GenerateSourcesArgs {
+    /// Database schema to introspect
+    #[arg(long)]
+    pub schema: String,
+
+    /// Tables to include (comma-separated, default: every table in the schema)
+    #[arg(long)]
+    pub tables: Option<String>,
+
+    /// Source name, also used as the node directory name (default: the schema name)
+    #[arg(long)]
+    pub name: Option<String>,
+
+    /// Print the resulting YAML instead of writing it
+    #[arg(long)]
+    pub dry_run: bool,
 }
 
 #[cfg(test)]
@@ -2,8 +2,10 @@
 
 use anyhow::{Context, Result};
 use ff_analysis::{
-    apply_severity_overrides, propagate_schemas, AnalysisContext, PlanPassManager, RelSchema,
-    SchemaCatalog, Severity, SeverityOverrides,
+    apply_severity_overrides, column_span, engine_describe_failure,
+    propagate_schemas_with_fallback, verify_engine_schema, AnalysisContext, Baseline, Baselined,
+    Diagnostic, DiagnosticCode, PlanPassManager, PropagationResult, RelSchema, SchemaCatalog,
+    Severity, SeverityOverrides, ENGINE_VERIFY_PASS,
 };
 use ff_core::classification::{
     build_classification_lookup, propagate_classifications_topo, ClassificationEdge,
@@ -12,16 +14,22 @@ use ff_core::dag::ModelDag;
 use ff_core::ModelName;
 use ff_sql::{extract_column_lineage, extract_dependencies, ExprType, ProjectLineage, SqlParser};
 use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use std::sync::Arc;
 
 use crate::cli::{AnalyzeArgs, AnalyzeCommands, AnalyzeOutput, AnalyzeSeverity, GlobalArgs};
+use crate::commands::code_frame::{severity_label, CodeFrame, ModelSource};
 use crate::commands::common::{
     self, build_external_tables_lookup, build_schema_catalog, load_project,
     print_table as print_common_table,
 };
+use crate::commands::engine_stub::{EngineFallback, EngineStub};
 use crate::commands::meta;
 use ff_core::rules::{discover_rules, resolve_rule_paths, OnRuleFailure, RuleSeverity};
 
+/// JSON schema of the SARIF logs `--output sarif` writes
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
 /// Execute the analyze command
 pub(crate) async fn execute(args: &AnalyzeArgs, global: &GlobalArgs) -> Result<()> {
     match &args.command {
@@ -38,7 +46,12 @@ async fn run_analysis(args: &AnalyzeArgs, global: &GlobalArgs) -> Result<()> {
 
     let parser = SqlParser::from_dialect_name(&project.config.dialect.to_string())
         .context("Invalid SQL dialect")?;
-    let jinja = common::build_jinja_env_with_context(&project, global.database.as_deref(), false);
+    let jinja = common::build_jinja_env_with_context(
+        &project,
+        global,
+        false,
+        &common::build_node_catalog(&project),
+    );
 
     let known_models: HashSet<&str> = project.models.keys().map(|k| k.as_str()).collect();
 
@@ -85,6 +98,7 @@ async fn run_analysis(args: &AnalyzeArgs, global: &GlobalArgs) -> Result<()> {
         .into_iter()
         .filter(|n| resolved_set.contains(n))
         .filter(|n| project.models.contains_key(n.as_str()))
+        .filter(|n| common::is_sql_model(&project, n))
         .map(ModelName::new)
         .collect();
 
@@ -115,7 +129,8 @@ async fn run_analysis(args: &AnalyzeArgs, global: &GlobalArgs) -> Result<()> {
         .collect();
 
     let severity_overrides =
-        SeverityOverrides::from_config(&project.config.analysis.severity_overrides);
+        SeverityOverrides::from_config(&project.config.analysis.severity_overrides)
+            .with_node_rules(&project, &sql_sources);
 
     let ctx = AnalysisContext::new(project, dag, yaml_schemas, project_lineage);
 
@@ -128,16 +143,23 @@ async fn run_analysis(args: &AnalyzeArgs, global: &GlobalArgs) -> Result<()> {
 
     let (user_fn_stubs, user_table_fn_stubs) =
         ff_analysis::build_user_function_stubs(ctx.project());
-    let propagation = propagate_schemas(
+    let mut fallback = EngineFallback::new(ctx.project(), global.verbose);
+    let propagation = propagate_schemas_with_fallback(
         &order,
         &sql_sources,
         ctx.yaml_schemas(),
         plan_catalog,
         &user_fn_stubs,
         &user_table_fn_stubs,
-    );
-
-    if propagation.model_plans.is_empty() && propagation.failures.is_empty() {
+        &mut fallback,
+    )
+    .await;
+    drop(fallback);
+
+    if propagation.model_plans.is_empty()
+        && propagation.engine_inferred.is_empty()
+        && propagation.failures.is_empty()
+    {
         println!("No models to analyze.");
         return Ok(());
     }
@@ -154,15 +176,122 @@ async fn run_analysis(args: &AnalyzeArgs, global: &GlobalArgs) -> Result<()> {
         .map(|p| p.split(',').map(|s| s.trim().to_string()).collect());
 
     let plan_pass_manager = PlanPassManager::with_defaults();
-    let diagnostics = plan_pass_manager.run(
+    let mut diagnostics = plan_pass_manager.run(
         &order,
         &propagation.model_plans,
         &ctx,
         pass_filter.as_deref(),
     );
+    diagnostics.extend(plan_pass_manager.run_engine_inferred(
+        &propagation.engine_inferred,
+        &ctx,
+        pass_filter.as_deref(),
+    ));
+
+    let runs_pass = |name: &str| {
+        pass_filter
+            .as_ref()
+            .is_none_or(|passes| passes.iter().any(|p| p == name))
+    };
+    if runs_pass(SCHEMA_PROPAGATION_PASS) {
+        diagnostics.extend(engine_inferred_diagnostics(&order, &propagation));
+    }
+
+    if args.verify_engine && runs_pass(ENGINE_VERIFY_PASS) {
+        diagnostics.extend(
+            verify_engine(
+                &ctx,
+                &parser,
+                &order,
+                &sql_sources,
+                &propagation,
+                global.verbose,
+            )
+            .await?,
+        );
+    }
+
+    let analyzed: Vec<ModelName> = order
+        .iter()
+        .filter(|n| {
+            propagation.model_plans.contains_key(*n) || propagation.engine_inferred.contains_key(*n)
+        })
+        .cloned()
+        .collect();
+    let suppression_issues = severity_overrides.audit_suppressions(
+        &diagnostics,
+        &propagation,
+        &analyzed,
+        pass_filter.is_none(),
+    );
+    diagnostics.extend(suppression_issues);
 
     let diagnostics = apply_severity_overrides(diagnostics, &severity_overrides);
 
+    // Models whose diagnostics are complete: planned or engine-inferred
+    let checked: Vec<ModelName> = order
+        .iter()
+        .filter(|n| !propagation.failures.contains_key(*n))
+        .cloned()
+        .collect();
+    let baseline_path = match &args.baseline {
+        Some(path) => ctx.project().root.join(path),
+        None => ctx
+            .project()
+            .config
+            .analysis
+            .baseline_path(&ctx.project().root),
+    };
+    if args.baseline.is_some() && !args.write_baseline && !baseline_path.exists() {
+        anyhow::bail!("Baseline file not found: {}", baseline_path.display());
+    }
+    let baseline = common::load_baseline(&baseline_path)?;
+
+    if args.write_baseline {
+        // Schema mismatches are recorded too, for `ff dt compile --strict`
+        let mismatches: Vec<_> = common::active_mismatches(&propagation, &severity_overrides)
+            .map(|(model, mismatch, _)| (model, mismatch))
+            .collect();
+        let recorded = Baseline::record(&diagnostics, &mismatches, baseline.as_ref(), &checked);
+        common::write_json_results(&baseline_path, &recorded)?;
+        println!(
+            "Recorded {} diagnostics in {}",
+            recorded.entries.len(),
+            baseline_path.display()
+        );
+        return Ok(());
+    }
+
+    let (diagnostics, baselined) = match &baseline {
+        Some(baseline) => {
+            let comparison = baseline.compare(diagnostics, &checked, pass_filter.is_none());
+            let fixed =
+                apply_severity_overrides(comparison.fixed_diagnostics(), &severity_overrides);
+            let mut reported = comparison.new;
+            reported.extend(fixed);
+            (reported, comparison.baselined)
+        }
+        None => (diagnostics, 0),
+    };
+
+    // Spans point into the rendered SQL; report them against the `.sql` files
+    let sources: HashMap<&ModelName, ModelSource> = sql_sources
+        .iter()
+        .filter_map(|(name, sql)| {
+            let model = ctx.project().get_model(name)?;
+            Some((name, ModelSource::new(ctx.project(), model, sql)))
+        })
+        .collect();
+    let diagnostics: Vec<Diagnostic> = diagnostics
+        .into_iter()
+        .map(|mut d| {
+            if let (Some(span), Some(source)) = (d.span, sources.get(&d.model)) {
+                d.span = Some(source.map_span(span));
+            }
+            d
+        })
+        .collect();
+
     let min_severity = match args.severity {
         AnalyzeSeverity::Info => Severity::Info,
         AnalyzeSeverity::Warning => Severity::Warning,
@@ -175,15 +304,24 @@ async fn run_analysis(args: &AnalyzeArgs, global: &GlobalArgs) -> Result<()> {
         .collect();
 
     match args.output {
+        AnalyzeOutput::Pretty => print_frames(&filtered, &sources),
         AnalyzeOutput::Json => print_json(&filtered)?,
+        AnalyzeOutput::Sarif => print_sarif(&filtered, ctx.project(), baseline.is_some())?,
         AnalyzeOutput::Table => print_table(&filtered),
     }
+    if baselined > 0 && matches!(args.output, AnalyzeOutput::Pretty | AnalyzeOutput::Table) {
+        println!(
+            "{} baselined diagnostics not shown ({})",
+            baselined,
+            baseline_path.display()
+        );
+    }
 
     if let Some(meta_db) = common::open_meta_db(ctx.project()) {
         if let Some((_project_id, run_id, model_id_map)) =
             common::populate_meta_phase1(&meta_db, ctx.project(), "analyze", args.nodes.as_deref())
         {
-            populate_meta_analysis(&meta_db, &ctx, &order, &model_id_map);
+            populate_meta_analysis(&meta_db, &ctx, &order, &propagation, &model_id_map);
 
             let status = if filtered.iter().any(|d| d.severity == Severity::Error) {
                 "error"
@@ -208,6 +346,95 @@ async fn run_analysis(args: &AnalyzeArgs, global: &GlobalArgs) -> Result<()> {
     Ok(())
 }
 
+/// Pass name reported on engine-inferred schema diagnostics (A042)
+const SCHEMA_PROPAGATION_PASS: &str = "schema_propagation";
+
+/// Mark models whose schema came from DuckDB rather than a DataFusion plan (A042).
+///
+/// Plan-based passes skip these models, so the diagnostic also explains why
+/// they have no other findings.
+fn engine_inferred_diagnostics(
+    order: &[ModelName],
+    propagation: &PropagationResult,
+) -> Vec<Diagnostic> {
+    order
+        .iter()
+        .filter_map(|name| {
+            let result = propagation.engine_inferred.get(name)?;
+            Some(Diagnostic {
+                code: DiagnosticCode::A042,
+                severity: Severity::Info,
+                message: format!(
+                    "Schema engine-inferred by DuckDB; plan-based passes skipped ({})",
+                    result.planning_error
+                ),
+                model: name.clone(),
+                column: None,
+                hint: Some(
+                    "DataFusion cannot plan this SQL (e.g. PIVOT, ASOF JOIN, QUALIFY); downstream models still see its schema".to_string(),
+                ),
+                pass_name: SCHEMA_PROPAGATION_PASS.into(),
+                span: None,
+            })
+        })
+        .collect()
+}
+
+/// Describe each model in an in-memory DuckDB, in DAG order, and report the
+/// columns whose engine type or nullability disagrees with the inferred schema.
+///
+/// Models outside the selection are stubbed from their YAML columns, mirroring
+/// the catalog DataFusion planned against.
+async fn verify_engine(
+    ctx: &AnalysisContext,
+    parser: &SqlParser,
+    order: &[ModelName],
+    sql_sources: &HashMap<ModelName, String>,
+    propagation: &PropagationResult,
+    verbose: bool,
+) -> Result<Vec<Diagnostic>> {
+    let mut stub = EngineStub::build(ctx.project(), verbose).await?;
+
+    let mut declared: Vec<_> = ctx
+        .yaml_schemas()
+        .iter()
+        .filter(|(name, _)| !sql_sources.contains_key(*name))
+        .collect();
+    declared.sort_by(|a, b| a.0.cmp(b.0));
+    for (name, schema) in declared {
+        stub.declare_model(name.as_str(), schema).await;
+    }
+
+    let mut diagnostics = Vec::new();
+    for name in order {
+        let Some(sql) = sql_sources.get(name) else {
+            continue;
+        };
+        let engine_columns = match stub.describe_model(name.as_str(), sql).await {
+            Ok(columns) => columns,
+            Err(e) => {
+                diagnostics.push(engine_describe_failure(name, &e.to_string()));
+                continue;
+            }
+        };
+        if let Some(result) = propagation.model_plans.get(name) {
+            diagnostics.extend(
+                verify_engine_schema(name, &result.inferred_schema, &engine_columns)
+                    .into_iter()
+                    .map(|mut d| {
+                        d.span = d
+                            .column
+                            .as_deref()
+                            .and_then(|column| column_span(parser, sql, column));
+                        d
+                    }),
+            );
+        }
+    }
+
+    Ok(diagnostics)
+}
+
 /// Execute SQL rules against the meta database. Returns true if there were errors.
 fn execute_rules(
     project: &ff_core::Project,
@@ -364,9 +591,13 @@ fn populate_meta_analysis(
     meta_db: &ff_meta::MetaDb,
     ctx: &AnalysisContext,
     order: &[ModelName],
+    propagation: &PropagationResult,
     model_id_map: &HashMap<ModelName, i64>,
 ) {
     if let Err(e) = meta_db.transaction(|conn| {
+        let inference = build_schema_inference(propagation, model_id_map);
+        ff_meta::populate::analysis::populate_schema_inference(conn, &inference)?;
+
         let meta_edges = build_meta_lineage_edges(ctx.lineage(), model_id_map);
         ff_meta::populate::analysis::populate_column_lineage(conn, &meta_edges)?;
 
@@ -382,6 +613,54 @@ fn populate_meta_analysis(
     }
 }
 
+/// Record for each analyzed model whether DataFusion or DuckDB inferred its schema
+fn build_schema_inference(
+    propagation: &PropagationResult,
+    model_id_map: &HashMap<ModelName, i64>,
+) -> Vec<ff_meta::populate::analysis::SchemaInference> {
+    let planned = propagation.model_plans.keys().map(|n| (n, "datafusion"));
+    let engine = propagation.engine_inferred.keys().map(|n| (n, "engine"));
+    planned
+        .chain(engine)
+        .filter_map(|(name, inferred_by)| {
+            Some(ff_meta::populate::analysis::SchemaInference {
+                model_id: *model_id_map.get(name)?,
+                inferred_by: inferred_by.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Print diagnostics as rustc-style code frames pointing into each model's `.sql` file
+fn print_frames(diagnostics: &[Diagnostic], sources: &HashMap<&ModelName, ModelSource>) {
+    if diagnostics.is_empty() {
+        println!("No diagnostics found.");
+        return;
+    }
+
+    for (i, d) in diagnostics.iter().enumerate() {
+        if i > 0 {
+            println!();
+        }
+        match sources.get(&d.model) {
+            Some(source) => print!("{}", source.frame(d)),
+            None => print!(
+                "{}",
+                CodeFrame {
+                    level: severity_label(d.severity),
+                    code: d.code.to_string(),
+                    message: &d.message,
+                    path: d.model.as_str(),
+                    source: None,
+                    span: d.span,
+                    hint: d.hint.as_deref(),
+                }
+            ),
+        }
+    }
+    print_summary(diagnostics);
+}
+
 /// Print diagnostics as a table
 fn print_table(diagnostics: &[ff_analysis::Diagnostic]) {
     if diagnostics.is_empty() {
@@ -445,6 +724,11 @@ fn print_table(diagnostics: &[ff_analysis::Diagnostic]) {
         );
     }
 
+    print_summary(diagnostics);
+}
+
+/// Print the diagnostic count line that ends human-readable output
+fn print_summary(diagnostics: &[Diagnostic]) {
     let errors = diagnostics
         .iter()
         .filter(|d| d.severity == Severity::Error)
@@ -474,6 +758,88 @@ fn print_json(diagnostics: &[ff_analysis::Diagnostic]) -> Result<()> {
     Ok(())
 }
 
+/// Print diagnostics as a SARIF 2.1.0 log for code scanning tools.
+///
+/// When a baseline was applied, results carry a `baselineState`: `new` for
+/// diagnostics outside the baseline and `absent` for fixed entries (A062).
+fn print_sarif(
+    diagnostics: &[Diagnostic],
+    project: &ff_core::Project,
+    baseline_applied: bool,
+) -> Result<()> {
+    let mut rule_ids: Vec<String> = diagnostics.iter().map(|d| d.code.to_string()).collect();
+    rule_ids.sort();
+    rule_ids.dedup();
+    let rules: Vec<serde_json::Value> = rule_ids
+        .iter()
+        .map(|id| serde_json::json!({ "id": id }))
+        .collect();
+
+    let results: Vec<serde_json::Value> = diagnostics
+        .iter()
+        .map(|d| {
+            let mut result = serde_json::json!({
+                "ruleId": d.code.to_string(),
+                "level": match d.severity {
+                    Severity::Error => "error",
+                    Severity::Warning => "warning",
+                    Severity::Info => "note",
+                },
+                "message": { "text": d.message },
+                "partialFingerprints": {
+                    "featherflow/v1": d.baseline_entry().fingerprint,
+                },
+            });
+            if let Some(model) = project.get_model(&d.model) {
+                let uri = model
+                    .path
+                    .strip_prefix(&project.root)
+                    .unwrap_or(&model.path)
+                    .to_string_lossy()
+                    .replace('\\', "/");
+                let mut location = serde_json::json!({
+                    "physicalLocation": { "artifactLocation": { "uri": uri } },
+                });
+                if let Some(span) = d.span {
+                    location["physicalLocation"]["region"] = serde_json::json!({
+                        "startLine": span.line,
+                        "startColumn": span.column,
+                        "endLine": span.end_line,
+                        "endColumn": span.end_column,
+                    });
+                }
+                result["locations"] = serde_json::json!([location]);
+            }
+            if baseline_applied {
+                result["baselineState"] = if d.code == DiagnosticCode::A062 {
+                    "absent".into()
+                } else {
+                    "new".into()
+                };
+            }
+            result
+        })
+        .collect();
+
+    let log = serde_json::json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "featherflow",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                },
+            },
+            "results": results,
+        }],
+    });
+    let json = serde_json::to_string_pretty(&log).context("Failed to serialize SARIF log")?;
+    println!("{}", json);
+    Ok(())
+}
+
 /// Convert ff-sql lineage edges to ff-meta lineage edges using model ID lookup.
 fn build_meta_lineage_edges(
     lineage: &ProjectLineage,
@@ -152,8 +152,9 @@ pub(crate) async fn execute(args: &BuildArgs, global: &GlobalArgs) -> Result<()>
     let comment_ctx =
         common::build_query_comment_context(&project.config, global.database.as_deref());
 
-    let compiled_models = load_or_compile_models(&project, &run_args, global, comment_ctx.as_ref())
-        .context("Failed to compile models")?;
+    let (compiled_models, _) =
+        load_or_compile_models(&project, &db, &run_args, global, comment_ctx.as_ref())
+            .context("Failed to compile models")?;
 
     common::run_static_analysis_gate(
         &project,
@@ -161,7 +162,8 @@ pub(crate) async fn execute(args: &BuildArgs, global: &GlobalArgs) -> Result<()>
         global,
         args.skip_static_analysis,
         quiet,
-    )?;
+    )
+    .await?;
 
     let execution_order = determine_execution_order(&compiled_models, &project, &run_args, global)?;
 
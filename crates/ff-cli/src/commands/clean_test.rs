@@ -29,6 +29,7 @@ database:
         verbose: false,
         project_dir: project_path.to_path_buf(),
         database: None,
+        offline: false,
     };
 
     execute(&args, &global).await.unwrap();
@@ -59,6 +60,7 @@ database:
         verbose: false,
         project_dir: project_path.to_path_buf(),
         database: None,
+        offline: false,
     };
 
     execute(&args, &global).await.unwrap();
@@ -90,6 +92,7 @@ database:
         verbose: true,
         project_dir: project_path.to_path_buf(),
         database: None,
+        offline: false,
     };
 
     // Should not error
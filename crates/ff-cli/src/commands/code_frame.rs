@@ -0,0 +1,242 @@
+//! rustc-style code frames for diagnostics
+//!
+//! Positions from static analysis and DuckDB refer to the rendered SQL; a
+//! [`ModelSource`] maps them back to the model's `.sql` template so the frame
+//! quotes the line the user actually wrote:
+//!
+//! ```text
+//! warning[A011]: Column 'email' is declared NOT NULL in YAML but becomes nullable after JOIN
+//!   --> nodes/dim_customers/dim_customers.sql:4:5
+//!    |
+//!  4 |     c.email,
+//!    |     ^^^^^^^
+//!    = hint: Add a COALESCE or filter to ensure NOT NULL
+//! ```
+
+use std::fmt;
+
+use ff_analysis::{Diagnostic, SourceSpan};
+use ff_core::{Model, Project};
+use ff_jinja::SourceMap;
+
+/// A model's template and the map from its rendered SQL back to it
+pub(crate) struct ModelSource {
+    /// Template path relative to the project root
+    path: String,
+    template: String,
+    map: SourceMap,
+}
+
+impl ModelSource {
+    /// Pair `model`'s template with SQL rendered (and possibly rewritten) from it.
+    pub(crate) fn new(project: &Project, model: &Model, rendered: &str) -> Self {
+        let path = model
+            .path
+            .strip_prefix(&project.root)
+            .map(|p| p.display().to_string())
+            .unwrap_or_else(|_| model.path.display().to_string());
+        Self {
+            path,
+            template: model.raw_sql.clone(),
+            map: SourceMap::new(&model.raw_sql, rendered),
+        }
+    }
+
+    /// Map a span in the rendered SQL to the template.
+    ///
+    /// Spans that collapse or reverse once mapped (the end fell inside text
+    /// produced by a tag) keep their start and underline a single character.
+    pub(crate) fn map_span(&self, span: SourceSpan) -> SourceSpan {
+        let (line, column) = self.map.locate(span.line, span.column);
+        let (end_line, end_column) = self
+            .map
+            .locate(span.end_line, span.end_column.saturating_sub(1).max(1));
+        let end = (end_line, end_column + 1);
+        let (end_line, end_column) = if end > (line, column) {
+            end
+        } else {
+            (line, column + 1)
+        };
+        SourceSpan {
+            line,
+            column,
+            end_line,
+            end_column,
+        }
+    }
+
+    /// A frame for `diagnostic`, whose span must already be mapped to the template
+    pub(crate) fn frame<'a>(&'a self, diagnostic: &'a Diagnostic) -> CodeFrame<'a> {
+        CodeFrame {
+            level: severity_label(diagnostic.severity),
+            code: diagnostic.code.to_string(),
+            message: &diagnostic.message,
+            path: &self.path,
+            source: Some(&self.template),
+            span: diagnostic.span,
+            hint: diagnostic.hint.as_deref(),
+        }
+    }
+
+    /// A frame for a message about this model, optionally pointing into it
+    pub(crate) fn frame_for<'a>(
+        &'a self,
+        level: &'a str,
+        code: &str,
+        message: &'a str,
+        span: Option<SourceSpan>,
+    ) -> CodeFrame<'a> {
+        CodeFrame {
+            level,
+            code: code.to_string(),
+            message,
+            path: &self.path,
+            source: Some(&self.template),
+            span,
+            hint: None,
+        }
+    }
+}
+
+/// One diagnostic rendered as a header, a `-->` location, the quoted source
+/// line with carets under the span, and an optional hint.
+pub(crate) struct CodeFrame<'a> {
+    pub(crate) level: &'a str,
+    pub(crate) code: String,
+    pub(crate) message: &'a str,
+    pub(crate) path: &'a str,
+    pub(crate) source: Option<&'a str>,
+    pub(crate) span: Option<SourceSpan>,
+    pub(crate) hint: Option<&'a str>,
+}
+
+impl fmt::Display for CodeFrame<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.code.is_empty() {
+            writeln!(f, "{}: {}", self.level, self.message)?;
+        } else {
+            writeln!(f, "{}[{}]: {}", self.level, self.code, self.message)?;
+        }
+
+        let quoted = self.span.and_then(|span| {
+            let line = self.source?.lines().nth(span.line.checked_sub(1)?)?;
+            Some((span, line))
+        });
+        let gutter = quoted.map_or(1, |(span, _)| span.line.to_string().len() + 1);
+        let pad = " ".repeat(gutter);
+
+        match quoted {
+            Some((span, line)) => {
+                writeln!(f, "{pad}--> {}:{}:{}", self.path, span.line, span.column)?;
+                writeln!(f, "{pad} |")?;
+                let (text, start, width) = underline(line, &span);
+                writeln!(f, "{:>w$} | {}", span.line, text, w = gutter)?;
+                writeln!(f, "{pad} | {}{}", " ".repeat(start), "^".repeat(width))?;
+            }
+            None => match self.span {
+                Some(span) => writeln!(f, "{pad}--> {}:{}:{}", self.path, span.line, span.column)?,
+                None => writeln!(f, "{pad}--> {}", self.path)?,
+            },
+        }
+        if let Some(hint) = self.hint {
+            writeln!(f, "{pad} = hint: {hint}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The quoted line with tabs expanded, and the caret offset and width
+///
+/// Spans running past the end of the line are underlined to its end.
+fn underline(line: &str, span: &SourceSpan) -> (String, usize, usize) {
+    let mut text = String::new();
+    let mut start = None;
+    let mut end = None;
+    for (idx, ch) in line.chars().enumerate() {
+        if idx + 1 == span.column {
+            start = Some(text.chars().count());
+        }
+        if span.end_line == span.line && idx + 1 == span.end_column {
+            end = Some(text.chars().count());
+        }
+        match ch {
+            '\t' => text.push_str("    "),
+            _ => text.push(ch),
+        }
+    }
+    let len = text.chars().count();
+    let start = start.unwrap_or(len);
+    let end = end.unwrap_or(len).max(start + 1);
+    (text, start, end - start)
+}
+
+/// The rustc-style level name for a severity
+pub(crate) fn severity_label(severity: ff_analysis::Severity) -> &'static str {
+    match severity {
+        ff_analysis::Severity::Error => "error",
+        ff_analysis::Severity::Warning => "warning",
+        ff_analysis::Severity::Info => "info",
+    }
+}
+
+/// Locate the `LINE n:` excerpt of a DuckDB error in the SQL that failed.
+///
+/// DuckDB quotes the offending line of the statement it ran (which wraps the
+/// model SQL in `CREATE ... AS`) and puts a caret under the error position.
+/// The excerpt is searched for in `sql`, preferring the match nearest to line
+/// `n`, so the span is correct even when the wrapper or a query comment
+/// shifted DuckDB's line numbers.
+pub(crate) fn locate_engine_error(message: &str, sql: &str) -> Option<SourceSpan> {
+    let mut lines = message.lines();
+    let (reported, prefix_len, excerpt) = lines.by_ref().find_map(|line| {
+        let rest = line.trim_start().strip_prefix("LINE ")?;
+        let (number, excerpt) = rest.split_once(": ")?;
+        let reported: usize = number.parse().ok()?;
+        let prefix_len = line.chars().count() - excerpt.chars().count();
+        Some((reported, prefix_len, excerpt))
+    })?;
+    let caret = lines
+        .next()
+        .and_then(|line| line.chars().position(|c| c == '^'))
+        .map_or(0, |pos| pos.saturating_sub(prefix_len));
+
+    let (excerpt, caret) = match excerpt.strip_prefix("...") {
+        Some(rest) => (rest, caret.saturating_sub(3)),
+        None => (excerpt, caret),
+    };
+    let excerpt = excerpt.strip_suffix("...").unwrap_or(excerpt);
+
+    let column_of = |line: &str| -> Option<usize> {
+        if let Some(pos) = line
+            .find(excerpt.trim_end())
+            .filter(|_| !excerpt.trim().is_empty())
+        {
+            return Some(line[..pos].chars().count() + caret);
+        }
+        // The excerpt also holds text DuckDB added before this line
+        let code = line.trim_start();
+        if code.is_empty() {
+            return None;
+        }
+        let pos = excerpt.find(code)?;
+        let indent = line.chars().count() - code.chars().count();
+        let offset = excerpt[..pos].chars().count();
+        Some(indent + caret.checked_sub(offset)?)
+    };
+
+    let (line, column) = sql
+        .lines()
+        .enumerate()
+        .filter_map(|(idx, line)| Some((idx + 1, column_of(line)?)))
+        .min_by_key(|(line, _)| line.abs_diff(reported))?;
+    Some(SourceSpan {
+        line,
+        column: column + 1,
+        end_line: line,
+        end_column: column + 2,
+    })
+}
+
+#[cfg(test)]
+#[path = "code_frame_test.rs"]
+mod tests;
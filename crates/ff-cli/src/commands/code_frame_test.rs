@@ -0,0 +1,77 @@
+use super::*;
+
+fn span(line: usize, column: usize, end_line: usize, end_column: usize) -> SourceSpan {
+    SourceSpan {
+        line,
+        column,
+        end_line,
+        end_column,
+    }
+}
+
+#[test]
+fn test_frame_quotes_line_with_carets() {
+    let source = "SELECT\n    id,\n    amount * 2 AS doubled\nFROM orders";
+    let frame = CodeFrame {
+        level: "warning",
+        code: "A002".to_string(),
+        message: "Type mismatch",
+        path: "nodes/orders/orders.sql",
+        source: Some(source),
+        span: Some(span(3, 5, 3, 15)),
+        hint: Some("Add an explicit CAST"),
+    };
+    assert_eq!(
+        frame.to_string(),
+        "warning[A002]: Type mismatch\n  --> nodes/orders/orders.sql:3:5\n   |\n 3 |     amount * 2 AS doubled\n   |     ^^^^^^^^^^\n   = hint: Add an explicit CAST\n"
+    );
+}
+
+#[test]
+fn test_frame_without_span_names_file() {
+    let frame = CodeFrame {
+        level: "error",
+        code: "SA01".to_string(),
+        message: "Column 'id' declared in YAML but missing from SQL output",
+        path: "nodes/orders/orders.sql",
+        source: Some("SELECT 1"),
+        span: None,
+        hint: None,
+    };
+    assert_eq!(
+        frame.to_string(),
+        "error[SA01]: Column 'id' declared in YAML but missing from SQL output\n --> nodes/orders/orders.sql\n"
+    );
+}
+
+#[test]
+fn test_multiline_span_underlines_to_end_of_line() {
+    let (text, start, width) = underline("\tsum(amount)", &span(1, 2, 2, 4));
+    assert_eq!(text, "    sum(amount)");
+    assert_eq!((start, width), (4, 11));
+}
+
+#[test]
+fn test_locate_engine_error_in_model_sql() {
+    let sql = "SELECT\n    id,\n    amout * 2 AS doubled\nFROM orders";
+    let message = "Binder Error: Referenced column \"amout\" not found in FROM clause!\n\nLINE 3:     amout * 2 AS doubled\n            ^";
+    assert_eq!(locate_engine_error(message, sql), Some(span(3, 5, 3, 6)));
+}
+
+#[test]
+fn test_locate_engine_error_behind_create_wrapper() {
+    let sql = "SELECT amout FROM orders";
+    let message = "Binder Error: Referenced column \"amout\" not found\n\nLINE 1: CREATE OR REPLACE TABLE \"orders_v\" AS SELECT amout FROM orders\n                                                     ^";
+    assert_eq!(locate_engine_error(message, sql), Some(span(1, 8, 1, 9)));
+}
+
+#[test]
+fn test_locate_engine_error_without_excerpt() {
+    assert_eq!(
+        locate_engine_error(
+            "Catalog Error: Table with name t does not exist!",
+            "SELECT 1"
+        ),
+        None
+    );
+}
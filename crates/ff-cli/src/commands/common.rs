@@ -4,6 +4,7 @@ use anyhow::{Context, Result};
 use chrono::{DateTime, Utc};
 use ff_core::config::Config;
 use ff_core::source::build_source_lookup;
+use ff_core::sql_utils::is_valid_identifier;
 use ff_core::Project;
 use ff_db::{Database, DuckDbBackend};
 use serde::Serialize;
@@ -13,6 +14,8 @@ use std::path::Path;
 use std::sync::Arc;
 
 use crate::cli::GlobalArgs;
+use crate::commands::code_frame::ModelSource;
+use crate::commands::introspection::DatabaseIntrospector;
 
 /// Error type representing a non-zero process exit code.
 ///
@@ -177,8 +180,15 @@ pub(crate) fn build_schema_catalog(
 }
 
 /// Load a project from the directory specified in global CLI arguments.
+///
+/// Missing column descriptions are inherited through lineage when
+/// `documentation.inherit_column_descriptions` is enabled.
 pub(crate) fn load_project(global: &GlobalArgs) -> Result<Project> {
-    Project::load(&global.project_dir).context("Failed to load project")
+    let mut project = Project::load(&global.project_dir).context("Failed to load project")?;
+    if project.config.documentation.inherit_column_descriptions {
+        inherit_column_descriptions(&mut project, global);
+    }
+    Ok(project)
 }
 
 /// Build a [`RelSchema`](ff_analysis::RelSchema) from a source table's column definitions.
@@ -226,8 +236,10 @@ fn build_source_table_schema(table: &ff_core::SourceTable) -> ff_analysis::RelSc
 /// Returns `(dependencies_map, dag)`.
 pub(crate) fn build_project_dag(
     project: &Project,
+    global: &GlobalArgs,
+    catalog: &Arc<ff_jinja::LazyNodeCatalog>,
 ) -> Result<(HashMap<String, Vec<String>>, ff_core::dag::ModelDag)> {
-    let jinja = build_jinja_env(project);
+    let jinja = build_jinja_env(project, global, catalog);
     let parser = ff_sql::SqlParser::from_dialect_name(&project.config.dialect.to_string())
         .context("Invalid SQL dialect")?;
     let external_tables = build_external_tables_lookup(project);
@@ -278,6 +290,106 @@ pub(crate) fn build_project_dag(
     Ok((dependencies, dag))
 }
 
+/// The `.yml` (or `.yaml`) file next to a model's SQL file, if one exists.
+pub(crate) fn model_yaml_path(sql_path: &Path) -> Option<std::path::PathBuf> {
+    ["yml", "yaml"]
+        .iter()
+        .map(|ext| sql_path.with_extension(ext))
+        .find(|p| p.exists())
+}
+
+/// Fill missing model column descriptions from copied/renamed upstream columns.
+///
+/// Column lineage is extracted from each model's rendered SQL; models that
+/// fail to render or parse contribute no edges. Descriptions declared in YAML
+/// are never replaced. Returns the number of columns that inherited a
+/// description (each marked with `description_inherited_from`).
+///
+/// Callers decide whether inheritance applies — normally only when
+/// `documentation.inherit_column_descriptions` is enabled.
+pub(crate) fn inherit_column_descriptions(project: &mut Project, global: &GlobalArgs) -> usize {
+    use ff_core::documentation::{
+        apply_inherited_descriptions, build_description_lookup, propagate_descriptions_topo,
+        DescriptionEdge,
+    };
+    use ff_sql::{extract_column_lineage, LineageKind, ProjectLineage};
+
+    let Ok(parser) = ff_sql::SqlParser::from_dialect_name(&project.config.dialect.to_string())
+    else {
+        return 0;
+    };
+    let catalog = build_node_catalog(project);
+    let jinja = build_jinja_env_with_context(project, global, false, &catalog);
+
+    let mut known_nodes: HashSet<&str> = project.models.keys().map(|k| k.as_str()).collect();
+    for seed in &project.seeds {
+        known_nodes.insert(seed.name.as_str());
+    }
+    for source_file in &project.sources {
+        for table in &source_file.tables {
+            known_nodes.insert(&table.name);
+        }
+    }
+
+    let mut dependencies: HashMap<String, Vec<String>> =
+        HashMap::with_capacity(project.models.len());
+    let mut lineage = ProjectLineage::new();
+    for (name, model) in &project.models {
+        let stmts = jinja
+            .render(&model.raw_sql)
+            .map_err(|e| e.to_string())
+            .and_then(|sql| parser.parse(&sql).map_err(|e| e.to_string()));
+        let stmts = match stmts {
+            Ok(stmts) => stmts,
+            Err(e) => {
+                if global.verbose {
+                    eprintln!(
+                        "[verbose] Skipping description inheritance for '{}': {}",
+                        name, e
+                    );
+                }
+                dependencies.insert(name.to_string(), Vec::new());
+                continue;
+            }
+        };
+        let deps: Vec<String> = ff_sql::extract_dependencies(&stmts)
+            .into_iter()
+            .filter(|d| project.models.contains_key(d.as_str()))
+            .collect();
+        dependencies.insert(name.to_string(), deps);
+        if let Some(model_lineage) = stmts.first().and_then(|s| extract_column_lineage(s, name)) {
+            lineage.add_model_lineage(model_lineage);
+        }
+    }
+    lineage.resolve_edges(&known_nodes);
+
+    let topo_order = ff_core::dag::ModelDag::build(&dependencies)
+        .and_then(|dag| dag.topological_order())
+        .unwrap_or_else(|_| dependencies.keys().cloned().collect());
+    let edges: Vec<DescriptionEdge> = lineage
+        .edges
+        .iter()
+        .filter(|e| matches!(e.kind, LineageKind::Copy | LineageKind::Rename))
+        .map(|e| DescriptionEdge {
+            source_model: e.source_model.clone(),
+            source_column: e.source_column.clone(),
+            target_model: e.target_model.clone(),
+            target_column: e.target_column.clone(),
+        })
+        .collect();
+
+    let declared = build_description_lookup(project);
+    let inherited = propagate_descriptions_topo(&topo_order, &edges, &declared);
+    let applied = apply_inherited_descriptions(project, &inherited);
+    if global.verbose && applied > 0 {
+        eprintln!(
+            "[verbose] Inherited {} column description(s) from upstream",
+            applied
+        );
+    }
+    applied
+}
+
 /// Result of a static analysis pipeline run.
 ///
 /// Contains the propagation result from DataFusion plus the set of external
@@ -285,123 +397,221 @@ pub(crate) fn build_project_dag(
 pub(crate) struct StaticAnalysisOutput {
     /// The propagation result from DataFusion
     pub result: ff_analysis::PropagationResult,
-    /// Whether any schema-mismatch errors were found (after applying overrides)
+    /// Whether any schema-mismatch errors were found (after applying overrides
+    /// and the baseline)
     pub has_errors: bool,
+    /// Whether any schema-mismatch warnings were found (after applying
+    /// overrides and the baseline); these fail `--strict`
+    pub has_warnings: bool,
     /// User-configured severity overrides for SA codes
     pub overrides: ff_analysis::SeverityOverrides,
+    /// Node suppressions without a reason (A060) or matching no schema mismatch (A061)
+    pub suppression_issues: Vec<ff_analysis::Diagnostic>,
+    /// Fingerprints of the schema mismatches the diagnostics baseline covers
+    pub baselined: HashSet<String>,
+    /// Baselined schema mismatches that are no longer reported (A062)
+    pub baseline_fixed: Vec<ff_analysis::Diagnostic>,
+}
+
+impl StaticAnalysisOutput {
+    /// Schema mismatches to report: not turned off by an override and not in
+    /// the baseline, with whether each is an error.
+    pub(crate) fn reported_mismatches(
+        &self,
+    ) -> impl Iterator<Item = (&ff_core::ModelName, &ff_analysis::SchemaMismatch, bool)> {
+        use ff_analysis::Baselined;
+
+        active_mismatches(&self.result, &self.overrides).filter(|(model, mismatch, _)| {
+            !self
+                .baselined
+                .contains(&(*model, *mismatch).baseline_entry().fingerprint)
+        })
+    }
 }
 
 /// Run the shared static analysis pipeline (schema catalog + propagation).
 ///
 /// This is the common core used by `compile`, `validate`, and `run` commands.
-/// Callers are responsible for reporting results in their own format.
-pub(crate) fn run_static_analysis_pipeline(
+/// Models DataFusion cannot plan fall back to DuckDB (see
+/// [`EngineFallback`](super::engine_stub::EngineFallback)); Python and
+/// command models are not analyzed, their YAML schemas stand in for them.
+/// Schema mismatches recorded in the project's diagnostics baseline are not
+/// reported. Callers are responsible for reporting results in their own format.
+pub(crate) async fn run_static_analysis_pipeline(
     project: &Project,
     sql_sources: &HashMap<String, String>,
     topo_order: &[String],
     external_tables: &HashSet<String>,
 ) -> Result<StaticAnalysisOutput> {
-    use ff_analysis::{propagate_schemas, SeverityOverrides};
+    use ff_analysis::{
+        apply_severity_overrides, propagate_schemas_with_fallback, Baselined, SeverityOverrides,
+    };
 
-    let overrides = SeverityOverrides::from_config(&project.config.analysis.severity_overrides);
     let (schema_catalog, yaml_schemas) = build_schema_catalog(project, external_tables);
 
-    let filtered_order: Vec<ff_core::ModelName> = topo_order
+    let sql_model_sources: HashMap<ff_core::ModelName, String> = sql_sources
         .iter()
-        .filter(|n| sql_sources.contains_key(n.as_str()))
-        .map(|n| ff_core::ModelName::new(n.clone()))
+        .filter(|(name, _)| is_sql_model(project, name))
+        .map(|(k, v)| (ff_core::ModelName::new(k.clone()), v.clone()))
         .collect();
 
-    let sql_model_sources: HashMap<ff_core::ModelName, String> = sql_sources
+    let filtered_order: Vec<ff_core::ModelName> = topo_order
         .iter()
-        .map(|(k, v)| (ff_core::ModelName::new(k.clone()), v.clone()))
+        .filter(|n| sql_model_sources.contains_key(n.as_str()))
+        .map(|n| ff_core::ModelName::new(n.clone()))
         .collect();
+    let overrides = SeverityOverrides::from_config(&project.config.analysis.severity_overrides)
+        .with_node_rules(project, &sql_model_sources);
 
     let (user_fn_stubs, user_table_fn_stubs) = ff_analysis::build_user_function_stubs(project);
-    let result = propagate_schemas(
+    let mut fallback = super::engine_stub::EngineFallback::new(project, false);
+    let result = propagate_schemas_with_fallback(
         &filtered_order,
         &sql_model_sources,
         &yaml_schemas,
         schema_catalog,
         &user_fn_stubs,
         &user_table_fn_stubs,
-    );
+        &mut fallback,
+    )
+    .await;
 
-    let has_errors = has_schema_errors(&result, &overrides);
+    // No passes run here, so only reasons and schema-mismatch suppressions
+    // can be audited
+    let analyzed: Vec<ff_core::ModelName> = filtered_order
+        .into_iter()
+        .filter(|n| result.model_plans.contains_key(n) || result.engine_inferred.contains_key(n))
+        .collect();
+    let suppression_issues = overrides.audit_suppressions(&[], &result, &analyzed, false);
+
+    let baseline_path = project.config.analysis.baseline_path(&project.root);
+    let (baselined, baseline_fixed) = match load_baseline(&baseline_path)? {
+        Some(baseline) => {
+            let active: Vec<_> = active_mismatches(&result, &overrides)
+                .map(|(model, mismatch, _)| (model, mismatch))
+                .collect();
+            let all: Vec<String> = active
+                .iter()
+                .map(|m| m.baseline_entry().fingerprint)
+                .collect();
+            let comparison = baseline.compare(active, &analyzed, true);
+            let new: HashSet<String> = comparison
+                .new
+                .iter()
+                .map(|m| m.baseline_entry().fingerprint)
+                .collect();
+            let fixed = apply_severity_overrides(comparison.fixed_diagnostics(), &overrides);
+            (
+                all.into_iter().filter(|f| !new.contains(f)).collect(),
+                fixed,
+            )
+        }
+        None => (HashSet::new(), Vec::new()),
+    };
 
-    Ok(StaticAnalysisOutput {
+    let mut output = StaticAnalysisOutput {
         result,
-        has_errors,
+        has_errors: false,
+        has_warnings: false,
         overrides,
-    })
+        suppression_issues,
+        baselined,
+        baseline_fixed,
+    };
+    let (has_errors, has_warnings) = output.reported_mismatches().fold(
+        (!output.result.failures.is_empty(), false),
+        |(errors, warnings), (_, _, is_error)| (errors || is_error, warnings || !is_error),
+    );
+    output.has_errors = has_errors;
+    output.has_warnings = has_warnings;
+    Ok(output)
 }
 
-/// Check whether any schema mismatch in the propagation result is an error,
-/// respecting severity overrides for SA codes.
-fn has_schema_errors(
-    result: &ff_analysis::PropagationResult,
-    overrides: &ff_analysis::SeverityOverrides,
-) -> bool {
+/// Whether `name` is a SQL model, the only kind static analysis can plan.
+///
+/// Python and command models have no SQL to plan; names that are not
+/// models at all are left to the caller.
+pub(crate) fn is_sql_model(project: &Project, name: &str) -> bool {
+    project
+        .get_model(name)
+        .is_none_or(|model| model.kind == ff_core::ModelKind::Sql)
+}
+
+/// Load the diagnostics baseline, if the file exists.
+pub(crate) fn load_baseline(path: &Path) -> Result<Option<ff_analysis::Baseline>> {
+    use ff_analysis::{Baseline, BASELINE_VERSION};
+
+    if !path.exists() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read baseline {}", path.display()))?;
+    let baseline: Baseline = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse baseline {}", path.display()))?;
+    if baseline.version != BASELINE_VERSION {
+        anyhow::bail!(
+            "Baseline {} has version {}, expected {}; re-create it with `ff dt analyze --write-baseline`",
+            path.display(),
+            baseline.version,
+            BASELINE_VERSION
+        );
+    }
+    Ok(Some(baseline))
+}
+
+/// Schema mismatches in the propagation result that severity overrides do
+/// not turn off, with whether each is an error.
+pub(crate) fn active_mismatches<'a>(
+    result: &'a ff_analysis::PropagationResult,
+    overrides: &'a ff_analysis::SeverityOverrides,
+) -> impl Iterator<
+    Item = (
+        &'a ff_core::ModelName,
+        &'a ff_analysis::SchemaMismatch,
+        bool,
+    ),
+> {
     use ff_analysis::OverriddenSeverity;
 
-    if !result.failures.is_empty() {
-        return true;
-    }
-    result.model_plans.values().any(|pr| {
-        pr.mismatches.iter().any(|m| {
-            let sa_code = m.code();
-            match overrides.get_for_sa(sa_code) {
-                Some(OverriddenSeverity::Off) => false,
-                Some(OverriddenSeverity::Level(ff_analysis::Severity::Error)) => true,
-                Some(OverriddenSeverity::Level(_)) => false,
-                None => m.is_error(),
-            }
+    result
+        .all_mismatches()
+        .flat_map(move |(model, mismatches)| {
+            mismatches.iter().filter_map(move |mismatch| {
+                let is_error =
+                    match overrides.get_for_node_sa(model, mismatch.code(), mismatch.column()) {
+                        Some(OverriddenSeverity::Off) => return None,
+                        Some(OverriddenSeverity::Level(severity)) => {
+                            severity == ff_analysis::Severity::Error
+                        }
+                        None => mismatch.is_error(),
+                    };
+                Some((model, mismatch, is_error))
+            })
         })
-    })
 }
 
 /// Report static analysis results: mismatches and failures.
 ///
-/// Iterates over mismatch diagnostics and failures from the propagation result.
-/// Returns `(mismatch_count, plan_count, failure_count)`.
+/// Iterates over the reported mismatches and failures from the pipeline
+/// output. Returns `(mismatch_count, plan_count, failure_count)`;
+/// engine-inferred models count as planned.
 ///
-/// The `on_mismatch` callback is called for each schema mismatch with
+/// The `on_mismatch` callback is called for each schema mismatch that is
+/// neither turned off by a severity override nor baselined, with
 /// `(model_name, &SchemaMismatch, is_error)`. The `is_error` flag reflects
-/// severity overrides when provided. The `on_failure` callback is called
-/// for each model that failed planning with `(model_name, error_message)`.
+/// severity overrides. The `on_failure` callback is called for each model
+/// that failed planning with `(model_name, error_message)`.
 pub(crate) fn report_static_analysis_results(
-    result: &ff_analysis::PropagationResult,
-    overrides: &ff_analysis::SeverityOverrides,
+    output: &StaticAnalysisOutput,
     mut on_mismatch: impl FnMut(&str, &ff_analysis::SchemaMismatch, bool),
     mut on_failure: impl FnMut(&str, &str),
 ) -> (usize, usize, usize) {
-    use ff_analysis::OverriddenSeverity;
-
-    let mut mismatch_count = 0;
-
-    let mut model_names: Vec<&ff_core::ModelName> = result.model_plans.keys().collect();
-    model_names.sort();
+    let result = &output.result;
 
-    for model_name in model_names {
-        let plan_result = &result.model_plans[model_name.as_str()];
-        for mismatch in &plan_result.mismatches {
-            let sa_code = mismatch.code();
-            match overrides.get_for_sa(sa_code) {
-                Some(OverriddenSeverity::Off) => {
-                    continue;
-                }
-                Some(OverriddenSeverity::Level(ff_analysis::Severity::Error)) => {
-                    on_mismatch(model_name, mismatch, true);
-                }
-                Some(OverriddenSeverity::Level(_)) => {
-                    on_mismatch(model_name, mismatch, false);
-                }
-                None => {
-                    on_mismatch(model_name, mismatch, mismatch.is_error());
-                }
-            }
-            mismatch_count += 1;
-        }
+    let mut mismatches: Vec<_> = output.reported_mismatches().collect();
+    mismatches.sort_by_key(|(name, _, _)| *name);
+    for (model_name, mismatch, is_error) in &mismatches {
+        on_mismatch(model_name, mismatch, *is_error);
     }
 
     let mut failure_names: Vec<&ff_core::ModelName> = result.failures.keys().collect();
@@ -413,18 +623,80 @@ pub(crate) fn report_static_analysis_results(
     }
 
     (
-        mismatch_count,
-        result.model_plans.len(),
+        mismatches.len(),
+        result.model_plans.len() + result.engine_inferred.len(),
         result.failures.len(),
     )
 }
 
+/// Print suppression audit warnings (A060/A061) and fixed baseline entries
+/// (A062) to stderr.
+pub(crate) fn print_analysis_notes(output: &StaticAnalysisOutput) {
+    for note in output
+        .suppression_issues
+        .iter()
+        .chain(&output.baseline_fixed)
+    {
+        let label = match note.severity {
+            ff_analysis::Severity::Error => "error",
+            ff_analysis::Severity::Warning => "warn",
+            ff_analysis::Severity::Info => "info",
+        };
+        eprintln!("  [{label}] {} {}: {}", note.code, note.model, note.message);
+    }
+}
+
+/// Print a schema mismatch (SA01/SA02) to stderr as a code frame.
+///
+/// The frame points at the column in the model's `.sql` template; a column
+/// declared in YAML but missing from the SQL has no position, so its frame
+/// only names the file. `suffix` is appended to the message.
+pub(crate) fn print_mismatch_frame(
+    project: &Project,
+    result: &ff_analysis::PropagationResult,
+    model_name: &str,
+    mismatch: &ff_analysis::SchemaMismatch,
+    is_error: bool,
+    suffix: &str,
+) {
+    let message = format!("{mismatch}{suffix}");
+    let sql = result
+        .model_plans
+        .get(model_name)
+        .map(|r| r.sql.as_str())
+        .or_else(|| {
+            result
+                .engine_inferred
+                .get(model_name)
+                .map(|r| r.sql.as_str())
+        });
+    let (Some(model), Some(sql)) = (project.get_model(model_name), sql) else {
+        let label = if is_error { "error" } else { "warn" };
+        eprintln!("  [{label}] {model_name}: {message}");
+        return;
+    };
+
+    let span = match mismatch {
+        ff_analysis::SchemaMismatch::MissingFromSql { .. } => None,
+        _ => ff_sql::SqlParser::from_dialect_name(&project.config.dialect.to_string())
+            .ok()
+            .and_then(|parser| ff_analysis::column_span(&parser, sql, mismatch.column())),
+    };
+    let source = ModelSource::new(project, model, sql);
+    let level = if is_error { "error" } else { "warning" };
+    let span = span.map(|span| source.map_span(span));
+    eprintln!(
+        "{}",
+        source.frame_for(level, mismatch.code(), &message, span)
+    );
+}
+
 /// Run DataFusion-based static analysis on compiled models before execution.
 ///
 /// Builds the dependency DAG and runs schema propagation. When `quiet` is
 /// `true`, mismatch and failure messages are suppressed.
 /// Returns `true` when schema errors that should block execution are found.
-pub(crate) fn run_pre_execution_analysis(
+pub(crate) async fn run_pre_execution_analysis(
     project: &Project,
     compiled_models: &HashMap<String, super::run::CompiledModel>,
     global: &GlobalArgs,
@@ -449,7 +721,7 @@ pub(crate) fn run_pre_execution_analysis(
 
     let sql_only_sources: HashMap<String, String> = compiled_models
         .iter()
-        .filter(|(_, model)| !model.is_python)
+        .filter(|(_, model)| !model.is_external())
         .map(|(name, model)| (name.clone(), model.sql.clone()))
         .collect();
 
@@ -458,16 +730,15 @@ pub(crate) fn run_pre_execution_analysis(
     }
 
     let output =
-        run_static_analysis_pipeline(project, &sql_only_sources, &topo_order, &external_tables)?;
+        run_static_analysis_pipeline(project, &sql_only_sources, &topo_order, &external_tables)
+            .await?;
     let result = &output.result;
 
     let (_, plan_count, failure_count) = report_static_analysis_results(
-        result,
-        &output.overrides,
+        &output,
         |model_name, mismatch, is_error| {
             if !quiet {
-                let label = if is_error { "error" } else { "warn" };
-                eprintln!("  [{label}] {model_name}: {mismatch}");
+                print_mismatch_frame(project, result, model_name, mismatch, is_error, "");
             }
         },
         |model, err| {
@@ -476,6 +747,9 @@ pub(crate) fn run_pre_execution_analysis(
             }
         },
     );
+    if !quiet {
+        print_analysis_notes(&output);
+    }
     if global.verbose {
         eprintln!(
             "[verbose] Static analysis: {} models planned, {} failures",
@@ -486,6 +760,38 @@ pub(crate) fn run_pre_execution_analysis(
     Ok(output.has_errors)
 }
 
+/// Drive a database future to completion from synchronous code running on
+/// the tokio runtime, such as a Jinja function.
+///
+/// On the multi-threaded runtime `main` starts, the current worker hands its
+/// other tasks off while it blocks. A current-thread runtime (as in
+/// `#[tokio::test]`) cannot do that, so the future runs on a scoped thread
+/// with a runtime of its own instead.
+pub(crate) fn block_on_runtime<F>(future: F) -> F::Output
+where
+    F: std::future::Future + Send,
+    F::Output: Send,
+{
+    use tokio::runtime::{Builder, Handle, RuntimeFlavor};
+
+    let handle = Handle::current();
+    if handle.runtime_flavor() == RuntimeFlavor::MultiThread {
+        return tokio::task::block_in_place(|| handle.block_on(future));
+    }
+    std::thread::scope(|scope| {
+        scope
+            .spawn(|| {
+                Builder::new_current_thread()
+                    .enable_all()
+                    .build()
+                    .expect("failed to build a runtime for a blocking database call")
+                    .block_on(future)
+            })
+            .join()
+            .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+    })
+}
+
 /// Generic wrapper for command results written to JSON.
 ///
 /// Many commands (run, etc.) produce a JSON file with the same
@@ -571,25 +877,67 @@ pub(crate) fn print_table(headers: &[&str], rows: &[Vec<String>]) {
 /// Build a `JinjaEnvironment` using the project's vars and macro paths.
 ///
 /// Use this for commands that don't need template context variables
-/// (`{{ project_name }}`, `{{ target }}`, etc.).
-pub(crate) fn build_jinja_env(project: &Project) -> ff_jinja::JinjaEnvironment<'static> {
+/// (`{{ project_name }}`, `{{ target }}`, etc.). `catalog` backs `star()`
+/// and friends, so models using them render the same as in compile, and
+/// `run_query()` and friends reach the target database unless `--offline`
+/// is set.
+pub(crate) fn build_jinja_env(
+    project: &Project,
+    global: &GlobalArgs,
+    catalog: &Arc<ff_jinja::LazyNodeCatalog>,
+) -> ff_jinja::JinjaEnvironment<'static> {
+    let mut jinja = build_offline_jinja_env(project, catalog);
+    attach_introspector(&mut jinja, project, global);
+    jinja
+}
+
+/// Build a `JinjaEnvironment` like [`build_jinja_env`] whose introspection
+/// functions always fail, as under `--offline`.
+///
+/// The engine stub renders function bodies with this, since it stands in
+/// for the target database.
+pub(crate) fn build_offline_jinja_env(
+    project: &Project,
+    catalog: &Arc<ff_jinja::LazyNodeCatalog>,
+) -> ff_jinja::JinjaEnvironment<'static> {
     let macro_paths = project.config.macro_paths_absolute(&project.root);
-    ff_jinja::JinjaEnvironment::with_macros(&project.config.vars, &macro_paths)
+    let mut jinja = ff_jinja::JinjaEnvironment::with_macros(&project.config.vars, &macro_paths);
+    jinja.set_catalog(Arc::clone(catalog));
+    jinja.set_packages(macro_packages(project));
+    jinja
 }
 
 /// Build a `JinjaEnvironment` with template context variables.
 ///
 /// Includes `{{ project_name }}`, `{{ target }}`, `{{ run_id }}`,
 /// `{{ executing }}`, etc. Set `executing` to `true` for `ff run`,
-/// `false` for compile/validate/analyze.
+/// `false` for compile/validate/analyze. The catalog and introspection
+/// follow [`build_jinja_env`].
 pub(crate) fn build_jinja_env_with_context(
     project: &Project,
-    database: Option<&str>,
+    global: &GlobalArgs,
     executing: bool,
+    catalog: &Arc<ff_jinja::LazyNodeCatalog>,
 ) -> ff_jinja::JinjaEnvironment<'static> {
     let macro_paths = project.config.macro_paths_absolute(&project.root);
-    let template_ctx = build_template_context(project, database, executing);
-    ff_jinja::JinjaEnvironment::with_context(&project.config.vars, &macro_paths, &template_ctx)
+    let template_ctx = build_template_context(project, global.database.as_deref(), executing);
+    let mut jinja =
+        ff_jinja::JinjaEnvironment::with_context(&project.config.vars, &macro_paths, &template_ctx);
+    jinja.set_catalog(Arc::clone(catalog));
+    attach_introspector(&mut jinja, project, global);
+    jinja
+}
+
+/// Let templates query the target database, connecting on first use,
+/// unless `--offline` is set.
+fn attach_introspector(
+    jinja: &mut ff_jinja::JinjaEnvironment<'static>,
+    project: &Project,
+    global: &GlobalArgs,
+) {
+    if !global.offline {
+        jinja.set_introspector(Arc::new(DatabaseIntrospector::lazy(project, global)));
+    }
 }
 
 /// Build a `TemplateContext` from project and database connection info.
@@ -624,6 +972,168 @@ pub(crate) fn build_template_context(
         },
         executing,
     )
+    .with_packages(macro_packages(project))
+}
+
+/// Macro namespaces: one per installed package, plus the project's own
+/// macros under its name, so a package's models can call
+/// `<package>.<macro>()` both standalone and when pulled into another project.
+fn macro_packages(project: &Project) -> Vec<ff_jinja::MacroPackage> {
+    let mut packages: Vec<ff_jinja::MacroPackage> = project
+        .packages
+        .iter()
+        .map(|package| ff_jinja::MacroPackage {
+            name: package.name.clone(),
+            macro_paths: package.macro_paths(),
+        })
+        .collect();
+
+    let name = &project.config.name;
+    if is_valid_identifier(name) && !packages.iter().any(|p| &p.name == name) {
+        packages.push(ff_jinja::MacroPackage {
+            name: name.clone(),
+            macro_paths: project.config.macro_paths_absolute(&project.root),
+        });
+    }
+    packages
+}
+
+/// Build the catalog read by `columns_of()`, `star()`, and `union_nodes()`.
+///
+/// Starts from the YAML-declared columns of every model and source table,
+/// the same declarations [`build_schema_catalog`] seeds static analysis
+/// with. SQL models that declare no columns get the schema static analysis
+/// propagates for them in topological order, so templates see what SA01/SA02
+/// validate against. That inference only runs on the first lookup of such a
+/// model, so build the catalog once per command and share it.
+pub(crate) fn build_node_catalog(project: &Project) -> Arc<ff_jinja::LazyNodeCatalog> {
+    let declared = declared_node_catalog(project);
+    let models: Vec<(ff_core::ModelName, String)> = project
+        .models
+        .iter()
+        .filter(|(_, model)| model.kind == ff_core::ModelKind::Sql)
+        .map(|(name, model)| (name.clone(), model.raw_sql.clone()))
+        .collect();
+    let undeclared =
+        |catalog: &ff_jinja::NodeCatalog, name: &str| catalog.get(name).map_or(true, Vec::is_empty);
+    if !models.iter().any(|(name, _)| undeclared(&declared, name)) {
+        return Arc::new(ff_jinja::LazyNodeCatalog::new(declared));
+    }
+
+    let dialect = project.config.dialect.to_string();
+    let vars = project.config.vars.clone();
+    let macro_paths = project.config.macro_paths_absolute(&project.root);
+    let context = build_template_context(project, None, false);
+    let external_tables = build_external_tables_lookup(project);
+    let known_models: HashSet<String> = project.models.keys().map(|k| k.to_string()).collect();
+    let (schema_catalog, yaml_schemas) = build_schema_catalog(project, &external_tables);
+    let (user_fn_stubs, user_table_fn_stubs) = ff_analysis::build_user_function_stubs(project);
+
+    Arc::new(ff_jinja::LazyNodeCatalog::with_inference(
+        declared,
+        move |declared| {
+            let mut catalog = declared.clone();
+            let Ok(parser) = ff_sql::SqlParser::from_dialect_name(&dialect) else {
+                return catalog;
+            };
+            let known_models: HashSet<&str> = known_models.iter().map(String::as_str).collect();
+
+            // A template may read a model whose own schema is only known once
+            // its upstream models render, so repeat until a pass adds no schema
+            while models.iter().any(|(name, _)| undeclared(&catalog, name)) {
+                let mut jinja =
+                    ff_jinja::JinjaEnvironment::with_context(&vars, &macro_paths, &context);
+                jinja.set_catalog(Arc::new(ff_jinja::LazyNodeCatalog::new(catalog.clone())));
+
+                let mut sources: HashMap<ff_core::ModelName, String> = HashMap::new();
+                let mut dependencies: HashMap<String, Vec<String>> = HashMap::new();
+                for (name, raw_sql) in &models {
+                    let Ok(rendered) = jinja.render(raw_sql) else {
+                        continue;
+                    };
+                    let Ok(stmts) = parser.parse(&rendered) else {
+                        continue;
+                    };
+                    let (model_deps, _) = ff_sql::extractor::categorize_dependencies(
+                        ff_sql::extract_dependencies(&stmts),
+                        &known_models,
+                        &external_tables,
+                    );
+                    dependencies.insert(name.to_string(), model_deps);
+                    sources.insert(name.clone(), rendered);
+                }
+                for deps in dependencies.values_mut() {
+                    deps.retain(|d| sources.contains_key(d.as_str()));
+                }
+                let Ok(order) = ff_core::dag::ModelDag::build(&dependencies)
+                    .and_then(|dag| dag.topological_order_names())
+                else {
+                    break;
+                };
+
+                let result = ff_analysis::propagate_schemas(
+                    &order,
+                    &sources,
+                    &yaml_schemas,
+                    schema_catalog.clone(),
+                    &user_fn_stubs,
+                    &user_table_fn_stubs,
+                );
+                let mut added = false;
+                for (name, schema) in &result.final_catalog {
+                    if undeclared(&catalog, name) && sources.contains_key(name.as_str()) {
+                        let columns = schema
+                            .columns
+                            .iter()
+                            .map(|c| ff_jinja::RelationColumn {
+                                name: c.name.clone(),
+                                data_type: c.sql_type.to_string(),
+                            })
+                            .collect();
+                        catalog.insert(name.clone(), columns);
+                        added = true;
+                    }
+                }
+                if !added || sources.len() == models.len() {
+                    break;
+                }
+            }
+            catalog
+        },
+    ))
+}
+
+/// The YAML-declared columns of every model and source table.
+fn declared_node_catalog(project: &Project) -> ff_jinja::NodeCatalog {
+    let column = |name: &str, data_type: &str| ff_jinja::RelationColumn {
+        name: name.to_string(),
+        data_type: data_type.to_string(),
+    };
+
+    let mut catalog = ff_jinja::NodeCatalog::new();
+    for (name, model) in &project.models {
+        if let Some(schema) = &model.schema {
+            let columns = schema
+                .columns
+                .iter()
+                .map(|c| column(&c.name, &c.data_type))
+                .collect();
+            catalog.insert(name.to_string(), columns);
+        }
+    }
+    for source_file in &project.sources {
+        for table in &source_file.tables {
+            if !catalog.contains_key(&table.name) {
+                let columns = table
+                    .columns
+                    .iter()
+                    .map(|c| column(&c.name, &c.data_type))
+                    .collect();
+                catalog.insert(table.name.clone(), columns);
+            }
+        }
+    }
+    catalog
 }
 
 /// Build a `ModelContext` for a given model.
@@ -679,7 +1189,7 @@ pub(crate) fn create_database_connection(
     Ok(db)
 }
 
-/// Set the DuckDB search path using the project's configured schema.
+/// Set the search path using the project's configured schema.
 ///
 /// This allows unqualified table references in SQL to resolve against
 /// the project's default schema (where seeds and models typically live)
@@ -688,6 +1198,15 @@ pub(crate) async fn set_project_search_path(
     db: &Arc<dyn Database>,
     project: &Project,
 ) -> Result<()> {
+    db.set_search_path(&project_search_path(project))
+        .await
+        .context("Failed to set search_path")?;
+
+    Ok(())
+}
+
+/// Search path schemas: the project's default schema, then `main`.
+pub(crate) fn project_search_path(project: &Project) -> Vec<String> {
     let mut schemas: Vec<String> = project
         .config
         .get_schema(None)
@@ -697,13 +1216,7 @@ pub(crate) async fn set_project_search_path(
     if !schemas.iter().any(|s| s == "main") {
         schemas.push("main".to_string());
     }
-
-    let path = schemas.join(",");
-    db.execute(&format!("SET search_path = '{path}'"))
-        .await
-        .context("Failed to set search_path")?;
-
-    Ok(())
+    schemas
 }
 
 /// Resolve table function references in a model's dependency list.
@@ -940,6 +1453,22 @@ pub(crate) fn populate_meta_phase1(
     }
 }
 
+/// Record the introspection calls templates made while compiling a run.
+pub(crate) fn populate_meta_introspection(
+    meta_db: &ff_meta::MetaDb,
+    run_id: i64,
+    entries: &[ff_meta::populate::compilation::IntrospectionEntry],
+) {
+    if entries.is_empty() {
+        return;
+    }
+    if let Err(e) = meta_db.transaction(|conn| {
+        ff_meta::populate::compilation::populate_introspection_cache(conn, run_id, entries)
+    }) {
+        log::warn!("Meta database: introspection cache population failed: {e}");
+    }
+}
+
 /// Complete a meta database population run.
 pub(crate) fn complete_meta_run(meta_db: &ff_meta::MetaDb, run_id: i64, status: &str) {
     if let Err(e) = meta_db
@@ -953,7 +1482,7 @@ pub(crate) fn complete_meta_run(meta_db: &ff_meta::MetaDb, run_id: i64, status:
 ///
 /// Returns `Ok(())` if analysis passes or is skipped, or an `ExitCode(1)`
 /// error if blocking diagnostics are found.
-pub(crate) fn run_static_analysis_gate(
+pub(crate) async fn run_static_analysis_gate(
     project: &Project,
     compiled_models: &HashMap<String, super::run::CompiledModel>,
     global: &GlobalArgs,
@@ -963,7 +1492,7 @@ pub(crate) fn run_static_analysis_gate(
     if skip {
         return Ok(());
     }
-    let has_errors = run_pre_execution_analysis(project, compiled_models, global, quiet)?;
+    let has_errors = run_pre_execution_analysis(project, compiled_models, global, quiet).await?;
     if has_errors {
         if !quiet {
             eprintln!("Static analysis found errors. Use --skip-static-analysis to bypass.");
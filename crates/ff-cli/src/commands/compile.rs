@@ -20,11 +20,14 @@ use ff_sql::{
 };
 use serde::Serialize;
 use std::collections::{HashMap, HashSet};
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::time::Instant;
 
 use crate::cli::{CompileArgs, GlobalArgs, OutputFormat};
 use crate::commands::common::{self, load_project, RunStatus};
+use crate::commands::introspection::DatabaseIntrospector;
+use crate::commands::schema_fix;
 use crate::commands::validation::{self, ValidationContext};
 
 /// Compile result for a single model
@@ -84,6 +87,10 @@ struct CompileContext<'a> {
     vars: &'a HashMap<String, serde_yaml::Value>,
     /// Macro paths for dual-path rendering
     macro_paths: &'a [std::path::PathBuf],
+    /// Node catalog for dual-path rendering
+    catalog: &'a Arc<ff_jinja::LazyNodeCatalog>,
+    /// Database introspection for dual-path rendering (`None` with `--offline`)
+    introspector: Option<&'a Arc<DatabaseIntrospector>>,
 }
 
 impl std::fmt::Debug for CompileContext<'_> {
@@ -124,6 +131,10 @@ struct AnalyzeOutput {
     qualification_map: HashMap<String, ff_sql::qualify::QualifiedRef>,
     /// Set of ephemeral model names
     ephemeral_models: HashSet<String>,
+    /// `--strict` found schema mismatches that are not in the baseline
+    strict_failed: bool,
+    /// Output schema static analysis inferred per model
+    inferred_schemas: HashMap<String, ff_analysis::RelSchema>,
 }
 
 /// Output of Stage 4 (Resolve): final SQL with ephemerals inlined, results written.
@@ -138,14 +149,80 @@ struct ValidateOutput {
     failed: bool,
 }
 
+/// Outcome of the compile pipeline over a project.
+pub(crate) struct CompileSummary {
+    results: Vec<ModelCompileResult>,
+    selected_count: usize,
+    success_count: usize,
+    ephemeral_count: usize,
+    output_dir: PathBuf,
+    /// Models that failed to compile; the meta database is only populated when there are none
+    pub(crate) failure_count: usize,
+    /// Validation failed or `--strict` found schema mismatches
+    failed: bool,
+}
+
 /// Execute the compile command.
+pub(crate) async fn execute(args: &CompileArgs, global: &GlobalArgs) -> Result<()> {
+    let start_time = Instant::now();
+    let mut project = load_project(global)?;
+    let json_mode = args.output == OutputFormat::Json;
+
+    let summary = compile_project(&mut project, args, global).await?;
+
+    if json_mode {
+        let results = CompileResults {
+            timestamp: Utc::now(),
+            elapsed_secs: start_time.elapsed().as_secs_f64(),
+            total_models: summary.selected_count,
+            success_count: summary.success_count,
+            failure_count: summary.failure_count,
+            manifest_path: None,
+            results: summary.results,
+        };
+        println!("{}", serde_json::to_string_pretty(&results)?);
+    } else if args.parse_only {
+        println!(
+            "\nValidated {} models successfully (no files written)",
+            summary.selected_count
+        );
+    } else {
+        let non_ephemeral_count = summary.selected_count - summary.ephemeral_count;
+        if summary.ephemeral_count > 0 {
+            println!(
+                "\nCompiled {} models ({} ephemeral inlined) to {}",
+                non_ephemeral_count,
+                summary.ephemeral_count,
+                summary.output_dir.display()
+            );
+        } else {
+            println!(
+                "\nCompiled {} models to {}",
+                summary.selected_count,
+                summary.output_dir.display()
+            );
+        }
+    }
+
+    if summary.failure_count > 0 || summary.failed {
+        return Err(crate::commands::common::ExitCode(1).into());
+    }
+
+    Ok(())
+}
+
+/// Compile a loaded project, recording each model's compiled SQL and
+/// dependencies on it.
 ///
 /// Orchestrates the 5-stage pipeline: Render → DAG → Analyze → Resolve → Validate.
-pub(crate) async fn execute(args: &CompileArgs, global: &GlobalArgs) -> Result<()> {
+/// Progress is printed unless `args.output` is JSON.
+pub(crate) async fn compile_project(
+    project: &mut Project,
+    args: &CompileArgs,
+    global: &GlobalArgs,
+) -> Result<CompileSummary> {
     use ff_core::config::Config;
 
-    let start_time = Instant::now();
-    let mut project = load_project(global)?;
     let json_mode = args.output == OutputFormat::Json;
 
     let database = Config::resolve_database(global.database.as_deref());
@@ -167,15 +244,22 @@ pub(crate) async fn execute(args: &CompileArgs, global: &GlobalArgs) -> Result<(
     let parser = SqlParser::from_dialect_name(&project.config.dialect.to_string())
         .context("Invalid SQL dialect")?;
     let macro_paths = project.config.macro_paths_absolute(&project.root);
-    let template_ctx = common::build_template_context(&project, global.database.as_deref(), false);
-    let jinja = JinjaEnvironment::with_context(&vars, &macro_paths, &template_ctx);
+    let template_ctx = common::build_template_context(project, global.database.as_deref(), false);
+    let catalog = common::build_node_catalog(project);
+    let mut jinja = JinjaEnvironment::with_context(&vars, &macro_paths, &template_ctx);
+    jinja.set_catalog(Arc::clone(&catalog));
+    let introspector =
+        (!global.offline).then(|| Arc::new(DatabaseIntrospector::lazy(project, global)));
+    if let Some(ref introspector) = introspector {
+        jinja.set_introspector(introspector.clone());
+    }
 
     let all_model_names: Vec<String> = project
         .model_names()
         .into_iter()
         .map(String::from)
         .collect();
-    let external_tables = common::build_external_tables_lookup(&project);
+    let external_tables = common::build_external_tables_lookup(project);
     let mut known_models: HashSet<String> = project.models.keys().map(|k| k.to_string()).collect();
     for seed in &project.seeds {
         known_models.insert(seed.name.to_string());
@@ -221,12 +305,14 @@ pub(crate) async fn execute(args: &CompileArgs, global: &GlobalArgs) -> Result<(
         template_ctx: &template_ctx,
         vars: &vars,
         macro_paths: &macro_paths,
+        catalog: &catalog,
+        introspector: introspector.as_ref(),
     };
 
-    let render_out = stage_render(&mut project, &all_model_names, &compile_ctx, json_mode);
+    let render_out = stage_render(project, &all_model_names, &compile_ctx, json_mode);
 
     let dag_out = stage_dag_build(
-        &project,
+        project,
         &render_out.dependencies,
         &args.nodes,
         &all_model_names,
@@ -243,21 +329,22 @@ pub(crate) async fn execute(args: &CompileArgs, global: &GlobalArgs) -> Result<(
         .collect();
 
     let analyze_out = stage_analyze(
-        &project,
+        project,
         compiled_models,
         &dag_out.topo_order,
         &external_tables,
         args,
         global,
         json_mode,
-    )?;
+    )
+    .await?;
 
     let resolve_out = stage_resolve(
         analyze_out.qualified_models,
         &render_out.dependencies,
         &render_out.materializations,
         &comment_ctx,
-        &mut project,
+        project,
         &output_dir,
         args,
         global,
@@ -271,7 +358,7 @@ pub(crate) async fn execute(args: &CompileArgs, global: &GlobalArgs) -> Result<(
         .filter(|r| matches!(r.status, RunStatus::Error))
         .count();
     let validate_out = stage_validate(
-        &project,
+        project,
         &resolve_out.results,
         &render_out.dependencies,
         &dag_out.dag,
@@ -279,51 +366,23 @@ pub(crate) async fn execute(args: &CompileArgs, global: &GlobalArgs) -> Result<(
         &known_models,
         &analyze_out.qualification_map,
         &analyze_out.ephemeral_models,
+        &analyze_out.inferred_schemas,
         failure_count,
+        introspector.as_deref(),
         args,
         global,
         json_mode,
     );
 
-    if json_mode {
-        let results = CompileResults {
-            timestamp: Utc::now(),
-            elapsed_secs: start_time.elapsed().as_secs_f64(),
-            total_models: dag_out.selected_models.len(),
-            success_count: resolve_out.success_count,
-            failure_count,
-            manifest_path: None,
-            results: resolve_out.results,
-        };
-        println!("{}", serde_json::to_string_pretty(&results)?);
-    } else if args.parse_only {
-        println!(
-            "\nValidated {} models successfully (no files written)",
-            dag_out.selected_models.len()
-        );
-    } else {
-        let non_ephemeral_count = dag_out.selected_models.len() - resolve_out.ephemeral_count;
-        if resolve_out.ephemeral_count > 0 {
-            println!(
-                "\nCompiled {} models ({} ephemeral inlined) to {}",
-                non_ephemeral_count,
-                resolve_out.ephemeral_count,
-                output_dir.display()
-            );
-        } else {
-            println!(
-                "\nCompiled {} models to {}",
-                dag_out.selected_models.len(),
-                output_dir.display()
-            );
-        }
-    }
-
-    if failure_count > 0 || validate_out.failed {
-        return Err(crate::commands::common::ExitCode(1).into());
-    }
-
-    Ok(())
+    Ok(CompileSummary {
+        results: resolve_out.results,
+        selected_count: dag_out.selected_models.len(),
+        success_count: resolve_out.success_count,
+        ephemeral_count: resolve_out.ephemeral_count,
+        output_dir,
+        failure_count,
+        failed: validate_out.failed || analyze_out.strict_failed,
+    })
 }
 
 /// Stage 1: Render all Jinja templates, parse SQL, and extract dependencies.
@@ -415,7 +474,7 @@ fn stage_dag_build(
 }
 
 /// Stage 3: Run static analysis and qualify table references.
-fn stage_analyze(
+async fn stage_analyze(
     project: &Project,
     mut compiled_models: Vec<CompileOutput>,
     topo_order: &[String],
@@ -424,6 +483,8 @@ fn stage_analyze(
     global: &GlobalArgs,
     json_mode: bool,
 ) -> Result<AnalyzeOutput> {
+    let mut strict_failed = false;
+    let mut inferred_schemas = HashMap::new();
     if !args.skip_static_analysis {
         let analysis_result = run_static_analysis(
             project,
@@ -433,10 +494,20 @@ fn stage_analyze(
             args,
             global,
             json_mode,
-        );
-        if let Err(e) = analysis_result {
-            if !json_mode {
-                eprintln!("Static analysis error: {}", e);
+        )
+        .await;
+        match analysis_result {
+            Ok((failed, schemas)) => {
+                strict_failed = failed;
+                inferred_schemas = schemas;
+            }
+            Err(e) => {
+                if args.fix {
+                    return Err(e.context("--fix could not be applied"));
+                }
+                if !json_mode {
+                    eprintln!("Static analysis error: {}", e);
+                }
             }
         }
     }
@@ -484,6 +555,8 @@ fn stage_analyze(
         qualified_models: compiled_models,
         qualification_map,
         ephemeral_models,
+        strict_failed,
+        inferred_schemas,
     })
 }
 
@@ -612,13 +685,22 @@ fn stage_validate(
     known_models: &HashSet<String>,
     qualification_map: &HashMap<String, ff_sql::qualify::QualifiedRef>,
     ephemeral_models: &HashSet<String>,
+    inferred_schemas: &HashMap<String, ff_analysis::RelSchema>,
     failure_count: usize,
+    introspector: Option<&DatabaseIntrospector>,
     args: &CompileArgs,
     global: &GlobalArgs,
     json_mode: bool,
 ) -> ValidateOutput {
     if !args.parse_only && failure_count == 0 {
-        populate_meta_compile(project, compile_results, dependencies, global);
+        populate_meta_compile(
+            project,
+            compile_results,
+            dependencies,
+            inferred_schemas,
+            introspector,
+            global,
+        );
     }
 
     let mut validation_failed = false;
@@ -944,12 +1026,16 @@ fn compile_model_phase1(
             .with_context(|| format!("Model not found: {}", name))?;
         let model_ctx_dp = common::build_model_context(model_ref, project);
 
-        let jinja_full = JinjaEnvironment::with_is_exists(
+        let mut jinja_full = JinjaEnvironment::with_is_exists(
             ctx.vars,
             ctx.macro_paths,
             Some(ctx.template_ctx),
             false,
         );
+        jinja_full.set_catalog(Arc::clone(ctx.catalog));
+        if let Some(introspector) = ctx.introspector {
+            jinja_full.set_introspector(introspector.clone());
+        }
         let full_rendered = jinja_full
             .render_with_model(&raw_sql, &model_ctx_dp)
             .with_context(|| format!("Failed to render full path template for model: {}", name))?;
@@ -958,12 +1044,16 @@ fn compile_model_phase1(
             .parse(&full_rendered)
             .with_context(|| format!("Failed to parse full path SQL for model: {}", name))?;
 
-        let jinja_inc = JinjaEnvironment::with_is_exists(
+        let mut jinja_inc = JinjaEnvironment::with_is_exists(
             ctx.vars,
             ctx.macro_paths,
             Some(ctx.template_ctx),
             true,
         );
+        jinja_inc.set_catalog(Arc::clone(ctx.catalog));
+        if let Some(introspector) = ctx.introspector {
+            jinja_inc.set_introspector(introspector.clone());
+        }
         let inc_rendered = jinja_inc
             .render_with_model(&raw_sql, &model_ctx_dp)
             .with_context(|| {
@@ -1005,7 +1095,7 @@ fn compile_model_phase1(
 }
 
 /// Compute the output path for a compiled model, preserving directory structure
-fn compute_compiled_path(
+pub(crate) fn compute_compiled_path(
     model_path: &Path,
     project_root: &Path,
     output_dir: &Path,
@@ -1072,6 +1162,8 @@ fn populate_meta_compile(
     project: &Project,
     results: &[ModelCompileResult],
     dependencies: &HashMap<String, Vec<String>>,
+    inferred_schemas: &HashMap<String, ff_analysis::RelSchema>,
+    introspector: Option<&DatabaseIntrospector>,
     global: &GlobalArgs,
 ) {
     let Some(meta_db) = common::open_meta_db(project) else {
@@ -1101,6 +1193,15 @@ fn populate_meta_compile(
                 &model_id_map,
             )?;
         }
+        let inferred = build_inferred_columns(inferred_schemas, &model_id_map);
+        ff_meta::populate::analysis::populate_inferred_schemas(conn, &inferred)?;
+        if let Some(introspector) = introspector {
+            ff_meta::populate::compilation::populate_introspection_cache(
+                conn,
+                run_id,
+                &introspector.entries(),
+            )?;
+        }
         Ok(())
     });
 
@@ -1121,11 +1222,43 @@ fn populate_meta_compile(
     }
 }
 
+/// Inferred columns of every analyzed model, for `ff_meta.model_columns`.
+///
+/// Types analysis could not determine are left to the declared YAML type.
+fn build_inferred_columns(
+    inferred_schemas: &HashMap<String, ff_analysis::RelSchema>,
+    model_id_map: &HashMap<ff_core::ModelName, i64>,
+) -> Vec<ff_meta::populate::analysis::InferredColumn> {
+    inferred_schemas
+        .iter()
+        .filter_map(|(name, schema)| Some((*model_id_map.get(name.as_str())?, schema)))
+        .flat_map(|(model_id, schema)| {
+            schema
+                .columns
+                .iter()
+                .map(move |c| ff_meta::populate::analysis::InferredColumn {
+                    model_id,
+                    column_name: c.name.clone(),
+                    inferred_type: (!c.sql_type.is_unknown()).then(|| c.sql_type.to_string()),
+                    nullability_inferred: match c.nullability {
+                        ff_analysis::Nullability::NotNull => Some("not_null".to_string()),
+                        ff_analysis::Nullability::Nullable => Some("nullable".to_string()),
+                        ff_analysis::Nullability::Unknown => None,
+                    },
+                })
+        })
+        .collect()
+}
+
 /// Run DataFusion-based static analysis on compiled models.
 ///
 /// For incremental models with dual-path compilation, static analysis is run on
 /// both the full path and incremental path SQL, with diagnostics annotated by path.
-fn run_static_analysis(
+///
+/// Returns whether `--strict` is set and schema mismatches outside the
+/// diagnostics baseline were reported, along with the output schema inferred
+/// for each model planned or described by the database.
+async fn run_static_analysis(
     project: &Project,
     compiled_models: &[CompileOutput],
     topo_order: &[String],
@@ -1133,7 +1266,7 @@ fn run_static_analysis(
     args: &CompileArgs,
     global: &GlobalArgs,
     json_mode: bool,
-) -> Result<()> {
+) -> Result<(bool, HashMap<String, ff_analysis::RelSchema>)> {
     if global.verbose {
         eprintln!("[verbose] Running DataFusion static analysis...");
     }
@@ -1148,13 +1281,19 @@ fn run_static_analysis(
         &sql_sources,
         topo_order,
         external_tables,
-    )?;
+    )
+    .await?;
     let result = &output.result;
 
     if let Some(ref explain_model) = args.explain {
         if let Some(plan_result) = result.model_plans.get(explain_model.as_str()) {
             println!("LogicalPlan for '{}':\n", explain_model);
             println!("{}", plan_result.plan.display_indent_schema());
+        } else if let Some(engine) = result.engine_inferred.get(explain_model.as_str()) {
+            eprintln!(
+                "Cannot explain '{}': schema was inferred by DuckDB because DataFusion could not plan it: {}",
+                explain_model, engine.planning_error
+            );
         } else if let Some(err) = result.failures.get(explain_model.as_str()) {
             eprintln!("Cannot explain '{}': {}", explain_model, err);
         } else {
@@ -1162,13 +1301,13 @@ fn run_static_analysis(
         }
     }
 
-    let (_, plan_count, failure_count) = common::report_static_analysis_results(
-        result,
-        &output.overrides,
+    let (mismatch_count, plan_count, failure_count) = common::report_static_analysis_results(
+        &output,
         |model_name, mismatch, is_error| {
             if !json_mode {
-                let label = if is_error { "error" } else { "warn" };
-                eprintln!("  [{label}] {model_name} [full]: {mismatch}");
+                common::print_mismatch_frame(
+                    project, result, model_name, mismatch, is_error, " [full]",
+                );
             }
         },
         |model, err| {
@@ -1181,6 +1320,10 @@ fn run_static_analysis(
         },
     );
 
+    if !json_mode {
+        common::print_analysis_notes(&output);
+    }
+
     let inc_sources: HashMap<String, String> = compiled_models
         .iter()
         .filter_map(|m| {
@@ -1190,6 +1333,7 @@ fn run_static_analysis(
         })
         .collect();
 
+    let mut inc_mismatch_count = 0;
     let (inc_plan_count, inc_failure_count) = if !inc_sources.is_empty() {
         if global.verbose {
             eprintln!(
@@ -1207,15 +1351,26 @@ fn run_static_analysis(
             &inc_all_sources,
             topo_order,
             external_tables,
-        ) {
+        )
+        .await
+        {
             Ok(inc_output) => {
                 let (_, pc, fc) = common::report_static_analysis_results(
-                    &inc_output.result,
-                    &inc_output.overrides,
+                    &inc_output,
                     |model_name, mismatch, is_error| {
-                        if inc_sources.contains_key(model_name) && !json_mode {
-                            let label = if is_error { "error" } else { "warn" };
-                            eprintln!("  [{label}] {model_name} [incremental]: {mismatch}");
+                        if !inc_sources.contains_key(model_name) {
+                            return;
+                        }
+                        inc_mismatch_count += 1;
+                        if !json_mode {
+                            common::print_mismatch_frame(
+                                project,
+                                &inc_output.result,
+                                model_name,
+                                mismatch,
+                                is_error,
+                                " [incremental]",
+                            );
                         }
                     },
                     |model, err| {
@@ -1253,7 +1408,31 @@ fn run_static_analysis(
         eprintln!("[verbose] Static analysis found schema errors");
     }
 
-    Ok(())
+    if args.fix {
+        schema_fix::fix_model_yaml(project, result, json_mode)?;
+    }
+
+    let total_mismatches = mismatch_count + inc_mismatch_count;
+    let strict_failed = args.strict && total_mismatches > 0;
+    if strict_failed && !json_mode {
+        eprintln!(
+            "Strict mode: {} schema mismatches are not in the diagnostics baseline",
+            total_mismatches
+        );
+    }
+
+    let inferred_schemas = result
+        .engine_inferred
+        .iter()
+        .map(|(name, r)| (name.to_string(), r.inferred_schema.clone()))
+        .chain(
+            result
+                .model_plans
+                .iter()
+                .map(|(name, r)| (name.to_string(), r.inferred_schema.clone())),
+        )
+        .collect();
+    Ok((strict_failed, inferred_schemas))
 }
 
 /// Write a single hook file to disk and return whether it succeeded.
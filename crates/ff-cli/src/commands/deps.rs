@@ -0,0 +1,130 @@
+//! Deps command implementation
+
+use anyhow::{Context, Result};
+use ff_core::config::Config;
+use ff_core::package::{LockedPackage, Lockfile, PACKAGES_DIR};
+use ff_core::{Package, PackageSpec};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::cli::{DepsArgs, GlobalArgs};
+
+/// Execute the deps command
+pub(crate) async fn execute(args: &DepsArgs, global: &GlobalArgs) -> Result<()> {
+    let root = global.project_dir.as_path();
+    // Not `load_project`: that requires the lockfile this command writes.
+    let config = Config::load_from_dir(root)
+        .with_context(|| format!("Failed to load config from {}", root.display()))?;
+
+    if config.packages.is_empty() {
+        println!("No packages declared in featherflow.yml");
+        return Ok(());
+    }
+
+    let mut lockfile = Lockfile::default();
+    for spec in &config.packages {
+        if !args.check {
+            if let Some(tarball) = spec.tarball_path(root) {
+                extract_tarball(root, spec, &tarball)?;
+            }
+        }
+        let package = Package::load(root, spec)?;
+        let locked = LockedPackage::new(root, spec, &package)?;
+        if global.verbose {
+            println!("  {} {} ({})", locked.name, locked.version, locked.source);
+        }
+        lockfile.packages.push(locked);
+    }
+
+    if args.check {
+        let current = Lockfile::load(root)?;
+        if current.as_ref() != Some(&lockfile) {
+            anyhow::bail!(
+                "{} is out of date; run `ff dt deps`",
+                ff_core::package::LOCKFILE_NAME
+            );
+        }
+        println!(
+            "{} is up to date ({} package{})",
+            ff_core::package::LOCKFILE_NAME,
+            lockfile.packages.len(),
+            if lockfile.packages.len() == 1 {
+                ""
+            } else {
+                "s"
+            }
+        );
+        return Ok(());
+    }
+
+    lockfile.save(root)?;
+    println!(
+        "Installed {} package{}, wrote {}",
+        lockfile.packages.len(),
+        if lockfile.packages.len() == 1 {
+            ""
+        } else {
+            "s"
+        },
+        ff_core::package::LOCKFILE_NAME
+    );
+    Ok(())
+}
+
+/// Extract a package tarball into `ff_packages/<name>/`, replacing any
+/// previous install.
+fn extract_tarball(root: &Path, spec: &PackageSpec, tarball: &Path) -> Result<()> {
+    let packages_dir = root.join(PACKAGES_DIR);
+    let staging = packages_dir.join(format!(".{}.tmp", spec.name));
+    if staging.exists() {
+        fs::remove_dir_all(&staging)?;
+    }
+    fs::create_dir_all(&staging)?;
+
+    let file = fs::File::open(tarball)
+        .with_context(|| format!("Failed to open package tarball {}", tarball.display()))?;
+    tar::Archive::new(flate2::read::GzDecoder::new(file))
+        .unpack(&staging)
+        .with_context(|| format!("Failed to extract {}", tarball.display()))?;
+
+    let package_root = tarball_package_root(&staging).with_context(|| {
+        format!(
+            "{} does not contain a featherflow.yml at its root or in a single top-level directory",
+            tarball.display()
+        )
+    })?;
+
+    let install_dir = spec.install_dir(root);
+    if install_dir.exists() {
+        fs::remove_dir_all(&install_dir)?;
+    }
+    fs::rename(&package_root, &install_dir)?;
+    if staging.exists() {
+        fs::remove_dir_all(&staging)?;
+    }
+    Ok(())
+}
+
+/// Locate the project inside an extracted tarball: either the archive root
+/// or its single top-level directory (as produced by `git archive --prefix`).
+fn tarball_package_root(extracted: &Path) -> Option<PathBuf> {
+    let has_config = |dir: &Path| {
+        dir.join("featherflow.yml").is_file() || dir.join("featherflow.yaml").is_file()
+    };
+    if has_config(extracted) {
+        return Some(extracted.to_path_buf());
+    }
+    let entries: Vec<PathBuf> = fs::read_dir(extracted)
+        .ok()?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .collect();
+    match entries.as_slice() {
+        [only] if has_config(only) => Some(only.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "deps_test.rs"]
+mod tests;
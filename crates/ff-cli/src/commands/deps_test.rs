@@ -0,0 +1,84 @@
+use super::*;
+use crate::commands::test_support::global;
+use tempfile::tempdir;
+
+fn write_package(dir: &Path, version: &str) {
+    fs::create_dir_all(dir.join("macros")).unwrap();
+    fs::write(
+        dir.join("featherflow.yml"),
+        format!("name: shared\nversion: \"{}\"\n", version),
+    )
+    .unwrap();
+    fs::write(
+        dir.join("macros/money.sql"),
+        "{% macro cents(col) %}{{ col }} / 100{% endmacro %}",
+    )
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_deps_extracts_tarball_and_writes_lockfile() {
+    let temp = tempdir().unwrap();
+    let root = temp.path();
+
+    // Build vendor/shared.tar.gz with the project under a `shared-1.2.0/` prefix
+    let source = root.join("src_pkg");
+    write_package(&source, "1.2.0");
+    fs::create_dir_all(root.join("vendor")).unwrap();
+    let tarball = fs::File::create(root.join("vendor/shared.tar.gz")).unwrap();
+    let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(
+        tarball,
+        flate2::Compression::default(),
+    ));
+    builder.append_dir_all("shared-1.2.0", &source).unwrap();
+    builder.into_inner().unwrap().finish().unwrap();
+
+    fs::write(
+        root.join("featherflow.yml"),
+        r#"
+name: app
+packages:
+  - name: shared
+    tarball: vendor/shared.tar.gz
+    version: ">=1.0, <2"
+"#,
+    )
+    .unwrap();
+
+    execute(&DepsArgs { check: false }, &global(root))
+        .await
+        .unwrap();
+
+    assert!(root.join("ff_packages/shared/macros/money.sql").is_file());
+    let lockfile = Lockfile::load(root).unwrap().unwrap();
+    assert_eq!(lockfile.packages.len(), 1);
+    assert_eq!(lockfile.packages[0].source, "tarball:vendor/shared.tar.gz");
+    assert_eq!(lockfile.packages[0].version, "1.2.0");
+    assert!(lockfile.packages[0].checksum.is_some());
+
+    execute(&DepsArgs { check: true }, &global(root))
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_deps_check_detects_stale_lockfile() {
+    let temp = tempdir().unwrap();
+    let root = temp.path();
+    write_package(&root.join("packages/shared"), "1.0.0");
+    fs::write(
+        root.join("featherflow.yml"),
+        "name: app\npackages:\n  - name: shared\n    path: packages/shared\n",
+    )
+    .unwrap();
+
+    execute(&DepsArgs { check: false }, &global(root))
+        .await
+        .unwrap();
+
+    write_package(&root.join("packages/shared"), "1.1.0");
+    let err = execute(&DepsArgs { check: true }, &global(root))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("out of date"), "{}", err);
+}
@@ -69,6 +69,9 @@ pub(crate) struct ColumnDoc {
     /// Human-readable description
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Upstream `node.column` the description was inherited from via lineage
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description_inherited_from: Option<String>,
     /// Whether this column is a primary key
     #[serde(skip_serializing_if = "std::ops::Not::not")]
     pub primary_key: bool,
@@ -209,6 +212,7 @@ pub(crate) fn build_model_doc(model: &Model) -> ModelDoc {
                     name: col.name.clone(),
                     data_type: col.data_type.clone(),
                     description: col.description.clone(),
+                    description_inherited_from: col.description_inherited_from.clone(),
                     primary_key: col.primary_key,
                     tests: test_names,
                     references,
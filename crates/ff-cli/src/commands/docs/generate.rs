@@ -1,14 +1,14 @@
 //! Static documentation generation (markdown, HTML, JSON)
 
 use anyhow::{Context, Result};
-use ff_jinja::{get_builtin_macros, get_macro_categories, MacroMetadata};
+use ff_jinja::{get_builtin_macros, get_macro_categories, MacroDoc};
 use std::collections::HashMap;
 use std::fs;
 
 use ff_core::Project;
 
 use crate::cli::{DocsArgs, DocsFormat, GlobalArgs};
-use crate::commands::common::load_project;
+use crate::commands::common::{self, load_project};
 
 use super::data::*;
 
@@ -32,7 +32,11 @@ pub(super) async fn execute(args: &DocsArgs, global: &GlobalArgs) -> Result<()>
     fs::create_dir_all(&output_dir).context("Failed to create output directory")?;
 
     let models_to_doc: Vec<String> = if args.nodes.is_some() {
-        let (_, dag) = crate::commands::common::build_project_dag(&project)?;
+        let (_, dag) = crate::commands::common::build_project_dag(
+            &project,
+            global,
+            &crate::commands::common::build_node_catalog(&project),
+        )?;
         crate::commands::common::resolve_nodes(&project, &dag, &args.nodes)?
     } else {
         project
@@ -52,7 +56,9 @@ pub(super) async fn execute(args: &DocsArgs, global: &GlobalArgs) -> Result<()>
 
     println!("Generating documentation...\n");
 
-    let builtin_macros = get_builtin_macros();
+    let builtin_macros: Vec<MacroDoc> = get_builtin_macros().iter().map(MacroDoc::from).collect();
+    let project_macros =
+        ff_jinja::discover_macro_docs(&project.config.macro_paths_absolute(&project.root))?;
     let mut models_with_schema = 0;
     let mut models_without_schema = 0;
     let mut model_docs: Vec<ModelDoc> = Vec::new();
@@ -139,6 +145,7 @@ pub(super) async fn execute(args: &DocsArgs, global: &GlobalArgs) -> Result<()>
                 &index_entries,
                 &source_entries,
                 &builtin_macros,
+                &project_macros,
             );
             let index_path = output_dir.join("index.md");
             fs::write(&index_path, &index_content)
@@ -180,6 +187,7 @@ pub(super) async fn execute(args: &DocsArgs, global: &GlobalArgs) -> Result<()>
                 &index_entries,
                 &source_entries,
                 &builtin_macros,
+                &project_macros,
             );
             let index_path = output_dir.join("index.html");
             fs::write(&index_path, &index_content)
@@ -196,21 +204,22 @@ pub(super) async fn execute(args: &DocsArgs, global: &GlobalArgs) -> Result<()>
 
     match args.format {
         DocsFormat::Markdown => {
-            let macros_content = generate_macros_markdown(&builtin_macros);
+            let macros_content = generate_macros_markdown(&builtin_macros, &project_macros);
             let macros_path = output_dir.join("macros.md");
             fs::write(&macros_path, &macros_content)
                 .context(format!("failed to write {}", macros_path.display()))?;
             println!("  {} macros.md", CHECKMARK);
         }
         DocsFormat::Html => {
-            let macros_content = generate_macros_html(&builtin_macros);
+            let macros_content = generate_macros_html(&builtin_macros, &project_macros);
             let macros_path = output_dir.join("macros.html");
             fs::write(&macros_path, &macros_content)
                 .context(format!("failed to write {}", macros_path.display()))?;
             println!("  {} macros.html", CHECKMARK);
         }
         DocsFormat::Json => {
-            let macros_json = serde_json::to_string_pretty(&builtin_macros)?;
+            let all_macros: Vec<&MacroDoc> = builtin_macros.iter().chain(&project_macros).collect();
+            let macros_json = serde_json::to_string_pretty(&all_macros)?;
             let macros_path = output_dir.join("macros.json");
             fs::write(&macros_path, &macros_json)
                 .context(format!("failed to write {}", macros_path.display()))?;
@@ -225,7 +234,7 @@ pub(super) async fn execute(args: &DocsArgs, global: &GlobalArgs) -> Result<()>
         models_with_schema,
         models_without_schema,
         source_entries.len(),
-        builtin_macros.len()
+        builtin_macros.len() + project_macros.len()
     );
     println!("Output: {}", output_dir.display());
 
@@ -287,7 +296,11 @@ fn generate_markdown(doc: &ModelDoc) -> String {
 
         for col in &doc.columns {
             let data_type = col.data_type.as_str();
-            let desc = col.description.as_deref().unwrap_or("-");
+            let desc = match (&col.description, &col.description_inherited_from) {
+                (Some(d), Some(origin)) => format!("{} _(inherited from `{}`)_", d, origin),
+                (Some(d), None) => d.clone(),
+                (None, _) => "-".to_string(),
+            };
             let cls = col.classification.as_deref().unwrap_or("-");
             let tests = if col.tests.is_empty() {
                 "-".to_string()
@@ -411,7 +424,8 @@ fn generate_index_markdown(
     project_name: &str,
     models: &[ModelSummary],
     sources: &[SourceSummary],
-    builtin_macros: &[MacroMetadata],
+    builtin_macros: &[MacroDoc],
+    project_macros: &[MacroDoc],
 ) -> String {
     let mut md = String::new();
 
@@ -436,10 +450,10 @@ fn generate_index_markdown(
         ));
     }
 
-    let macro_count = builtin_macros.len();
     md.push_str(&format!(
-        "**Macros**: {} built-in macros ([view documentation](macros.md))\n\n",
-        macro_count
+        "**Macros**: {} built-in macros{} ([view documentation](macros.md))\n\n",
+        builtin_macros.len(),
+        project_macro_count(project_macros)
     ));
 
     md.push_str("## Models\n\n");
@@ -608,7 +622,15 @@ fn generate_html(doc: &ModelDoc) -> String {
 
         for col in &doc.columns {
             let data_type = col.data_type.as_str();
-            let desc = col.description.as_deref().unwrap_or("-");
+            let desc = match (&col.description, &col.description_inherited_from) {
+                (Some(d), Some(origin)) => format!(
+                    "{} <em>(inherited from <code>{}</code>)</em>",
+                    html_escape(d),
+                    html_escape(origin)
+                ),
+                (Some(d), None) => html_escape(d),
+                (None, _) => "-".to_string(),
+            };
             let cls = col.classification.as_deref().unwrap_or("-");
             let tests = if col.tests.is_empty() {
                 "-".to_string()
@@ -619,7 +641,7 @@ fn generate_html(doc: &ModelDoc) -> String {
                 "<tr><td><code>{}</code></td><td>{}</td><td>{}</td><td>{}</td><td>{}</td></tr>\n",
                 html_escape(&col.name),
                 html_escape(data_type),
-                html_escape(desc),
+                desc,
                 html_escape(cls),
                 html_escape(&tests)
             ));
@@ -771,7 +793,8 @@ fn generate_index_html(
     project_name: &str,
     models: &[ModelSummary],
     sources: &[SourceSummary],
-    builtin_macros: &[MacroMetadata],
+    builtin_macros: &[MacroDoc],
+    project_macros: &[MacroDoc],
 ) -> String {
     let mut html = String::new();
 
@@ -805,10 +828,10 @@ fn generate_index_html(
         ));
     }
 
-    let macro_count = builtin_macros.len();
     html.push_str(&format!(
-        "<p><strong>Macros:</strong> {} built-in macros (<a href=\"macros.html\">view documentation</a>)</p>\n",
-        macro_count
+        "<p><strong>Macros:</strong> {} built-in macros{} (<a href=\"macros.html\">view documentation</a>)</p>\n",
+        builtin_macros.len(),
+        project_macro_count(project_macros)
     ));
 
     html.push_str("</div>\n");
@@ -995,8 +1018,24 @@ fn format_category_title(category: &str) -> String {
         .collect()
 }
 
+/// `, N project macros` for the index, or nothing without project macros.
+fn project_macro_count(project_macros: &[MacroDoc]) -> String {
+    match project_macros.len() {
+        0 => String::new(),
+        n => format!(", {} project macros", n),
+    }
+}
+
+/// Distinct categories of the project's macros, sorted.
+fn project_macro_categories(project_macros: &[MacroDoc]) -> Vec<&str> {
+    let mut categories: Vec<&str> = project_macros.iter().map(|m| m.category.as_str()).collect();
+    categories.sort_unstable();
+    categories.dedup();
+    categories
+}
+
 /// Render a single macro entry as markdown.
-fn render_macro_entry_markdown(md: &mut String, macro_info: &MacroMetadata) {
+fn render_macro_entry_markdown(md: &mut String, macro_info: &MacroDoc) {
     md.push_str(&format!("### `{}`\n\n", macro_info.name));
     md.push_str(&format!("{}\n\n", macro_info.description));
 
@@ -1014,17 +1053,34 @@ fn render_macro_entry_markdown(md: &mut String, macro_info: &MacroMetadata) {
         md.push('\n');
     }
 
-    md.push_str("**Example:**\n\n");
-    md.push_str(&format!("```jinja\n{}\n```\n\n", macro_info.example));
-    md.push_str("**Output:**\n\n");
-    md.push_str(&format!("```sql\n{}\n```\n\n", macro_info.example_output));
+    if !macro_info.example.is_empty() {
+        md.push_str("**Example:**\n\n");
+        md.push_str(&format!("```jinja\n{}\n```\n\n", macro_info.example));
+    }
+    if !macro_info.example_output.is_empty() {
+        md.push_str("**Output:**\n\n");
+        md.push_str(&format!("```sql\n{}\n```\n\n", macro_info.example_output));
+    }
     md.push_str("---\n\n");
 }
 
-/// Generate markdown documentation for built-in macros
-fn generate_macros_markdown(builtin_macros: &[MacroMetadata]) -> String {
+/// Generate markdown documentation for project and built-in macros
+fn generate_macros_markdown(builtin_macros: &[MacroDoc], project_macros: &[MacroDoc]) -> String {
     let mut md = String::new();
 
+    if !project_macros.is_empty() {
+        md.push_str("# Project Macros\n\n");
+        for category in project_macro_categories(project_macros) {
+            md.push_str(&format!(
+                "## {} Macros\n\n",
+                format_category_title(category)
+            ));
+            for macro_info in project_macros.iter().filter(|m| m.category == category) {
+                render_macro_entry_markdown(&mut md, macro_info);
+            }
+        }
+    }
+
     md.push_str("# Built-in Macros\n\n");
     md.push_str(
         "Featherflow provides a set of built-in macros that are available in all templates.\n\n",
@@ -1033,9 +1089,9 @@ fn generate_macros_markdown(builtin_macros: &[MacroMetadata]) -> String {
     let categories = get_macro_categories();
 
     for category in &categories {
-        let category_macros: Vec<&MacroMetadata> = builtin_macros
+        let category_macros: Vec<&MacroDoc> = builtin_macros
             .iter()
-            .filter(|m| &m.category == category)
+            .filter(|m| m.category == *category)
             .collect();
 
         if category_macros.is_empty() {
@@ -1054,13 +1110,16 @@ fn generate_macros_markdown(builtin_macros: &[MacroMetadata]) -> String {
 }
 
 /// Render a single macro entry as HTML.
-fn render_macro_entry_html(html: &mut String, macro_info: &MacroMetadata) {
+fn render_macro_entry_html(html: &mut String, macro_info: &MacroDoc) {
     html.push_str("<div class=\"macro-card\">\n");
     html.push_str(&format!(
         "<div class=\"macro-name\"><code>{}</code></div>\n",
         macro_info.name
     ));
-    html.push_str(&format!("<p>{}</p>\n", html_escape(macro_info.description)));
+    html.push_str(&format!(
+        "<p>{}</p>\n",
+        html_escape(&macro_info.description)
+    ));
 
     if !macro_info.params.is_empty() {
         html.push_str("<table class=\"param-table\">\n");
@@ -1076,28 +1135,32 @@ fn render_macro_entry_html(html: &mut String, macro_info: &MacroMetadata) {
                 param.name,
                 param.param_type,
                 required,
-                html_escape(param.description)
+                html_escape(&param.description)
             ));
         }
         html.push_str("</tbody></table>\n");
     }
 
-    html.push_str("<span class=\"example-label\">Example:</span>\n");
-    html.push_str(&format!(
-        "<pre><code>{}</code></pre>\n",
-        html_escape(macro_info.example)
-    ));
-    html.push_str("<span class=\"example-label\">Output:</span>\n");
-    html.push_str(&format!(
-        "<pre><code>{}</code></pre>\n",
-        html_escape(macro_info.example_output)
-    ));
+    if !macro_info.example.is_empty() {
+        html.push_str("<span class=\"example-label\">Example:</span>\n");
+        html.push_str(&format!(
+            "<pre><code>{}</code></pre>\n",
+            html_escape(&macro_info.example)
+        ));
+    }
+    if !macro_info.example_output.is_empty() {
+        html.push_str("<span class=\"example-label\">Output:</span>\n");
+        html.push_str(&format!(
+            "<pre><code>{}</code></pre>\n",
+            html_escape(&macro_info.example_output)
+        ));
+    }
 
     html.push_str("</div>\n");
 }
 
-/// Generate HTML documentation for built-in macros
-fn generate_macros_html(builtin_macros: &[MacroMetadata]) -> String {
+/// Generate HTML documentation for project and built-in macros
+fn generate_macros_html(builtin_macros: &[MacroDoc], project_macros: &[MacroDoc]) -> String {
     let mut html = String::new();
 
     html.push_str("<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n");
@@ -1120,15 +1183,29 @@ fn generate_macros_html(builtin_macros: &[MacroMetadata]) -> String {
 
     html.push_str("<nav><a href=\"index.html\">Home</a></nav>\n");
 
+    if !project_macros.is_empty() {
+        html.push_str("<h1>Project Macros</h1>\n");
+        for category in project_macro_categories(project_macros) {
+            html.push_str(&format!(
+                "<div class=\"macro-section\">\n<h2>{} Macros</h2>\n",
+                html_escape(&format_category_title(category))
+            ));
+            for macro_info in project_macros.iter().filter(|m| m.category == category) {
+                render_macro_entry_html(&mut html, macro_info);
+            }
+            html.push_str("</div>\n");
+        }
+    }
+
     html.push_str("<h1>Built-in Macros</h1>\n");
     html.push_str("<p>Featherflow provides a set of built-in macros that are available in all templates.</p>\n");
 
     let categories = get_macro_categories();
 
     for category in &categories {
-        let category_macros: Vec<&MacroMetadata> = builtin_macros
+        let category_macros: Vec<&MacroDoc> = builtin_macros
             .iter()
-            .filter(|m| &m.category == category)
+            .filter(|m| m.category == *category)
             .collect();
 
         if category_macros.is_empty() {
@@ -0,0 +1,116 @@
+//! `ff dt docs inherit` — report or persist descriptions inherited through lineage
+
+use anyhow::{Context, Result};
+use ff_core::model::ModelSchema;
+
+use crate::cli::{DocsInheritArgs, GlobalArgs};
+use crate::commands::common::{self, load_project};
+use crate::commands::yaml_edit::{unified_diff, ColumnsEditor};
+
+/// Execute the docs inherit subcommand.
+///
+/// Runs description inheritance regardless of
+/// `documentation.inherit_column_descriptions`, so it can be used to preview
+/// the effect before enabling it or to bake inherited text into YAML.
+pub(super) async fn execute(args: &DocsInheritArgs, global: &GlobalArgs) -> Result<()> {
+    let mut project = load_project(global)?;
+    // `load_project` has already inherited when the setting is enabled
+    if !project.config.documentation.inherit_column_descriptions {
+        common::inherit_column_descriptions(&mut project, global);
+    }
+
+    let mut selected: Vec<String> = if args.nodes.is_some() {
+        let (_, dag) =
+            common::build_project_dag(&project, global, &common::build_node_catalog(&project))?;
+        common::resolve_nodes(&project, &dag, &args.nodes)?
+    } else {
+        project
+            .model_names()
+            .into_iter()
+            .map(String::from)
+            .collect()
+    };
+    selected.sort();
+
+    let mut total = 0;
+    let mut files_written = 0;
+
+    for name in &selected {
+        let Some(model) = project.get_model(name) else {
+            continue;
+        };
+        let Some(schema) = &model.schema else {
+            continue;
+        };
+        let inherited: Vec<(&str, &str, &str)> = schema
+            .columns
+            .iter()
+            .filter_map(|c| {
+                Some((
+                    c.name.as_str(),
+                    c.description.as_deref()?,
+                    c.description_inherited_from.as_deref()?,
+                ))
+            })
+            .collect();
+        if inherited.is_empty() {
+            continue;
+        }
+        total += inherited.len();
+
+        if !args.write {
+            println!("{}", name);
+            for (column, description, origin) in &inherited {
+                println!("  {} <- {}: {}", column, origin, description);
+            }
+            continue;
+        }
+
+        let Some(yaml_path) = common::model_yaml_path(&model.path) else {
+            continue;
+        };
+        let content = std::fs::read_to_string(&yaml_path)
+            .with_context(|| format!("Failed to read {}", yaml_path.display()))?;
+        let mut editor = ColumnsEditor::new(&content);
+        for (column, description, _) in &inherited {
+            editor
+                .set_description(column, description)
+                .with_context(|| format!("Cannot edit {}", yaml_path.display()))?;
+        }
+        let updated = editor.render();
+        ModelSchema::load_from_str(&updated, &yaml_path).with_context(|| {
+            format!(
+                "Refusing to write {}: edited YAML no longer parses",
+                yaml_path.display()
+            )
+        })?;
+
+        print!(
+            "{}",
+            unified_diff(&yaml_path.display().to_string(), &content, &updated)
+        );
+        std::fs::write(&yaml_path, &updated)
+            .with_context(|| format!("Failed to write {}", yaml_path.display()))?;
+        files_written += 1;
+    }
+
+    if total == 0 {
+        println!("No column descriptions to inherit");
+    } else if args.write {
+        println!(
+            "Wrote {} inherited description(s) to {} file(s)",
+            total, files_written
+        );
+    } else {
+        println!(
+            "\n{} column(s) would inherit a description. Re-run with --write to persist them.",
+            total
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "inherit_test.rs"]
+mod tests;
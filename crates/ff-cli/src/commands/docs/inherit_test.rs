@@ -0,0 +1,68 @@
+use super::*;
+use crate::commands::test_support::{global, write, write_config};
+use std::fs;
+use tempfile::tempdir;
+
+#[tokio::test]
+async fn test_inherit_write_persists_upstream_descriptions() {
+    let temp_dir = tempdir().unwrap();
+    let root = temp_dir.path();
+
+    write_config(root, "test_project", "");
+    write(
+        root,
+        "nodes/raw_shop/raw_shop.yml",
+        r#"kind: source
+version: 1
+name: raw_shop
+schema: raw
+tables:
+  - name: orders
+    columns:
+      - name: id
+        type: INTEGER
+        description: "Order identifier"
+      - name: status
+        type: VARCHAR
+        description: "Raw status code"
+"#,
+    );
+    write(
+        root,
+        "nodes/stg_orders/stg_orders.sql",
+        "select id as order_id, status from orders\n",
+    );
+    write(
+        root,
+        "nodes/stg_orders/stg_orders.yml",
+        r#"version: 1
+kind: sql
+columns:
+  # renamed from orders.id
+  - name: order_id
+    type: INTEGER
+  - name: status
+    type: VARCHAR
+    description: "Normalised status"
+"#,
+    );
+
+    let args = DocsInheritArgs {
+        nodes: None,
+        write: true,
+    };
+    execute(&args, &global(root)).await.unwrap();
+
+    let stg_yaml = root.join("nodes/stg_orders/stg_orders.yml");
+    let content = fs::read_to_string(&stg_yaml).unwrap();
+    assert!(content.contains("# renamed from orders.id"));
+    let schema = ModelSchema::load_from_str(&content, &stg_yaml).unwrap();
+    assert_eq!(
+        schema.columns[0].description.as_deref(),
+        Some("Order identifier")
+    );
+    assert_eq!(
+        schema.columns[1].description.as_deref(),
+        Some("Normalised status")
+    );
+}
@@ -2,6 +2,7 @@
 
 pub(super) mod data;
 mod generate;
+mod inherit;
 #[cfg(feature = "docs-serve")]
 mod serve;
 
@@ -26,6 +27,9 @@ pub(crate) async fn execute(args: &DocsArgs, global: &GlobalArgs) -> Result<()>
                 );
             }
         }
+        Some(crate::cli::DocsCommands::Inherit(inherit_args)) => {
+            inherit::execute(inherit_args, global).await
+        }
         None => generate::execute(args, global).await,
     }
 }
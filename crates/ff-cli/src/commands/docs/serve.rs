@@ -37,6 +37,8 @@ struct AppState {
     lineage_json: Arc<str>,
     /// Search index JSON
     search_index_json: Arc<str>,
+    /// Project and built-in macro documentation JSON
+    macros_json: Arc<str>,
 }
 
 /// Model summary for the index endpoint
@@ -104,6 +106,13 @@ struct SearchEntry {
     columns: Vec<String>,
 }
 
+/// Macro documentation returned by /api/macros.json
+#[derive(Debug, Serialize)]
+struct MacrosResponse {
+    project: Vec<ff_jinja::MacroDoc>,
+    builtin: Vec<ff_jinja::MacroDoc>,
+}
+
 /// Lineage edge for column-level lineage
 #[derive(Debug, Serialize)]
 struct LineageEntry {
@@ -120,7 +129,7 @@ pub(super) async fn execute(args: &DocsServeArgs, global: &GlobalArgs) -> Result
 
     println!("Building documentation data...");
 
-    let state = build_app_state(&project)?;
+    let state = build_app_state(&project, global)?;
 
     if let Some(export_path) = &args.static_export {
         return export_static_site(&state, export_path);
@@ -133,6 +142,7 @@ pub(super) async fn execute(args: &DocsServeArgs, global: &GlobalArgs) -> Result
         .route("/api/models/{name}", get(get_model))
         .route("/api/lineage.json", get(get_lineage))
         .route("/api/search-index.json", get(get_search_index))
+        .route("/api/macros.json", get(get_macros))
         .fallback(get(static_handler))
         .with_state(state);
 
@@ -175,7 +185,7 @@ async fn shutdown_signal() {
 }
 
 /// Build all pre-computed state from the project
-fn build_app_state(project: &Project) -> Result<AppState> {
+fn build_app_state(project: &Project, global: &GlobalArgs) -> Result<AppState> {
     let model_count = project.models.len();
     let source_count = project.sources.len();
     let mut index_models = Vec::with_capacity(model_count);
@@ -190,7 +200,7 @@ fn build_app_state(project: &Project) -> Result<AppState> {
     let known_models: HashSet<&str> = project.model_names().into_iter().collect();
     let external_tables = project.source_table_names();
     let parser = SqlParser::duckdb();
-    let jinja = common::build_jinja_env(project);
+    let jinja = common::build_jinja_env(project, global, &common::build_node_catalog(project));
 
     for name in project.model_names() {
         if let Some(model) = project.get_model(name) {
@@ -285,6 +295,16 @@ fn build_app_state(project: &Project) -> Result<AppState> {
         },
     };
 
+    let macros = MacrosResponse {
+        project: ff_jinja::discover_macro_docs(
+            &project.config.macro_paths_absolute(&project.root),
+        )?,
+        builtin: ff_jinja::get_builtin_macros()
+            .iter()
+            .map(ff_jinja::MacroDoc::from)
+            .collect(),
+    };
+
     Ok(AppState {
         index_json: Arc::from(serde_json::to_string(&index)?),
         model_docs: model_docs_map
@@ -293,6 +313,7 @@ fn build_app_state(project: &Project) -> Result<AppState> {
             .collect(),
         lineage_json: Arc::from(serde_json::to_string(&lineage_entries)?),
         search_index_json: Arc::from(serde_json::to_string(&search_entries)?),
+        macros_json: Arc::from(serde_json::to_string(&macros)?),
     })
 }
 
@@ -339,6 +360,14 @@ async fn get_search_index(State(state): State<Arc<AppState>>) -> impl IntoRespon
     )
 }
 
+/// GET /api/macros.json
+async fn get_macros(State(state): State<Arc<AppState>>) -> impl IntoResponse {
+    (
+        [(header::CONTENT_TYPE, "application/json")],
+        String::from(&*state.macros_json),
+    )
+}
+
 /// Build an HTTP response for an embedded asset with the given content type.
 fn serve_asset(
     content: rust_embed::EmbeddedFile,
@@ -412,6 +441,8 @@ fn export_static_site(state: &AppState, export_path: &str) -> Result<()> {
         .context("failed to write api/lineage.json")?;
     fs::write(api_dir.join("search-index.json"), &*state.search_index_json)
         .context("failed to write api/search-index.json")?;
+    fs::write(api_dir.join("macros.json"), &*state.macros_json)
+        .context("failed to write api/macros.json")?;
 
     let models_dir = api_dir.join("models");
     fs::create_dir_all(&models_dir).context(format!(
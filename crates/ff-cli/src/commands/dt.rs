@@ -3,7 +3,10 @@
 use anyhow::Result;
 
 use crate::cli::{DtArgs, DtCommands, GlobalArgs};
-use crate::commands::{analyze, clean, compile, deploy, docs, fmt, init, lineage, ls};
+use crate::commands::{
+    analyze, clean, compile, deploy, deps, docs, export, fmt, generate, import, init, lineage, ls,
+    test_macros,
+};
 
 /// Execute the dt (developer tooling) command.
 pub(crate) async fn execute(args: &DtArgs, global: &GlobalArgs) -> Result<()> {
@@ -17,5 +20,10 @@ pub(crate) async fn execute(args: &DtArgs, global: &GlobalArgs) -> Result<()> {
         DtCommands::Docs(sub) => docs::execute(sub, global).await,
         DtCommands::Deploy(sub) => deploy::execute(sub, global).await,
         DtCommands::Analyze(sub) => analyze::execute(sub, global).await,
+        DtCommands::Generate(sub) => generate::execute(sub, global).await,
+        DtCommands::Deps(sub) => deps::execute(sub, global).await,
+        DtCommands::TestMacros(sub) => test_macros::execute(sub, global).await,
+        DtCommands::Import(sub) => import::execute(sub, global).await,
+        DtCommands::Export(sub) => export::execute(sub, global).await,
     }
 }
@@ -0,0 +1,262 @@
+//! In-memory DuckDB with empty input tables, for engine-backed schema checks
+
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use ff_analysis::{parse_sql_type, Nullability, RelSchema, SchemaFallback, TypedColumn};
+use ff_core::sql_utils::{quote_ident, quote_qualified};
+use ff_core::{ModelName, Project};
+use ff_db::{DatabaseCore, DatabaseCsv, DatabaseFunction, DatabaseSchema, DuckDbBackend};
+use std::collections::HashSet;
+
+use crate::commands::common;
+
+/// Column type used for source columns declared without a `type`
+const UNTYPED_COLUMN: &str = "VARCHAR";
+
+/// An in-memory DuckDB holding an empty table for every project input.
+///
+/// Models are described against it in DAG order; each described model is
+/// registered as an empty table so the models downstream of it resolve.
+pub(crate) struct EngineStub {
+    db: DuckDbBackend,
+    created: HashSet<String>,
+    verbose: bool,
+}
+
+impl EngineStub {
+    /// Create empty tables for every source table with declared columns and
+    /// every enabled seed, then deploy the project's functions.
+    ///
+    /// Declared source types win over CSV inference so the stub sees the same
+    /// inputs as the DataFusion catalog. Relations or functions DuckDB rejects
+    /// are skipped (reported with `verbose`).
+    pub(crate) async fn build(project: &Project, verbose: bool) -> Result<Self> {
+        let db = DuckDbBackend::in_memory().context("Failed to open in-memory DuckDB")?;
+        let mut stub = Self {
+            db,
+            created: HashSet::new(),
+            verbose,
+        };
+
+        for source_file in &project.sources {
+            stub.db
+                .create_schema_if_not_exists(&source_file.schema)
+                .await
+                .with_context(|| format!("Failed to create schema '{}'", source_file.schema))?;
+            for table in &source_file.tables {
+                if table.columns.is_empty() {
+                    continue;
+                }
+                let columns: Vec<(String, String)> = table
+                    .columns
+                    .iter()
+                    .map(|c| {
+                        let data_type = if c.data_type.trim().is_empty() {
+                            UNTYPED_COLUMN.to_string()
+                        } else {
+                            c.data_type.clone()
+                        };
+                        (c.name.clone(), data_type)
+                    })
+                    .collect();
+                let mut names = vec![table.name.clone()];
+                names.extend(table.identifier.clone());
+                let qualified: Vec<String> = names
+                    .iter()
+                    .map(|n| format!("{}.{}", source_file.schema, n))
+                    .collect();
+                names.extend(qualified);
+                stub.register(&names, &columns).await;
+            }
+        }
+
+        for seed in project.seeds.iter().filter(|s| s.is_enabled()) {
+            let name = seed.name.to_string();
+            if stub.created.contains(&name) {
+                continue;
+            }
+            let path = seed.path.display().to_string();
+            let mut columns = match stub.db.infer_csv_schema(&path).await {
+                Ok(columns) => columns,
+                Err(e) => {
+                    stub.skip("seed", &name, &e);
+                    continue;
+                }
+            };
+            for (column, data_type) in &mut columns {
+                if let Some(overridden) = seed.column_types().get(column.as_str()) {
+                    *data_type = overridden.clone();
+                }
+            }
+            stub.register(&[name], &columns).await;
+        }
+
+        let jinja = common::build_offline_jinja_env(project, &common::build_node_catalog(project));
+        for func in &project.functions {
+            let deployed = match jinja.render(&func.sql_body) {
+                Ok(body) => stub
+                    .db
+                    .deploy_function(&func.to_create_sql(&body))
+                    .await
+                    .map_err(anyhow::Error::from),
+                Err(e) => Err(anyhow::Error::from(e)),
+            };
+            if let Err(e) = deployed {
+                stub.skip("function", &func.name.to_string(), &e);
+            }
+        }
+
+        Ok(stub)
+    }
+
+    /// Register a model outside the analyzed selection from its YAML columns.
+    pub(crate) async fn declare_model(&mut self, name: &str, schema: &RelSchema) {
+        let columns: Vec<(String, String)> = schema
+            .columns
+            .iter()
+            .map(|c| {
+                let data_type = if c.sql_type.is_unknown() {
+                    UNTYPED_COLUMN.to_string()
+                } else {
+                    c.sql_type.display_name().into_owned()
+                };
+                (c.name.clone(), data_type)
+            })
+            .collect();
+        self.register(&[name.to_string()], &columns).await;
+    }
+
+    /// Describe a model's compiled SQL, then register it as an empty table.
+    ///
+    /// Returns `(name, type, nullable)` for each output column.
+    pub(crate) async fn describe_model(
+        &mut self,
+        name: &str,
+        sql: &str,
+    ) -> Result<Vec<(String, String, bool)>> {
+        // A trailing line comment would swallow the wrapping subquery's closing paren
+        let sql = format!("{}\n", sql.trim_end().trim_end_matches(';'));
+        let columns = self.db.describe_query_columns(&sql).await?;
+        self.db
+            .create_table_as(
+                name,
+                &format!("SELECT * FROM ({sql}) AS subq LIMIT 0"),
+                true,
+            )
+            .await?;
+        self.created.insert(name.to_string());
+        Ok(columns)
+    }
+
+    /// Create the first unclaimed name as an empty table and the rest as views of it
+    async fn register(&mut self, names: &[String], columns: &[(String, String)]) {
+        let mut unclaimed: Vec<String> = Vec::with_capacity(names.len());
+        for name in names {
+            if !self.created.contains(name) && !unclaimed.contains(name) {
+                unclaimed.push(name.clone());
+            }
+        }
+        let Some((table, aliases)) = unclaimed.split_first() else {
+            return;
+        };
+        if columns.is_empty() {
+            return;
+        }
+        let column_defs: Vec<String> = columns
+            .iter()
+            .map(|(name, data_type)| format!("{} {}", quote_ident(name), data_type))
+            .collect();
+        let sql = format!(
+            "CREATE TABLE {} ({})",
+            quote_qualified(table),
+            column_defs.join(", ")
+        );
+        if let Err(e) = self.db.execute_batch(&sql).await {
+            self.skip("table", table, &e);
+            return;
+        }
+        self.created.insert(table.clone());
+
+        let select = format!("SELECT * FROM {}", quote_qualified(table));
+        for alias in aliases {
+            if self.db.create_view_as(alias, &select, false).await.is_ok() {
+                self.created.insert(alias.clone());
+            }
+        }
+    }
+
+    fn skip(&self, kind: &str, name: &str, err: &dyn std::fmt::Display) {
+        if self.verbose {
+            eprintln!("[verbose] Engine stub: skipping {kind} '{name}': {err}");
+        }
+    }
+}
+
+/// [`SchemaFallback`] that describes unplannable models with an [`EngineStub`].
+///
+/// The stub is only built on the first `describe`, so projects DataFusion
+/// plans completely never open DuckDB. Schemas registered before then are
+/// replayed as empty tables once it exists.
+pub(crate) struct EngineFallback<'a> {
+    project: &'a Project,
+    stub: Option<EngineStub>,
+    pending: Vec<(ModelName, RelSchema)>,
+    verbose: bool,
+}
+
+impl<'a> EngineFallback<'a> {
+    pub(crate) fn new(project: &'a Project, verbose: bool) -> Self {
+        Self {
+            project,
+            stub: None,
+            pending: Vec::new(),
+            verbose,
+        }
+    }
+}
+
+#[async_trait]
+impl SchemaFallback for EngineFallback<'_> {
+    async fn register(&mut self, model: &ModelName, schema: &RelSchema) {
+        match &mut self.stub {
+            Some(stub) => stub.declare_model(model, schema).await,
+            None => self.pending.push((model.clone(), schema.clone())),
+        }
+    }
+
+    async fn describe(&mut self, model: &ModelName, sql: &str) -> Result<RelSchema, String> {
+        if self.stub.is_none() {
+            let mut stub = EngineStub::build(self.project, self.verbose)
+                .await
+                .map_err(|e| e.to_string())?;
+            for (name, schema) in self.pending.drain(..) {
+                stub.declare_model(&name, &schema).await;
+            }
+            self.stub = Some(stub);
+        }
+        let Some(stub) = self.stub.as_mut() else {
+            return Err("engine stub unavailable".to_string());
+        };
+
+        let columns = stub
+            .describe_model(model, sql)
+            .await
+            .map_err(|e| e.to_string())?;
+        Ok(RelSchema::new(
+            columns
+                .into_iter()
+                .map(|(name, data_type, nullable)| TypedColumn {
+                    name,
+                    source_table: None,
+                    sql_type: parse_sql_type(&data_type),
+                    nullability: if nullable {
+                        Nullability::Nullable
+                    } else {
+                        Nullability::NotNull
+                    },
+                    provenance: vec![],
+                })
+                .collect(),
+        ))
+    }
+}
@@ -0,0 +1,540 @@
+//! `ff dt export contracts` — node schemas as JSON Schema, Avro or ODCS contracts
+//!
+//! Declared column types are parsed into [`SqlType`] so nested STRUCT, MAP and
+//! LIST columns become the nested constructs of each format. A column is
+//! required when it is a primary key or has a `not_null` test, and an
+//! `accepted_values` test becomes an enum.
+
+use anyhow::{Context, Result};
+use ff_analysis::{parse_sql_type, FloatBitWidth, IntBitWidth, SqlType};
+use ff_core::config::Materialization;
+use ff_core::model::{Model, ModelSchema, SchemaColumnDef, TestDefinition};
+use ff_core::Project;
+use serde_json::{json, Map, Value};
+
+use crate::cli::{ContractFormat, ExportContractsArgs, GlobalArgs};
+use crate::commands::common::{self, load_project};
+
+/// Directory under `target/` the contracts go to without `--output`
+const DEFAULT_OUTPUT_DIR: &str = "contracts";
+
+/// JSON Schema dialect the contracts declare
+const JSON_SCHEMA_DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// ODCS release the contracts follow
+const ODCS_API_VERSION: &str = "v3.0.2";
+
+/// DuckDB's precision and scale for a bare `DECIMAL`
+const DEFAULT_DECIMAL: (u16, u16) = (18, 3);
+
+/// Digits needed for any HUGEINT value
+const HUGEINT_PRECISION: u16 = 39;
+
+/// Execute the export contracts subcommand
+pub(super) fn execute(args: &ExportContractsArgs, global: &GlobalArgs) -> Result<()> {
+    let project = load_project(global)?;
+    let (_, dag) =
+        common::build_project_dag(&project, global, &common::build_node_catalog(&project))?;
+    let names = common::resolve_nodes(&project, &dag, &args.nodes)?;
+
+    let output_dir = args
+        .output
+        .clone()
+        .unwrap_or_else(|| project.target_dir().join(DEFAULT_OUTPUT_DIR));
+    std::fs::create_dir_all(&output_dir)
+        .with_context(|| format!("Failed to create {}", output_dir.display()))?;
+
+    let mut written = 0;
+    for name in &names {
+        let Some(model) = project.get_model(name) else {
+            continue;
+        };
+        let Some(contract) = NodeContract::new(&project, model) else {
+            if global.verbose {
+                eprintln!("[verbose] Skipping '{name}': no columns declared");
+            }
+            continue;
+        };
+        for column in contract
+            .columns
+            .iter()
+            .filter(|c| contains_unknown(&c.sql_type))
+        {
+            eprintln!(
+                "Warning: {name}.{} has unrecognized type '{}'; exported loosely typed",
+                column.def.name, column.def.data_type
+            );
+        }
+
+        let (file, content) = match args.format {
+            ContractFormat::Jsonschema => (
+                format!("{name}.schema.json"),
+                serde_json::to_string_pretty(&contract.json_schema())?,
+            ),
+            ContractFormat::Avro => (
+                format!("{name}.avsc"),
+                serde_json::to_string_pretty(&contract.avro())?,
+            ),
+            ContractFormat::Odcs => (
+                format!("{name}.odcs.yaml"),
+                serde_yaml::to_string(&contract.odcs())?,
+            ),
+        };
+        let path = output_dir.join(file);
+        std::fs::write(&path, content)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        written += 1;
+    }
+
+    println!("Exported {written} contracts to {}", output_dir.display());
+    Ok(())
+}
+
+/// A model's declared schema, ready to render in any contract format
+struct NodeContract<'a> {
+    project: &'a Project,
+    model: &'a Model,
+    schema: &'a ModelSchema,
+    columns: Vec<ContractColumn<'a>>,
+}
+
+/// A declared column with its parsed type and the constraints its tests imply
+struct ContractColumn<'a> {
+    def: &'a SchemaColumnDef,
+    sql_type: SqlType,
+    required: bool,
+    unique: bool,
+    accepted_values: Option<Vec<Value>>,
+}
+
+impl<'a> NodeContract<'a> {
+    /// Build the contract for a model, or `None` when it declares no columns
+    fn new(project: &'a Project, model: &'a Model) -> Option<Self> {
+        let schema = model.schema.as_ref().filter(|s| !s.columns.is_empty())?;
+        let columns = schema.columns.iter().map(ContractColumn::new).collect();
+        Some(Self {
+            project,
+            model,
+            schema,
+            columns,
+        })
+    }
+
+    /// JSON Schema describing one row of the node
+    fn json_schema(&self) -> Value {
+        let properties: Map<String, Value> = self
+            .columns
+            .iter()
+            .map(|c| (c.def.name.clone(), c.json_schema()))
+            .collect();
+        let required: Vec<&str> = self
+            .columns
+            .iter()
+            .filter(|c| c.required)
+            .map(|c| c.def.name.as_str())
+            .collect();
+
+        let mut schema = json!({
+            "$schema": JSON_SCHEMA_DIALECT,
+            "title": self.model.name,
+            "type": "object",
+            "properties": properties,
+            "required": required,
+            "additionalProperties": false,
+        });
+        insert_some(
+            &mut schema,
+            "description",
+            self.schema.description.as_deref(),
+        );
+        insert_some(&mut schema, "x-owner", self.model.get_owner().as_deref());
+        if self.schema.deprecated {
+            schema["deprecated"] = json!(true);
+        }
+        schema
+    }
+
+    /// Avro record schema for one row of the node
+    fn avro(&self) -> Value {
+        let fields: Vec<Value> = self
+            .columns
+            .iter()
+            .map(|c| c.avro_field(&self.model.name))
+            .collect();
+        let mut record = json!({
+            "type": "record",
+            "name": avro_name(&self.model.name),
+            "namespace": avro_name(&self.project.config.name),
+            "fields": fields,
+        });
+        insert_some(&mut record, "doc", self.schema.description.as_deref());
+        insert_some(&mut record, "owner", self.model.get_owner().as_deref());
+        record
+    }
+
+    /// Open Data Contract Standard document for the node
+    fn odcs(&self) -> Value {
+        let name = self.model.name.as_str();
+        let physical_name = match self
+            .model
+            .target_schema(self.project.config.get_schema(None))
+        {
+            Some(schema) => format!("{schema}.{name}"),
+            None => name.to_string(),
+        };
+        let physical_type = match self
+            .model
+            .materialization(self.project.config.materialization)
+        {
+            Materialization::View | Materialization::Ephemeral => "view",
+            Materialization::Table | Materialization::Incremental => "table",
+        };
+        let properties: Vec<Value> = self.columns.iter().map(ContractColumn::odcs).collect();
+
+        let mut object = json!({
+            "name": name,
+            "physicalName": physical_name,
+            "physicalType": physical_type,
+            "logicalType": "object",
+            "properties": properties,
+        });
+        insert_some(
+            &mut object,
+            "description",
+            self.schema.description.as_deref(),
+        );
+
+        let status = if self.schema.deprecated {
+            "deprecated"
+        } else {
+            "active"
+        };
+        let mut contract = json!({
+            "apiVersion": ODCS_API_VERSION,
+            "kind": "DataContract",
+            "id": format!("{}.{name}", self.project.config.name),
+            "name": name,
+            "version": self.project.config.version,
+            "status": status,
+        });
+        if let Some(description) = &self.schema.description {
+            contract["description"] = json!({ "purpose": description });
+        }
+        if !self.schema.tags.is_empty() {
+            contract["tags"] = json!(self.schema.tags);
+        }
+        if let Some(owner) = self.model.get_owner() {
+            contract["team"] = json!([{ "username": owner, "role": "owner" }]);
+        }
+        contract["schema"] = json!([object]);
+        contract
+    }
+}
+
+impl<'a> ContractColumn<'a> {
+    fn new(def: &'a SchemaColumnDef) -> Self {
+        Self {
+            def,
+            sql_type: parse_sql_type(&def.data_type),
+            required: def.primary_key || has_test(&def.tests, "not_null"),
+            unique: def.primary_key || has_test(&def.tests, "unique"),
+            accepted_values: accepted_values(&def.tests),
+        }
+    }
+
+    fn json_schema(&self) -> Value {
+        let mut schema = json_schema_type(&self.sql_type);
+        if let Some(values) = &self.accepted_values {
+            schema["enum"] = json!(values);
+        }
+        if !self.required {
+            schema = json_nullable(schema);
+        }
+        insert_some(&mut schema, "description", self.def.description.as_deref());
+        if let Some(classification) = self.def.classification {
+            schema["x-classification"] = json!(classification.to_string());
+        }
+        schema
+    }
+
+    /// Avro field; `path` prefixes the names of nested named types
+    fn avro_field(&self, path: &str) -> Value {
+        let path = format!("{path}_{}", self.def.name);
+        let avro_type = match self.avro_enum(&path) {
+            Some(avro_enum) => avro_enum,
+            None => avro_type(&self.sql_type, &path),
+        };
+        let mut field = json!({ "name": self.def.name });
+        if self.required {
+            field["type"] = avro_type;
+        } else {
+            field["type"] = avro_nullable(avro_type);
+            field["default"] = Value::Null;
+        }
+        insert_some(&mut field, "doc", self.def.description.as_deref());
+        if let Some(classification) = self.def.classification {
+            field["classification"] = json!(classification.to_string());
+        }
+        field
+    }
+
+    /// Avro enum for string columns whose accepted values are valid symbols
+    fn avro_enum(&self, path: &str) -> Option<Value> {
+        if !matches!(self.sql_type, SqlType::String { .. }) {
+            return None;
+        }
+        let symbols = self
+            .accepted_values
+            .as_ref()?
+            .iter()
+            .map(|v| v.as_str().filter(|s| avro_name(s) == *s))
+            .collect::<Option<Vec<_>>>()?;
+        Some(json!({
+            "type": "enum",
+            "name": avro_name(&format!("{path}_values")),
+            "symbols": symbols,
+        }))
+    }
+
+    fn odcs(&self) -> Value {
+        let mut property = odcs_property(&self.def.name, &self.sql_type);
+        property["physicalType"] = json!(self.def.data_type);
+        property["required"] = json!(self.required);
+        if self.unique {
+            property["unique"] = json!(true);
+        }
+        if self.def.primary_key {
+            property["primaryKey"] = json!(true);
+        }
+        insert_some(
+            &mut property,
+            "description",
+            self.def.description.as_deref(),
+        );
+        if let Some(classification) = self.def.classification {
+            property["classification"] = json!(classification.to_string());
+        }
+        if let Some(values) = &self.accepted_values {
+            property["quality"] = json!([{ "rule": "validValues", "validValues": values }]);
+        }
+        property
+    }
+}
+
+/// Whether a column declares the named test, with or without parameters
+fn has_test(tests: &[TestDefinition], name: &str) -> bool {
+    tests.iter().any(|test| match test {
+        TestDefinition::Simple(test) => test == name,
+        TestDefinition::Parameterized(map) => map.contains_key(name),
+    })
+}
+
+/// The values of a column's `accepted_values` test, keeping their YAML types
+fn accepted_values(tests: &[TestDefinition]) -> Option<Vec<Value>> {
+    tests.iter().find_map(|test| match test {
+        TestDefinition::Parameterized(map) => map.get("accepted_values").map(|params| {
+            params
+                .values
+                .iter()
+                .filter_map(|v| serde_json::to_value(v).ok())
+                .collect()
+        }),
+        TestDefinition::Simple(_) => None,
+    })
+}
+
+/// Whether a type, or any type nested in it, failed to parse
+fn contains_unknown(sql_type: &SqlType) -> bool {
+    match sql_type {
+        SqlType::Unknown(_) => true,
+        SqlType::Array(inner) => contains_unknown(inner),
+        SqlType::Struct(fields) => fields.iter().any(|(_, t)| contains_unknown(t)),
+        SqlType::Map { key, value } => contains_unknown(key) || contains_unknown(value),
+        _ => false,
+    }
+}
+
+/// Set `key` on a JSON object when the value is present
+fn insert_some(object: &mut Value, key: &str, value: Option<&str>) {
+    if let Some(value) = value {
+        object[key] = json!(value);
+    }
+}
+
+/// JSON Schema for a SQL type; nested values are nullable like their SQL counterparts
+fn json_schema_type(sql_type: &SqlType) -> Value {
+    let string_format = |format: &str| json!({ "type": "string", "format": format });
+    match sql_type {
+        SqlType::Boolean => json!({ "type": "boolean" }),
+        SqlType::Integer { .. } | SqlType::HugeInt => json!({ "type": "integer" }),
+        SqlType::Float { .. } | SqlType::Decimal { .. } => json!({ "type": "number" }),
+        SqlType::String { max_length } => {
+            let mut schema = json!({ "type": "string" });
+            if let Some(max_length) = max_length {
+                schema["maxLength"] = json!(max_length);
+            }
+            schema
+        }
+        SqlType::Date => string_format("date"),
+        SqlType::Time => string_format("time"),
+        SqlType::Timestamp => string_format("date-time"),
+        SqlType::Interval => string_format("duration"),
+        SqlType::Uuid => string_format("uuid"),
+        SqlType::Binary => json!({ "type": "string", "contentEncoding": "base64" }),
+        SqlType::Json | SqlType::Unknown(_) => json!({}),
+        SqlType::Array(inner) => json!({
+            "type": "array",
+            "items": json_nullable(json_schema_type(inner)),
+        }),
+        SqlType::Struct(fields) => {
+            let properties: Map<String, Value> = fields
+                .iter()
+                .map(|(name, t)| (name.clone(), json_nullable(json_schema_type(t))))
+                .collect();
+            let required: Vec<&str> = fields.iter().map(|(name, _)| name.as_str()).collect();
+            json!({
+                "type": "object",
+                "properties": properties,
+                "required": required,
+                "additionalProperties": false,
+            })
+        }
+        SqlType::Map { value, .. } => json!({
+            "type": "object",
+            "additionalProperties": json_nullable(json_schema_type(value)),
+        }),
+    }
+}
+
+/// Allow `null` in a JSON Schema that names a single type
+fn json_nullable(mut schema: Value) -> Value {
+    if let Some(t) = schema.get("type").and_then(Value::as_str) {
+        schema["type"] = json!([t, "null"]);
+    }
+    if let Some(values) = schema.get_mut("enum").and_then(Value::as_array_mut) {
+        values.push(Value::Null);
+    }
+    schema
+}
+
+/// Avro schema for a SQL type; `path` names nested records and fixed types
+fn avro_type(sql_type: &SqlType, path: &str) -> Value {
+    let decimal = |precision: u16, scale: u16| json!({ "type": "bytes", "logicalType": "decimal", "precision": precision, "scale": scale });
+    match sql_type {
+        SqlType::Boolean => json!("boolean"),
+        SqlType::Integer { bits } => match bits {
+            IntBitWidth::I64 => json!("long"),
+            _ => json!("int"),
+        },
+        SqlType::HugeInt => decimal(HUGEINT_PRECISION, 0),
+        SqlType::Float { bits } => match bits {
+            FloatBitWidth::F32 => json!("float"),
+            FloatBitWidth::F64 => json!("double"),
+        },
+        SqlType::Decimal { precision, scale } => decimal(
+            precision.unwrap_or(DEFAULT_DECIMAL.0),
+            scale.unwrap_or(if precision.is_some() {
+                0
+            } else {
+                DEFAULT_DECIMAL.1
+            }),
+        ),
+        SqlType::String { .. } | SqlType::Json | SqlType::Unknown(_) => json!("string"),
+        SqlType::Date => json!({ "type": "int", "logicalType": "date" }),
+        SqlType::Time => json!({ "type": "long", "logicalType": "time-micros" }),
+        SqlType::Timestamp => json!({ "type": "long", "logicalType": "timestamp-micros" }),
+        SqlType::Interval => json!({
+            "type": "fixed",
+            "name": avro_name(&format!("{path}_duration")),
+            "size": 12,
+            "logicalType": "duration",
+        }),
+        SqlType::Binary => json!("bytes"),
+        SqlType::Uuid => json!({ "type": "string", "logicalType": "uuid" }),
+        SqlType::Array(inner) => json!({
+            "type": "array",
+            "items": avro_nullable(avro_type(inner, &format!("{path}_item"))),
+        }),
+        SqlType::Struct(fields) => {
+            let fields: Vec<Value> = fields
+                .iter()
+                .map(|(name, t)| {
+                    json!({
+                        "name": name,
+                        "type": avro_nullable(avro_type(t, &format!("{path}_{name}"))),
+                        "default": null,
+                    })
+                })
+                .collect();
+            json!({ "type": "record", "name": avro_name(path), "fields": fields })
+        }
+        SqlType::Map { value, .. } => json!({
+            "type": "map",
+            "values": avro_nullable(avro_type(value, &format!("{path}_value"))),
+        }),
+    }
+}
+
+/// Union of `null` and an Avro type; `null` first so a `null` default is valid
+fn avro_nullable(avro_type: Value) -> Value {
+    json!(["null", avro_type])
+}
+
+/// Replace characters Avro does not allow in names with underscores
+fn avro_name(name: &str) -> String {
+    let mut sanitized: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if sanitized.starts_with(|c: char| c.is_ascii_digit()) {
+        sanitized.insert(0, '_');
+    }
+    sanitized
+}
+
+/// ODCS property for a SQL type, with nested properties and items
+fn odcs_property(name: &str, sql_type: &SqlType) -> Value {
+    let mut property = json!({ "name": name });
+    let logical_type = match sql_type {
+        SqlType::Boolean => Some("boolean"),
+        SqlType::Integer { .. } | SqlType::HugeInt => Some("integer"),
+        SqlType::Float { .. } | SqlType::Decimal { .. } => Some("number"),
+        SqlType::Date | SqlType::Timestamp => Some("date"),
+        SqlType::String { .. }
+        | SqlType::Time
+        | SqlType::Interval
+        | SqlType::Uuid
+        | SqlType::Binary => Some("string"),
+        SqlType::Array(_) => Some("array"),
+        SqlType::Struct(_) | SqlType::Map { .. } => Some("object"),
+        SqlType::Json | SqlType::Unknown(_) => None,
+    };
+    if let Some(logical_type) = logical_type {
+        property["logicalType"] = json!(logical_type);
+    }
+    match sql_type {
+        SqlType::String {
+            max_length: Some(max_length),
+        } => property["logicalTypeOptions"] = json!({ "maxLength": max_length }),
+        SqlType::Array(inner) => property["items"] = odcs_nested(&format!("{name}_item"), inner),
+        SqlType::Struct(fields) => {
+            property["properties"] = fields
+                .iter()
+                .map(|(field, t)| odcs_nested(field, t))
+                .collect();
+        }
+        _ => {}
+    }
+    property
+}
+
+/// ODCS property for a nested value, which keeps its SQL type as the physical type
+fn odcs_nested(name: &str, sql_type: &SqlType) -> Value {
+    let mut property = odcs_property(name, sql_type);
+    property["physicalType"] = json!(sql_type.to_string());
+    property
+}
+
+#[cfg(test)]
+#[path = "contracts_test.rs"]
+mod tests;
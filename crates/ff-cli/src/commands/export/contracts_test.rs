@@ -0,0 +1,138 @@
+use super::*;
+use crate::commands::test_support::{global, write, write_config};
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+fn write_project(root: &Path) {
+    write_config(root, "shop", "version: 2.1.0\n");
+    write(
+        root,
+        "nodes/customers/customers.sql",
+        "select 1 as customer_id\n",
+    );
+    write(
+        root,
+        "nodes/customers/customers.yml",
+        r#"version: 1
+description: One row per customer
+owner: crm-team
+tags: [public]
+columns:
+  - name: customer_id
+    type: INTEGER
+    primary_key: true
+  - name: email
+    type: VARCHAR(320)
+    classification: pii
+    tests: [not_null]
+  - name: status
+    type: VARCHAR
+    tests:
+      - accepted_values:
+          values: [active, churned]
+  - name: address
+    type: STRUCT(city VARCHAR, zip VARCHAR)
+  - name: attributes
+    type: MAP(VARCHAR, INTEGER)
+  - name: scores
+    type: LIST(DOUBLE)
+"#,
+    );
+    write(
+        root,
+        "nodes/stg_customers/stg_customers.sql",
+        "select 1 as customer_id\n",
+    );
+    write(
+        root,
+        "nodes/stg_customers/stg_customers.yml",
+        "version: 1\ncolumns:\n  - name: customer_id\n    type: INTEGER\n",
+    );
+}
+
+#[test]
+fn test_contract_formats_map_types_and_constraints() {
+    let dir = tempdir().unwrap();
+    write_project(dir.path());
+    let project = load_project(&global(dir.path())).unwrap();
+    let contract = NodeContract::new(&project, project.get_model("customers").unwrap()).unwrap();
+
+    let schema = contract.json_schema();
+    assert_eq!(schema["x-owner"], "crm-team");
+    assert_eq!(schema["required"], json!(["customer_id", "email"]));
+    let properties = &schema["properties"];
+    assert_eq!(properties["customer_id"]["type"], "integer");
+    assert_eq!(
+        properties["email"],
+        json!({"type": "string", "maxLength": 320, "x-classification": "pii"})
+    );
+    assert_eq!(
+        properties["status"]["enum"],
+        json!(["active", "churned", null])
+    );
+    assert_eq!(
+        properties["address"]["properties"]["city"]["type"],
+        json!(["string", "null"])
+    );
+    assert_eq!(
+        properties["attributes"]["additionalProperties"]["type"],
+        json!(["integer", "null"])
+    );
+    assert_eq!(properties["scores"]["type"], json!(["array", "null"]));
+
+    let avro = contract.avro();
+    assert_eq!(avro["namespace"], "shop");
+    assert_eq!(avro["owner"], "crm-team");
+    let fields = avro["fields"].as_array().unwrap();
+    assert_eq!(fields[0]["type"], "int");
+    assert_eq!(fields[1]["classification"], "pii");
+    assert_eq!(
+        fields[2]["type"][1],
+        json!({"type": "enum", "name": "customers_status_values", "symbols": ["active", "churned"]})
+    );
+    assert_eq!(fields[2]["default"], Value::Null);
+    assert_eq!(fields[3]["type"][1]["name"], "customers_address");
+    assert_eq!(fields[4]["type"][1]["values"], json!(["null", "int"]));
+    assert_eq!(fields[5]["type"][1]["items"], json!(["null", "double"]));
+
+    let odcs = contract.odcs();
+    assert_eq!(odcs["id"], "shop.customers");
+    assert_eq!(odcs["version"], "2.1.0");
+    assert_eq!(
+        odcs["team"],
+        json!([{"username": "crm-team", "role": "owner"}])
+    );
+    let properties = &odcs["schema"][0]["properties"];
+    assert_eq!(properties[0]["primaryKey"], true);
+    assert_eq!(properties[0]["unique"], true);
+    assert_eq!(properties[1]["required"], true);
+    assert_eq!(properties[1]["classification"], "pii");
+    assert_eq!(
+        properties[2]["quality"][0]["validValues"],
+        json!(["active", "churned"])
+    );
+    assert_eq!(properties[3]["logicalType"], "object");
+    assert_eq!(properties[3]["properties"][1]["name"], "zip");
+    assert_eq!(properties[5]["items"]["logicalType"], "number");
+}
+
+#[test]
+fn test_export_writes_selected_nodes() {
+    let dir = tempdir().unwrap();
+    write_project(dir.path());
+    let out = dir.path().join("contracts");
+    let args = ExportContractsArgs {
+        nodes: Some("tag:public".to_string()),
+        format: ContractFormat::Odcs,
+        output: Some(out.clone()),
+    };
+
+    execute(&args, &global(dir.path())).unwrap();
+
+    let odcs: serde_yaml::Value =
+        serde_yaml::from_str(&fs::read_to_string(out.join("customers.odcs.yaml")).unwrap())
+            .unwrap();
+    assert_eq!(odcs["kind"].as_str(), Some("DataContract"));
+    assert!(!out.join("stg_customers.odcs.yaml").exists());
+}
@@ -0,0 +1,1039 @@
+//! `ff dt export dbt-artifacts` — dbt-compatible `manifest.json` and `catalog.json`
+//!
+//! The project is compiled first, so nodes are mapped from the [`Manifest`]
+//! of the compiled project and catalog column types are read back from the
+//! `ff_meta.model_columns` rows the compile recorded. When the project does
+//! not compile cleanly, models fall back to their declared YAML types.
+//!
+//! dbt models are SQL or Python, so `kind: command` nodes are exported as
+//! sources of the [`COMMAND_SOURCE_NAME`] group: tables loaded outside dbt.
+
+use anyhow::{Context, Result};
+use ff_core::config::{IncrementalStrategy, Materialization};
+use ff_core::model::{Model, SchemaTest, TestConfig, TestSeverity, TestType};
+use ff_core::sql_utils::quote_qualified;
+use ff_core::Project;
+use ff_db::{DatabaseCsv, DuckDbBackend};
+use ff_meta::manifest::{Manifest, ManifestModel, ManifestSource, ManifestSourceColumn};
+use ff_sql::SqlParser;
+use ff_test::GeneratedTest;
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+
+use crate::cli::{CompileArgs, ExportDbtArtifactsArgs, GlobalArgs, OutputFormat};
+use crate::commands::common::{self, load_project};
+use crate::commands::compile::{self, compute_compiled_path};
+
+/// Schema of the `manifest.json` written
+const MANIFEST_SCHEMA_VERSION: &str = "https://schemas.getdbt.com/dbt/manifest/v12.json";
+
+/// Schema of the `catalog.json` written
+const CATALOG_SCHEMA_VERSION: &str = "https://schemas.getdbt.com/dbt/catalog/v1.json";
+
+/// dbt release whose artifact schemas the export follows; tools gate on it
+const DBT_VERSION: &str = "1.8.0";
+
+/// dbt source group that command nodes are exported under
+const COMMAND_SOURCE_NAME: &str = "commands";
+
+/// Directory under `target/` the artifacts go to without `--output`
+const DEFAULT_OUTPUT_DIR: &str = "dbt";
+
+/// Column names and types per relation, in column order
+type ColumnTypes = HashMap<String, Vec<(String, String)>>;
+
+/// Execute the export dbt-artifacts subcommand
+pub(super) async fn execute(args: &ExportDbtArtifactsArgs, global: &GlobalArgs) -> Result<()> {
+    let mut project = load_project(global)?;
+
+    let summary = compile::compile_project(&mut project, &compile_args(), global).await?;
+    let model_types = if summary.failure_count == 0 {
+        compiled_column_types(&project)
+    } else {
+        eprintln!(
+            "Warning: {} models failed to compile; exporting declared column types",
+            summary.failure_count
+        );
+        None
+    }
+    .unwrap_or_else(|| declared_column_types(&project));
+    let seed_types = seed_column_types(&project).await?;
+    let singular_tests = render_singular_tests(&project, global)?;
+    let manifest = build_manifest(&project)?;
+
+    let export = DbtExport::new(&project, &manifest, uuid::Uuid::new_v4().to_string());
+    let dbt_manifest = export.dbt_manifest(&singular_tests);
+    let catalog = export.catalog(&model_types, &seed_types);
+
+    let output_dir = args
+        .output
+        .clone()
+        .unwrap_or_else(|| project.target_dir().join(DEFAULT_OUTPUT_DIR));
+    std::fs::create_dir_all(&output_dir)
+        .with_context(|| format!("Failed to create {}", output_dir.display()))?;
+    for (file, artifact) in [("manifest.json", &dbt_manifest), ("catalog.json", &catalog)] {
+        let path = output_dir.join(file);
+        std::fs::write(&path, serde_json::to_string_pretty(artifact)?)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+    }
+
+    println!(
+        "Exported {} nodes and {} sources to {}",
+        dbt_manifest["nodes"].as_object().map_or(0, Map::len),
+        dbt_manifest["sources"].as_object().map_or(0, Map::len),
+        output_dir.display()
+    );
+    Ok(())
+}
+
+/// Arguments for compiling the whole project before the export.
+///
+/// JSON output keeps compile quiet; models that fail are exported without compiled code.
+fn compile_args() -> CompileArgs {
+    CompileArgs {
+        nodes: None,
+        output_dir: None,
+        vars: None,
+        parse_only: false,
+        skip_static_analysis: false,
+        explain: None,
+        output: OutputFormat::Json,
+        strict: false,
+        governance: false,
+        fix: false,
+    }
+}
+
+/// Output columns of every model as compile recorded them in the meta database
+fn compiled_column_types(project: &Project) -> Option<ColumnTypes> {
+    let meta_db = common::open_meta_db(project)?;
+    match ff_meta::query::model_column_types(meta_db.conn(), &project.config.name) {
+        Ok(types) => Some(types),
+        Err(e) => {
+            eprintln!("Warning: exporting declared column types: {e}");
+            None
+        }
+    }
+}
+
+/// Declared YAML columns of every model
+fn declared_column_types(project: &Project) -> ColumnTypes {
+    project
+        .models
+        .iter()
+        .map(|(name, model)| {
+            let columns = model
+                .schema
+                .iter()
+                .flat_map(|s| &s.columns)
+                .map(|c| (c.name.clone(), c.data_type.clone()))
+                .collect();
+            (name.to_string(), columns)
+        })
+        .collect()
+}
+
+/// Render every singular test: test name → (rendered SQL, tables it reads).
+///
+/// Tests that fail to render are exported without compiled code.
+fn render_singular_tests(
+    project: &Project,
+    global: &GlobalArgs,
+) -> Result<HashMap<String, (String, Vec<String>)>> {
+    let parser = SqlParser::from_dialect_name(&project.config.dialect.to_string())
+        .context("Invalid SQL dialect")?;
+    let jinja = common::build_jinja_env_with_context(
+        project,
+        global,
+        false,
+        &common::build_node_catalog(project),
+    );
+
+    let mut tests = HashMap::with_capacity(project.singular_tests.len());
+    for test in &project.singular_tests {
+        let Ok(rendered) = jinja.render(&test.sql) else {
+            continue;
+        };
+        let tables = parser
+            .parse(&rendered)
+            .map(|statements| {
+                ff_sql::extract_dependencies(&statements)
+                    .into_iter()
+                    .collect()
+            })
+            .unwrap_or_default();
+        tests.insert(test.name.to_string(), (rendered, tables));
+    }
+    Ok(tests)
+}
+
+/// Columns of every enabled seed as DuckDB infers them from the CSV, with the
+/// seed's `column_types` overrides applied.
+async fn seed_column_types(project: &Project) -> Result<ColumnTypes> {
+    let db = DuckDbBackend::in_memory().context("Failed to open in-memory DuckDB")?;
+    let mut types = ColumnTypes::with_capacity(project.seeds.len());
+    for seed in project.seeds.iter().filter(|s| s.enabled) {
+        let columns = match db.infer_csv_schema(&seed.path.display().to_string()).await {
+            Ok(columns) => columns,
+            Err(e) => {
+                eprintln!(
+                    "Warning: exporting seed '{}' without columns: {e}",
+                    seed.name
+                );
+                continue;
+            }
+        };
+        let overrides = seed.column_types();
+        let columns = columns
+            .into_iter()
+            .map(|(name, data_type)| {
+                let data_type = overrides.get(&name).cloned().unwrap_or(data_type);
+                (name, data_type)
+            })
+            .collect();
+        types.insert(seed.name.to_string(), columns);
+    }
+    Ok(types)
+}
+
+/// The project's [`Manifest`], built after compile resolved dependencies.
+fn build_manifest(project: &Project) -> Result<Manifest> {
+    let mut manifest = Manifest::new(&project.config.name);
+    let compiled_dir = project.compiled_dir();
+    for model in project.models.values() {
+        let compiled_path = compute_compiled_path(&model.path, &project.root, &compiled_dir)?;
+        manifest.add_model_relative(
+            model,
+            &compiled_path,
+            &project.root,
+            project.config.materialization,
+            project.config.get_schema(None),
+        );
+    }
+    for source in &project.sources {
+        manifest.add_source(source);
+    }
+    Ok(manifest)
+}
+
+/// Maps project resources onto dbt unique ids and artifact entries
+struct DbtExport<'a> {
+    project: &'a Project,
+    manifest: &'a Manifest,
+    invocation_id: String,
+    /// Seconds since the epoch, dbt's `created_at`
+    created_at: f64,
+    /// Lowercased model and seed names → unique id
+    nodes: HashMap<String, String>,
+    /// Command nodes as sources, keyed like manifest sources
+    command_sources: HashMap<String, ManifestSource>,
+    /// Lowercased table names a query can use for a source → source key
+    sources: HashMap<String, String>,
+}
+
+impl<'a> DbtExport<'a> {
+    fn new(project: &'a Project, manifest: &'a Manifest, invocation_id: String) -> Self {
+        let name = &project.config.name;
+        let command_sources: HashMap<String, ManifestSource> = manifest
+            .models
+            .values()
+            .filter_map(|m| {
+                let model = project
+                    .get_model(&m.name)
+                    .filter(|model| model.is_command())?;
+                let key = format!("{COMMAND_SOURCE_NAME}.{}", m.name);
+                Some((key, command_source(m, model)))
+            })
+            .collect();
+        let nodes = project
+            .models
+            .iter()
+            .filter(|(_, model)| !model.is_command())
+            .map(|(m, _)| (m.to_lowercase(), format!("model.{name}.{m}")))
+            .chain(
+                project
+                    .seeds
+                    .iter()
+                    .map(|s| (s.name.to_lowercase(), format!("seed.{name}.{}", s.name))),
+            )
+            .collect();
+
+        let mut sources = HashMap::new();
+        for (key, source) in manifest.sources.iter().chain(&command_sources) {
+            let mut names = vec![source.name.clone()];
+            names.extend(source.identifier.clone());
+            for table in names {
+                sources.insert(
+                    format!("{}.{}", source.schema, table).to_lowercase(),
+                    key.clone(),
+                );
+                sources.insert(table.to_lowercase(), key.clone());
+            }
+        }
+
+        Self {
+            project,
+            manifest,
+            invocation_id,
+            created_at: chrono::Utc::now().timestamp() as f64,
+            nodes,
+            command_sources,
+            sources,
+        }
+    }
+
+    /// Declared sources followed by command nodes, keyed by source key
+    fn all_sources(&self) -> impl Iterator<Item = (&String, &ManifestSource)> {
+        self.manifest.sources.iter().chain(&self.command_sources)
+    }
+
+    /// The source key and entry a lowercased table name refers to
+    fn source_for(&self, table: &str) -> Option<(&String, &ManifestSource)> {
+        let key = self.sources.get(table)?;
+        let source = self
+            .manifest
+            .sources
+            .get(key)
+            .or_else(|| self.command_sources.get(key))?;
+        Some((key, source))
+    }
+
+    fn project_name(&self) -> &str {
+        &self.project.config.name
+    }
+
+    fn source_id(&self, key: &str) -> String {
+        format!("source.{}.{}", self.project_name(), key)
+    }
+
+    fn test_id(&self, name: &str) -> String {
+        format!("test.{}.{}", self.project_name(), name)
+    }
+
+    /// Unique id of whatever a table name read by a query refers to
+    fn relation_id(&self, table: &str) -> Option<String> {
+        let table = table.to_lowercase();
+        self.nodes
+            .get(&table)
+            .cloned()
+            .or_else(|| self.sources.get(&table).map(|key| self.source_id(key)))
+    }
+
+    fn metadata(&self, schema_version: &str) -> Value {
+        json!({
+            "dbt_schema_version": schema_version,
+            "dbt_version": DBT_VERSION,
+            "generated_at": self.manifest.compiled_at,
+            "invocation_id": self.invocation_id,
+            "env": {},
+        })
+    }
+
+    /// The `manifest.json` document
+    fn dbt_manifest(&self, singular_tests: &HashMap<String, (String, Vec<String>)>) -> Value {
+        let mut nodes = Map::new();
+        for manifest_model in self.manifest.models.values() {
+            let model = self.project.get_model(&manifest_model.name);
+            if let Some(model) = model.filter(|m| !m.is_command()) {
+                let (id, node) = self.model_node(manifest_model, model);
+                nodes.insert(id, node);
+            }
+        }
+        for seed in &self.project.seeds {
+            let (id, node) = self.seed_node(seed);
+            nodes.insert(id, node);
+        }
+        for test in &self.project.tests {
+            let (id, node) = self.schema_test_node(test);
+            nodes.insert(id, node);
+        }
+        for (key, source) in &self.manifest.sources {
+            for (id, node) in self.source_test_nodes(key, source) {
+                nodes.insert(id, node);
+            }
+        }
+        for test in &self.project.singular_tests {
+            let (sql, tables) = singular_tests
+                .get(test.name.as_str())
+                .map(|(sql, tables)| (Some(sql.as_str()), tables.as_slice()))
+                .unwrap_or_default();
+            let (id, node) = self.singular_test_node(test, sql, tables);
+            nodes.insert(id, node);
+        }
+
+        let sources: Map<String, Value> = self
+            .all_sources()
+            .map(|(key, source)| (self.source_id(key), self.source_node(key, source)))
+            .collect();
+
+        let mut metadata = self.metadata(MANIFEST_SCHEMA_VERSION);
+        metadata["project_name"] = json!(self.project_name());
+        metadata["adapter_type"] = json!(self.project.config.dialect.to_string());
+
+        let (parent_map, child_map) = lineage_maps(&nodes, &sources);
+        json!({
+            "metadata": metadata,
+            "nodes": nodes,
+            "sources": sources,
+            "macros": {},
+            "docs": {},
+            "exposures": {},
+            "metrics": {},
+            "groups": {},
+            "selectors": {},
+            "disabled": {},
+            "parent_map": parent_map,
+            "child_map": child_map,
+            "group_map": {},
+            "saved_queries": {},
+            "semantic_models": {},
+            "unit_tests": {},
+        })
+    }
+
+    fn model_node(&self, m: &ManifestModel, model: &Model) -> (String, Value) {
+        let id = format!("model.{}.{}", self.project_name(), m.name);
+        let schema = model.schema.as_ref();
+        let meta = schema
+            .and_then(|s| serde_json::to_value(&s.meta).ok())
+            .unwrap_or_else(|| json!({}));
+
+        let mut depends_on: BTreeSet<String> = m
+            .depends_on
+            .iter()
+            .filter_map(|d| self.relation_id(d))
+            .collect();
+        let mut sources = BTreeSet::new();
+        let source_tables = m
+            .external_deps
+            .iter()
+            .map(|t| t.as_str())
+            .chain(m.depends_on.iter().map(|d| d.as_str()));
+        for table in source_tables {
+            let table = table.to_lowercase();
+            if self.nodes.contains_key(&table) {
+                continue;
+            }
+            let Some((key, source)) = self.source_for(&table) else {
+                continue;
+            };
+            depends_on.insert(self.source_id(key));
+            sources.insert((source.source_name.clone(), source.name.clone()));
+        }
+        let refs: Vec<Value> = m
+            .depends_on
+            .iter()
+            .filter(|d| self.nodes.contains_key(&d.to_lowercase()))
+            .map(|d| json!({"name": d, "package": null, "version": null}))
+            .collect();
+
+        let columns: Map<String, Value> = m
+            .columns
+            .iter()
+            .map(|c| {
+                let column = column_entry(&c.name, c.description.as_deref(), &c.data_type);
+                (c.name.clone(), column)
+            })
+            .collect();
+
+        let relation_name = (m.materialized != Materialization::Ephemeral)
+            .then(|| relation_name(None, m.schema.as_deref(), &m.name));
+        let patch_path = common::model_yaml_path(&model.path)
+            .map(|p| format!("{}://{}", self.project_name(), self.relative(&p)));
+        let language = if model.is_python() { "python" } else { "sql" };
+
+        let node = json!({
+            "unique_id": id,
+            "resource_type": "model",
+            "package_name": self.project_name(),
+            "name": m.name,
+            "path": resource_path(&m.source_path),
+            "original_file_path": m.source_path,
+            "patch_path": patch_path,
+            "fqn": fqn(self.project_name(), &m.source_path, &m.name),
+            "database": null,
+            "schema": m.schema,
+            "alias": m.name,
+            "relation_name": relation_name,
+            "checksum": {"name": "sha256", "checksum": m.sql_checksum},
+            "language": language,
+            "raw_code": model.raw_sql,
+            "compiled": model.compiled_sql.is_some(),
+            "compiled_code": model.compiled_sql,
+            "compiled_path": m.compiled_path,
+            "config": {
+                "enabled": true,
+                "materialized": m.materialized.to_string(),
+                "schema": m.schema,
+                "tags": m.tags,
+                "meta": meta,
+                "unique_key": m.unique_key.as_deref().map(unique_key),
+                "incremental_strategy": m.incremental_strategy.map(incremental_strategy),
+                "on_schema_change": m.on_schema_change,
+                "pre-hook": hooks(&m.pre_hook),
+                "post-hook": hooks(&m.post_hook),
+                "persist_docs": {},
+                "contract": {"enforced": false, "alias_types": true},
+            },
+            "unrendered_config": {},
+            "tags": m.tags,
+            "description": schema.and_then(|s| s.description.as_deref()).unwrap_or_default(),
+            "columns": columns,
+            "meta": meta,
+            "docs": {"show": true, "node_color": null},
+            "depends_on": {"macros": [], "nodes": depends_on},
+            "refs": refs,
+            "sources": sources.into_iter().map(|(s, t)| [s, t]).collect::<Vec<_>>(),
+            "metrics": [],
+            "access": "protected",
+            "contract": {"enforced": false, "alias_types": true},
+            "constraints": [],
+            "version": model.version,
+            "latest_version": null,
+            "deprecation_date": null,
+            "created_at": self.created_at,
+        });
+        (id, node)
+    }
+
+    fn seed_node(&self, seed: &ff_core::Seed) -> (String, Value) {
+        let id = format!("seed.{}.{}", self.project_name(), seed.name);
+        let original_file_path = self.relative(&seed.path);
+        let checksum = std::fs::read_to_string(&seed.path)
+            .map(|content| ff_core::compute_checksum(&content))
+            .unwrap_or_default();
+        let schema = seed
+            .schema
+            .as_deref()
+            .or(self.project.config.get_schema(None));
+
+        let node = json!({
+            "unique_id": id,
+            "resource_type": "seed",
+            "package_name": self.project_name(),
+            "name": seed.name,
+            "path": resource_path(&original_file_path),
+            "original_file_path": original_file_path,
+            "root_path": self.project.root.display().to_string(),
+            "patch_path": null,
+            "fqn": fqn(self.project_name(), &original_file_path, &seed.name),
+            "database": null,
+            "schema": schema,
+            "alias": seed.name,
+            "relation_name": relation_name(None, schema, &seed.name),
+            "checksum": {"name": "sha256", "checksum": checksum},
+            "raw_code": "",
+            "config": {
+                "enabled": seed.enabled,
+                "materialized": "seed",
+                "schema": schema,
+                "tags": [],
+                "meta": {},
+                "quote_columns": seed.quote_columns,
+                "column_types": seed.column_types,
+                "delimiter": seed.delimiter.to_string(),
+            },
+            "unrendered_config": {},
+            "tags": [],
+            "description": seed.description.as_deref().unwrap_or_default(),
+            "columns": {},
+            "meta": {},
+            "docs": {"show": true, "node_color": null},
+            "depends_on": {"macros": []},
+            "created_at": self.created_at,
+        });
+        (id, node)
+    }
+
+    /// A generic test declared on a model column
+    fn schema_test_node(&self, test: &SchemaTest) -> (String, Value) {
+        let generated = GeneratedTest::from_schema_test(test);
+        let model_id = self.relation_id(&test.model);
+        let mut depends_on: BTreeSet<String> = model_id.iter().cloned().collect();
+
+        let mut kwargs = json!({
+            "column_name": test.column,
+            "model": format!("{{{{ get_where_subquery(ref('{}')) }}}}", test.model),
+        });
+        match &test.test_type {
+            TestType::AcceptedValues { values, quote } => {
+                kwargs["values"] = json!(values);
+                kwargs["quote"] = json!(quote);
+            }
+            TestType::MinValue { value } | TestType::MaxValue { value } => {
+                kwargs["value"] = json!(value);
+            }
+            TestType::Regex { pattern } => kwargs["pattern"] = json!(pattern),
+            TestType::Relationship { to, field } => {
+                kwargs["to"] = json!(format!("ref('{to}')"));
+                kwargs["field"] = json!(field.as_deref().unwrap_or(&test.column));
+                depends_on.extend(self.relation_id(to));
+            }
+            TestType::Custom { kwargs: extra, .. } => {
+                for (key, value) in extra {
+                    kwargs[key.as_str()] = value.clone();
+                }
+            }
+            TestType::Unique | TestType::NotNull | TestType::Positive | TestType::NonNegative => {}
+        }
+
+        let yaml_path = self
+            .project
+            .get_model(&test.model)
+            .and_then(|m| common::model_yaml_path(&m.path))
+            .map(|p| self.relative(&p))
+            .unwrap_or_default();
+        let node = self.test_node(
+            &generated.name,
+            &yaml_path,
+            json!({
+                "test_metadata": {
+                    "name": test_metadata_name(&test.test_type),
+                    "kwargs": kwargs,
+                    "namespace": null,
+                },
+                "column_name": test.column,
+                "attached_node": model_id,
+                "raw_code": generic_test_code(&test.test_type.to_string()),
+                "compiled_code": generated.sql,
+                "config": test_config(&test.config),
+                "depends_on": {"macros": [], "nodes": depends_on},
+            }),
+        );
+        (self.test_id(&generated.name), node)
+    }
+
+    /// The generic tests declared on the columns of one source table
+    fn source_test_nodes(&self, key: &str, source: &ManifestSource) -> Vec<(String, Value)> {
+        let mut nodes = Vec::new();
+        for column in &source.columns {
+            for test in &column.tests {
+                let name = format!(
+                    "source_{}_{}_{}__{}",
+                    test, source.source_name, source.name, column.name
+                );
+                let node = self.test_node(
+                    &name,
+                    "",
+                    json!({
+                        "test_metadata": {
+                            "name": test,
+                            "kwargs": {
+                                "column_name": column.name,
+                                "model": format!(
+                                    "{{{{ get_where_subquery(source('{}', '{}')) }}}}",
+                                    source.source_name, source.name
+                                ),
+                            },
+                            "namespace": null,
+                        },
+                        "column_name": column.name,
+                        "attached_node": null,
+                        "raw_code": generic_test_code(test),
+                        "compiled_code": null,
+                        "config": test_config(&TestConfig::default()),
+                        "depends_on": {"macros": [], "nodes": [self.source_id(key)]},
+                    }),
+                );
+                nodes.push((self.test_id(&name), node));
+            }
+        }
+        nodes
+    }
+
+    fn singular_test_node(
+        &self,
+        test: &ff_core::model::SingularTest,
+        compiled: Option<&str>,
+        tables: &[String],
+    ) -> (String, Value) {
+        let depends_on: BTreeSet<String> =
+            tables.iter().filter_map(|t| self.relation_id(t)).collect();
+        let node = self.test_node(
+            &test.name,
+            &self.relative(&test.path),
+            json!({
+                "column_name": null,
+                "attached_node": null,
+                "raw_code": test.sql,
+                "compiled_code": compiled,
+                "config": test_config(&TestConfig::default()),
+                "depends_on": {"macros": [], "nodes": depends_on},
+            }),
+        );
+        (self.test_id(&test.name), node)
+    }
+
+    /// Fields every test node has, merged with the test-specific `fields`
+    fn test_node(&self, name: &str, original_file_path: &str, fields: Value) -> Value {
+        let mut node = json!({
+            "unique_id": self.test_id(name),
+            "resource_type": "test",
+            "package_name": self.project_name(),
+            "name": name,
+            "path": resource_path(original_file_path),
+            "original_file_path": original_file_path,
+            "fqn": [self.project_name(), name],
+            "database": null,
+            "schema": self.project.config.get_schema(None),
+            "alias": name,
+            "relation_name": null,
+            "checksum": {"name": "none", "checksum": ""},
+            "language": "sql",
+            "compiled": true,
+            "unrendered_config": {},
+            "tags": [],
+            "description": "",
+            "columns": {},
+            "meta": {},
+            "docs": {"show": true, "node_color": null},
+            "refs": [],
+            "sources": [],
+            "metrics": [],
+            "created_at": self.created_at,
+        });
+        if let (Some(node), Value::Object(fields)) = (node.as_object_mut(), fields) {
+            node.extend(fields);
+        }
+        node
+    }
+
+    fn source_node(&self, key: &str, source: &ManifestSource) -> Value {
+        let source_file = self
+            .project
+            .sources
+            .iter()
+            .find(|s| s.name.as_str() == source.source_name);
+        let identifier = source.identifier.as_deref().unwrap_or(&source.name);
+        let columns: Map<String, Value> = source
+            .columns
+            .iter()
+            .map(|c| {
+                let column = column_entry(&c.name, c.description.as_deref(), &c.data_type);
+                (c.name.clone(), column)
+            })
+            .collect();
+        let tags = source_file.map(|s| s.tags.clone()).unwrap_or_default();
+
+        json!({
+            "unique_id": self.source_id(key),
+            "resource_type": "source",
+            "package_name": self.project_name(),
+            "source_name": source.source_name,
+            "name": source.name,
+            "identifier": identifier,
+            "path": "",
+            "original_file_path": "",
+            "fqn": [self.project_name(), &source.source_name, &source.name],
+            "database": source.database,
+            "schema": source.schema,
+            "relation_name": relation_name(source.database.as_deref(), Some(source.schema.as_str()), identifier),
+            "source_description": source_file
+                .and_then(|s| s.description.as_deref())
+                .unwrap_or_default(),
+            "description": source.description.as_deref().unwrap_or_default(),
+            "loader": "",
+            "loaded_at_field": null,
+            "freshness": null,
+            "external": null,
+            "quoting": {},
+            "columns": columns,
+            "meta": {},
+            "source_meta": {},
+            "tags": tags,
+            "config": {"enabled": true},
+            "unrendered_config": {},
+            "created_at": self.created_at,
+        })
+    }
+
+    /// The `catalog.json` document
+    fn catalog(&self, model_types: &ColumnTypes, seed_types: &ColumnTypes) -> Value {
+        let mut nodes = Map::new();
+        for m in self.manifest.models.values() {
+            let model = self.project.get_model(&m.name);
+            if m.materialized == Materialization::Ephemeral || model.is_some_and(Model::is_command)
+            {
+                continue;
+            }
+            let descriptions: HashMap<&str, &str> = m
+                .columns
+                .iter()
+                .filter_map(|c| Some((c.name.as_str(), c.description.as_deref()?)))
+                .collect();
+            let kind = if m.materialized == Materialization::View {
+                "VIEW"
+            } else {
+                "BASE TABLE"
+            };
+            let id = format!("model.{}.{}", self.project_name(), m.name);
+            let table = catalog_table(
+                &id,
+                json!({
+                    "type": kind,
+                    "database": null,
+                    "schema": m.schema,
+                    "name": m.name,
+                    "comment": model
+                        .and_then(|m| m.schema.as_ref())
+                        .and_then(|s| s.description.as_deref()),
+                    "owner": model.and_then(Model::get_owner),
+                }),
+                model_types.get(m.name.as_str()),
+                &descriptions,
+            );
+            nodes.insert(id, table);
+        }
+        for seed in &self.project.seeds {
+            let id = format!("seed.{}.{}", self.project_name(), seed.name);
+            let table = catalog_table(
+                &id,
+                json!({
+                    "type": "BASE TABLE",
+                    "database": null,
+                    "schema": seed.schema.as_deref().or(self.project.config.get_schema(None)),
+                    "name": seed.name,
+                    "comment": seed.description,
+                    "owner": null,
+                }),
+                seed_types.get(seed.name.as_str()),
+                &HashMap::new(),
+            );
+            nodes.insert(id, table);
+        }
+
+        let mut sources = Map::new();
+        for (key, source) in self.all_sources() {
+            let id = self.source_id(key);
+            let columns: Vec<(String, String)> = source
+                .columns
+                .iter()
+                .map(|c| (c.name.clone(), c.data_type.clone()))
+                .collect();
+            let descriptions: HashMap<&str, &str> = source
+                .columns
+                .iter()
+                .filter_map(|c| Some((c.name.as_str(), c.description.as_deref()?)))
+                .collect();
+            let table = catalog_table(
+                &id,
+                json!({
+                    "type": "BASE TABLE",
+                    "database": source.database,
+                    "schema": source.schema,
+                    "name": source.identifier.as_deref().unwrap_or(&source.name),
+                    "comment": source.description,
+                    "owner": null,
+                }),
+                Some(&columns),
+                &descriptions,
+            );
+            sources.insert(id, table);
+        }
+
+        json!({
+            "metadata": self.metadata(CATALOG_SCHEMA_VERSION),
+            "nodes": nodes,
+            "sources": sources,
+            "errors": null,
+        })
+    }
+
+    /// A path relative to the project root
+    fn relative(&self, path: &Path) -> String {
+        path.strip_prefix(&self.project.root)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    }
+}
+
+/// A command node as a dbt source: its table is produced outside dbt
+fn command_source(m: &ManifestModel, model: &Model) -> ManifestSource {
+    ManifestSource {
+        name: m.name.to_string(),
+        source_name: COMMAND_SOURCE_NAME.to_string(),
+        schema: m.schema.clone().unwrap_or_else(|| "main".to_string()),
+        database: None,
+        identifier: None,
+        description: model.schema.as_ref().and_then(|s| s.description.clone()),
+        columns: m
+            .columns
+            .iter()
+            .map(|c| ManifestSourceColumn {
+                name: c.name.clone(),
+                data_type: c.data_type.clone(),
+                description: c.description.clone(),
+                tests: Vec::new(),
+            })
+            .collect(),
+    }
+}
+
+/// Every node's and source's parents and children, keyed by unique id
+fn lineage_maps(nodes: &Map<String, Value>, sources: &Map<String, Value>) -> (Value, Value) {
+    let mut parents: Map<String, Value> = Map::new();
+    let mut children: HashMap<&str, BTreeSet<&str>> = HashMap::new();
+    for id in nodes.keys().chain(sources.keys()) {
+        children.entry(id).or_default();
+    }
+    for (id, node) in nodes {
+        let node_parents: Vec<&str> = node["depends_on"]["nodes"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect();
+        for parent in &node_parents {
+            children.entry(parent).or_default().insert(id);
+        }
+        parents.insert(id.clone(), json!(node_parents));
+    }
+    for id in sources.keys() {
+        parents.insert(id.clone(), json!([]));
+    }
+    let children: Map<String, Value> = children
+        .into_iter()
+        .map(|(id, ids)| (id.to_string(), json!(ids)))
+        .collect();
+    (Value::Object(parents), Value::Object(children))
+}
+
+/// A catalog entry with columns numbered in order
+fn catalog_table(
+    unique_id: &str,
+    metadata: Value,
+    columns: Option<&Vec<(String, String)>>,
+    descriptions: &HashMap<&str, &str>,
+) -> Value {
+    let columns: Map<String, Value> = columns
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .map(|(i, (name, data_type))| {
+            let column = json!({
+                "type": data_type,
+                "index": i + 1,
+                "name": name,
+                "comment": descriptions.get(name.as_str()),
+            });
+            (name.clone(), column)
+        })
+        .collect();
+    json!({
+        "metadata": metadata,
+        "columns": columns,
+        "stats": {
+            "has_stats": {
+                "id": "has_stats",
+                "label": "Has Stats?",
+                "value": false,
+                "include": false,
+                "description": "Indicates whether there are statistics for this table",
+            },
+        },
+        "unique_id": unique_id,
+    })
+}
+
+fn column_entry(name: &str, description: Option<&str>, data_type: &str) -> Value {
+    json!({
+        "name": name,
+        "description": description.unwrap_or_default(),
+        "data_type": (!data_type.is_empty()).then_some(data_type),
+        "meta": {},
+        "constraints": [],
+        "quote": null,
+        "tags": [],
+    })
+}
+
+fn hooks(sqls: &[String]) -> Vec<Value> {
+    sqls.iter()
+        .map(|sql| json!({"sql": sql, "transaction": true}))
+        .collect()
+}
+
+fn test_config(config: &TestConfig) -> Value {
+    let severity = match config.severity {
+        TestSeverity::Error => "ERROR",
+        TestSeverity::Warn => "WARN",
+    };
+    json!({
+        "enabled": true,
+        "materialized": "test",
+        "severity": severity,
+        "where": config.where_clause,
+        "limit": config.limit,
+        "fail_calc": "count(*)",
+        "warn_if": config.warn_if.as_deref().unwrap_or("!= 0"),
+        "error_if": config.error_if.as_deref().unwrap_or("!= 0"),
+        "store_failures": null,
+        "tags": [],
+        "meta": {},
+    })
+}
+
+/// dbt's name for a built-in test; custom tests keep the macro name
+fn test_metadata_name(test_type: &TestType) -> String {
+    match test_type {
+        TestType::Relationship { .. } => "relationships".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// The raw code dbt records for a generic test instance
+fn generic_test_code(test: &str) -> String {
+    format!("{{{{ test_{test}(**_dbt_generic_test_kwargs) }}}}")
+}
+
+/// dbt takes a single unique key as a string and several as a list
+fn unique_key(keys: &[String]) -> Value {
+    match keys {
+        [key] => json!(key),
+        keys => json!(keys),
+    }
+}
+
+fn incremental_strategy(strategy: IncrementalStrategy) -> &'static str {
+    match strategy {
+        IncrementalStrategy::Append => "append",
+        IncrementalStrategy::Merge => "merge",
+        IncrementalStrategy::DeleteInsert => "delete+insert",
+    }
+}
+
+fn relation_name(database: Option<&str>, schema: Option<&str>, name: &str) -> String {
+    let qualified: Vec<&str> = database.into_iter().chain(schema).chain([name]).collect();
+    quote_qualified(&qualified.join("."))
+}
+
+/// dbt's `path`: the file relative to its resource directory (`nodes/`, `tests/`)
+fn resource_path(original_file_path: &str) -> String {
+    Path::new(original_file_path)
+        .components()
+        .skip(1)
+        .collect::<PathBuf>()
+        .display()
+        .to_string()
+}
+
+/// `[project, ...folders, name]`, leaving out the node's own directory
+fn fqn(project_name: &str, original_file_path: &str, name: &str) -> Vec<String> {
+    let folders = Path::new(original_file_path)
+        .parent()
+        .into_iter()
+        .flat_map(Path::components)
+        .skip(1)
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .filter(|folder| folder != name);
+    std::iter::once(project_name.to_string())
+        .chain(folders)
+        .chain(std::iter::once(name.to_string()))
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "dbt_test.rs"]
+mod tests;
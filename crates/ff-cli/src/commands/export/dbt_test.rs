@@ -0,0 +1,243 @@
+use super::*;
+use crate::commands::test_support::{global, write, write_config};
+use std::fs;
+use tempfile::tempdir;
+
+fn write_project(root: &Path) {
+    write_config(root, "shop", "");
+    write(
+        root,
+        "nodes/raw_shop/raw_shop.yml",
+        r#"kind: source
+version: 1
+name: raw_shop
+schema: raw
+tables:
+  - name: orders
+    columns:
+      - name: id
+        type: INTEGER
+        tests: [not_null]
+      - name: status
+        type: VARCHAR
+"#,
+    );
+    write(
+        root,
+        "nodes/countries/countries.csv",
+        "code,name\nNL,Netherlands\n",
+    );
+    write(
+        root,
+        "nodes/countries/countries.yml",
+        "kind: seed\nversion: 1\n",
+    );
+    write(
+        root,
+        "nodes/stg_orders/stg_orders.sql",
+        "select id as order_id, status from orders\n",
+    );
+    write(
+        root,
+        "nodes/stg_orders/stg_orders.yml",
+        r#"version: 1
+description: One row per order
+tags: [staging]
+columns:
+  - name: order_id
+    type: INTEGER
+    description: Order identifier
+    tests: [unique]
+  - name: status
+    type: VARCHAR
+"#,
+    );
+    write(
+        root,
+        "nodes/fct_orders/fct_orders.sql",
+        "select o.order_id, c.name as country\n\
+         from stg_orders o join countries c on o.status = c.code\n",
+    );
+    write(
+        root,
+        "nodes/fct_orders/fct_orders.yml",
+        "version: 1\nmaterialized: table\n",
+    );
+    write(
+        root,
+        "tests/assert_no_negative_orders.sql",
+        "select * from fct_orders where order_id < 0\n",
+    );
+}
+
+#[tokio::test]
+async fn test_manifest_maps_nodes_and_lineage() {
+    let dir = tempdir().unwrap();
+    write_project(dir.path());
+    let global = global(dir.path());
+    let mut project = load_project(&global).unwrap();
+
+    compile::compile_project(&mut project, &compile_args(), &global)
+        .await
+        .unwrap();
+    let tests = render_singular_tests(&project, &global).unwrap();
+    let manifest = build_manifest(&project).unwrap();
+    let export = DbtExport::new(&project, &manifest, "run-1".to_string());
+    let dbt = export.dbt_manifest(&tests);
+
+    assert_eq!(
+        dbt["metadata"]["dbt_schema_version"],
+        MANIFEST_SCHEMA_VERSION
+    );
+    assert_eq!(dbt["metadata"]["project_name"], "shop");
+
+    let stg = &dbt["nodes"]["model.shop.stg_orders"];
+    assert_eq!(stg["resource_type"], "model");
+    assert_eq!(stg["original_file_path"], "nodes/stg_orders/stg_orders.sql");
+    assert_eq!(stg["fqn"], json!(["shop", "stg_orders"]));
+    assert_eq!(stg["description"], "One row per order");
+    assert_eq!(stg["tags"], json!(["staging"]));
+    assert_eq!(
+        stg["columns"]["order_id"]["description"],
+        "Order identifier"
+    );
+    assert_eq!(
+        stg["depends_on"]["nodes"],
+        json!(["source.shop.raw_shop.orders"])
+    );
+    assert_eq!(stg["sources"], json!([["raw_shop", "orders"]]));
+
+    let fct = &dbt["nodes"]["model.shop.fct_orders"];
+    assert_eq!(fct["config"]["materialized"], "table");
+    assert_eq!(
+        fct["depends_on"]["nodes"],
+        json!(["model.shop.stg_orders", "seed.shop.countries"])
+    );
+    assert_eq!(dbt["nodes"]["seed.shop.countries"]["resource_type"], "seed");
+
+    let unique = &dbt["nodes"]["test.shop.unique_stg_orders__order_id"];
+    assert_eq!(unique["test_metadata"]["name"], "unique");
+    assert_eq!(unique["attached_node"], "model.shop.stg_orders");
+    assert_eq!(unique["column_name"], "order_id");
+    let source_test = &dbt["nodes"]["test.shop.source_not_null_raw_shop_orders__id"];
+    assert_eq!(
+        source_test["depends_on"]["nodes"],
+        json!(["source.shop.raw_shop.orders"])
+    );
+    let singular = &dbt["nodes"]["test.shop.assert_no_negative_orders"];
+    assert_eq!(
+        singular["depends_on"]["nodes"],
+        json!(["model.shop.fct_orders"])
+    );
+
+    assert_eq!(
+        dbt["sources"]["source.shop.raw_shop.orders"]["relation_name"],
+        "\"raw\".\"orders\""
+    );
+    assert_eq!(
+        dbt["child_map"]["model.shop.stg_orders"],
+        json!([
+            "model.shop.fct_orders",
+            "test.shop.unique_stg_orders__order_id"
+        ])
+    );
+    assert_eq!(dbt["parent_map"]["source.shop.raw_shop.orders"], json!([]));
+}
+
+#[tokio::test]
+async fn test_command_nodes_export_as_sources() {
+    let dir = tempdir().unwrap();
+    write_project(dir.path());
+    write(
+        dir.path(),
+        "nodes/ext_rates/ext_rates.yml",
+        r#"kind: command
+version: 1
+description: Exchange rates from the rates API
+command:
+  program: ./extract.sh
+columns:
+  - name: code
+    type: VARCHAR
+  - name: rate
+    type: DOUBLE
+"#,
+    );
+    write(
+        dir.path(),
+        "nodes/fct_rates/fct_rates.sql",
+        "select code, rate from ext_rates\n",
+    );
+    write(dir.path(), "nodes/fct_rates/fct_rates.yml", "version: 1\n");
+    let global = global(dir.path());
+    let mut project = load_project(&global).unwrap();
+
+    compile::compile_project(&mut project, &compile_args(), &global)
+        .await
+        .unwrap();
+    let tests = render_singular_tests(&project, &global).unwrap();
+    let manifest = build_manifest(&project).unwrap();
+    let export = DbtExport::new(&project, &manifest, "run-1".to_string());
+    let dbt = export.dbt_manifest(&tests);
+
+    assert!(dbt["nodes"].get("model.shop.ext_rates").is_none());
+    let source = &dbt["sources"]["source.shop.commands.ext_rates"];
+    assert_eq!(source["resource_type"], "source");
+    assert_eq!(source["source_name"], "commands");
+    assert_eq!(source["description"], "Exchange rates from the rates API");
+    assert_eq!(source["columns"]["rate"]["data_type"], "DOUBLE");
+
+    let fct = &dbt["nodes"]["model.shop.fct_rates"];
+    assert_eq!(
+        fct["depends_on"]["nodes"],
+        json!(["source.shop.commands.ext_rates"])
+    );
+    assert_eq!(fct["sources"], json!([["commands", "ext_rates"]]));
+    assert_eq!(fct["refs"], json!([]));
+
+    let catalog = export.catalog(&ColumnTypes::new(), &ColumnTypes::new());
+    assert!(catalog["nodes"].get("model.shop.ext_rates").is_none());
+    assert_eq!(
+        catalog["sources"]["source.shop.commands.ext_rates"]["columns"]["code"]["type"],
+        "VARCHAR"
+    );
+}
+
+#[tokio::test]
+async fn test_export_writes_catalog_with_inferred_types() {
+    let dir = tempdir().unwrap();
+    write_project(dir.path());
+    let out = dir.path().join("artifacts");
+    let args = ExportDbtArtifactsArgs {
+        output: Some(out.clone()),
+    };
+
+    execute(&args, &global(dir.path())).await.unwrap();
+
+    let manifest: Value =
+        serde_json::from_str(&fs::read_to_string(out.join("manifest.json")).unwrap()).unwrap();
+    assert!(manifest["nodes"]["model.shop.stg_orders"].is_object());
+
+    let catalog: Value =
+        serde_json::from_str(&fs::read_to_string(out.join("catalog.json")).unwrap()).unwrap();
+    assert_eq!(
+        catalog["metadata"]["dbt_schema_version"],
+        CATALOG_SCHEMA_VERSION
+    );
+    let stg = &catalog["nodes"]["model.shop.stg_orders"];
+    assert_eq!(stg["metadata"]["type"], "VIEW");
+    assert_eq!(stg["columns"]["order_id"]["type"], "INTEGER");
+    assert_eq!(stg["columns"]["order_id"]["index"], 1);
+    assert_eq!(stg["columns"]["order_id"]["comment"], "Order identifier");
+    assert_eq!(stg["columns"]["status"]["index"], 2);
+    let fct = &catalog["nodes"]["model.shop.fct_orders"];
+    assert_eq!(fct["metadata"]["type"], "BASE TABLE");
+    assert_eq!(fct["columns"]["order_id"]["type"], "INTEGER");
+    assert_eq!(fct["columns"]["country"]["index"], 2);
+    let seed = &catalog["nodes"]["seed.shop.countries"];
+    assert_eq!(seed["columns"]["code"]["index"], 1);
+    assert_eq!(
+        catalog["sources"]["source.shop.raw_shop.orders"]["columns"]["id"]["type"],
+        "INTEGER"
+    );
+}
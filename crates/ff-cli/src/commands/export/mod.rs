@@ -0,0 +1,16 @@
+//! Export command implementation — write project metadata in other tools' formats
+
+mod contracts;
+mod dbt;
+
+use anyhow::Result;
+
+use crate::cli::{ExportArgs, ExportCommands, GlobalArgs};
+
+/// Execute the export command
+pub(crate) async fn execute(args: &ExportArgs, global: &GlobalArgs) -> Result<()> {
+    match &args.command {
+        ExportCommands::DbtArtifacts(sub) => dbt::execute(sub, global).await,
+        ExportCommands::Contracts(sub) => contracts::execute(sub, global),
+    }
+}
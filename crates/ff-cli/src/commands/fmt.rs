@@ -76,7 +76,11 @@ pub(crate) async fn execute(args: &FmtArgs, global: &GlobalArgs) -> Result<()> {
             .collect();
 
         if let Some(ref nodes_arg) = args.nodes {
-            let (_, dag) = crate::commands::common::build_project_dag(&project)?;
+            let (_, dag) = crate::commands::common::build_project_dag(
+                &project,
+                global,
+                &crate::commands::common::build_node_catalog(&project),
+            )?;
             let selected =
                 crate::commands::common::resolve_nodes(&project, &dag, &Some(nodes_arg.clone()))?;
             let selected_set: std::collections::HashSet<String> = selected.into_iter().collect();
@@ -113,7 +113,7 @@ async fn list(args: &FunctionListArgs, global: &GlobalArgs) -> Result<()> {
 async fn deploy(args: &FunctionDeployArgs, global: &GlobalArgs) -> Result<()> {
     let project = load_project(global)?;
     let db = create_database_connection(&project.config, global.database.as_deref())?;
-    let jinja = common::build_jinja_env(&project);
+    let jinja = common::build_jinja_env(&project, global, &common::build_node_catalog(&project));
 
     common::set_project_search_path(&db, &project).await?;
 
@@ -164,7 +164,8 @@ async fn show(args: &FunctionShowArgs, global: &GlobalArgs) -> Result<()> {
         .ok_or_else(|| anyhow::anyhow!("Function '{}' not found", args.name))?;
 
     if args.sql {
-        let jinja = common::build_jinja_env(&project);
+        let jinja =
+            common::build_jinja_env(&project, global, &common::build_node_catalog(&project));
         let rendered_body = jinja
             .render(&func.sql_body)
             .with_context(|| format!("Failed to render SQL for function '{}'", func.name))?;
@@ -0,0 +1,366 @@
+//! Generate command implementation — scaffold node YAML from the live database
+
+use anyhow::{Context, Result};
+use ff_db::Database;
+use serde_yaml::{Mapping, Value};
+use std::collections::HashSet;
+use std::path::PathBuf;
+
+use crate::cli::{GenerateArgs, GenerateCommands, GenerateSourcesArgs, GlobalArgs};
+use crate::commands::common::{self, load_project};
+use crate::commands::yaml_edit::ColumnsEditor;
+
+/// Column names and types for one introspected table, in ordinal order.
+#[derive(Debug, Clone)]
+pub(crate) struct IntrospectedTable {
+    pub name: String,
+    pub columns: Vec<(String, String)>,
+}
+
+/// What changed when merging introspected tables into an existing source file.
+///
+/// Existing entries are never removed or rewritten; drift is reported so the
+/// user can decide what to do about it.
+#[derive(Debug, Default)]
+pub(crate) struct SourceMergeSummary {
+    /// Tables appended to the `tables:` list
+    pub tables_added: Vec<String>,
+    /// Columns appended to existing tables, as `table.column`
+    pub columns_added: Vec<String>,
+    /// Declared types that differ from the database, as `(table.column, declared, actual)`
+    pub type_drift: Vec<(String, String, String)>,
+    /// Declared columns the database no longer reports, as `table.column`
+    pub missing_in_database: Vec<String>,
+}
+
+impl SourceMergeSummary {
+    fn is_unchanged(&self) -> bool {
+        self.tables_added.is_empty() && self.columns_added.is_empty()
+    }
+}
+
+/// Execute the generate command
+pub(crate) async fn execute(args: &GenerateArgs, global: &GlobalArgs) -> Result<()> {
+    match &args.command {
+        GenerateCommands::Sources(sub) => execute_sources(sub, global).await,
+    }
+}
+
+/// Introspect a schema and write (or merge into) a `kind: source` node.
+async fn execute_sources(args: &GenerateSourcesArgs, global: &GlobalArgs) -> Result<()> {
+    let project = load_project(global)?;
+    let source_name = args.name.as_deref().unwrap_or(&args.schema);
+    validate_node_name(source_name)?;
+
+    let db = common::create_database_connection(&project.config, global.database.as_deref())?;
+    let tables = introspect_schema(db.as_ref(), &args.schema, args.tables.as_deref()).await?;
+
+    let node_dir = project
+        .config
+        .node_paths_absolute(&project.root)
+        .into_iter()
+        .next()
+        .context("Project has no node path")?
+        .join(source_name);
+    let yaml_path = existing_yaml_path(&node_dir, source_name)
+        .unwrap_or_else(|| node_dir.join(format!("{source_name}.yml")));
+
+    let (yaml, summary) = if yaml_path.exists() {
+        let content = std::fs::read_to_string(&yaml_path)
+            .with_context(|| format!("Failed to read {}", yaml_path.display()))?;
+        let (yaml, summary) = merge_source_yaml(&content, &args.schema, &tables)
+            .with_context(|| format!("Cannot merge into {}", yaml_path.display()))?;
+        (yaml, Some(summary))
+    } else {
+        if project.sources.iter().any(|s| s.name == source_name) {
+            anyhow::bail!(
+                "A source named '{}' already exists outside {}. Use --name to pick a different name.",
+                source_name,
+                node_dir.display()
+            );
+        }
+        let doc = build_source_yaml(source_name, &args.schema, &tables);
+        let yaml = serde_yaml::to_string(&doc).context("Failed to serialize source YAML")?;
+        (yaml, None)
+    };
+
+    if args.dry_run {
+        print!("{yaml}");
+        return Ok(());
+    }
+
+    if summary.as_ref().is_some_and(|s| s.is_unchanged()) {
+        println!("{} is up to date", yaml_path.display());
+    } else {
+        std::fs::create_dir_all(&node_dir)
+            .with_context(|| format!("Failed to create directory: {}", node_dir.display()))?;
+        std::fs::write(&yaml_path, &yaml)
+            .with_context(|| format!("Failed to write {}", yaml_path.display()))?;
+    }
+
+    match summary {
+        None => {
+            let column_count: usize = tables.iter().map(|t| t.columns.len()).sum();
+            println!(
+                "Created {} ({} tables, {} columns)",
+                yaml_path.display(),
+                tables.len(),
+                column_count
+            );
+        }
+        Some(summary) => print_merge_summary(&yaml_path, &summary),
+    }
+
+    Ok(())
+}
+
+/// Reject names that would escape the node directory or produce confusing paths.
+fn validate_node_name(name: &str) -> Result<()> {
+    if name.is_empty()
+        || name.contains('/')
+        || name.contains('\\')
+        || name.contains("..")
+        || name.starts_with('.')
+    {
+        anyhow::bail!(
+            "Invalid source name '{}': must be non-empty and not contain '/', '\\', '..', or start with '.'",
+            name
+        );
+    }
+    Ok(())
+}
+
+/// Find `<dir>/<name>.yml` or `<dir>/<name>.yaml` if either exists.
+fn existing_yaml_path(dir: &std::path::Path, name: &str) -> Option<PathBuf> {
+    ["yml", "yaml"]
+        .iter()
+        .map(|ext| dir.join(format!("{name}.{ext}")))
+        .find(|p| p.exists())
+}
+
+/// Read table and column metadata for `schema` through the [`Database`] trait.
+///
+/// When `tables_filter` is given (comma-separated), only those tables are
+/// introspected and every requested name must exist.
+pub(crate) async fn introspect_schema(
+    db: &dyn Database,
+    schema: &str,
+    tables_filter: Option<&str>,
+) -> Result<Vec<IntrospectedTable>> {
+    let available = db
+        .list_tables(schema)
+        .await
+        .with_context(|| format!("Failed to list tables in schema '{schema}'"))?;
+
+    let selected: Vec<String> = match tables_filter {
+        Some(filter) => {
+            let requested: Vec<&str> = filter
+                .split(',')
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .collect();
+            let missing: Vec<&str> = requested
+                .iter()
+                .copied()
+                .filter(|r| !available.iter().any(|a| a == r))
+                .collect();
+            if !missing.is_empty() {
+                anyhow::bail!(
+                    "Tables not found in schema '{}': {}",
+                    schema,
+                    missing.join(", ")
+                );
+            }
+            requested.into_iter().map(String::from).collect()
+        }
+        None => available,
+    };
+
+    if selected.is_empty() {
+        anyhow::bail!("No tables found in schema '{}'", schema);
+    }
+
+    let mut tables = Vec::with_capacity(selected.len());
+    for name in selected {
+        let qualified = format!("{schema}.{name}");
+        let columns = db
+            .get_table_schema(&qualified)
+            .await
+            .with_context(|| format!("Failed to read columns for {qualified}"))?;
+        tables.push(IntrospectedTable { name, columns });
+    }
+    Ok(tables)
+}
+
+/// Build a fresh `kind: source` document for the introspected tables.
+pub(crate) fn build_source_yaml(name: &str, schema: &str, tables: &[IntrospectedTable]) -> Value {
+    let mut doc = Mapping::new();
+    doc.insert("kind".into(), "source".into());
+    doc.insert("version".into(), Value::Number(1.into()));
+    doc.insert("name".into(), name.into());
+    doc.insert("schema".into(), schema.into());
+    doc.insert(
+        "tables".into(),
+        Value::Sequence(tables.iter().map(table_entry).collect()),
+    );
+    Value::Mapping(doc)
+}
+
+/// Merge introspected tables into the text of an existing source file.
+///
+/// New tables and columns are appended through [`ColumnsEditor`], so
+/// everything already declared (descriptions, tests, identifiers, unknown
+/// keys, comments and formatting) is carried over verbatim.
+pub(crate) fn merge_source_yaml(
+    content: &str,
+    schema: &str,
+    tables: &[IntrospectedTable],
+) -> Result<(String, SourceMergeSummary)> {
+    let doc: Value = serde_yaml::from_str(content).context("Failed to parse source YAML")?;
+    if !doc.is_mapping() {
+        anyhow::bail!("source file is not a YAML mapping");
+    }
+    let declared_schema = doc.get("schema").and_then(Value::as_str).unwrap_or("");
+    if declared_schema != schema {
+        anyhow::bail!(
+            "existing source declares schema '{}', not '{}'",
+            declared_schema,
+            schema
+        );
+    }
+    let entries: &[Value] = match doc.get("tables") {
+        Some(tables) => tables.as_sequence().context("'tables' is not a list")?,
+        None => &[],
+    };
+
+    let mut editor = ColumnsEditor::new(content);
+    let mut summary = SourceMergeSummary::default();
+
+    for table in tables {
+        let existing = entries
+            .iter()
+            .position(|entry| table_entry_matches(entry, &table.name));
+        let Some(index) = existing else {
+            editor.append_table(&table.name, &table.columns)?;
+            summary.tables_added.push(table.name.clone());
+            continue;
+        };
+        for (name, data_type) in merge_table_columns(&entries[index], table, &mut summary)? {
+            editor.append_table_column(index, name, data_type)?;
+        }
+    }
+
+    Ok((editor.render(), summary))
+}
+
+/// Compare one table's declared columns with the database, returning the
+/// columns to append.
+fn merge_table_columns<'t>(
+    entry: &Value,
+    table: &'t IntrospectedTable,
+    summary: &mut SourceMergeSummary,
+) -> Result<Vec<(&'t str, &'t str)>> {
+    let columns: &[Value] = match entry.get("columns") {
+        Some(columns) => columns
+            .as_sequence()
+            .with_context(|| format!("'columns' of table '{}' is not a list", table.name))?,
+        None => &[],
+    };
+
+    let mut seen: HashSet<String> = HashSet::new();
+    for column in columns {
+        let Some(col_name) = column.get("name").and_then(Value::as_str) else {
+            continue;
+        };
+        seen.insert(col_name.to_lowercase());
+        let actual = table
+            .columns
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(col_name));
+        match actual {
+            Some((_, actual_type)) => {
+                let declared = column.get("type").and_then(Value::as_str).unwrap_or("");
+                if !declared.eq_ignore_ascii_case(actual_type) {
+                    summary.type_drift.push((
+                        format!("{}.{}", table.name, col_name),
+                        declared.to_string(),
+                        actual_type.clone(),
+                    ));
+                }
+            }
+            None => summary
+                .missing_in_database
+                .push(format!("{}.{}", table.name, col_name)),
+        }
+    }
+
+    let mut added = Vec::new();
+    for (name, data_type) in &table.columns {
+        if seen.contains(&name.to_lowercase()) {
+            continue;
+        }
+        added.push((name.as_str(), data_type.as_str()));
+        summary
+            .columns_added
+            .push(format!("{}.{}", table.name, name));
+    }
+
+    Ok(added)
+}
+
+/// A table entry matches when its `identifier` (or `name`, if no identifier) is the database table name.
+fn table_entry_matches(entry: &Value, table_name: &str) -> bool {
+    entry
+        .get("identifier")
+        .or_else(|| entry.get("name"))
+        .and_then(Value::as_str)
+        .is_some_and(|n| n == table_name)
+}
+
+fn table_entry(table: &IntrospectedTable) -> Value {
+    let mut map = Mapping::new();
+    map.insert("name".into(), table.name.as_str().into());
+    map.insert(
+        "columns".into(),
+        Value::Sequence(
+            table
+                .columns
+                .iter()
+                .map(|(name, data_type)| column_entry(name, data_type))
+                .collect(),
+        ),
+    );
+    Value::Mapping(map)
+}
+
+fn column_entry(name: &str, data_type: &str) -> Value {
+    let mut map = Mapping::new();
+    map.insert("name".into(), name.into());
+    map.insert("type".into(), data_type.into());
+    Value::Mapping(map)
+}
+
+fn print_merge_summary(path: &std::path::Path, summary: &SourceMergeSummary) {
+    if !summary.is_unchanged() {
+        println!("Updated {}", path.display());
+    }
+    for table in &summary.tables_added {
+        println!("  + table {}", table);
+    }
+    for column in &summary.columns_added {
+        println!("  + column {}", column);
+    }
+    for (column, declared, actual) in &summary.type_drift {
+        println!(
+            "  ~ {}: declared {}, database reports {} (kept)",
+            column, declared, actual
+        );
+    }
+    for column in &summary.missing_in_database {
+        println!("  ? {}: not found in database (kept)", column);
+    }
+}
+
+#[cfg(test)]
+#[path = "generate_test.rs"]
+mod tests;
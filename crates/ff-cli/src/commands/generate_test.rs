@@ -0,0 +1,315 @@
+use super::*;
+use ff_core::source::SourceFile;
+use ff_db::{DatabaseCore, DuckDbBackend};
+use std::path::Path;
+use tempfile::tempdir;
+
+fn orders_table() -> IntrospectedTable {
+    IntrospectedTable {
+        name: "orders".to_string(),
+        columns: vec![
+            ("id".to_string(), "INTEGER".to_string()),
+            ("amount".to_string(), "DECIMAL(10,2)".to_string()),
+            ("created_at".to_string(), "TIMESTAMP".to_string()),
+        ],
+    }
+}
+
+#[test]
+fn test_build_source_yaml_roundtrips_as_source_file() {
+    let doc = build_source_yaml("raw_shop", "raw", &[orders_table()]);
+    let yaml = serde_yaml::to_string(&doc).unwrap();
+
+    let source = SourceFile::load_from_str(&yaml, Path::new("raw_shop.yml")).unwrap();
+    assert_eq!(source.name, "raw_shop");
+    assert_eq!(source.schema, "raw");
+    assert_eq!(source.tables.len(), 1);
+    let columns: Vec<(&str, &str)> = source.tables[0]
+        .columns
+        .iter()
+        .map(|c| (c.name.as_str(), c.data_type.as_str()))
+        .collect();
+    assert_eq!(
+        columns,
+        vec![
+            ("id", "INTEGER"),
+            ("amount", "DECIMAL(10,2)"),
+            ("created_at", "TIMESTAMP")
+        ]
+    );
+}
+
+#[test]
+fn test_merge_keeps_descriptions_and_tests() {
+    let existing = r#"
+kind: source
+version: 1
+name: raw_shop
+description: "Raw shop data"
+schema: raw
+tables:
+  - name: orders
+    description: "One row per order"
+    columns:
+      - name: id
+        type: INTEGER
+        description: "Order id"
+        tests:
+          - unique
+          - not_null
+"#;
+    let (yaml, summary) = merge_source_yaml(existing, "raw", &[orders_table()]).unwrap();
+
+    assert!(summary.tables_added.is_empty());
+    assert_eq!(
+        summary.columns_added,
+        vec!["orders.amount", "orders.created_at"]
+    );
+
+    let source = SourceFile::load_from_str(&yaml, Path::new("raw_shop.yml")).unwrap();
+    assert_eq!(source.description.as_deref(), Some("Raw shop data"));
+    let table = &source.tables[0];
+    assert_eq!(table.description.as_deref(), Some("One row per order"));
+    assert_eq!(table.columns.len(), 3);
+    assert_eq!(table.columns[0].description.as_deref(), Some("Order id"));
+    assert_eq!(table.columns[0].tests.len(), 2);
+    assert_eq!(table.columns[2].name, "created_at");
+}
+
+#[test]
+fn test_merge_appends_new_tables_and_reports_drift() {
+    let existing = r#"
+kind: source
+version: 1
+name: raw_shop
+schema: raw
+tables:
+  - name: orders
+    columns:
+      - name: id
+        type: BIGINT
+      - name: legacy_flag
+        type: BOOLEAN
+"#;
+    let customers = IntrospectedTable {
+        name: "customers".to_string(),
+        columns: vec![("id".to_string(), "INTEGER".to_string())],
+    };
+    let (yaml, summary) = merge_source_yaml(existing, "raw", &[orders_table(), customers]).unwrap();
+
+    assert_eq!(summary.tables_added, vec!["customers"]);
+    assert_eq!(
+        summary.type_drift,
+        vec![(
+            "orders.id".to_string(),
+            "BIGINT".to_string(),
+            "INTEGER".to_string()
+        )]
+    );
+    assert_eq!(summary.missing_in_database, vec!["orders.legacy_flag"]);
+
+    // Drifted and missing columns are reported, never rewritten or removed
+    let source = SourceFile::load_from_str(&yaml, Path::new("raw_shop.yml")).unwrap();
+    assert_eq!(source.tables.len(), 2);
+    assert_eq!(source.tables[0].columns[0].data_type, "BIGINT");
+    assert!(source.tables[0]
+        .columns
+        .iter()
+        .any(|c| c.name == "legacy_flag"));
+}
+
+#[test]
+fn test_merge_matches_tables_by_identifier() {
+    let existing = r#"
+kind: source
+name: raw_shop
+schema: raw
+tables:
+  - name: shop_orders
+    identifier: orders
+    columns:
+      - name: id
+        type: INTEGER
+      - name: amount
+        type: DECIMAL(10,2)
+      - name: created_at
+        type: TIMESTAMP
+"#;
+    let (yaml, summary) = merge_source_yaml(existing, "raw", &[orders_table()]).unwrap();
+
+    assert!(summary.is_unchanged());
+    assert_eq!(yaml, existing);
+    assert!(summary.type_drift.is_empty());
+}
+
+#[test]
+fn test_merge_keeps_comments_and_formatting() {
+    let existing = r#"# Raw shop tables, loaded nightly
+kind: source
+version: 1
+name: raw_shop
+schema: raw # landing schema
+tables:
+- name: orders
+  columns:
+  - name: id
+    type: INTEGER # surrogate key
+
+  # Shipping address rows
+- name: addresses
+  description: 'Addresses'
+"#;
+    let addresses = IntrospectedTable {
+        name: "addresses".to_string(),
+        columns: vec![("city".to_string(), "VARCHAR".to_string())],
+    };
+    let customers = IntrospectedTable {
+        name: "customers".to_string(),
+        columns: vec![("id".to_string(), "INTEGER".to_string())],
+    };
+    let (yaml, summary) =
+        merge_source_yaml(existing, "raw", &[orders_table(), addresses, customers]).unwrap();
+
+    assert_eq!(summary.tables_added, vec!["customers"]);
+    assert_eq!(
+        summary.columns_added,
+        vec!["orders.amount", "orders.created_at", "addresses.city"]
+    );
+    assert_eq!(
+        yaml,
+        r#"# Raw shop tables, loaded nightly
+kind: source
+version: 1
+name: raw_shop
+schema: raw # landing schema
+tables:
+- name: orders
+  columns:
+  - name: id
+    type: INTEGER # surrogate key
+  - name: amount
+    type: DECIMAL(10,2)
+  - name: created_at
+    type: TIMESTAMP
+
+  # Shipping address rows
+- name: addresses
+  description: 'Addresses'
+  columns:
+  - name: city
+    type: VARCHAR
+- name: customers
+  columns:
+  - name: id
+    type: INTEGER
+"#
+    );
+    let source = SourceFile::load_from_str(&yaml, Path::new("raw_shop.yml")).unwrap();
+    assert_eq!(source.tables.len(), 3);
+}
+
+#[test]
+fn test_merge_rejects_schema_mismatch() {
+    let existing = r#"
+kind: source
+name: raw_shop
+schema: staging
+tables:
+  - name: orders
+"#;
+    let err = merge_source_yaml(existing, "raw", &[orders_table()]).unwrap_err();
+    assert!(err.to_string().contains("staging"));
+}
+
+#[test]
+fn test_validate_node_name() {
+    assert!(validate_node_name("raw_shop").is_ok());
+    assert!(validate_node_name("").is_err());
+    assert!(validate_node_name("../escape").is_err());
+    assert!(validate_node_name("a/b").is_err());
+    assert!(validate_node_name(".hidden").is_err());
+}
+
+#[tokio::test]
+async fn test_introspect_schema_filters_tables() {
+    let db = DuckDbBackend::in_memory().unwrap();
+    db.execute_batch(
+        "CREATE SCHEMA raw; \
+         CREATE TABLE raw.orders (id INTEGER, amount DOUBLE); \
+         CREATE TABLE raw.customers (id INTEGER, name VARCHAR)",
+    )
+    .await
+    .unwrap();
+
+    let all = introspect_schema(&db, "raw", None).await.unwrap();
+    let names: Vec<&str> = all.iter().map(|t| t.name.as_str()).collect();
+    assert_eq!(names, vec!["customers", "orders"]);
+
+    let filtered = introspect_schema(&db, "raw", Some("orders")).await.unwrap();
+    assert_eq!(filtered.len(), 1);
+    assert_eq!(
+        filtered[0].columns,
+        vec![
+            ("id".to_string(), "INTEGER".to_string()),
+            ("amount".to_string(), "DOUBLE".to_string())
+        ]
+    );
+
+    let err = introspect_schema(&db, "raw", Some("orders,nope"))
+        .await
+        .unwrap_err();
+    assert!(err.to_string().contains("nope"));
+}
+
+#[tokio::test]
+async fn test_generate_sources_writes_node_directory() {
+    let temp_dir = tempdir().unwrap();
+    let project_path = temp_dir.path();
+    let db_path = project_path.join("dev.duckdb");
+
+    {
+        let db = DuckDbBackend::new(db_path.to_str().unwrap()).unwrap();
+        db.execute_batch(
+            "CREATE SCHEMA raw_shop; CREATE TABLE raw_shop.orders (id INTEGER, status VARCHAR)",
+        )
+        .await
+        .unwrap();
+    }
+
+    std::fs::write(
+        project_path.join("featherflow.yml"),
+        format!(
+            "name: test_project\ndatabase:\n  default:\n    type: duckdb\n    path: \"{}\"\n",
+            db_path.display()
+        ),
+    )
+    .unwrap();
+
+    let args = GenerateArgs {
+        command: GenerateCommands::Sources(GenerateSourcesArgs {
+            schema: "raw_shop".to_string(),
+            tables: None,
+            name: None,
+            dry_run: false,
+        }),
+    };
+    let global = GlobalArgs {
+        verbose: false,
+        project_dir: project_path.to_path_buf(),
+        database: None,
+        offline: false,
+    };
+
+    execute(&args, &global).await.unwrap();
+
+    let yaml_path = project_path.join("nodes/raw_shop/raw_shop.yml");
+    let source = SourceFile::load(&yaml_path).unwrap();
+    assert_eq!(source.schema, "raw_shop");
+    assert_eq!(source.tables[0].name, "orders");
+    assert_eq!(source.tables[0].columns.len(), 2);
+
+    // Re-running against an unchanged schema is a no-op and the project still loads
+    execute(&args, &global).await.unwrap();
+    let project = ff_core::Project::load(project_path).unwrap();
+    assert_eq!(project.sources.len(), 1);
+}
@@ -0,0 +1,1474 @@
+//! `ff dt import dbt` — convert a dbt project into node layout
+//!
+//! Models, seeds, and sources become `nodes/<name>/` directories whose YAML
+//! combines the dbt properties files, the `dbt_project.yml` folder configs,
+//! and each model's `{{ config(...) }}` call. Macros, generic tests, singular
+//! tests, and doc blocks are copied with their Jinja rewritten by
+//! [`translate`](super::translate). Anything without a Featherflow
+//! equivalent is listed in `dbt_import_report.md`, and existing files are
+//! never overwritten.
+
+use anyhow::{Context, Result};
+use serde_yaml::{Mapping, Value};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use super::report::{ImportCounts, ImportReport};
+use super::translate::{
+    extract_config, relation_name, report_complex_query, translate_template, TranslateContext,
+};
+use crate::cli::{CompileArgs, GlobalArgs, ImportDbtArgs, OutputFormat};
+use crate::commands::common::ExitCode;
+use crate::commands::compile;
+
+/// Report written to the root of the imported project
+pub(crate) const REPORT_FILE: &str = "dbt_import_report.md";
+
+/// Column type written when dbt declares none; static analysis treats it as
+/// compatible with anything
+const UNKNOWN_TYPE: &str = "UNKNOWN";
+
+/// File the dbt `macros:` properties are collected into
+const MACRO_DOCS_FILE: &str = "dbt_macros.yml";
+
+/// `dbt_project.yml` keys that only matter to dbt itself
+const IGNORED_PROJECT_KEYS: &[&str] = &[
+    "config-version",
+    "require-dbt-version",
+    "clean-targets",
+    "target-path",
+    "log-path",
+    "packages-install-path",
+    "asset-paths",
+];
+
+/// Test properties that configure how a dbt test runs rather than what it checks
+const TEST_CONFIG_KEYS: &[&str] = &[
+    "config",
+    "severity",
+    "where",
+    "limit",
+    "warn_if",
+    "error_if",
+    "store_failures",
+    "tags",
+    "enabled",
+    "meta",
+    "name",
+    "description",
+];
+
+/// dbt config values by key, with the file each was set in
+type DbtConfig = BTreeMap<String, (Value, String)>;
+
+/// Execute `ff dt import dbt`
+pub(super) async fn execute(args: &ImportDbtArgs, global: &GlobalArgs) -> Result<()> {
+    let output = args
+        .output
+        .clone()
+        .unwrap_or_else(|| global.project_dir.clone());
+    let (counts, report) = import_project(&args.path, &output)?;
+
+    let report_path = output.join(REPORT_FILE);
+    std::fs::write(
+        &report_path,
+        report.to_markdown("dbt import report", &counts),
+    )
+    .with_context(|| format!("Failed to write {}", report_path.display()))?;
+
+    println!("Imported {} into {}", args.path.display(), output.display());
+    println!("  {counts}");
+    if report.issues.is_empty() {
+        println!("  Every construct was translated");
+    } else {
+        println!(
+            "  {} constructs were not translated; see {}",
+            report.issues.len(),
+            report_path.display()
+        );
+    }
+
+    if args.no_compile {
+        return Ok(());
+    }
+
+    println!("\nCompiling the imported project...\n");
+    let compile_global = GlobalArgs {
+        project_dir: output,
+        ..global.clone()
+    };
+    let compile_args = CompileArgs {
+        nodes: None,
+        output_dir: None,
+        vars: None,
+        parse_only: true,
+        skip_static_analysis: false,
+        explain: None,
+        output: OutputFormat::Text,
+        strict: false,
+        governance: false,
+        fix: false,
+    };
+    match compile::execute(&compile_args, &compile_global).await {
+        Err(err) if err.downcast_ref::<ExitCode>().is_some() => {
+            println!(
+                "\nThe imported project does not compile cleanly yet; fix the diagnostics above."
+            );
+            Ok(())
+        }
+        result => result.context("Failed to compile the imported project"),
+    }
+}
+
+/// Convert the dbt project at `dbt_root` into `out_root`.
+pub(super) fn import_project(
+    dbt_root: &Path,
+    out_root: &Path,
+) -> Result<(ImportCounts, ImportReport)> {
+    let project_file = dbt_root.join("dbt_project.yml");
+    if !project_file.is_file() {
+        anyhow::bail!(
+            "No dbt_project.yml in {}; pass the root of a dbt project",
+            dbt_root.display()
+        );
+    }
+    let project = read_yaml_mapping(&project_file)?;
+    let project_name = project
+        .get("name")
+        .and_then(Value::as_str)
+        .context("dbt_project.yml has no name")?
+        .to_string();
+
+    let mut importer = Importer {
+        dbt_root,
+        out_root,
+        paths: DbtPaths::new(dbt_root, &project),
+        project,
+        project_name,
+        props: Properties::default(),
+        ctx: TranslateContext::default(),
+        report: ImportReport::default(),
+        counts: ImportCounts::default(),
+        nodes: HashSet::new(),
+    };
+    std::fs::create_dir_all(out_root)
+        .with_context(|| format!("Failed to create directory: {}", out_root.display()))?;
+
+    importer.check_project_keys();
+    importer.collect_properties()?;
+    importer.import_sources()?;
+    importer.import_models()?;
+    importer.import_seeds()?;
+    importer.import_macros()?;
+    importer.import_tests()?;
+    importer.import_doc_files()?;
+    importer.report_skipped_resources()?;
+    importer.write_config()?;
+
+    Ok((importer.counts, importer.report))
+}
+
+/// Resource directories declared in `dbt_project.yml`
+struct DbtPaths {
+    models: Vec<PathBuf>,
+    seeds: Vec<PathBuf>,
+    macros: Vec<PathBuf>,
+    tests: Vec<PathBuf>,
+    snapshots: Vec<PathBuf>,
+    analyses: Vec<PathBuf>,
+    docs: Vec<PathBuf>,
+}
+
+impl DbtPaths {
+    fn new(root: &Path, project: &Mapping) -> Self {
+        let paths = |keys: &[&str], default: &str| -> Vec<PathBuf> {
+            let declared = keys.iter().find_map(|key| project.get(*key));
+            match declared.map(string_list) {
+                Some(list) => list.iter().map(|p| root.join(p)).collect(),
+                None => vec![root.join(default)],
+            }
+        };
+        let models = paths(&["model-paths", "source-paths"], "models");
+        let docs = match project.get("docs-paths") {
+            Some(list) => string_list(list).iter().map(|p| root.join(p)).collect(),
+            None => models.clone(),
+        };
+        Self {
+            seeds: paths(&["seed-paths", "data-paths"], "seeds"),
+            macros: paths(&["macro-paths"], "macros"),
+            tests: paths(&["test-paths"], "tests"),
+            snapshots: paths(&["snapshot-paths"], "snapshots"),
+            analyses: paths(&["analysis-paths"], "analyses"),
+            models,
+            docs,
+        }
+    }
+}
+
+/// A resource entry from a dbt properties file
+#[derive(Debug, Clone)]
+struct PropertyEntry {
+    map: Mapping,
+    /// Properties file, relative to the dbt project
+    file: String,
+}
+
+/// Resource properties gathered from every properties file
+#[derive(Debug, Default)]
+struct Properties {
+    models: HashMap<String, PropertyEntry>,
+    seeds: HashMap<String, PropertyEntry>,
+    sources: Vec<PropertyEntry>,
+    macros: Vec<PropertyEntry>,
+}
+
+struct Importer<'a> {
+    dbt_root: &'a Path,
+    out_root: &'a Path,
+    paths: DbtPaths,
+    project: Mapping,
+    project_name: String,
+    props: Properties,
+    ctx: TranslateContext,
+    report: ImportReport,
+    counts: ImportCounts,
+    /// Node names this import has written
+    nodes: HashSet<String>,
+}
+
+impl Importer<'_> {
+    fn check_project_keys(&mut self) {
+        const HANDLED: &[&str] = &[
+            "name",
+            "version",
+            "profile",
+            "model-paths",
+            "source-paths",
+            "seed-paths",
+            "data-paths",
+            "macro-paths",
+            "test-paths",
+            "snapshot-paths",
+            "analysis-paths",
+            "docs-paths",
+            "vars",
+            "models",
+            "seeds",
+            "on-run-start",
+            "on-run-end",
+        ];
+        let unhandled: Vec<String> = self
+            .project
+            .keys()
+            .filter_map(Value::as_str)
+            .filter(|key| !HANDLED.contains(key) && !IGNORED_PROJECT_KEYS.contains(key))
+            .map(str::to_string)
+            .collect();
+        for key in unhandled {
+            self.report.add(
+                "dbt_project.yml",
+                key,
+                "project setting has no Featherflow equivalent",
+            );
+        }
+    }
+
+    /// Read the `models:`, `seeds:`, `sources:`, and `macros:` entries of
+    /// every properties file under the model, seed, and macro paths.
+    fn collect_properties(&mut self) -> Result<()> {
+        let dirs: Vec<PathBuf> = self
+            .paths
+            .models
+            .iter()
+            .chain(&self.paths.seeds)
+            .chain(&self.paths.macros)
+            .cloned()
+            .collect();
+        for dir in dirs {
+            for path in walk_files(&dir, &["yml", "yaml"])? {
+                let file = self.rel(&path);
+                let doc = read_yaml_mapping(&path)?;
+                for (key, value) in &doc {
+                    let Some(key) = key.as_str() else { continue };
+                    let entries = value.as_sequence().map(Vec::as_slice).unwrap_or_default();
+                    let entries =
+                        entries
+                            .iter()
+                            .filter_map(Value::as_mapping)
+                            .map(|map| PropertyEntry {
+                                map: map.clone(),
+                                file: file.clone(),
+                            });
+                    match key {
+                        "version" => {}
+                        "models" | "seeds" => {
+                            for entry in entries {
+                                let Some(name) = entry.map.get("name").and_then(Value::as_str)
+                                else {
+                                    continue;
+                                };
+                                let name = name.to_string();
+                                if key == "models" {
+                                    self.props.models.insert(name, entry);
+                                } else {
+                                    self.props.seeds.insert(name, entry);
+                                }
+                            }
+                        }
+                        "sources" => self.props.sources.extend(entries),
+                        "macros" => self.props.macros.extend(entries),
+                        other => self.report.add(
+                            &file,
+                            format!("{other}:"),
+                            "resource type has no Featherflow equivalent",
+                        ),
+                    }
+                }
+            }
+        }
+
+        for entry in &self.props.sources {
+            let Some(source) = entry.map.get("name").and_then(Value::as_str) else {
+                continue;
+            };
+            let schema = entry
+                .map
+                .get("schema")
+                .and_then(Value::as_str)
+                .unwrap_or(source);
+            for table in mapping_list(entry.map.get("tables")) {
+                let Some(name) = table.get("name").and_then(Value::as_str) else {
+                    continue;
+                };
+                let identifier = table
+                    .get("identifier")
+                    .and_then(Value::as_str)
+                    .unwrap_or(name);
+                self.ctx.sources.insert(
+                    (source.to_string(), name.to_string()),
+                    format!("{schema}.{identifier}"),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// One `kind: source` node per dbt source.
+    fn import_sources(&mut self) -> Result<()> {
+        for entry in std::mem::take(&mut self.props.sources) {
+            let file = entry.file.clone();
+            let Some(name) = entry.map.get("name").and_then(Value::as_str) else {
+                self.report
+                    .add(&file, "sources: entry", "source has no name; skipped");
+                continue;
+            };
+            let tables = mapping_list(entry.map.get("tables"));
+            if tables.is_empty() {
+                self.report
+                    .add(&file, name, "source declares no tables; skipped");
+                continue;
+            }
+            let Some(node_dir) = self.claim_node(name, &file) else {
+                continue;
+            };
+
+            let mut yaml = Mapping::new();
+            yaml.insert("kind".into(), "source".into());
+            yaml.insert("version".into(), Value::Number(1.into()));
+            yaml.insert("name".into(), name.into());
+            copy_key(&entry.map, &mut yaml, "description");
+            copy_key(&entry.map, &mut yaml, "database");
+            let schema = entry.map.get("schema").cloned().unwrap_or(name.into());
+            yaml.insert("schema".into(), schema);
+            if let Some(tags) = entry.map.get("tags") {
+                yaml.insert("tags".into(), Value::Sequence(tag_list(tags)));
+            }
+            self.report_unhandled(
+                &entry.map,
+                &[
+                    "name",
+                    "description",
+                    "database",
+                    "schema",
+                    "tags",
+                    "tables",
+                ],
+                &file,
+                name,
+            );
+
+            let mut table_yaml = Vec::new();
+            for table in tables {
+                let Some(table_name) = table.get("name").and_then(Value::as_str) else {
+                    continue;
+                };
+                let context = format!("{name}.{table_name}");
+                let mut out = Mapping::new();
+                out.insert("name".into(), table_name.into());
+                copy_key(table, &mut out, "identifier");
+                copy_key(table, &mut out, "description");
+                self.report_table_tests(table, &file, &context);
+                self.report_unhandled(
+                    table,
+                    &[
+                        "name",
+                        "identifier",
+                        "description",
+                        "columns",
+                        "tests",
+                        "data_tests",
+                    ],
+                    &file,
+                    &context,
+                );
+                let columns = self.columns_yaml(table, &file, &context, None, false);
+                if !columns.is_empty() {
+                    out.insert("columns".into(), Value::Sequence(columns));
+                }
+                table_yaml.push(Value::Mapping(out));
+            }
+            yaml.insert("tables".into(), Value::Sequence(table_yaml));
+
+            write_node_yaml(&node_dir, name, &yaml)?;
+            self.counts.sources += 1;
+        }
+        Ok(())
+    }
+
+    /// One `kind: sql` node per model `.sql` file.
+    fn import_models(&mut self) -> Result<()> {
+        for model_dir in self.paths.models.clone() {
+            for path in walk_files(&model_dir, &["sql", "py"])? {
+                let file = self.rel(&path);
+                if path.extension().is_some_and(|ext| ext == "py") {
+                    self.report.add(
+                        &file,
+                        "Python model",
+                        "dbt Python models are not imported; port it to a `kind: python` node",
+                    );
+                    continue;
+                }
+                let folders = folders_between(&model_dir, &path);
+                self.import_model(&path, &folders)?;
+            }
+        }
+
+        let mut orphans: Vec<_> = self.props.models.drain().collect();
+        orphans.sort_by(|a, b| a.0.cmp(&b.0));
+        for (name, entry) in orphans {
+            self.report.add(
+                entry.file,
+                name,
+                "properties for a model with no SQL file; skipped",
+            );
+        }
+        Ok(())
+    }
+
+    fn import_model(&mut self, path: &Path, folders: &[String]) -> Result<()> {
+        let name = file_stem(path);
+        let file = self.rel(path);
+        let props = self.props.models.remove(&name);
+        let Some(node_dir) = self.claim_node(&name, &file) else {
+            return Ok(());
+        };
+
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let (sql, inline_config) = extract_config(&content, &file, &mut self.report);
+
+        let mut config = tree_config(
+            self.project.get("models"),
+            &self.project_name,
+            folders,
+            "dbt_project.yml",
+        );
+        if let Some(entry) = &props {
+            if let Some(Value::Mapping(props_config)) = entry.map.get("config") {
+                for (key, value) in props_config {
+                    if let Some(key) = key.as_str() {
+                        merge_config(&mut config, key, value.clone(), &entry.file);
+                    }
+                }
+            }
+        }
+        for (key, value) in inline_config {
+            merge_config(&mut config, &key, value, &file);
+        }
+
+        let sql = translate_template(&sql, &self.ctx, Some(&name), &file, &mut self.report);
+        report_complex_query(&sql, &file, &mut self.report);
+
+        let mut yaml = Mapping::new();
+        yaml.insert("kind".into(), "sql".into());
+        yaml.insert("version".into(), Value::Number(1.into()));
+        if let Some(entry) = &props {
+            copy_key(&entry.map, &mut yaml, "description");
+        }
+        self.model_config_yaml(&config, &name, &mut yaml);
+        let mut meta = config_mapping(&config, "meta");
+        if let Some(entry) = &props {
+            if let Some(Value::Mapping(props_meta)) = entry.map.get("meta") {
+                meta.extend(props_meta.clone());
+            }
+            self.report_table_tests(&entry.map, &entry.file, &name);
+            self.report_unhandled(
+                &entry.map,
+                &[
+                    "name",
+                    "description",
+                    "config",
+                    "meta",
+                    "columns",
+                    "tests",
+                    "data_tests",
+                ],
+                &entry.file,
+                &name,
+            );
+        }
+        if !meta.is_empty() {
+            yaml.insert("meta".into(), Value::Mapping(meta));
+        }
+        if let Some(entry) = &props {
+            let columns = self.columns_yaml(&entry.map, &entry.file, &name, None, true);
+            if !columns.is_empty() {
+                yaml.insert("columns".into(), Value::Sequence(columns));
+            }
+        }
+
+        write_node_yaml(&node_dir, &name, &yaml)?;
+        let sql_path = node_dir.join(format!("{name}.sql"));
+        std::fs::write(&sql_path, sql)
+            .with_context(|| format!("Failed to write {}", sql_path.display()))?;
+        self.counts.models += 1;
+        Ok(())
+    }
+
+    /// Translate the model configs Featherflow supports into node YAML keys.
+    fn model_config_yaml(&mut self, config: &DbtConfig, this: &str, yaml: &mut Mapping) {
+        for (key, (value, file)) in config {
+            if !model_config_supported(key, value) {
+                self.report.add(
+                    file,
+                    format!("{key}: {}", inline(value)),
+                    "config has no Featherflow equivalent",
+                );
+            }
+        }
+
+        // dbt deletes and re-inserts matching rows when an incremental model
+        // has a unique_key but no strategy; Featherflow's default only appends
+        let delete_insert = config
+            .get("materialized")
+            .is_some_and(|(value, _)| value.as_str() == Some("incremental"))
+            && config.contains_key("unique_key");
+        for key in [
+            "materialized",
+            "schema",
+            "unique_key",
+            "incremental_strategy",
+            "on_schema_change",
+            "enabled",
+        ] {
+            match config.get(key) {
+                Some((value, _)) if model_config_supported(key, value) => {
+                    yaml.insert(key.into(), value.clone());
+                }
+                _ if key == "incremental_strategy" && delete_insert => {
+                    yaml.insert(key.into(), "delete+insert".into());
+                }
+                _ => {}
+            }
+        }
+        for key in ["pre_hook", "post_hook"] {
+            if let Some((value, file)) = config.get(key) {
+                let hooks: Vec<Value> = hook_list(value)
+                    .iter()
+                    .map(|hook| {
+                        translate_template(hook, &self.ctx, Some(this), file, &mut self.report)
+                            .into()
+                    })
+                    .collect();
+                let hooks = match <[Value; 1]>::try_from(hooks) {
+                    Ok([single]) => single,
+                    Err(list) => Value::Sequence(list),
+                };
+                yaml.insert(key.into(), hooks);
+            }
+        }
+        if let Some((tags, _)) = config.get("tags") {
+            yaml.insert("tags".into(), Value::Sequence(tag_list(tags)));
+        }
+    }
+
+    /// One `kind: seed` node per CSV file.
+    fn import_seeds(&mut self) -> Result<()> {
+        const SUPPORTED: &[&str] = &[
+            "schema",
+            "column_types",
+            "delimiter",
+            "quote_columns",
+            "tags",
+            "enabled",
+            "meta",
+        ];
+        for seed_dir in self.paths.seeds.clone() {
+            for path in walk_files(&seed_dir, &["csv"])? {
+                let name = file_stem(&path);
+                let file = self.rel(&path);
+                let props = self.props.seeds.remove(&name);
+                let Some(node_dir) = self.claim_node(&name, &file) else {
+                    continue;
+                };
+
+                let mut config = tree_config(
+                    self.project.get("seeds"),
+                    &self.project_name,
+                    &folders_between(&seed_dir, &path),
+                    "dbt_project.yml",
+                );
+                if let Some(entry) = &props {
+                    if let Some(Value::Mapping(props_config)) = entry.map.get("config") {
+                        for (key, value) in props_config {
+                            if let Some(key) = key.as_str() {
+                                merge_config(&mut config, key, value.clone(), &entry.file);
+                            }
+                        }
+                    }
+                }
+
+                let mut yaml = Mapping::new();
+                yaml.insert("kind".into(), "seed".into());
+                yaml.insert("version".into(), Value::Number(1.into()));
+                if let Some(entry) = &props {
+                    copy_key(&entry.map, &mut yaml, "description");
+                }
+                for (key, (value, file)) in &config {
+                    if !SUPPORTED.contains(&key.as_str()) {
+                        self.report.add(
+                            file,
+                            format!("{key}: {}", inline(value)),
+                            "config has no Featherflow equivalent",
+                        );
+                    }
+                }
+                for key in [
+                    "schema",
+                    "column_types",
+                    "delimiter",
+                    "quote_columns",
+                    "enabled",
+                ] {
+                    if let Some((value, _)) = config.get(key) {
+                        yaml.insert(key.into(), value.clone());
+                    }
+                }
+                if let Some((tags, _)) = config.get("tags") {
+                    yaml.insert("tags".into(), Value::Sequence(tag_list(tags)));
+                }
+                let meta = config_mapping(&config, "meta");
+                if !meta.is_empty() {
+                    yaml.insert("meta".into(), Value::Mapping(meta));
+                }
+                if let Some(entry) = &props {
+                    self.report_unhandled(
+                        &entry.map,
+                        &["name", "description", "config", "columns"],
+                        &entry.file,
+                        &name,
+                    );
+                    let column_types = config_mapping(&config, "column_types");
+                    let columns = self.columns_yaml(
+                        &entry.map,
+                        &entry.file,
+                        &name,
+                        Some(&column_types),
+                        false,
+                    );
+                    if !columns.is_empty() {
+                        yaml.insert("columns".into(), Value::Sequence(columns));
+                    }
+                }
+
+                write_node_yaml(&node_dir, &name, &yaml)?;
+                let csv_path = node_dir.join(format!("{name}.csv"));
+                std::fs::copy(&path, &csv_path)
+                    .with_context(|| format!("Failed to copy {}", path.display()))?;
+                self.counts.seeds += 1;
+            }
+        }
+
+        let mut orphans: Vec<_> = self.props.seeds.drain().collect();
+        orphans.sort_by(|a, b| a.0.cmp(&b.0));
+        for (name, entry) in orphans {
+            self.report.add(
+                entry.file,
+                name,
+                "properties for a seed with no CSV file; skipped",
+            );
+        }
+        Ok(())
+    }
+
+    /// Copy macro files into `macros/` and their `macros:` properties into
+    /// macro documentation.
+    fn import_macros(&mut self) -> Result<()> {
+        for macro_dir in self.paths.macros.clone() {
+            for path in walk_files(&macro_dir, &["sql"])? {
+                self.import_macro_file(&path)?;
+            }
+        }
+
+        let mut docs = Vec::new();
+        for entry in std::mem::take(&mut self.props.macros) {
+            let Some(name) = entry.map.get("name").and_then(Value::as_str) else {
+                continue;
+            };
+            let mut doc = Mapping::new();
+            doc.insert("name".into(), name.into());
+            let description = entry.map.get("description").cloned();
+            doc.insert(
+                "description".into(),
+                description.unwrap_or_else(|| "".into()),
+            );
+            let params: Vec<Value> = mapping_list(entry.map.get("arguments"))
+                .into_iter()
+                .filter(|arg| arg.get("name").is_some())
+                .map(|arg| {
+                    let mut param = Mapping::new();
+                    copy_key(arg, &mut param, "name");
+                    copy_key(arg, &mut param, "type");
+                    copy_key(arg, &mut param, "description");
+                    Value::Mapping(param)
+                })
+                .collect();
+            if !params.is_empty() {
+                doc.insert("params".into(), Value::Sequence(params));
+            }
+            self.report_unhandled(
+                &entry.map,
+                &["name", "description", "arguments", "docs"],
+                &entry.file,
+                name,
+            );
+            docs.push(Value::Mapping(doc));
+        }
+        if !docs.is_empty() {
+            let mut file = Mapping::new();
+            file.insert("macros".into(), Value::Sequence(docs));
+            let content = serde_yaml::to_string(&file).context("Failed to serialize macro docs")?;
+            let path = self.out_root.join("macros").join(MACRO_DOCS_FILE);
+            self.write_new(&path, &content, "macros:")?;
+        }
+        Ok(())
+    }
+
+    fn import_macro_file(&mut self, path: &Path) -> Result<()> {
+        let file = self.rel(path);
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let translated = translate_template(&content, &self.ctx, None, &file, &mut self.report);
+        let target = self
+            .out_root
+            .join("macros")
+            .join(path.file_name().unwrap_or_default());
+        if self.write_new(&target, &translated, &file)? {
+            self.counts.macro_files += 1;
+        }
+        Ok(())
+    }
+
+    /// Generic tests become `test_<name>` macros; singular tests keep their
+    /// path under `tests/`.
+    fn import_tests(&mut self) -> Result<()> {
+        for test_dir in self.paths.tests.clone() {
+            for path in walk_files(&test_dir, &["sql"])? {
+                let content = std::fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read {}", path.display()))?;
+                if content.contains("{% test") || content.contains("{%- test") {
+                    self.import_macro_file(&path)?;
+                    continue;
+                }
+                let file = self.rel(&path);
+                let translated =
+                    translate_template(&content, &self.ctx, None, &file, &mut self.report);
+                let relative = path.strip_prefix(&test_dir).unwrap_or(&path);
+                let target = self.out_root.join("tests").join(relative);
+                if self.write_new(&target, &translated, &file)? {
+                    self.counts.singular_tests += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Markdown files with `{% docs %}` blocks go to the node root, where doc
+    /// block discovery finds them.
+    fn import_doc_files(&mut self) -> Result<()> {
+        for docs_dir in self.paths.docs.clone() {
+            for path in walk_files(&docs_dir, &["md"])? {
+                let content = std::fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read {}", path.display()))?;
+                if !content.contains("docs") || !content.contains("enddocs") {
+                    continue;
+                }
+                let file = self.rel(&path);
+                let target = self
+                    .out_root
+                    .join("nodes")
+                    .join(path.file_name().unwrap_or_default());
+                if self.write_new(&target, &content, &file)? {
+                    self.counts.doc_files += 1;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn report_skipped_resources(&mut self) -> Result<()> {
+        for dir in self.paths.snapshots.clone() {
+            for path in walk_files(&dir, &["sql", "yml", "yaml"])? {
+                let file = self.rel(&path);
+                self.report
+                    .add(file, "snapshot", "snapshots have no Featherflow equivalent");
+            }
+        }
+        for dir in self.paths.analyses.clone() {
+            for path in walk_files(&dir, &["sql"])? {
+                let file = self.rel(&path);
+                self.report
+                    .add(file, "analysis", "analyses are not imported");
+            }
+        }
+        for packages_file in ["packages.yml", "dependencies.yml"] {
+            let path = self.dbt_root.join(packages_file);
+            if !path.is_file() {
+                continue;
+            }
+            let doc = read_yaml_mapping(&path)?;
+            for package in mapping_list(doc.get("packages")) {
+                let name = ["package", "git", "local", "tarball", "name"]
+                    .iter()
+                    .find_map(|key| package.get(*key).and_then(Value::as_str))
+                    .unwrap_or("package");
+                self.report.add(
+                    packages_file,
+                    name,
+                    "dbt packages are not imported; their macros are reported where they are used",
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Write `featherflow.yml` from `dbt_project.yml`, unless the output
+    /// already is a Featherflow project.
+    fn write_config(&mut self) -> Result<()> {
+        let vars = self.project_vars();
+        let hooks: Vec<(&str, Vec<Value>)> = [
+            ("on-run-start", "on_run_start"),
+            ("on-run-end", "on_run_end"),
+        ]
+        .into_iter()
+        .filter_map(|(dbt_key, key)| {
+            let hooks = hook_list(self.project.get(dbt_key)?);
+            Some((key, hooks))
+        })
+        .map(|(key, hooks)| {
+            let translated = hooks
+                .iter()
+                .map(|hook| {
+                    translate_template(hook, &self.ctx, None, "dbt_project.yml", &mut self.report)
+                        .into()
+                })
+                .collect();
+            (key, translated)
+        })
+        .collect();
+
+        let existing = ["featherflow.yml", "featherflow.yaml"]
+            .iter()
+            .any(|name| self.out_root.join(name).exists());
+        if existing {
+            if !vars.is_empty() {
+                self.report.add(
+                    "dbt_project.yml",
+                    "vars",
+                    "featherflow.yml already exists; merge the vars by hand",
+                );
+            }
+            for (key, _) in &hooks {
+                self.report.add(
+                    "dbt_project.yml",
+                    *key,
+                    "featherflow.yml already exists; merge the hooks by hand",
+                );
+            }
+            return Ok(());
+        }
+
+        let mut config = Mapping::new();
+        config.insert("name".into(), self.project_name.clone().into());
+        let version = self
+            .project
+            .get("version")
+            .and_then(Value::as_str)
+            .unwrap_or("1.0.0");
+        config.insert("version".into(), version.into());
+        config.insert("materialization".into(), "view".into());
+        config.insert("dialect".into(), "duckdb".into());
+        let mut connection = Mapping::new();
+        connection.insert("type".into(), "duckdb".into());
+        connection.insert("path".into(), "target/dev.duckdb".into());
+        let mut database = Mapping::new();
+        database.insert("default".into(), Value::Mapping(connection));
+        config.insert("database".into(), Value::Mapping(database));
+        if !vars.is_empty() {
+            config.insert("vars".into(), Value::Mapping(vars));
+        }
+        for (key, hooks) in hooks {
+            config.insert(key.into(), Value::Sequence(hooks));
+        }
+
+        let profile = self
+            .project
+            .get("profile")
+            .and_then(Value::as_str)
+            .unwrap_or(self.project_name.as_str())
+            .to_string();
+        self.report.add(
+            "dbt_project.yml",
+            format!("profile: {profile}"),
+            "connection settings are not imported; featherflow.yml connects to DuckDB at target/dev.duckdb",
+        );
+
+        let content =
+            serde_yaml::to_string(&config).context("Failed to serialize featherflow.yml")?;
+        let path = self.out_root.join("featherflow.yml");
+        std::fs::write(&path, content)
+            .with_context(|| format!("Failed to write {}", path.display()))
+    }
+
+    /// Project vars, with the vars dbt scopes to this project flattened in.
+    fn project_vars(&self) -> Mapping {
+        let mut vars = Mapping::new();
+        let Some(Value::Mapping(declared)) = self.project.get("vars") else {
+            return vars;
+        };
+        for (key, value) in declared {
+            match (key.as_str(), value) {
+                (Some(scope), Value::Mapping(scoped)) if scope == self.project_name => {
+                    vars.extend(scoped.clone());
+                }
+                _ => {
+                    vars.insert(key.clone(), value.clone());
+                }
+            }
+        }
+        vars
+    }
+
+    /// Translate the `columns:` of a model, seed, or source table.
+    ///
+    /// `column_types` supplies types for seed columns; `primary_key` allows
+    /// the `primary_key` constraint, which only models support.
+    fn columns_yaml(
+        &mut self,
+        entry: &Mapping,
+        file: &str,
+        context: &str,
+        column_types: Option<&Mapping>,
+        primary_key: bool,
+    ) -> Vec<Value> {
+        let mut columns = Vec::new();
+        let mut untyped = 0usize;
+        for column in mapping_list(entry.get("columns")) {
+            let Some(name) = column.get("name").and_then(Value::as_str) else {
+                continue;
+            };
+            let location = format!("{context}.{name}");
+            let mut out = Mapping::new();
+            out.insert("name".into(), name.into());
+            let data_type = column
+                .get("data_type")
+                .or_else(|| column_types.and_then(|types| types.get(name)))
+                .and_then(Value::as_str);
+            match data_type {
+                Some(data_type) => out.insert("type".into(), data_type.into()),
+                None => {
+                    untyped += 1;
+                    out.insert("type".into(), UNKNOWN_TYPE.into())
+                }
+            };
+            copy_key(column, &mut out, "description");
+
+            let mut tests = Vec::new();
+            for key in ["tests", "data_tests"] {
+                if let Some(Value::Sequence(defs)) = column.get(key) {
+                    for def in defs {
+                        tests.extend(self.translate_test(def, file, &location));
+                    }
+                }
+            }
+            for constraint in mapping_list(column.get("constraints")) {
+                match constraint.get("type").and_then(Value::as_str) {
+                    Some("not_null") => tests.push("not_null".into()),
+                    Some("unique") => tests.push("unique".into()),
+                    Some("primary_key") if primary_key => {
+                        out.insert("primary_key".into(), true.into());
+                    }
+                    other => self.report.add(
+                        file,
+                        format!("{location}: constraint {}", other.unwrap_or("?")),
+                        "constraint has no Featherflow equivalent",
+                    ),
+                }
+            }
+            let mut seen = HashSet::new();
+            tests.retain(|test| seen.insert(inline(test)));
+            if !tests.is_empty() {
+                out.insert("tests".into(), Value::Sequence(tests));
+            }
+            self.report_unhandled(
+                column,
+                &[
+                    "name",
+                    "description",
+                    "data_type",
+                    "tests",
+                    "data_tests",
+                    "constraints",
+                ],
+                file,
+                &location,
+            );
+            columns.push(Value::Mapping(out));
+        }
+        if untyped > 0 {
+            self.report.add(
+                file,
+                format!("{context}: {untyped} columns without data_type"),
+                format!("written as type {UNKNOWN_TYPE}; declare the real types"),
+            );
+        }
+        columns
+    }
+
+    /// Translate one dbt column test into Featherflow tests.
+    fn translate_test(&mut self, def: &Value, file: &str, location: &str) -> Vec<Value> {
+        let (name, params) = match def {
+            Value::String(name) => (name.as_str(), Mapping::new()),
+            Value::Mapping(map) if map.len() == 1 => {
+                let (name, params) = map.iter().next().expect("len is 1");
+                let Some(name) = name.as_str() else {
+                    return Vec::new();
+                };
+                (name, params.as_mapping().cloned().unwrap_or_default())
+            }
+            other => {
+                self.report.add(
+                    file,
+                    format!("{location}: {}", inline(other)),
+                    "test syntax not recognized; skipped",
+                );
+                return Vec::new();
+            }
+        };
+
+        // dbt 1.10 nests test arguments under `arguments:`
+        let mut args = Mapping::new();
+        for (key, value) in params {
+            match (key.as_str(), value) {
+                (Some("arguments"), Value::Mapping(nested)) => args.extend(nested),
+                (Some(key), _) if TEST_CONFIG_KEYS.contains(&key) => self.report.add(
+                    file,
+                    format!("{location}: {name} {key}"),
+                    "test configuration is not supported; the test runs with default settings",
+                ),
+                (_, value) => {
+                    args.insert(key, value);
+                }
+            }
+        }
+        let arg = |key: &str| args.get(key).cloned();
+
+        let translated = match name {
+            "unique" | "not_null" => vec![Value::from(name)],
+            "accepted_values" => match arg("values") {
+                Some(values @ Value::Sequence(_)) => {
+                    let quote = arg("quote").and_then(|q| q.as_bool()).unwrap_or(true);
+                    vec![parameterized(
+                        "accepted_values",
+                        [("values", values), ("quote", quote.into())],
+                    )]
+                }
+                _ => Vec::new(),
+            },
+            "relationships" => {
+                let to = arg("to")
+                    .and_then(|to| to.as_str().map(str::to_string))
+                    .and_then(|to| relation_name(&to, &self.ctx));
+                match to {
+                    Some(to) => {
+                        let mut params = vec![("to", Value::from(to))];
+                        if let Some(field) = arg("field") {
+                            params.push(("field", field));
+                        }
+                        vec![parameterized("relationships", params)]
+                    }
+                    None => Vec::new(),
+                }
+            }
+            "dbt_utils.accepted_range"
+                if arg("inclusive").and_then(|v| v.as_bool()) != Some(false) =>
+            {
+                let mut tests = Vec::new();
+                for (dbt_key, key) in [("min_value", "min_value"), ("max_value", "max_value")] {
+                    if let Some(value @ Value::Number(_)) = arg(dbt_key) {
+                        tests.push(parameterized(key, [("value", value)]));
+                    }
+                }
+                tests
+            }
+            "dbt_expectations.expect_column_values_to_match_regex" => match arg("regex") {
+                Some(pattern @ Value::String(_)) => {
+                    vec![parameterized("regex", [("pattern", pattern)])]
+                }
+                _ => Vec::new(),
+            },
+            _ => Vec::new(),
+        };
+
+        if translated.is_empty() {
+            self.report.add(
+                file,
+                format!("{location}: {name}"),
+                "test has no Featherflow equivalent; write a singular test",
+            );
+        }
+        translated
+    }
+
+    /// Model- and table-level tests have no YAML equivalent.
+    fn report_table_tests(&mut self, entry: &Mapping, file: &str, context: &str) {
+        for key in ["tests", "data_tests"] {
+            for def in entry
+                .get(key)
+                .and_then(Value::as_sequence)
+                .map(Vec::as_slice)
+                .unwrap_or_default()
+            {
+                let name = match def {
+                    Value::String(name) => name.clone(),
+                    Value::Mapping(map) => map
+                        .keys()
+                        .next()
+                        .and_then(Value::as_str)
+                        .unwrap_or("test")
+                        .to_string(),
+                    _ => continue,
+                };
+                self.report.add(
+                    file,
+                    format!("{context}: {name}"),
+                    "model-level tests are not supported; write a singular test",
+                );
+            }
+        }
+    }
+
+    /// Report every key of a properties entry outside `handled`.
+    fn report_unhandled(&mut self, entry: &Mapping, handled: &[&str], file: &str, context: &str) {
+        for (key, value) in entry {
+            let Some(key) = key.as_str() else { continue };
+            if !handled.contains(&key) {
+                self.report.add(
+                    file,
+                    format!("{context}: {key}: {}", inline(value)),
+                    "property has no Featherflow equivalent",
+                );
+            }
+        }
+    }
+
+    /// Reserve a node name, returning its directory unless another imported
+    /// resource took the name or the directory already exists.
+    fn claim_node(&mut self, name: &str, file: &str) -> Option<PathBuf> {
+        if !self.nodes.insert(name.to_string()) {
+            self.report.add(
+                file,
+                name,
+                "another imported resource already uses this name; skipped",
+            );
+            return None;
+        }
+        let dir = self.out_root.join("nodes").join(name);
+        if dir.exists() {
+            self.report
+                .add(file, name, "node directory already exists; skipped");
+            return None;
+        }
+        Some(dir)
+    }
+
+    /// Write a file unless it exists, reporting the conflict instead.
+    fn write_new(&mut self, path: &Path, content: &str, file: &str) -> Result<bool> {
+        if path.exists() {
+            let target = path
+                .strip_prefix(self.out_root)
+                .unwrap_or(path)
+                .display()
+                .to_string();
+            self.report
+                .add(file, target, "target file already exists; left unchanged");
+            return Ok(false);
+        }
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create directory: {}", parent.display()))?;
+        }
+        std::fs::write(path, content)
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        Ok(true)
+    }
+
+    /// Path relative to the dbt project, for report locations.
+    fn rel(&self, path: &Path) -> String {
+        path.strip_prefix(self.dbt_root)
+            .unwrap_or(path)
+            .display()
+            .to_string()
+    }
+}
+
+/// Collect the configs that apply to a resource from a `dbt_project.yml`
+/// `models:`/`seeds:` tree: root-level configs, then the project's, then
+/// each folder's down to the resource's directory.
+fn tree_config(
+    tree: Option<&Value>,
+    project_name: &str,
+    folders: &[String],
+    file: &str,
+) -> DbtConfig {
+    const MAPPING_CONFIGS: &[&str] = &[
+        "meta",
+        "column_types",
+        "persist_docs",
+        "docs",
+        "grants",
+        "contract",
+    ];
+
+    let mut config = DbtConfig::new();
+    let Some(mut level) = tree.and_then(Value::as_mapping) else {
+        return config;
+    };
+    let collect = |level: &Mapping, config: &mut DbtConfig| {
+        for (key, value) in level {
+            let Some(key) = key.as_str() else { continue };
+            if key.starts_with('+') || !value.is_mapping() || MAPPING_CONFIGS.contains(&key) {
+                merge_config(config, key, value.clone(), file);
+            }
+        }
+    };
+    collect(level, &mut config);
+    for segment in std::iter::once(project_name).chain(folders.iter().map(String::as_str)) {
+        match level.get(segment).and_then(Value::as_mapping) {
+            Some(next) => {
+                level = next;
+                collect(level, &mut config);
+            }
+            None => break,
+        }
+    }
+    config
+}
+
+/// Set a config value the way dbt layers them: later values win, except
+/// that tags accumulate and meta merges.
+fn merge_config(config: &mut DbtConfig, key: &str, value: Value, file: &str) {
+    let key = key.trim_start_matches('+').replace('-', "_");
+    let value = match (key.as_str(), config.get(&key)) {
+        ("tags", Some((existing, _))) => {
+            let mut tags = tag_list(existing);
+            for tag in tag_list(&value) {
+                if !tags.contains(&tag) {
+                    tags.push(tag);
+                }
+            }
+            Value::Sequence(tags)
+        }
+        ("meta", Some((Value::Mapping(existing), _))) => {
+            let mut meta = existing.clone();
+            if let Value::Mapping(new) = value {
+                meta.extend(new);
+            }
+            Value::Mapping(meta)
+        }
+        _ => value,
+    };
+    config.insert(key, (value, file.to_string()));
+}
+
+fn config_mapping(config: &DbtConfig, key: &str) -> Mapping {
+    match config.get(key) {
+        Some((Value::Mapping(map), _)) => map.clone(),
+        _ => Mapping::new(),
+    }
+}
+
+/// Whether a model config translates to a node YAML key.
+fn model_config_supported(key: &str, value: &Value) -> bool {
+    match key {
+        "materialized" => one_of(value, &["view", "table", "incremental", "ephemeral"]),
+        "incremental_strategy" => one_of(
+            value,
+            &["append", "merge", "delete+insert", "delete_insert"],
+        ),
+        "on_schema_change" => one_of(value, &["ignore", "fail", "append_new_columns"]),
+        "schema" => value.is_string(),
+        "unique_key" => value.is_string() || value.is_sequence(),
+        "enabled" => value.is_bool(),
+        "pre_hook" | "post_hook" | "tags" | "meta" => true,
+        _ => false,
+    }
+}
+
+fn one_of(value: &Value, allowed: &[&str]) -> bool {
+    value.as_str().is_some_and(|v| allowed.contains(&v))
+}
+
+fn parameterized<'a>(name: &str, params: impl IntoIterator<Item = (&'a str, Value)>) -> Value {
+    let params: Mapping = params
+        .into_iter()
+        .map(|(key, value)| (Value::from(key), value))
+        .collect();
+    let mut test = Mapping::new();
+    test.insert(name.into(), Value::Mapping(params));
+    Value::Mapping(test)
+}
+
+fn tag_list(value: &Value) -> Vec<Value> {
+    match value {
+        Value::String(tag) => vec![tag.as_str().into()],
+        Value::Sequence(tags) => tags.iter().filter(|t| t.is_string()).cloned().collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Hook SQL from a dbt hook config: a string, a `{sql: ...}` mapping, or a list of either.
+fn hook_list(value: &Value) -> Vec<String> {
+    match value {
+        Value::String(sql) => vec![sql.clone()],
+        Value::Mapping(map) => map
+            .get("sql")
+            .and_then(Value::as_str)
+            .map(|sql| vec![sql.to_string()])
+            .unwrap_or_default(),
+        Value::Sequence(hooks) => hooks.iter().flat_map(hook_list).collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn string_list(value: &Value) -> Vec<String> {
+    match value {
+        Value::String(s) => vec![s.clone()],
+        Value::Sequence(items) => items
+            .iter()
+            .filter_map(Value::as_str)
+            .map(str::to_string)
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+fn mapping_list(value: Option<&Value>) -> Vec<&Mapping> {
+    value
+        .and_then(Value::as_sequence)
+        .map(|items| items.iter().filter_map(Value::as_mapping).collect())
+        .unwrap_or_default()
+}
+
+fn copy_key(from: &Mapping, to: &mut Mapping, key: &str) {
+    if let Some(value) = from.get(key) {
+        to.insert(key.into(), value.clone());
+    }
+}
+
+/// A YAML value on one line, for report entries.
+fn inline(value: &Value) -> String {
+    serde_json::to_string(value).unwrap_or_else(|_| format!("{value:?}"))
+}
+
+fn file_stem(path: &Path) -> String {
+    path.file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
+
+/// Directory names between a resource root and a file inside it.
+fn folders_between(root: &Path, path: &Path) -> Vec<String> {
+    path.parent()
+        .and_then(|parent| parent.strip_prefix(root).ok())
+        .map(|rel| {
+            rel.components()
+                .map(|c| c.as_os_str().to_string_lossy().into_owned())
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn read_yaml_mapping(path: &Path) -> Result<Mapping> {
+    let content = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?;
+    let value: Value = serde_yaml::from_str(&content)
+        .with_context(|| format!("Failed to parse {}", path.display()))?;
+    Ok(match value {
+        Value::Mapping(map) => map,
+        _ => Mapping::new(),
+    })
+}
+
+fn write_node_yaml(node_dir: &Path, name: &str, yaml: &Mapping) -> Result<()> {
+    std::fs::create_dir_all(node_dir)
+        .with_context(|| format!("Failed to create directory: {}", node_dir.display()))?;
+    let content = serde_yaml::to_string(yaml).context("Failed to serialize node YAML")?;
+    let path = node_dir.join(format!("{name}.yml"));
+    std::fs::write(&path, content).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Files under `dir` with one of `extensions`, recursively and sorted,
+/// skipping hidden entries.
+fn walk_files(dir: &Path, extensions: &[&str]) -> Result<Vec<PathBuf>> {
+    let mut files = Vec::new();
+    if !dir.is_dir() {
+        return Ok(files);
+    }
+    let mut pending = vec![dir.to_path_buf()];
+    while let Some(current) = pending.pop() {
+        let entries = std::fs::read_dir(&current)
+            .with_context(|| format!("Failed to read directory: {}", current.display()))?;
+        for entry in entries.flatten() {
+            let path = entry.path();
+            if entry.file_name().to_string_lossy().starts_with('.') {
+                continue;
+            }
+            if path.is_dir() {
+                pending.push(path);
+            } else if path
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| extensions.contains(&ext))
+            {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+#[cfg(test)]
+#[path = "dbt_test.rs"]
+mod tests;
@@ -0,0 +1,230 @@
+use super::*;
+use crate::commands::test_support::write;
+use ff_core::Project;
+use std::fs;
+use tempfile::tempdir;
+
+fn write_dbt_project(root: &Path) {
+    write(
+        root,
+        "dbt_project.yml",
+        r#"
+name: jaffle
+version: "1.0.0"
+profile: jaffle
+config-version: 2
+vars:
+  start_date: "2024-01-01"
+models:
+  jaffle:
+    +materialized: view
+    marts:
+      +materialized: table
+      +persist_docs:
+        relation: true
+"#,
+    );
+    write(
+        root,
+        "models/staging/sources.yml",
+        r#"
+version: 2
+sources:
+  - name: shop
+    schema: raw
+    tables:
+      - name: orders
+        identifier: raw_orders
+        columns:
+          - name: id
+            data_type: integer
+            tests: [unique, not_null]
+          - name: status
+            data_type: varchar
+"#,
+    );
+    write(
+        root,
+        "models/staging/schema.yml",
+        r#"
+version: 2
+models:
+  - name: stg_orders
+    description: Orders, one row per order
+    columns:
+      - name: id
+        data_type: integer
+        data_tests:
+          - unique
+          - not_null
+      - name: status
+        tests:
+          - accepted_values:
+              values: [placed, shipped]
+              config:
+                severity: warn
+  - name: fct_orders
+    columns:
+      - name: order_id
+        data_type: integer
+        tests:
+          - relationships:
+              to: ref('stg_orders')
+              field: id
+          - dbt_utils.expression_is_true:
+              expression: "> 0"
+"#,
+    );
+    write(
+        root,
+        "models/staging/stg_orders.sql",
+        "select id, status from {{ source('shop', 'orders') }}\n",
+    );
+    write(
+        root,
+        "models/marts/fct_orders.sql",
+        "{{ config(materialized='incremental', unique_key='order_id') }}\n\
+         select id as order_id from {{ ref('stg_orders') }}\n",
+    );
+    write(root, "seeds/countries.csv", "code,name\nNL,Netherlands\n");
+    write(
+        root,
+        "macros/cents.sql",
+        "{% macro cents(col) %}{{ col }} / 100{% endmacro %}\n",
+    );
+    write(
+        root,
+        "tests/generic/positive.sql",
+        "{% test positive_value(model, column_name) %}\n\
+         select * from {{ model }} where {{ column_name }} <= 0\n\
+         {% endtest %}\n",
+    );
+    write(
+        root,
+        "tests/assert_no_negative_orders.sql",
+        "select * from {{ ref('fct_orders') }} where order_id < 0\n",
+    );
+    write(
+        root,
+        "snapshots/orders_snapshot.sql",
+        "{% snapshot orders_snapshot %}select 1{% endsnapshot %}\n",
+    );
+}
+
+fn node_yaml(root: &Path, name: &str) -> Value {
+    let content = fs::read_to_string(root.join(format!("nodes/{name}/{name}.yml"))).unwrap();
+    serde_yaml::from_str(&content).unwrap()
+}
+
+fn constructs(report: &ImportReport) -> Vec<&str> {
+    report.issues.iter().map(|i| i.construct.as_str()).collect()
+}
+
+#[test]
+fn test_import_dbt_project() {
+    let dbt = tempdir().unwrap();
+    let out = tempdir().unwrap();
+    write_dbt_project(dbt.path());
+
+    let (counts, report) = import_project(dbt.path(), out.path()).unwrap();
+
+    assert_eq!(counts.models, 2);
+    assert_eq!(counts.sources, 1);
+    assert_eq!(counts.seeds, 1);
+    assert_eq!(counts.macro_files, 2);
+    assert_eq!(counts.singular_tests, 1);
+
+    let project = Project::load(out.path()).unwrap();
+    assert_eq!(project.models.len(), 2);
+    assert_eq!(project.seeds.len(), 1);
+    assert_eq!(project.sources.len(), 1);
+    assert_eq!(project.singular_tests.len(), 1);
+    assert_eq!(project.config.vars["start_date"], Value::from("2024-01-01"));
+
+    let fct = node_yaml(out.path(), "fct_orders");
+    assert_eq!(fct["materialized"], Value::from("incremental"));
+    assert_eq!(fct["unique_key"], Value::from("order_id"));
+    assert_eq!(fct["incremental_strategy"], Value::from("delete+insert"));
+    assert_eq!(
+        fct["columns"][0]["tests"][0]["relationships"]["to"],
+        Value::from("stg_orders")
+    );
+    let fct_sql = fs::read_to_string(out.path().join("nodes/fct_orders/fct_orders.sql")).unwrap();
+    assert_eq!(fct_sql, "select id as order_id from stg_orders\n");
+
+    let stg = node_yaml(out.path(), "stg_orders");
+    assert_eq!(stg["materialized"], Value::from("view"));
+    assert_eq!(stg["columns"][1]["type"], Value::from(UNKNOWN_TYPE));
+    assert_eq!(
+        stg["columns"][1]["tests"][0]["accepted_values"]["quote"],
+        Value::Bool(true)
+    );
+    let stg_sql = fs::read_to_string(out.path().join("nodes/stg_orders/stg_orders.sql")).unwrap();
+    assert!(stg_sql.contains("from raw.raw_orders"), "{stg_sql}");
+
+    let generic = fs::read_to_string(out.path().join("macros/positive.sql")).unwrap();
+    assert!(generic.starts_with("{% macro test_positive_value(model, column_name) %}"));
+    let singular =
+        fs::read_to_string(out.path().join("tests/assert_no_negative_orders.sql")).unwrap();
+    assert!(singular.contains("from fct_orders"), "{singular}");
+
+    let constructs = constructs(&report);
+    assert!(
+        constructs.contains(&"persist_docs: {\"relation\":true}"),
+        "{constructs:?}"
+    );
+    assert!(constructs.contains(&"fct_orders.order_id: dbt_utils.expression_is_true"));
+    assert!(constructs.contains(&"stg_orders.status: accepted_values config"));
+    assert!(constructs.contains(&"stg_orders: 1 columns without data_type"));
+    assert!(constructs.contains(&"snapshot"));
+    assert!(constructs.contains(&"profile: jaffle"));
+}
+
+#[test]
+fn test_import_leaves_existing_files_alone() {
+    let dbt = tempdir().unwrap();
+    let out = tempdir().unwrap();
+    write_dbt_project(dbt.path());
+    let config = "name: existing\n";
+    write(out.path(), "featherflow.yml", config);
+    write(out.path(), "nodes/stg_orders/stg_orders.sql", "select 1\n");
+
+    let (counts, report) = import_project(dbt.path(), out.path()).unwrap();
+
+    assert_eq!(counts.models, 1);
+    assert_eq!(
+        fs::read_to_string(out.path().join("featherflow.yml")).unwrap(),
+        config
+    );
+    assert_eq!(
+        fs::read_to_string(out.path().join("nodes/stg_orders/stg_orders.sql")).unwrap(),
+        "select 1\n"
+    );
+    let constructs = constructs(&report);
+    assert!(constructs.contains(&"stg_orders"), "{constructs:?}");
+    assert!(constructs.contains(&"vars"), "{constructs:?}");
+}
+
+#[test]
+fn test_import_reports_ctes() {
+    let dbt = tempdir().unwrap();
+    let out = tempdir().unwrap();
+    write_dbt_project(dbt.path());
+    write(
+        dbt.path(),
+        "models/marts/order_totals.sql",
+        "with orders as (\n    select * from {{ ref('stg_orders') }}\n)\n\
+         select status, count(*) as total from orders group by status\n",
+    );
+
+    let (counts, report) = import_project(dbt.path(), out.path()).unwrap();
+
+    assert_eq!(counts.models, 3);
+    let issue = report
+        .issues
+        .iter()
+        .find(|i| i.construct == "WITH orders")
+        .unwrap_or_else(|| panic!("{:?}", constructs(&report)));
+    assert_eq!(issue.location, "models/marts/order_totals.sql");
+    assert!(issue.note.contains("its own node"), "{}", issue.note);
+}
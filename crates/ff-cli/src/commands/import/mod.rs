@@ -0,0 +1,16 @@
+//! Import command implementation — convert projects from other tools into node layout
+
+mod dbt;
+mod report;
+mod translate;
+
+use anyhow::Result;
+
+use crate::cli::{GlobalArgs, ImportArgs, ImportCommands};
+
+/// Execute the import command
+pub(crate) async fn execute(args: &ImportArgs, global: &GlobalArgs) -> Result<()> {
+    match &args.command {
+        ImportCommands::Dbt(sub) => dbt::execute(sub, global).await,
+    }
+}
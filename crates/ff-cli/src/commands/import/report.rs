@@ -0,0 +1,90 @@
+//! Report of the constructs an import could not translate
+
+use std::fmt::Write;
+
+/// A construct that was dropped or left as-is because it has no equivalent
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(super) struct ImportIssue {
+    /// Where the construct was found, relative to the imported project (`path[:line]`)
+    pub location: String,
+    /// The construct itself (`config(alias=...)`, `dbt_utils.star`, ...)
+    pub construct: String,
+    /// What the user has to do about it
+    pub note: String,
+}
+
+/// How many resources of each kind were written
+#[derive(Debug, Default)]
+pub(super) struct ImportCounts {
+    pub models: usize,
+    pub sources: usize,
+    pub seeds: usize,
+    pub macro_files: usize,
+    pub singular_tests: usize,
+    pub doc_files: usize,
+}
+
+impl std::fmt::Display for ImportCounts {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} models, {} sources, {} seeds, {} macro files, {} singular tests, {} doc files",
+            self.models,
+            self.sources,
+            self.seeds,
+            self.macro_files,
+            self.singular_tests,
+            self.doc_files
+        )
+    }
+}
+
+/// Everything an import did not carry over, in the order it was found
+#[derive(Debug, Default)]
+pub(super) struct ImportReport {
+    pub issues: Vec<ImportIssue>,
+}
+
+impl ImportReport {
+    /// Record an untranslated construct, ignoring exact repeats.
+    pub(super) fn add(
+        &mut self,
+        location: impl Into<String>,
+        construct: impl Into<String>,
+        note: impl Into<String>,
+    ) {
+        let issue = ImportIssue {
+            location: location.into(),
+            construct: construct.into(),
+            note: note.into(),
+        };
+        if !self.issues.contains(&issue) {
+            self.issues.push(issue);
+        }
+    }
+
+    /// Render the report as markdown.
+    pub(super) fn to_markdown(&self, title: &str, counts: &ImportCounts) -> String {
+        let mut out = format!("# {title}\n\nImported {counts}.\n\n");
+        if self.issues.is_empty() {
+            out.push_str("Every construct was translated.\n");
+            return out;
+        }
+        let _ = writeln!(out, "## Not translated ({})\n", self.issues.len());
+        out.push_str("| Location | Construct | Note |\n|---|---|---|\n");
+        for issue in &self.issues {
+            let _ = writeln!(
+                out,
+                "| {} | `{}` | {} |",
+                escape_cell(&issue.location),
+                escape_cell(&issue.construct).replace('`', "'"),
+                escape_cell(&issue.note)
+            );
+        }
+        out
+    }
+}
+
+fn escape_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
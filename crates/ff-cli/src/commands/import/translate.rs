@@ -0,0 +1,442 @@
+//! Rewriting dbt Jinja into Featherflow templates
+//!
+//! dbt resolves relations through `ref()` and `source()`, while Featherflow
+//! reads dependencies from the table names in the rendered SQL. Standalone
+//! `{{ ref('x') }}` and `{{ source('s', 't') }}` calls are therefore replaced
+//! by the bare model name and the source table's qualified name, which is
+//! what the SQL extractor resolves. dbt context with a Featherflow
+//! counterpart is renamed; everything else is left in place and reported.
+
+use ff_sql::{SqlError, SqlParser};
+use regex::{Captures, NoExpand, Regex};
+use serde_yaml::{Mapping, Value};
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+use super::report::ImportReport;
+
+const REF_CALL: &str = r#"ref\(\s*['"](\w+)['"]\s*(?:,\s*['"](\w+)['"]\s*)?(?:,\s*(?:v|version)\s*=\s*([^)]*?)\s*)?\)"#;
+const SOURCE_CALL: &str = r#"source\(\s*['"](\w+)['"]\s*,\s*['"](\w+)['"]\s*\)"#;
+
+static REF_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(r"\{{\{{-?\s*{REF_CALL}\s*-?\}}\}}")).expect("valid regex literal")
+});
+
+static REF_CALL_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!("^{REF_CALL}$")).expect("valid regex literal"));
+
+static SOURCE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(r"\{{\{{-?\s*{SOURCE_CALL}\s*-?\}}\}}")).expect("valid regex literal")
+});
+
+static SOURCE_CALL_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!("^{SOURCE_CALL}$")).expect("valid regex literal"));
+
+static CONFIG_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?s)\{\{-?\s*config\((.*?)\)\s*-?\}\}[ \t]*\n?").expect("valid regex literal")
+});
+
+static THIS_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{\{-?\s*this\s*-?\}\}").expect("valid regex literal"));
+
+static TEST_BLOCK_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{%(-?)\s*test\s+(\w+)\s*\(").expect("valid regex literal"));
+
+static END_TEST_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\{%(-?)\s*endtest\s*(-?)%\}").expect("valid regex literal"));
+
+static TAG_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)\{\{.*?\}\}|\{%.*?%\}").expect("valid regex literal"));
+
+static COMMENT_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?s)\{#.*?#\}").expect("valid regex literal"));
+
+static STRING_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"'(?:[^'\\]|\\.)*'|"(?:[^"\\]|\\.)*""#).expect("valid regex literal")
+});
+
+static IDENT_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?:^|[^\w.])([A-Za-z_]\w*(?:\.\w+)?)").expect("valid regex literal")
+});
+
+static RENAME_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(^|[^\w.])(is_incremental|env_var|execute|fromjson|tojson)\b")
+        .expect("valid regex literal")
+});
+
+/// What the rewrite needs to know about the project being imported
+#[derive(Debug, Default)]
+pub(super) struct TranslateContext {
+    /// `(source, table)` -> qualified `schema.identifier`
+    pub sources: HashMap<(String, String), String>,
+}
+
+impl TranslateContext {
+    /// Qualified name of a source table declared in a properties file
+    fn source_table(&self, source: &str, table: &str) -> Option<&str> {
+        self.sources
+            .get(&(source.to_string(), table.to_string()))
+            .map(String::as_str)
+    }
+}
+
+/// Rewrite the dbt Jinja in a model, macro, test, or hook.
+///
+/// `this` is the node name `{{ this }}` stands for, when there is one.
+/// `file` is used for report locations.
+pub(super) fn translate_template(
+    text: &str,
+    ctx: &TranslateContext,
+    this: Option<&str>,
+    file: &str,
+    report: &mut ImportReport,
+) -> String {
+    let text = TEST_BLOCK_RE.replace_all(text, "{%$1 macro test_$2(");
+    let text = END_TEST_RE.replace_all(&text, "{%$1 endmacro $2%}");
+
+    let text = REF_RE.replace_all(&text, |caps: &Captures<'_>| {
+        let location = format!("{file}:{}", line_of(&text, match_start(caps)));
+        let model = caps
+            .get(2)
+            .or_else(|| caps.get(1))
+            .map_or("", |m| m.as_str());
+        if caps.get(2).is_some() {
+            report.add(
+                &location,
+                &caps[0],
+                format!("cross-project ref; resolved to the local name '{model}'"),
+            );
+        }
+        if let Some(version) = caps.get(3) {
+            report.add(
+                &location,
+                &caps[0],
+                format!(
+                    "model versions are not supported; resolved to '{model}' instead of version {}",
+                    version.as_str()
+                ),
+            );
+        }
+        model.to_string()
+    });
+
+    let text = SOURCE_RE.replace_all(&text, |caps: &Captures<'_>| {
+        match ctx.source_table(&caps[1], &caps[2]) {
+            Some(qualified) => qualified.to_string(),
+            None => {
+                let location = format!("{file}:{}", line_of(&text, match_start(caps)));
+                report.add(
+                    location,
+                    &caps[0],
+                    "source table is not declared in any properties file; declare it in a source node",
+                );
+                format!("{}.{}", &caps[1], &caps[2])
+            }
+        }
+    });
+
+    let text = match this {
+        Some(name) => THIS_RE.replace_all(&text, NoExpand(name)).into_owned(),
+        None => text.into_owned(),
+    };
+
+    TAG_RE
+        .replace_all(&text, |caps: &Captures<'_>| {
+            let tag = &caps[0];
+            let location = format!("{file}:{}", line_of(&text, match_start(caps)));
+            let code = STRING_RE.replace_all(tag, "''");
+            for ident in IDENT_RE.captures_iter(&code) {
+                let name = &ident[1];
+                let head = name.split('.').next().unwrap_or(name);
+                if let Some(note) = dbt_only_note(head) {
+                    report.add(&location, name, note);
+                }
+            }
+            RENAME_RE
+                .replace_all(tag, |c: &Captures<'_>| {
+                    format!("{}{}", &c[1], featherflow_name(&c[2]))
+                })
+                .into_owned()
+        })
+        .into_owned()
+}
+
+/// Remove standalone `{{ config(...) }}` calls from a model, returning the
+/// remaining SQL and the literal keyword arguments in call order.
+pub(super) fn extract_config(
+    text: &str,
+    file: &str,
+    report: &mut ImportReport,
+) -> (String, Vec<(String, Value)>) {
+    let mut config = Vec::new();
+    let stripped = CONFIG_RE.replace_all(text, |caps: &Captures<'_>| {
+        let location = format!("{file}:{}", line_of(text, match_start(caps)));
+        for (key, value) in parse_kwargs(&caps[1]) {
+            match value {
+                Ok(value) => config.push((key, value)),
+                Err(expr) if key.is_empty() => report.add(
+                    &location,
+                    format!("config({expr})"),
+                    "positional config argument; set it in the node YAML by hand",
+                ),
+                Err(expr) => report.add(
+                    &location,
+                    format!("config({key}={expr})"),
+                    "config value is not a literal; set it in the node YAML by hand",
+                ),
+            }
+        }
+        String::new()
+    });
+    (stripped.into_owned(), config)
+}
+
+/// Report CTEs and derived tables in a translated model. Featherflow rejects
+/// both, since each transform is its own node.
+///
+/// Jinja expressions stand in as a placeholder name and tags are dropped so
+/// the SQL parses; SQL that still does not parse is only checked for a
+/// leading `WITH`.
+pub(super) fn report_complex_query(sql: &str, location: &str, report: &mut ImportReport) {
+    let without_comments = COMMENT_RE.replace_all(sql, "");
+    let plain = TAG_RE.replace_all(&without_comments, |caps: &Captures<'_>| {
+        if caps[0].starts_with("{{") {
+            "__jinja__"
+        } else {
+            ""
+        }
+    });
+    let error = match SqlParser::duckdb().parse(&plain) {
+        Ok(statements) => ff_sql::validate_no_complex_queries(&statements).err(),
+        Err(_) => starts_with_with(&plain).then_some(SqlError::CteNotAllowed {
+            cte_names: Vec::new(),
+        }),
+    };
+    match error {
+        Some(SqlError::CteNotAllowed { cte_names }) => {
+            let construct = if cte_names.is_empty() {
+                "WITH".to_string()
+            } else {
+                format!("WITH {}", cte_names.join(", "))
+            };
+            report.add(
+                location,
+                construct,
+                "CTEs are not supported; move each CTE into its own node and select from it by name",
+            );
+        }
+        Some(SqlError::DerivedTableNotAllowed) => report.add(
+            location,
+            "subquery in FROM",
+            "derived tables are not supported; move the subquery into its own node and select from it by name",
+        ),
+        _ => {}
+    }
+}
+
+/// Whether the first keyword of `sql`, after comments, is `WITH`.
+fn starts_with_with(sql: &str) -> bool {
+    let mut rest = sql;
+    loop {
+        rest = rest.trim_start();
+        if let Some(line_comment) = rest.strip_prefix("--") {
+            rest = line_comment.split_once('\n').map_or("", |(_, after)| after);
+        } else if let Some(block_comment) = rest.strip_prefix("/*") {
+            rest = block_comment
+                .split_once("*/")
+                .map_or("", |(_, after)| after);
+        } else {
+            break;
+        }
+    }
+    rest.split(|c: char| !c.is_ascii_alphanumeric() && c != '_')
+        .next()
+        .is_some_and(|word| word.eq_ignore_ascii_case("with"))
+}
+
+/// Resolve a relationship target (`ref('x')` or `source('s', 't')`) to the
+/// name Featherflow uses for it.
+pub(super) fn relation_name(expr: &str, ctx: &TranslateContext) -> Option<String> {
+    let expr = expr.trim();
+    if let Some(caps) = REF_CALL_RE.captures(expr) {
+        return caps
+            .get(2)
+            .or_else(|| caps.get(1))
+            .map(|m| m.as_str().to_string());
+    }
+    SOURCE_CALL_RE.captures(expr).map(|caps| {
+        ctx.source_table(&caps[1], &caps[2])
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("{}.{}", &caps[1], &caps[2]))
+    })
+}
+
+/// Parse the keyword arguments of a dbt `config(...)` call.
+///
+/// Values are Python-style literals (strings, numbers, booleans, `None`,
+/// lists, and dicts). Any other expression comes back as `Err` with its
+/// source text; positional arguments come back with an empty key.
+pub(super) fn parse_kwargs(args: &str) -> Vec<(String, Result<Value, String>)> {
+    split_top_level(args, ',')
+        .into_iter()
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+        .map(|part| match split_kwarg(part) {
+            Some((key, expr)) => (
+                key.to_string(),
+                parse_literal(expr).ok_or_else(|| expr.trim().to_string()),
+            ),
+            None => (String::new(), Err(part.to_string())),
+        })
+        .collect()
+}
+
+/// Parse a Python-style literal.
+pub(super) fn parse_literal(expr: &str) -> Option<Value> {
+    let expr = expr.trim();
+    match expr {
+        "True" | "true" => return Some(Value::Bool(true)),
+        "False" | "false" => return Some(Value::Bool(false)),
+        "None" | "none" => return Some(Value::Null),
+        _ => {}
+    }
+    if let Some(inner) = expr.strip_prefix('[').and_then(|e| e.strip_suffix(']')) {
+        return split_top_level(inner, ',')
+            .into_iter()
+            .filter(|item| !item.trim().is_empty())
+            .map(parse_literal)
+            .collect::<Option<Vec<_>>>()
+            .map(Value::Sequence);
+    }
+    if let Some(inner) = expr.strip_prefix('{').and_then(|e| e.strip_suffix('}')) {
+        let mut map = Mapping::new();
+        for entry in split_top_level(inner, ',') {
+            if entry.trim().is_empty() {
+                continue;
+            }
+            let parts = split_top_level(entry, ':');
+            let [key, value] = parts.as_slice() else {
+                return None;
+            };
+            map.insert(parse_literal(key)?, parse_literal(value)?);
+        }
+        return Some(Value::Mapping(map));
+    }
+    if let Some(text) = parse_string_literal(expr) {
+        return Some(Value::String(text));
+    }
+    if let Ok(int) = expr.parse::<i64>() {
+        return Some(Value::Number(int.into()));
+    }
+    expr.parse::<f64>()
+        .ok()
+        .filter(|f| f.is_finite())
+        .map(|f| Value::Number(f.into()))
+}
+
+fn parse_string_literal(expr: &str) -> Option<String> {
+    let quote = expr.chars().next().filter(|c| *c == '\'' || *c == '"')?;
+    if expr.len() < 2 || !expr.ends_with(quote) {
+        return None;
+    }
+    let mut out = String::new();
+    let mut chars = expr[1..expr.len() - 1].chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                'n' => out.push('\n'),
+                't' => out.push('\t'),
+                other => out.push(other),
+            },
+            c if c == quote => return None,
+            c => out.push(c),
+        }
+    }
+    Some(out)
+}
+
+fn split_kwarg(part: &str) -> Option<(&str, &str)> {
+    let key_len = part.find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))?;
+    let (key, rest) = part.split_at(key_len);
+    let rest = rest.trim_start().strip_prefix('=')?;
+    (!key.is_empty() && !rest.starts_with('=')).then_some((key, rest))
+}
+
+/// Split on `sep` outside brackets and string literals.
+fn split_top_level(text: &str, sep: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut quote = None;
+    let mut escaped = false;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        if let Some(q) = quote {
+            if escaped {
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+            } else if c == q {
+                quote = None;
+            }
+            continue;
+        }
+        match c {
+            '\'' | '"' => quote = Some(c),
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            _ if c == sep && depth == 0 => {
+                parts.push(&text[start..i]);
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+/// Featherflow name of a renamed dbt function or global.
+fn featherflow_name(dbt_name: &str) -> &str {
+    match dbt_name {
+        "is_incremental" => "is_exists",
+        "env_var" => "env",
+        "execute" => "executing",
+        "fromjson" => "from_json",
+        "tojson" => "to_json",
+        other => other,
+    }
+}
+
+/// Why a name left in a Jinja tag cannot work in Featherflow, if it can't.
+fn dbt_only_note(name: &str) -> Option<&'static str> {
+    let note = match name {
+        "ref" | "source" => "relation lookup inside an expression; replace it with the table name",
+        "config" => {
+            "config() outside a standalone {{ config(...) }} call; move it to the node YAML"
+        }
+        "this" => "{{ this }} is only rewritten in models and hooks when used on its own",
+        "adapter" | "statement" | "load_result" | "api" | "graph" | "selected_resources"
+        | "flags" | "invocation_id" => "dbt runtime context has no Featherflow equivalent",
+        "return" | "exceptions" | "modules" | "builtins" | "fromyaml" | "toyaml" | "zip"
+        | "doc" => "dbt-only Jinja function",
+        "materialization" => "custom materializations are not supported",
+        _ if name == "dbt" || name.starts_with("dbt_") => {
+            "dbt package macro; replace it with a built-in or project macro"
+        }
+        _ => return None,
+    };
+    Some(note)
+}
+
+fn match_start(caps: &Captures<'_>) -> usize {
+    caps.get(0).map_or(0, |m| m.start())
+}
+
+/// 1-based line of a byte offset.
+fn line_of(text: &str, offset: usize) -> usize {
+    text[..offset].matches('\n').count() + 1
+}
+
+#[cfg(test)]
+#[path = "translate_test.rs"]
+mod tests;
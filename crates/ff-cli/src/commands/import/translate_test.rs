@@ -0,0 +1,121 @@
+use super::*;
+
+fn context() -> TranslateContext {
+    let mut ctx = TranslateContext::default();
+    ctx.sources.insert(
+        ("jaffle".to_string(), "orders".to_string()),
+        "raw.raw_orders".to_string(),
+    );
+    ctx
+}
+
+#[test]
+fn test_refs_and_sources_become_table_names() {
+    let mut report = ImportReport::default();
+    let sql = r#"select o.id, c.name
+from {{ source('jaffle', 'orders') }} o
+join {{ ref("stg_customers") }} c on c.id = o.customer_id
+{% if is_incremental() %}
+where o.ordered_at > (select max(ordered_at) from {{ this }})
+{% endif %}"#;
+
+    let translated = translate_template(
+        sql,
+        &context(),
+        Some("fct_orders"),
+        "models/fct_orders.sql",
+        &mut report,
+    );
+
+    assert_eq!(
+        translated,
+        r#"select o.id, c.name
+from raw.raw_orders o
+join stg_customers c on c.id = o.customer_id
+{% if is_exists() %}
+where o.ordered_at > (select max(ordered_at) from fct_orders)
+{% endif %}"#
+    );
+    assert!(report.issues.is_empty(), "{:?}", report.issues);
+}
+
+#[test]
+fn test_dbt_only_constructs_are_reported() {
+    let mut report = ImportReport::default();
+    let sql =
+        "select {{ dbt_utils.star(from=ref('orders')) }}\nfrom {{ source('jaffle', 'customers') }}";
+
+    let translated = translate_template(sql, &context(), None, "models/x.sql", &mut report);
+
+    assert!(translated.contains("from jaffle.customers"), "{translated}");
+    let constructs: Vec<(&str, &str)> = report
+        .issues
+        .iter()
+        .map(|i| (i.location.as_str(), i.construct.as_str()))
+        .collect();
+    assert!(constructs.contains(&("models/x.sql:2", "{{ source('jaffle', 'customers') }}")));
+    assert!(constructs.contains(&("models/x.sql:1", "dbt_utils.star")));
+    assert!(constructs.contains(&("models/x.sql:1", "ref")));
+}
+
+#[test]
+fn test_extract_config_parses_literal_kwargs() {
+    let mut report = ImportReport::default();
+    let sql = "{{ config(materialized='incremental', unique_key=['id', \"day\"], tags=['daily'], \
+               full_refresh=var('refresh'), enabled=True) }}\nselect 1";
+
+    let (rest, config) = extract_config(sql, "models/m.sql", &mut report);
+
+    assert_eq!(rest, "select 1");
+    let keys: Vec<&str> = config.iter().map(|(k, _)| k.as_str()).collect();
+    assert_eq!(keys, ["materialized", "unique_key", "tags", "enabled"]);
+    assert_eq!(
+        config[1].1,
+        Value::Sequence(vec!["id".into(), "day".into()])
+    );
+    assert_eq!(config[3].1, Value::Bool(true));
+    assert_eq!(report.issues.len(), 1);
+    assert_eq!(
+        report.issues[0].construct,
+        "config(full_refresh=var('refresh'))"
+    );
+
+    assert_eq!(
+        parse_literal("{'owner': 'data', 'tier': 2}"),
+        serde_yaml::from_str("{owner: data, tier: 2}").ok()
+    );
+    assert_eq!(parse_literal("'a' ~ 'b'"), None);
+}
+
+#[test]
+fn test_report_complex_query() {
+    let mut report = ImportReport::default();
+    report_complex_query(
+        "select id from (select id from {{ var('table') }}) as t",
+        "models/derived.sql",
+        &mut report,
+    );
+    report_complex_query(
+        "-- staged\nWITH {% if true %}base{% endif %} AS (select 1) select * from base",
+        "models/cte.sql",
+        &mut report,
+    );
+    report_complex_query(
+        "select id from orders where id in (select id from returns)",
+        "models/plain.sql",
+        &mut report,
+    );
+
+    let found: Vec<(&str, &str)> = report
+        .issues
+        .iter()
+        .map(|i| (i.location.as_str(), i.construct.as_str()))
+        .collect();
+    assert_eq!(
+        found,
+        vec![
+            ("models/derived.sql", "subquery in FROM"),
+            ("models/cte.sql", "WITH base"),
+        ]
+    );
+}
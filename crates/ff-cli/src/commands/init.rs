@@ -143,7 +143,7 @@ returns:
     )
     .context("Failed to write example function SQL")?;
 
-    let gitignore = "target/\n*.duckdb\n*.duckdb.wal\n";
+    let gitignore = "target/\nff_packages/\n*.duckdb\n*.duckdb.wal\n";
     fs::write(project_dir.join(".gitignore"), gitignore).context("Failed to write .gitignore")?;
 
     println!("  Created featherflow.yml");
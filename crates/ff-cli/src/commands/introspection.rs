@@ -0,0 +1,216 @@
+//! Database-backed answers for the Jinja introspection functions
+//!
+//! `run_query()`, `get_columns()`, and `get_relation()` are answered from the
+//! target database while templates render. Every answer is memoized for the
+//! rest of the compile, so the full and incremental renders of a model see
+//! the same data, and the memo is what `compile` and `run` write to the meta
+//! database.
+
+use ff_core::config::Config;
+use ff_core::sql_utils::{quote_qualified, split_qualified_name};
+use ff_core::Project;
+use ff_db::Database;
+use ff_jinja::{Introspector, QueryResult, Relation, RelationColumn};
+use ff_meta::populate::compilation::IntrospectionEntry;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex, OnceLock};
+
+use crate::cli::GlobalArgs;
+use crate::commands::common;
+
+/// Most rows `run_query()` returns; larger results fail the render
+const MAX_QUERY_ROWS: usize = 10_000;
+
+/// How to reach the database
+enum Connection {
+    /// Open the configured database on first use
+    Lazy {
+        config: Config,
+        database: Option<String>,
+    },
+    /// Reuse a connection the command already holds
+    Connected(Arc<dyn Database>),
+}
+
+/// [`Introspector`] that queries the project's target database.
+pub(crate) struct DatabaseIntrospector {
+    connection: Connection,
+    /// Set on first use so unqualified names in `run_query()` resolve as in models
+    search_path: Vec<String>,
+    db: OnceLock<Result<Arc<dyn Database>, String>>,
+    /// Bare model, seed, and source names -> qualified relation name
+    relations: HashMap<String, String>,
+    /// `(function, argument)` -> JSON of the answer
+    calls: Mutex<BTreeMap<(String, String), String>>,
+}
+
+impl DatabaseIntrospector {
+    /// Introspector that only connects when a template first calls one of
+    /// the functions, so compiles that never use them stay offline.
+    pub(crate) fn lazy(project: &Project, global: &GlobalArgs) -> Self {
+        Self::new(
+            project,
+            Connection::Lazy {
+                config: project.config.clone(),
+                database: global.database.clone(),
+            },
+        )
+    }
+
+    /// Introspector over an already open connection.
+    pub(crate) fn connected(project: &Project, db: Arc<dyn Database>) -> Self {
+        Self::new(project, Connection::Connected(db))
+    }
+
+    fn new(project: &Project, connection: Connection) -> Self {
+        let default_schema = project.config.get_schema(None);
+        let mut relations = HashMap::new();
+        for source in &project.sources {
+            for table in &source.tables {
+                let identifier = table.identifier.as_deref().unwrap_or(&table.name);
+                relations.insert(
+                    table.name.clone(),
+                    format!("{}.{}", source.schema, identifier),
+                );
+            }
+        }
+        for seed in &project.seeds {
+            if let Some(schema) = seed.target_schema().or(default_schema) {
+                relations.insert(seed.name.to_string(), format!("{}.{}", schema, seed.name));
+            }
+        }
+        for (name, model) in &project.models {
+            if let Some(schema) = model.target_schema(default_schema) {
+                relations.insert(name.to_string(), format!("{}.{}", schema, name));
+            }
+        }
+
+        Self {
+            connection,
+            search_path: common::project_search_path(project),
+            db: OnceLock::new(),
+            relations,
+            calls: Mutex::new(BTreeMap::new()),
+        }
+    }
+
+    /// Every answered call, for the meta database's introspection cache.
+    pub(crate) fn entries(&self) -> Vec<IntrospectionEntry> {
+        let calls = self.calls.lock().unwrap_or_else(|e| e.into_inner());
+        calls
+            .iter()
+            .map(
+                |((function_name, argument), result_json)| IntrospectionEntry {
+                    function_name: function_name.clone(),
+                    argument: argument.clone(),
+                    result_json: result_json.clone(),
+                },
+            )
+            .collect()
+    }
+
+    fn db(&self) -> Result<&Arc<dyn Database>, String> {
+        self.db
+            .get_or_init(|| {
+                let db = match &self.connection {
+                    Connection::Connected(db) => Arc::clone(db),
+                    Connection::Lazy { config, database } => {
+                        common::create_database_connection(config, database.as_deref())
+                            .map_err(|e| format!("{:#}", e))?
+                    }
+                };
+                common::block_on_runtime(db.set_search_path(&self.search_path))
+                    .map_err(|e| e.to_string())?;
+                Ok(db)
+            })
+            .as_ref()
+            .map_err(Clone::clone)
+    }
+
+    /// Resolve a bare project name to its qualified relation.
+    fn qualify(&self, name: &str) -> String {
+        if name.contains('.') {
+            return name.to_string();
+        }
+        self.relations
+            .get(name)
+            .cloned()
+            .unwrap_or_else(|| name.to_string())
+    }
+
+    /// Answer a call from the memo, or compute and record it.
+    fn memoized<T>(
+        &self,
+        function: &str,
+        argument: &str,
+        compute: impl FnOnce() -> Result<T, String>,
+    ) -> Result<T, String>
+    where
+        T: serde::Serialize + serde::de::DeserializeOwned,
+    {
+        let key = (function.to_string(), argument.to_string());
+        if let Some(json) = self
+            .calls
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&key)
+        {
+            return serde_json::from_str(json).map_err(|e| e.to_string());
+        }
+
+        let value = compute()?;
+        let json = serde_json::to_string(&value).map_err(|e| e.to_string())?;
+        self.calls
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(key, json);
+        Ok(value)
+    }
+}
+
+impl Introspector for DatabaseIntrospector {
+    fn run_query(&self, sql: &str) -> Result<QueryResult, String> {
+        self.memoized("run_query", sql, || {
+            let (columns, rows) = common::block_on_runtime(
+                self.db()?.query_rows_with_columns(sql, MAX_QUERY_ROWS + 1),
+            )
+            .map_err(|e| e.to_string())?;
+            if rows.len() > MAX_QUERY_ROWS {
+                return Err(format!(
+                    "query returned more than {} rows; aggregate or filter it",
+                    MAX_QUERY_ROWS
+                ));
+            }
+            Ok(QueryResult { columns, rows })
+        })
+    }
+
+    fn get_columns(&self, relation: &str) -> Result<Vec<RelationColumn>, String> {
+        self.memoized("get_columns", relation, || {
+            let qualified = self.qualify(relation);
+            let schema = common::block_on_runtime(self.db()?.get_table_schema(&qualified))
+                .map_err(|e| e.to_string())?;
+            if schema.is_empty() {
+                return Err(format!("relation '{}' does not exist", qualified));
+            }
+            Ok(schema
+                .into_iter()
+                .map(|(name, data_type)| RelationColumn { name, data_type })
+                .collect())
+        })
+    }
+
+    fn get_relation(&self, name: &str) -> Result<Option<Relation>, String> {
+        self.memoized("get_relation", name, || {
+            let qualified = self.qualify(name);
+            let exists = common::block_on_runtime(self.db()?.relation_exists(&qualified))
+                .map_err(|e| e.to_string())?;
+            let (schema, table) = split_qualified_name(&qualified);
+            Ok(exists.then(|| Relation {
+                schema: schema.to_string(),
+                name: table.to_string(),
+                quoted: quote_qualified(&qualified),
+            }))
+        })
+    }
+}
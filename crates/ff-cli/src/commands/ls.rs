@@ -15,7 +15,7 @@ pub(crate) async fn execute(args: &LsArgs, global: &GlobalArgs) -> Result<()> {
 
     let parser = SqlParser::from_dialect_name(&project.config.dialect.to_string())
         .context("Invalid SQL dialect")?;
-    let jinja = common::build_jinja_env(&project);
+    let jinja = common::build_jinja_env(&project, global, &common::build_node_catalog(&project));
 
     let external_tables = common::build_external_tables_lookup(&project);
     let known_models: HashSet<&str> = project.models.keys().map(|k| k.as_str()).collect();
@@ -3,20 +3,32 @@
 pub(crate) mod analyze;
 pub(crate) mod build;
 pub(crate) mod clean;
+pub(crate) mod code_frame;
 pub(crate) mod common;
 pub(crate) mod compile;
 pub(crate) mod deploy;
+pub(crate) mod deps;
 pub(crate) mod docs;
 pub(crate) mod dt;
+pub(crate) mod engine_stub;
+pub(crate) mod export;
 pub(crate) mod fmt;
 pub(crate) mod format_helpers;
 pub(crate) mod function;
+pub(crate) mod generate;
+pub(crate) mod import;
 pub(crate) mod init;
+pub(crate) mod introspection;
 pub(crate) mod lineage;
 pub(crate) mod ls;
 pub(crate) mod meta;
 pub(crate) mod run;
 pub(crate) mod run_macro;
+pub(crate) mod schema_fix;
 pub(crate) mod seed;
 pub(crate) mod test;
+pub(crate) mod test_macros;
+#[cfg(test)]
+pub(crate) mod test_support;
 pub(crate) mod validation;
+pub(crate) mod yaml_edit;
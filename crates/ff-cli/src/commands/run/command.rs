@@ -0,0 +1,139 @@
+//! Command model execution.
+//!
+//! A `kind: command` model runs an arbitrary program — a compiled binary, a
+//! shell script, a JVM extractor — declared in its YAML `command` block. The
+//! program gets the file exchange contract of Python models: upstream tables
+//! are exported to files listed in `FF_INPUT_FILES`, and the program writes
+//! its result to `FF_OUTPUT_FILE`, which Feather-Flow loads and checks
+//! against the declared columns.
+
+use anyhow::{Context, Result};
+use ff_core::model::{CommandConfig, CommandFormat};
+use ff_db::{Database, ExchangeFormat};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
+
+use crate::commands::common::RunStatus;
+
+use super::compile::CompiledModel;
+use super::exchange;
+use super::state::ModelRunResult;
+
+/// Resolve the program to spawn.
+///
+/// Paths containing a separator are relative to the node directory so a
+/// node can ship its own script; bare names are left for `PATH` lookup.
+fn resolve_program(program: &str, node_dir: &Path) -> PathBuf {
+    let path = Path::new(program);
+    if path.is_absolute() || !program.contains('/') {
+        path.to_path_buf()
+    } else {
+        node_dir.join(path)
+    }
+}
+
+/// Execute a command model and load its output.
+pub(crate) async fn run_command_model(
+    db: &Arc<dyn Database>,
+    name: &str,
+    compiled: &CompiledModel,
+    compiled_models: &HashMap<String, CompiledModel>,
+) -> ModelRunResult {
+    let model_start = Instant::now();
+    let failed = |error: String| ModelRunResult {
+        model: name.to_string(),
+        status: RunStatus::Error,
+        materialization: "command".to_string(),
+        duration_secs: model_start.elapsed().as_secs_f64(),
+        error: Some(error),
+    };
+    let execution_error = |message: String| {
+        failed(
+            ff_core::error::CoreError::CommandExecutionError {
+                name: name.to_string(),
+                message,
+            }
+            .to_string(),
+        )
+    };
+
+    let Some(config) = compiled
+        .model_schema
+        .as_ref()
+        .and_then(|s| s.command.as_ref())
+    else {
+        return execution_error("missing `command` configuration".to_string());
+    };
+    let Some(node_dir) = compiled.script_path.as_deref().and_then(Path::parent) else {
+        return execution_error("missing node directory".to_string());
+    };
+
+    let format = match config.format {
+        CommandFormat::Parquet => ExchangeFormat::Parquet,
+        CommandFormat::Arrow => ExchangeFormat::ArrowIpc,
+    };
+    let files = match exchange::export_inputs(db, compiled, compiled_models, format).await {
+        Ok(files) => files,
+        Err(e) => return failed(format!("{:#}", e)),
+    };
+
+    let mut env_vars = exchange::base_env_vars(name, compiled, compiled_models);
+    files.add_env_vars(&mut env_vars);
+
+    let output = match execute_command(config, node_dir, &env_vars).await {
+        Ok(output) => output,
+        Err(e) => return execution_error(format!("{:#}", e)),
+    };
+    if !output.status.success() {
+        return execution_error(format!(
+            "exit {}:\n{}",
+            output.status.code().unwrap_or(-1),
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    let qualified_name = exchange::build_qualified_name(compiled.schema.as_deref(), name);
+    if let Err(message) = exchange::load_output(db, &qualified_name, &files, compiled).await {
+        return failed(
+            ff_core::error::CoreError::CommandSchemaViolation {
+                model: name.to_string(),
+                message,
+            }
+            .to_string(),
+        );
+    }
+
+    ModelRunResult {
+        model: name.to_string(),
+        status: RunStatus::Success,
+        materialization: "command".to_string(),
+        duration_secs: model_start.elapsed().as_secs_f64(),
+        error: None,
+    }
+}
+
+/// Spawn the program in the node directory and wait for it to exit.
+///
+/// The YAML `env` is applied first so it cannot override the `FF_*`
+/// exchange variables.
+async fn execute_command(
+    config: &CommandConfig,
+    node_dir: &Path,
+    env_vars: &HashMap<String, String>,
+) -> Result<std::process::Output> {
+    let program = resolve_program(&config.program, node_dir);
+    tokio::process::Command::new(&program)
+        .args(&config.args)
+        .current_dir(node_dir)
+        .envs(&config.env)
+        .envs(env_vars)
+        .output()
+        .await
+        .with_context(|| format!("failed to start '{}'", program.display()))
+}
+
+#[cfg(test)]
+#[path = "command_test.rs"]
+mod tests;
@@ -0,0 +1,24 @@
+use super::*;
+
+#[test]
+fn test_resolve_program_relative_to_node_dir() {
+    let node_dir = Path::new("nodes/ext_rates");
+    assert_eq!(
+        resolve_program("./extract.sh", node_dir),
+        PathBuf::from("nodes/ext_rates/./extract.sh")
+    );
+    assert_eq!(
+        resolve_program("bin/extract", node_dir),
+        PathBuf::from("nodes/ext_rates/bin/extract")
+    );
+}
+
+#[test]
+fn test_resolve_program_bare_name_and_absolute_path() {
+    let node_dir = Path::new("nodes/ext_rates");
+    assert_eq!(resolve_program("java", node_dir), PathBuf::from("java"));
+    assert_eq!(
+        resolve_program("/usr/bin/env", node_dir),
+        PathBuf::from("/usr/bin/env")
+    );
+}
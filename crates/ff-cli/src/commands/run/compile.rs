@@ -3,16 +3,20 @@
 use anyhow::{Context, Result};
 use ff_core::config::{IncrementalStrategy, Materialization, OnSchemaChange};
 use ff_core::dag::ModelDag;
-use ff_core::model::ModelSchema;
+use ff_core::model::{ModelKind, ModelSchema, PythonExchange};
 use ff_core::selector::Selector;
 use ff_core::Project;
+use ff_db::Database;
 use ff_meta::manifest::Manifest;
+use ff_meta::populate::compilation::IntrospectionEntry;
 use ff_sql::{extract_dependencies, SqlParser};
 use std::collections::{HashMap, HashSet};
 use std::path::Path;
+use std::sync::Arc;
 
 use crate::cli::{GlobalArgs, RunArgs};
 use crate::commands::common;
+use crate::commands::introspection::DatabaseIntrospector;
 
 /// Compiled model data needed for execution
 #[derive(Clone)]
@@ -45,22 +49,36 @@ pub(crate) struct CompiledModel {
     pub(crate) comment_placement: ff_core::config::CommentPlacement,
     /// Whether this model uses Write-Audit-Publish pattern
     pub(crate) wap: bool,
-    /// Whether this is a Python model (executed via `uv run`)
-    pub(crate) is_python: bool,
-    /// Path to the Python script (only set for Python models)
+    /// How the model runs: SQL in the database, a Python script (via
+    /// `uv run`), or an external command
+    pub(crate) kind: ModelKind,
+    /// Path to the Python script, or the YAML file of a command model
     pub(crate) script_path: Option<std::path::PathBuf>,
+    /// Directory for the input and output files of a model that exchanges
+    /// data through files rather than the database
+    pub(crate) exchange_dir: Option<std::path::PathBuf>,
+}
+
+impl CompiledModel {
+    /// Whether the model runs outside the database and has no SQL
+    pub(crate) fn is_external(&self) -> bool {
+        matches!(self.kind, ModelKind::Python | ModelKind::Command)
+    }
 }
 
 /// Compile all models from source templates.
 ///
 /// Previously supported a manifest-based cache path; now always compiles
 /// fresh since template rendering is fast and avoids staleness issues.
+/// Templates introspect `db` unless `--offline` is set; the calls they made
+/// are returned alongside the models for the meta database.
 pub(crate) fn load_or_compile_models(
     project: &Project,
+    db: &Arc<dyn Database>,
     _args: &RunArgs,
     global: &GlobalArgs,
     comment_ctx: Option<&ff_core::query_comment::QueryCommentContext>,
-) -> Result<HashMap<String, CompiledModel>> {
+) -> Result<(HashMap<String, CompiledModel>, Vec<IntrospectionEntry>)> {
     let all_model_names: Vec<String> = project
         .model_names()
         .into_iter()
@@ -70,12 +88,17 @@ pub(crate) fn load_or_compile_models(
     if global.verbose {
         eprintln!("[verbose] Compiling {} models", all_model_names.len());
     }
-    compile_all_models(
+    let introspector = (!global.offline)
+        .then(|| Arc::new(DatabaseIntrospector::connected(project, Arc::clone(db))));
+    let compiled = compile_all_models(
         project,
         &all_model_names,
-        global.database.as_deref(),
+        global,
         comment_ctx,
-    )
+        introspector.clone(),
+    )?;
+    let entries = introspector.map(|i| i.entries()).unwrap_or_default();
+    Ok((compiled, entries))
 }
 
 /// Shared state for compiling SQL models.
@@ -86,18 +109,27 @@ struct CompileEnv<'a> {
     known_models: HashSet<&'a str>,
     external_tables: HashSet<String>,
     comment_ctx: Option<&'a ff_core::query_comment::QueryCommentContext>,
+    catalog: Arc<ff_jinja::LazyNodeCatalog>,
+    introspector: Option<Arc<DatabaseIntrospector>>,
 }
 
 /// Compile all models fresh
 fn compile_all_models(
     project: &Project,
     model_names: &[String],
-    database: Option<&str>,
+    global: &GlobalArgs,
     comment_ctx: Option<&ff_core::query_comment::QueryCommentContext>,
+    introspector: Option<Arc<DatabaseIntrospector>>,
 ) -> Result<HashMap<String, CompiledModel>> {
+    let catalog = common::build_node_catalog(project);
+    let mut jinja = common::build_jinja_env_with_context(project, global, true, &catalog);
+    // Reuse run's connection rather than opening the database a second time
+    if let Some(ref introspector) = introspector {
+        jinja.set_introspector(introspector.clone());
+    }
     let env = CompileEnv {
         project,
-        jinja: common::build_jinja_env_with_context(project, database, true),
+        jinja,
         parser: SqlParser::from_dialect_name(&project.config.dialect.to_string())
             .context("Invalid SQL dialect")?,
         known_models: {
@@ -109,6 +141,8 @@ fn compile_all_models(
         },
         external_tables: common::build_external_tables_lookup(project),
         comment_ctx,
+        catalog,
+        introspector,
     };
 
     let mut compiled_models = HashMap::with_capacity(model_names.len());
@@ -118,10 +152,10 @@ fn compile_all_models(
             .get_model(name)
             .with_context(|| format!("Model not found: {}", name))?;
 
-        let compiled = if model.is_python() {
-            compile_python_model(model, project)
-        } else {
-            compile_sql_model(name, model, &env)?
+        let compiled = match model.kind {
+            ModelKind::Python => compile_python_model(model, project),
+            ModelKind::Command => compile_command_model(model, project),
+            _ => compile_sql_model(name, model, &env)?,
         };
 
         compiled_models.insert(name.to_string(), compiled);
@@ -141,6 +175,18 @@ fn compile_python_model(
         .schema
         .clone()
         .or_else(|| project.config.get_schema(None).map(|s| s.to_string()));
+    let exchange = model
+        .schema
+        .as_ref()
+        .and_then(|s| s.python.as_ref())
+        .map(|p| p.exchange)
+        .unwrap_or_default();
+    let exchange_dir = (exchange != PythonExchange::Database).then(|| {
+        project
+            .target_dir()
+            .join("python")
+            .join(model.name.as_str())
+    });
 
     CompiledModel {
         sql: String::new(),
@@ -157,8 +203,46 @@ fn compile_python_model(
         query_comment: None,
         comment_placement: Default::default(),
         wap: false,
-        is_python: true,
+        kind: ModelKind::Python,
+        script_path: Some(model.path.clone()),
+        exchange_dir,
+    }
+}
+
+/// Compile a command model (no Jinja/SQL — deps come from YAML).
+fn compile_command_model(
+    model: &ff_core::model::Model,
+    project: &ff_core::Project,
+) -> CompiledModel {
+    let schema = model
+        .config
+        .schema
+        .clone()
+        .or_else(|| project.config.get_schema(None).map(|s| s.to_string()));
+
+    CompiledModel {
+        sql: String::new(),
+        incremental_sql: None,
+        materialization: Materialization::Table,
+        schema,
+        dependencies: model.depends_on.iter().map(|m| m.to_string()).collect(),
+        unique_key: None,
+        incremental_strategy: None,
+        on_schema_change: None,
+        pre_hook: Vec::new(),
+        post_hook: Vec::new(),
+        model_schema: model.schema.clone(),
+        query_comment: None,
+        comment_placement: Default::default(),
+        wap: false,
+        kind: ModelKind::Command,
         script_path: Some(model.path.clone()),
+        exchange_dir: Some(
+            project
+                .target_dir()
+                .join("command")
+                .join(model.name.as_str()),
+        ),
     }
 }
 
@@ -261,22 +345,30 @@ fn compile_sql_model(
         let macro_paths = env.project.config.macro_paths_absolute(&env.project.root);
         let template_ctx = common::build_template_context(env.project, None, true);
 
-        let jinja_full = ff_jinja::JinjaEnvironment::with_is_exists(
+        let mut jinja_full = ff_jinja::JinjaEnvironment::with_is_exists(
             &env.project.config.vars,
             &macro_paths,
             Some(&template_ctx),
             false,
         );
+        jinja_full.set_catalog(Arc::clone(&env.catalog));
+        if let Some(ref introspector) = env.introspector {
+            jinja_full.set_introspector(introspector.clone());
+        }
         let full_rendered = jinja_full
             .render(&model.raw_sql)
             .with_context(|| format!("Failed to render full path template for model: {}", name))?;
 
-        let jinja_inc = ff_jinja::JinjaEnvironment::with_is_exists(
+        let mut jinja_inc = ff_jinja::JinjaEnvironment::with_is_exists(
             &env.project.config.vars,
             &macro_paths,
             Some(&template_ctx),
             true,
         );
+        jinja_inc.set_catalog(Arc::clone(&env.catalog));
+        if let Some(ref introspector) = env.introspector {
+            jinja_inc.set_introspector(introspector.clone());
+        }
         let inc_rendered = jinja_inc.render(&model.raw_sql).with_context(|| {
             format!(
                 "Failed to render incremental path template for model: {}",
@@ -304,8 +396,9 @@ fn compile_sql_model(
         query_comment,
         comment_placement,
         wap,
-        is_python: false,
+        kind: ModelKind::Sql,
         script_path: None,
+        exchange_dir: None,
     })
 }
 
@@ -0,0 +1,234 @@
+//! File-based data exchange for models that run outside the database.
+//!
+//! Python models with `python.exchange: parquet|arrow` and `kind: command`
+//! models share one contract: Feather-Flow exports each upstream table to a
+//! file, hands the program the file paths through `FF_*` environment
+//! variables, and loads the file the program writes back into the model's
+//! table, casting declared columns to their YAML types.
+
+use anyhow::{Context, Result};
+use ff_core::sql_utils::{quote_ident, quote_qualified};
+use ff_db::{Database, ExchangeFormat};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use super::compile::CompiledModel;
+
+/// Files a model reads its inputs from and writes its output to
+pub(crate) struct ExchangeFiles {
+    pub(crate) format: ExchangeFormat,
+    /// Upstream model name -> exported file
+    pub(crate) inputs: BTreeMap<String, PathBuf>,
+    pub(crate) output: PathBuf,
+}
+
+impl ExchangeFiles {
+    /// Add `FF_EXCHANGE_FORMAT`, `FF_INPUT_FILES`, and `FF_OUTPUT_FILE` to `env`.
+    pub(crate) fn add_env_vars(&self, env: &mut HashMap<String, String>) {
+        let format = match self.format {
+            ExchangeFormat::Parquet => "parquet",
+            ExchangeFormat::ArrowIpc => "arrow",
+        };
+        env.insert("FF_EXCHANGE_FORMAT".to_string(), format.to_string());
+        env.insert(
+            "FF_INPUT_FILES".to_string(),
+            serde_json::to_string(&self.inputs).unwrap_or_else(|_| "{}".to_string()),
+        );
+        env.insert(
+            "FF_OUTPUT_FILE".to_string(),
+            self.output.display().to_string(),
+        );
+    }
+}
+
+/// Build the qualified table name for a model.
+pub(crate) fn build_qualified_name(schema: Option<&str>, name: &str) -> String {
+    match schema {
+        Some(s) => format!("{}.{}", s, name),
+        None => name.to_string(),
+    }
+}
+
+/// Qualified table name of an upstream dependency
+fn input_table(dep: &str, compiled_models: &HashMap<String, CompiledModel>) -> String {
+    let dep_schema = compiled_models.get(dep).and_then(|m| m.schema.as_deref());
+    build_qualified_name(dep_schema, dep)
+}
+
+/// Build the environment variables every external model receives.
+///
+/// - `FF_INPUT_TABLES` — JSON array of qualified upstream table names
+/// - `FF_OUTPUT_TABLE` — qualified output table name
+/// - `FF_SCHEMA` — JSON array of `{name, type}` objects describing expected output columns
+/// - `FF_MODEL_NAME` — the model name
+pub(crate) fn base_env_vars(
+    name: &str,
+    compiled: &CompiledModel,
+    compiled_models: &HashMap<String, CompiledModel>,
+) -> HashMap<String, String> {
+    let mut env = HashMap::new();
+
+    env.insert("FF_MODEL_NAME".to_string(), name.to_string());
+
+    let input_tables: Vec<String> = compiled
+        .dependencies
+        .iter()
+        .map(|dep| input_table(dep, compiled_models))
+        .collect();
+    env.insert(
+        "FF_INPUT_TABLES".to_string(),
+        serde_json::to_string(&input_tables).unwrap_or_else(|_| "[]".to_string()),
+    );
+
+    let output_table = build_qualified_name(compiled.schema.as_deref(), name);
+    env.insert("FF_OUTPUT_TABLE".to_string(), output_table);
+
+    if let Some(ref model_schema) = compiled.model_schema {
+        let columns: Vec<serde_json::Value> = model_schema
+            .columns
+            .iter()
+            .map(|col| {
+                serde_json::json!({
+                    "name": col.name,
+                    "type": col.data_type,
+                })
+            })
+            .collect();
+        env.insert(
+            "FF_SCHEMA".to_string(),
+            serde_json::to_string(&columns).unwrap_or_else(|_| "[]".to_string()),
+        );
+    }
+
+    env
+}
+
+/// Export every upstream table into a fresh exchange directory.
+pub(crate) async fn export_inputs(
+    db: &Arc<dyn Database>,
+    compiled: &CompiledModel,
+    compiled_models: &HashMap<String, CompiledModel>,
+    format: ExchangeFormat,
+) -> Result<ExchangeFiles> {
+    let dir = compiled
+        .exchange_dir
+        .as_deref()
+        .context("Model missing exchange directory")?;
+    if dir.exists() {
+        std::fs::remove_dir_all(dir)
+            .with_context(|| format!("Failed to clear {}", dir.display()))?;
+    }
+    let input_dir = dir.join("inputs");
+    std::fs::create_dir_all(&input_dir)
+        .with_context(|| format!("Failed to create {}", input_dir.display()))?;
+
+    let mut inputs = BTreeMap::new();
+    for dep in &compiled.dependencies {
+        let table = input_table(dep, compiled_models);
+        let path = input_dir.join(format!("{}.{}", dep, format.extension()));
+        db.export_query(
+            &format!("SELECT * FROM {}", quote_qualified(&table)),
+            &path.display().to_string(),
+            format,
+        )
+        .await
+        .with_context(|| format!("Failed to export input '{}'", table))?;
+        inputs.insert(dep.clone(), path);
+    }
+
+    Ok(ExchangeFiles {
+        format,
+        inputs,
+        output: dir.join(format!("output.{}", format.extension())),
+    })
+}
+
+/// Load the output file written by the program into the model's table.
+///
+/// Declared columns are cast to their YAML types, so a program that writes
+/// e.g. 64-bit integers for an `INTEGER` column still produces the declared
+/// schema, and values that do not fit fail the model. Errors are returned as
+/// messages for the caller to wrap in its own schema-violation error.
+pub(crate) async fn load_output(
+    db: &Arc<dyn Database>,
+    qualified_name: &str,
+    files: &ExchangeFiles,
+    compiled: &CompiledModel,
+) -> Result<(), String> {
+    if !files.output.exists() {
+        return Err(format!(
+            "no output file was written to '{}'",
+            files.output.display()
+        ));
+    }
+
+    let scan = db
+        .scan_file(&files.output.display().to_string(), files.format)
+        .await
+        .map_err(|e| format!("failed to read output file: {}", e))?;
+    let actual_schema = db
+        .describe_query(&format!("SELECT * FROM {}", scan))
+        .await
+        .map_err(|e| format!("failed to read output file schema: {}", e))?;
+    check_declared_columns("output file", &actual_schema, compiled)?;
+
+    let declared: HashMap<String, &str> = compiled
+        .model_schema
+        .iter()
+        .flat_map(|s| &s.columns)
+        .filter(|col| !col.data_type.trim().is_empty())
+        .map(|col| (col.name.to_lowercase(), col.data_type.as_str()))
+        .collect();
+    let select_list: Vec<String> = actual_schema
+        .iter()
+        .map(|(col_name, _)| {
+            let quoted = quote_ident(col_name);
+            match declared.get(&col_name.to_lowercase()) {
+                Some(data_type) => format!("CAST({} AS {}) AS {}", quoted, data_type, quoted),
+                None => quoted,
+            }
+        })
+        .collect();
+
+    db.create_table_as(
+        qualified_name,
+        &format!("SELECT {} FROM {}", select_list.join(", "), scan),
+        true,
+    )
+    .await
+    .map_err(|e| format!("failed to load output with the declared types: {}", e))
+}
+
+/// Check that every column declared in the model's YAML is present in `actual_schema`.
+pub(crate) fn check_declared_columns(
+    output: &str,
+    actual_schema: &[(String, String)],
+    compiled: &CompiledModel,
+) -> Result<(), String> {
+    let Some(ref model_schema) = compiled.model_schema else {
+        return Ok(());
+    };
+
+    let actual_columns: HashSet<String> = actual_schema
+        .iter()
+        .map(|(col_name, _)| col_name.to_lowercase())
+        .collect();
+
+    let missing: Vec<String> = model_schema
+        .columns
+        .iter()
+        .filter(|col| !actual_columns.contains(&col.name.to_lowercase()))
+        .map(|col| col.name.clone())
+        .collect();
+
+    if !missing.is_empty() {
+        return Err(format!(
+            "{} is missing declared columns: {}",
+            output,
+            missing.join(", ")
+        ));
+    }
+
+    Ok(())
+}
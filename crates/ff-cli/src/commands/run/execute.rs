@@ -1,6 +1,7 @@
 //! Model execution: sequential and parallel runners, single-model execution.
 
 use ff_core::config::Materialization;
+use ff_core::model::ModelKind;
 use ff_core::run_state::RunState;
 use ff_core::sql_utils::quote_qualified;
 use ff_db::Database;
@@ -573,24 +574,31 @@ async fn execute_models_sequential(
         }
         executable_idx += 1;
 
-        let model_result = if compiled.is_python {
-            super::python::run_python_model(
-                ctx.db,
-                name,
-                compiled,
-                &ctx.compiled_models,
-                ctx.db_path.unwrap_or(":memory:"),
-            )
-            .await
-        } else {
-            run_single_model(
-                ctx.db,
-                name,
-                compiled,
-                ctx.args.full_refresh,
-                ctx.wap_schema,
-            )
-            .await
+        let model_result = match compiled.kind {
+            ModelKind::Python => {
+                super::python::run_python_model(
+                    ctx.db,
+                    name,
+                    compiled,
+                    &ctx.compiled_models,
+                    ctx.db_path.unwrap_or(":memory:"),
+                )
+                .await
+            }
+            ModelKind::Command => {
+                super::command::run_command_model(ctx.db, name, compiled, &ctx.compiled_models)
+                    .await
+            }
+            _ => {
+                run_single_model(
+                    ctx.db,
+                    name,
+                    compiled,
+                    ctx.args.full_refresh,
+                    ctx.wap_schema,
+                )
+                .await
+            }
         };
 
         println!("{}", format_model_status(&model_result));
@@ -731,24 +739,31 @@ async fn execute_model_task(
         return;
     };
 
-    let model_result = if compiled.is_python {
-        super::python::run_python_model(
-            &db,
-            &name,
-            compiled,
-            &state.all_compiled_models,
-            state.db_path.as_deref().unwrap_or(":memory:"),
-        )
-        .await
-    } else {
-        run_single_model(
-            &db,
-            &name,
-            compiled,
-            state.full_refresh,
-            state.wap_schema.as_deref(),
-        )
-        .await
+    let model_result = match compiled.kind {
+        ModelKind::Python => {
+            super::python::run_python_model(
+                &db,
+                &name,
+                compiled,
+                &state.all_compiled_models,
+                state.db_path.as_deref().unwrap_or(":memory:"),
+            )
+            .await
+        }
+        ModelKind::Command => {
+            super::command::run_command_model(&db, &name, compiled, &state.all_compiled_models)
+                .await
+        }
+        _ => {
+            run_single_model(
+                &db,
+                &name,
+                compiled,
+                state.full_refresh,
+                state.wap_schema.as_deref(),
+            )
+            .await
+        }
     };
 
     let is_error = matches!(model_result.status, RunStatus::Error);
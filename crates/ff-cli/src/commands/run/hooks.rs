@@ -55,7 +55,7 @@ pub(crate) async fn create_schemas(
     Ok(())
 }
 
-/// Set the DuckDB search path to include all project schemas.
+/// Set the search path to include all project schemas.
 ///
 /// Without this, unqualified table references in SQL (e.g. `FROM raw_customers`)
 /// only resolve against the default `main` schema. By including all model schemas
@@ -97,8 +97,7 @@ pub(crate) async fn set_search_path(
         eprintln!("[verbose] Setting search_path to: {}", schemas.join(","));
     }
 
-    let path = schemas.join(",");
-    db.execute(&format!("SET search_path = '{path}'"))
+    db.set_search_path(&schemas)
         .await
         .context("Failed to set search_path")?;
 
@@ -6,8 +6,11 @@
 //! - `incremental` — incremental strategies and Write-Audit-Publish (WAP)
 //! - `hooks` — pre/post hooks, schema creation, DB connection
 //! - `state` — run results, state tracking, smart builds, resume support
+//! - `python`, `command`, `exchange` — models executed outside the database
 
+mod command;
 mod compile;
+mod exchange;
 mod execute;
 mod hooks;
 mod incremental;
@@ -25,6 +28,7 @@ use std::sync::Arc;
 use std::time::Instant;
 
 use crate::cli::{CliRunMode, GlobalArgs, OutputFormat, RunArgs};
+use crate::commands::code_frame;
 use crate::commands::common::{self, load_project};
 
 pub(crate) use compile::{determine_execution_order, load_or_compile_models, CompiledModel};
@@ -33,6 +37,47 @@ use execute::{execute_models_with_state, ExecutionContext};
 pub(crate) use hooks::{create_database_connection, create_schemas, set_search_path};
 use state::{compute_config_hash, compute_smart_skips, write_run_results, RunResults};
 
+/// Quote the template line DuckDB reported for each failed SQL model.
+///
+/// Errors without a `LINE n:` excerpt, or whose excerpt cannot be found in
+/// the model's SQL, were already printed with the model's status line.
+fn print_error_frames(
+    project: &ff_core::Project,
+    compiled_models: &HashMap<String, CompiledModel>,
+    run_results: &[state::ModelRunResult],
+) {
+    for result in run_results
+        .iter()
+        .filter(|r| matches!(r.status, common::RunStatus::Error))
+    {
+        let Some(error) = result.error.as_deref() else {
+            continue;
+        };
+        let Some(compiled) = compiled_models
+            .get(&result.model)
+            .filter(|c| !c.is_external())
+        else {
+            continue;
+        };
+        let Some(model) = project.get_model(&result.model) else {
+            continue;
+        };
+        let located = std::iter::once(&compiled.sql)
+            .chain(compiled.incremental_sql.as_ref())
+            .find_map(|sql| Some((sql, code_frame::locate_engine_error(error, sql)?)));
+        let Some((sql, span)) = located else {
+            continue;
+        };
+        let source = code_frame::ModelSource::new(project, model, sql);
+        let message = error.lines().next().unwrap_or(error);
+        eprintln!();
+        eprint!(
+            "{}",
+            source.frame_for("error", "", message, Some(source.map_span(span)))
+        );
+    }
+}
+
 /// Qualify bare table names in compiled SQL to fully-qualified references.
 fn qualify_sql_references(
     compiled_models: &mut HashMap<String, CompiledModel>,
@@ -45,7 +90,7 @@ fn qualify_sql_references(
         .collect();
     let qualification_map = common::build_qualification_map(project, &compiled_schemas);
     for (name, compiled) in compiled_models.iter_mut() {
-        if compiled.is_python {
+        if compiled.is_external() {
             continue;
         }
         match ff_sql::qualify_table_references(&compiled.sql, &qualification_map) {
@@ -172,7 +217,8 @@ async fn execute_models_mode(
         common::build_query_comment_context(&project.config, global.database.as_deref())
             .map(|ctx| ctx.with_runtime_fields("models", args.full_refresh));
 
-    let mut compiled_models = load_or_compile_models(project, args, global, comment_ctx.as_ref())?;
+    let (mut compiled_models, introspection) =
+        load_or_compile_models(project, &db, args, global, comment_ctx.as_ref())?;
     qualify_sql_references(&mut compiled_models, project, global);
 
     let compiled_models = Arc::new(compiled_models);
@@ -183,7 +229,8 @@ async fn execute_models_mode(
         global,
         args.skip_static_analysis,
         json_mode,
-    )?;
+    )
+    .await?;
 
     let meta_db = common::open_meta_db(project);
 
@@ -328,6 +375,9 @@ async fn execute_models_mode(
         Some((_project_id, run_id, model_id_map)) => (Some(*run_id), Some(model_id_map)),
         None => (None, None),
     };
+    if let (Some(ref meta_db), Some(run_id)) = (&meta_db, meta_run_id) {
+        common::populate_meta_introspection(meta_db, run_id, &introspection);
+    }
 
     let db_path_str = project
         .config
@@ -404,6 +454,8 @@ async fn execute_models_mode(
             }
         }
 
+        print_error_frames(project, &compiled_models, &run_results);
+
         println!();
         println!(
             "Completed: {} succeeded, {} failed",
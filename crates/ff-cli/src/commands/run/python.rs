@@ -1,13 +1,21 @@
 //! Python model execution via `uv run`.
 //!
-//! Python models are executed as standalone scripts using `uv run`. The script
-//! receives database connection info and table names via environment variables,
-//! reads from upstream tables, and writes its output table. After execution,
-//! Feather-Flow validates the output schema matches the declared columns.
+//! Python models are executed as standalone scripts using `uv run`. How data
+//! reaches the script depends on the model's `python.exchange` setting:
+//!
+//! - `parquet` (default) / `arrow`: Feather-Flow exports each upstream table to a file,
+//!   the script writes its output to a file in the same format, and
+//!   Feather-Flow loads it, casting declared columns to their YAML types. The
+//!   script never touches the database, so this works with in-memory DuckDB
+//!   and while Feather-Flow holds the writer lock.
+//! - `database`: the script receives the DuckDB file path and table names,
+//!   reads upstream tables, and writes its output table itself. Feather-Flow
+//!   then checks the table has the declared columns.
 
 use anyhow::{Context, Result};
+use ff_core::model::PythonExchange;
 use ff_core::sql_utils::quote_qualified;
-use ff_db::Database;
+use ff_db::{Database, ExchangeFormat};
 use std::collections::HashMap;
 use std::path::Path;
 use std::sync::Arc;
@@ -16,6 +24,7 @@ use std::time::Instant;
 use crate::commands::common::RunStatus;
 
 use super::compile::CompiledModel;
+use super::exchange::{self, ExchangeFiles};
 use super::state::ModelRunResult;
 
 /// Check that `uv` is available on the system PATH.
@@ -31,66 +40,41 @@ pub(crate) fn check_uv_available() -> Result<(), ff_core::error::CoreError> {
     }
 }
 
-/// Build the qualified output table name for a Python model.
-fn build_qualified_name(schema: Option<&str>, name: &str) -> String {
-    match schema {
-        Some(s) => format!("{}.{}", s, name),
-        None => name.to_string(),
+/// The file format a model exchanges data in, or `None` for the database.
+fn exchange_format(compiled: &CompiledModel) -> Option<ExchangeFormat> {
+    let exchange = compiled
+        .model_schema
+        .as_ref()
+        .and_then(|s| s.python.as_ref())
+        .map(|p| p.exchange)
+        .unwrap_or_default();
+    match exchange {
+        PythonExchange::Database => None,
+        PythonExchange::Parquet => Some(ExchangeFormat::Parquet),
+        PythonExchange::Arrow => Some(ExchangeFormat::ArrowIpc),
     }
 }
 
 /// Build environment variables for the Python script.
 ///
-/// The following variables are set:
-/// - `FF_DATABASE_PATH` — DuckDB file path (or `:memory:`)
-/// - `FF_INPUT_TABLES` — JSON array of qualified upstream table names
-/// - `FF_OUTPUT_TABLE` — qualified output table name
-/// - `FF_SCHEMA` — JSON array of `{name, type}` objects describing expected output columns
-/// - `FF_MODEL_NAME` — the model name
+/// On top of the variables every external model receives (see
+/// [`exchange::base_env_vars`]), database exchange sets `FF_DATABASE_PATH`
+/// and file exchange sets `FF_EXCHANGE_FORMAT`, `FF_INPUT_FILES`, and
+/// `FF_OUTPUT_FILE`.
 fn build_env_vars(
     name: &str,
     compiled: &CompiledModel,
     compiled_models: &HashMap<String, CompiledModel>,
     db_path: &str,
+    files: Option<&ExchangeFiles>,
 ) -> HashMap<String, String> {
-    let mut env = HashMap::new();
-
-    env.insert("FF_DATABASE_PATH".to_string(), db_path.to_string());
-    env.insert("FF_MODEL_NAME".to_string(), name.to_string());
-
-    let input_tables: Vec<String> = compiled
-        .dependencies
-        .iter()
-        .map(|dep| {
-            let dep_schema = compiled_models.get(dep).and_then(|m| m.schema.as_deref());
-            build_qualified_name(dep_schema, dep)
-        })
-        .collect();
-    env.insert(
-        "FF_INPUT_TABLES".to_string(),
-        serde_json::to_string(&input_tables).unwrap_or_else(|_| "[]".to_string()),
-    );
-
-    let output_table = build_qualified_name(compiled.schema.as_deref(), name);
-    env.insert("FF_OUTPUT_TABLE".to_string(), output_table);
-
-    if let Some(ref model_schema) = compiled.model_schema {
-        let columns: Vec<serde_json::Value> = model_schema
-            .columns
-            .iter()
-            .map(|col| {
-                serde_json::json!({
-                    "name": col.name,
-                    "type": col.data_type,
-                })
-            })
-            .collect();
-        env.insert(
-            "FF_SCHEMA".to_string(),
-            serde_json::to_string(&columns).unwrap_or_else(|_| "[]".to_string()),
-        );
+    let mut env = exchange::base_env_vars(name, compiled, compiled_models);
+    match files {
+        Some(files) => files.add_env_vars(&mut env),
+        None => {
+            env.insert("FF_DATABASE_PATH".to_string(), db_path.to_string());
+        }
     }
-
     env
 }
 
@@ -103,73 +87,65 @@ pub(crate) async fn run_python_model(
     db_path: &str,
 ) -> ModelRunResult {
     let model_start = Instant::now();
+    let failed = |error: String| ModelRunResult {
+        model: name.to_string(),
+        status: RunStatus::Error,
+        materialization: "python".to_string(),
+        duration_secs: model_start.elapsed().as_secs_f64(),
+        error: Some(error),
+    };
 
-    let script_path = match &compiled.script_path {
-        Some(p) => p.clone(),
-        None => {
-            return ModelRunResult {
-                model: name.to_string(),
-                status: RunStatus::Error,
-                materialization: "python".to_string(),
-                duration_secs: model_start.elapsed().as_secs_f64(),
-                error: Some("Python model missing script_path".to_string()),
-            };
-        }
+    let Some(script_path) = &compiled.script_path else {
+        return failed("Python model missing script_path".to_string());
     };
 
     if let Err(e) = check_uv_available() {
-        return ModelRunResult {
-            model: name.to_string(),
-            status: RunStatus::Error,
-            materialization: "python".to_string(),
-            duration_secs: model_start.elapsed().as_secs_f64(),
-            error: Some(e.to_string()),
-        };
+        return failed(e.to_string());
     }
 
-    let env_vars = build_env_vars(name, compiled, compiled_models, db_path);
-
-    match execute_uv_run(&script_path, &env_vars).await {
-        Ok(output) => {
-            if !output.success {
-                return ModelRunResult {
-                    model: name.to_string(),
-                    status: RunStatus::Error,
-                    materialization: "python".to_string(),
-                    duration_secs: model_start.elapsed().as_secs_f64(),
-                    error: Some(format!(
-                        "uv run failed (exit {}):\n{}",
-                        output.exit_code, output.stderr
-                    )),
-                };
+    let files = match exchange_format(compiled) {
+        Some(format) => {
+            match exchange::export_inputs(db, compiled, compiled_models, format).await {
+                Ok(files) => Some(files),
+                Err(e) => return failed(format!("{:#}", e)),
             }
+        }
+        None => None,
+    };
 
-            let qualified_name = build_qualified_name(compiled.schema.as_deref(), name);
-            if let Err(e) = validate_python_output(db, name, &qualified_name, compiled).await {
-                return ModelRunResult {
-                    model: name.to_string(),
-                    status: RunStatus::Error,
-                    materialization: "python".to_string(),
-                    duration_secs: model_start.elapsed().as_secs_f64(),
-                    error: Some(e.to_string()),
-                };
-            }
+    let env_vars = build_env_vars(name, compiled, compiled_models, db_path, files.as_ref());
+
+    let output = match execute_uv_run(script_path, &env_vars).await {
+        Ok(output) => output,
+        Err(e) => return failed(format!("Failed to execute uv run: {}", e)),
+    };
+    if !output.success {
+        return failed(format!(
+            "uv run failed (exit {}):\n{}",
+            output.exit_code, output.stderr
+        ));
+    }
 
-            ModelRunResult {
+    let qualified_name = exchange::build_qualified_name(compiled.schema.as_deref(), name);
+    let loaded = match &files {
+        Some(files) => exchange::load_output(db, &qualified_name, files, compiled)
+            .await
+            .map_err(|message| ff_core::error::CoreError::PythonSchemaViolation {
                 model: name.to_string(),
-                status: RunStatus::Success,
-                materialization: "python".to_string(),
-                duration_secs: model_start.elapsed().as_secs_f64(),
-                error: None,
-            }
-        }
-        Err(e) => ModelRunResult {
-            model: name.to_string(),
-            status: RunStatus::Error,
-            materialization: "python".to_string(),
-            duration_secs: model_start.elapsed().as_secs_f64(),
-            error: Some(format!("Failed to execute uv run: {}", e)),
-        },
+                message,
+            }),
+        None => validate_python_output(db, name, &qualified_name, compiled).await,
+    };
+    if let Err(e) = loaded {
+        return failed(e.to_string());
+    }
+
+    ModelRunResult {
+        model: name.to_string(),
+        status: RunStatus::Success,
+        materialization: "python".to_string(),
+        duration_secs: model_start.elapsed().as_secs_f64(),
+        error: None,
     }
 }
 
@@ -207,55 +183,34 @@ async fn validate_python_output(
     qualified_name: &str,
     compiled: &CompiledModel,
 ) -> Result<(), ff_core::error::CoreError> {
-    let quoted = quote_qualified(qualified_name);
-    let exists = db.relation_exists(qualified_name).await.map_err(|e| {
-        ff_core::error::CoreError::PythonSchemaViolation {
-            model: name.to_string(),
-            message: format!("failed to check if output table exists: {}", e),
-        }
-    })?;
+    let violation = |message: String| ff_core::error::CoreError::PythonSchemaViolation {
+        model: name.to_string(),
+        message,
+    };
+
+    let exists = db
+        .relation_exists(qualified_name)
+        .await
+        .map_err(|e| violation(format!("failed to check if output table exists: {}", e)))?;
 
     if !exists {
-        return Err(ff_core::error::CoreError::PythonSchemaViolation {
-            model: name.to_string(),
-            message: format!(
-                "Python script did not create the expected output table '{}'",
-                qualified_name
-            ),
-        });
+        return Err(violation(format!(
+            "Python script did not create the expected output table '{}'",
+            qualified_name
+        )));
     }
 
-    if let Some(ref model_schema) = compiled.model_schema {
-        if !model_schema.columns.is_empty() {
-            let actual_schema = db.get_table_schema(&quoted).await.map_err(|e| {
-                ff_core::error::CoreError::PythonSchemaViolation {
-                    model: name.to_string(),
-                    message: format!("failed to read output table schema: {}", e),
-                }
-            })?;
-
-            let actual_columns: std::collections::HashSet<String> = actual_schema
-                .iter()
-                .map(|(col_name, _)| col_name.to_lowercase())
-                .collect();
-
-            let missing: Vec<String> = model_schema
-                .columns
-                .iter()
-                .filter(|col| !actual_columns.contains(&col.name.to_lowercase()))
-                .map(|col| col.name.clone())
-                .collect();
-
-            if !missing.is_empty() {
-                return Err(ff_core::error::CoreError::PythonSchemaViolation {
-                    model: name.to_string(),
-                    message: format!(
-                        "output table is missing declared columns: {}",
-                        missing.join(", ")
-                    ),
-                });
-            }
-        }
+    if compiled
+        .model_schema
+        .as_ref()
+        .is_some_and(|s| !s.columns.is_empty())
+    {
+        let actual_schema = db
+            .get_table_schema(&quote_qualified(qualified_name))
+            .await
+            .map_err(|e| violation(format!("failed to read output table schema: {}", e)))?;
+        exchange::check_declared_columns("output table", &actual_schema, compiled)
+            .map_err(violation)?;
     }
 
     Ok(())
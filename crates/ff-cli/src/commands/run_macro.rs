@@ -12,7 +12,7 @@ pub(crate) async fn execute(args: &RunMacroArgs, global: &GlobalArgs) -> Result<
     let start_time = Instant::now();
     let project = load_project(global)?;
 
-    let jinja = common::build_jinja_env(&project);
+    let jinja = common::build_jinja_env(&project, global, &common::build_node_catalog(&project));
 
     let macro_args: HashMap<String, serde_json::Value> = if let Some(args_json) = &args.args {
         serde_json::from_str(args_json).context("Invalid JSON in --args")?
@@ -0,0 +1,172 @@
+//! `ff dt compile --fix` — rewrite node YAML columns from the inferred schema
+//!
+//! Static analysis already knows, per model, which declared columns disagree
+//! with the DataFusion plan (`SchemaMismatch`). This module turns those
+//! mismatches into edits of the node's `columns:` list, previews them as a
+//! diff, and writes the result back with comments and key order intact.
+
+use anyhow::{Context, Result};
+use ff_analysis::{PropagationResult, RelSchema, SchemaMismatch};
+use ff_core::model::ModelSchema;
+use ff_core::Project;
+
+use crate::commands::common::model_yaml_path;
+use crate::commands::yaml_edit::{unified_diff, ColumnsEditor};
+
+/// One edit to a node's `columns:` list.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ColumnFix {
+    /// Column produced by the SQL but not declared
+    Add { column: String, data_type: String },
+    /// Declared column the SQL no longer produces
+    Remove { column: String },
+    /// Declared type incompatible with the inferred type
+    Retype {
+        column: String,
+        from: String,
+        to: String,
+    },
+}
+
+/// Edits derived for one model, plus columns that could not be fixed automatically.
+#[derive(Debug, Default)]
+pub(crate) struct ModelFixPlan {
+    pub fixes: Vec<ColumnFix>,
+    /// Undeclared columns whose type could not be inferred
+    pub skipped: Vec<String>,
+}
+
+/// Translate schema mismatches into column edits.
+///
+/// Nullability mismatches are left alone: nullability is expressed through
+/// tests rather than the column type, so there is nothing to rewrite.
+pub(crate) fn plan_fixes(mismatches: &[SchemaMismatch], inferred: &RelSchema) -> ModelFixPlan {
+    let mut plan = ModelFixPlan::default();
+    for mismatch in mismatches {
+        match mismatch {
+            SchemaMismatch::ExtraInSql { column } => {
+                match inferred.find_column(column).map(|c| &c.sql_type) {
+                    Some(sql_type) if !sql_type.is_unknown() => plan.fixes.push(ColumnFix::Add {
+                        column: column.clone(),
+                        data_type: sql_type.display_name().into_owned(),
+                    }),
+                    _ => plan.skipped.push(column.clone()),
+                }
+            }
+            SchemaMismatch::MissingFromSql { column } => plan.fixes.push(ColumnFix::Remove {
+                column: column.clone(),
+            }),
+            SchemaMismatch::TypeMismatch {
+                column,
+                yaml_type,
+                inferred_type,
+            } => plan.fixes.push(ColumnFix::Retype {
+                column: column.clone(),
+                from: yaml_type.clone(),
+                to: inferred_type.clone(),
+            }),
+            SchemaMismatch::NullabilityMismatch { .. } => {}
+        }
+    }
+    plan
+}
+
+/// Apply column edits to the raw YAML text, preserving everything else.
+pub(crate) fn apply_fixes(content: &str, fixes: &[ColumnFix]) -> Result<String> {
+    let mut editor = ColumnsEditor::new(content);
+    for fix in fixes {
+        match fix {
+            ColumnFix::Remove { column } => {
+                editor.remove_column(column)?;
+            }
+            ColumnFix::Retype { column, to, .. } => {
+                editor.set_type(column, to)?;
+            }
+            ColumnFix::Add { column, data_type } => {
+                editor.append_column(column, data_type)?;
+            }
+        }
+    }
+    Ok(editor.render())
+}
+
+/// Mismatches and inferred schema of every analyzed model, sorted by name.
+///
+/// Covers both DataFusion-planned models and those whose schema the engine
+/// fallback described.
+fn analyzed_models(result: &PropagationResult) -> Vec<(&str, &[SchemaMismatch], &RelSchema)> {
+    let planned = result
+        .model_plans
+        .iter()
+        .map(|(name, r)| (name.as_str(), r.mismatches.as_slice(), &*r.inferred_schema));
+    let engine = result
+        .engine_inferred
+        .iter()
+        .map(|(name, r)| (name.as_str(), r.mismatches.as_slice(), &*r.inferred_schema));
+    let mut models: Vec<_> = planned.chain(engine).collect();
+    models.sort_unstable_by_key(|(name, ..)| *name);
+    models
+}
+
+/// Rewrite the YAML of every analyzed model whose declared columns disagree
+/// with its inferred schema. Returns the number of files written.
+///
+/// Diffs go to stdout, or to stderr when stdout carries JSON output.
+pub(crate) fn fix_model_yaml(
+    project: &Project,
+    result: &PropagationResult,
+    json_mode: bool,
+) -> Result<usize> {
+    let mut written = 0;
+    for (name, mismatches, inferred) in analyzed_models(result) {
+        let plan = plan_fixes(mismatches, inferred);
+        for column in &plan.skipped {
+            eprintln!(
+                "[warn] {}: type of column '{}' could not be inferred; declare it manually",
+                name, column
+            );
+        }
+        if plan.fixes.is_empty() {
+            continue;
+        }
+
+        let Some(yaml_path) = project
+            .get_model(name)
+            .and_then(|m| model_yaml_path(&m.path))
+        else {
+            continue;
+        };
+        let content = std::fs::read_to_string(&yaml_path)
+            .with_context(|| format!("Failed to read {}", yaml_path.display()))?;
+        let updated = apply_fixes(&content, &plan.fixes)
+            .with_context(|| format!("Cannot edit {}", yaml_path.display()))?;
+        if updated == content {
+            continue;
+        }
+        ModelSchema::load_from_str(&updated, &yaml_path).with_context(|| {
+            format!(
+                "Refusing to write {}: edited YAML no longer parses",
+                yaml_path.display()
+            )
+        })?;
+
+        let diff = unified_diff(&yaml_path.display().to_string(), &content, &updated);
+        if json_mode {
+            eprint!("{diff}");
+        } else {
+            print!("{diff}");
+        }
+        std::fs::write(&yaml_path, &updated)
+            .with_context(|| format!("Failed to write {}", yaml_path.display()))?;
+        written += 1;
+    }
+
+    if written > 0 && !json_mode {
+        println!("Fixed {} node YAML file(s)", written);
+    }
+    Ok(written)
+}
+
+#[cfg(test)]
+#[path = "schema_fix_test.rs"]
+mod tests;
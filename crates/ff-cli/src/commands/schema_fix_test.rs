@@ -0,0 +1,181 @@
+use super::*;
+use ff_analysis::{EngineInferredResult, IntBitWidth, Nullability, SqlType, TypedColumn};
+use ff_core::ModelName;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+fn col(name: &str, sql_type: SqlType) -> TypedColumn {
+    TypedColumn {
+        name: name.to_string(),
+        source_table: None,
+        sql_type,
+        nullability: Nullability::Nullable,
+        provenance: vec![],
+    }
+}
+
+fn inferred() -> RelSchema {
+    RelSchema::new(vec![
+        col(
+            "order_id",
+            SqlType::Integer {
+                bits: IntBitWidth::I64,
+            },
+        ),
+        col(
+            "amount",
+            SqlType::Decimal {
+                precision: Some(10),
+                scale: Some(2),
+            },
+        ),
+        col("mystery", SqlType::Unknown("udf".to_string())),
+    ])
+}
+
+#[test]
+fn test_plan_fixes_maps_mismatches() {
+    let mismatches = vec![
+        SchemaMismatch::ExtraInSql {
+            column: "amount".to_string(),
+        },
+        SchemaMismatch::ExtraInSql {
+            column: "mystery".to_string(),
+        },
+        SchemaMismatch::MissingFromSql {
+            column: "legacy_code".to_string(),
+        },
+        SchemaMismatch::TypeMismatch {
+            column: "order_id".to_string(),
+            yaml_type: "VARCHAR".to_string(),
+            inferred_type: "BIGINT".to_string(),
+        },
+        SchemaMismatch::NullabilityMismatch {
+            column: "order_id".to_string(),
+            yaml_nullable: false,
+            inferred_nullable: true,
+        },
+    ];
+
+    let plan = plan_fixes(&mismatches, &inferred());
+    assert_eq!(
+        plan.fixes,
+        vec![
+            ColumnFix::Add {
+                column: "amount".to_string(),
+                data_type: "DECIMAL(10,2)".to_string(),
+            },
+            ColumnFix::Remove {
+                column: "legacy_code".to_string(),
+            },
+            ColumnFix::Retype {
+                column: "order_id".to_string(),
+                from: "VARCHAR".to_string(),
+                to: "BIGINT".to_string(),
+            },
+        ]
+    );
+    assert_eq!(plan.skipped, vec!["mystery"]);
+}
+
+#[test]
+fn test_apply_fixes_keeps_comments_and_tests() {
+    let yaml = r#"version: 1
+# Orders fact table
+description: "Orders"
+columns:
+  - name: order_id
+    type: VARCHAR # cast upstream
+    tests:
+      - unique
+  - name: legacy_code
+    type: VARCHAR
+"#;
+    let fixes = vec![
+        ColumnFix::Remove {
+            column: "legacy_code".to_string(),
+        },
+        ColumnFix::Retype {
+            column: "order_id".to_string(),
+            from: "VARCHAR".to_string(),
+            to: "BIGINT".to_string(),
+        },
+        ColumnFix::Add {
+            column: "amount".to_string(),
+            data_type: "DECIMAL(10,2)".to_string(),
+        },
+    ];
+
+    let updated = apply_fixes(yaml, &fixes).unwrap();
+    assert_eq!(
+        updated,
+        r#"version: 1
+# Orders fact table
+description: "Orders"
+columns:
+  - name: order_id
+    type: BIGINT # cast upstream
+    tests:
+      - unique
+  - name: amount
+    type: DECIMAL(10,2)
+"#
+    );
+
+    let schema = ModelSchema::load_from_str(&updated, Path::new("orders.yml")).unwrap();
+    assert_eq!(schema.column_names(), vec!["order_id", "amount"]);
+}
+
+#[test]
+fn test_apply_fixes_scaffolds_stub_yaml() {
+    let fixes = vec![
+        ColumnFix::Add {
+            column: "order_id".to_string(),
+            data_type: "BIGINT".to_string(),
+        },
+        ColumnFix::Add {
+            column: "amount".to_string(),
+            data_type: "DECIMAL(10,2)".to_string(),
+        },
+    ];
+
+    let updated = apply_fixes("kind: sql\nversion: 1\n", &fixes).unwrap();
+    let schema = ModelSchema::load_from_str(&updated, Path::new("orders.yml")).unwrap();
+    assert_eq!(schema.column_names(), vec!["order_id", "amount"]);
+    assert_eq!(schema.columns[1].data_type, "DECIMAL(10,2)");
+}
+
+#[test]
+fn test_analyzed_models_include_engine_inferred() {
+    let mut engine_inferred = HashMap::new();
+    engine_inferred.insert(
+        ModelName::new("pivoted"),
+        EngineInferredResult {
+            sql: "PIVOT orders ON status USING count(*)".to_string(),
+            planning_error: "PIVOT is not supported".to_string(),
+            inferred_schema: Arc::new(inferred()),
+            mismatches: vec![SchemaMismatch::ExtraInSql {
+                column: "amount".to_string(),
+            }],
+        },
+    );
+    let result = PropagationResult {
+        model_plans: HashMap::new(),
+        engine_inferred,
+        final_catalog: HashMap::new(),
+        failures: HashMap::new(),
+    };
+
+    let models = analyzed_models(&result);
+    assert_eq!(models.len(), 1);
+    let (name, mismatches, schema) = models[0];
+    assert_eq!(name, "pivoted");
+    assert_eq!(
+        plan_fixes(mismatches, schema).fixes,
+        vec![ColumnFix::Add {
+            column: "amount".to_string(),
+            data_type: "DECIMAL(10,2)".to_string(),
+        }]
+    );
+}
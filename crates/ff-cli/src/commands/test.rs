@@ -155,7 +155,8 @@ pub(crate) async fn execute(args: &TestArgs, global: &GlobalArgs) -> Result<()>
     }
 
     let model_filter: Option<std::collections::HashSet<String>> = if args.nodes.is_some() {
-        let (_, dag) = common::build_project_dag(&project)?;
+        let (_, dag) =
+            common::build_project_dag(&project, global, &common::build_node_catalog(&project))?;
         let resolved = common::resolve_nodes(&project, &dag, &args.nodes)?;
         Some(resolved.into_iter().collect())
     } else {
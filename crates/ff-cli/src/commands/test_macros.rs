@@ -0,0 +1,262 @@
+//! Test-macros command implementation
+//!
+//! Runs the `*.test.yml` fixtures next to the project's macro files. Each
+//! case renders one macro call and checks the output against
+//! `expected_sql` (both sides normalized through the SQL parser, so only
+//! formatting may differ) and/or runs it against `given` tables in an
+//! in-memory DuckDB and compares the rows with `expected_rows`.
+
+use anyhow::{Context, Result};
+use ff_core::sql_utils::{quote_ident, quote_qualified, split_qualified_name};
+use ff_db::{DatabaseCore, DuckDbBackend};
+use ff_jinja::{JinjaEnvironment, LazyNodeCatalog, MacroTestCase, TemplateContext};
+use ff_sql::SqlParser;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
+use std::sync::Arc;
+
+use crate::cli::{GlobalArgs, TestMacrosArgs};
+use crate::commands::common::{self, load_project, ExitCode};
+
+/// Most rows read back from a macro's query
+const MAX_RESULT_ROWS: usize = 10_000;
+
+/// Everything a test case needs besides the case itself
+struct MacroTestEnv<'a> {
+    vars: &'a HashMap<String, serde_yaml::Value>,
+    macro_paths: &'a [PathBuf],
+    template_ctx: &'a TemplateContext,
+    catalog: &'a Arc<LazyNodeCatalog>,
+    parser: &'a SqlParser,
+}
+
+/// Execute the test-macros command
+pub(crate) async fn execute(args: &TestMacrosArgs, global: &GlobalArgs) -> Result<()> {
+    let project = load_project(global)?;
+    let macro_paths = project.config.macro_paths_absolute(&project.root);
+    let fixtures = ff_jinja::discover_macro_tests(&macro_paths)?;
+
+    let selected: Option<HashSet<&str>> = args
+        .macros
+        .as_deref()
+        .map(|m| m.split(',').map(str::trim).collect());
+    let wanted = |case: &MacroTestCase| {
+        selected
+            .as_ref()
+            .is_none_or(|s| s.contains(case.macro_name.as_str()))
+    };
+
+    let total: usize = fixtures
+        .iter()
+        .map(|f| f.tests.iter().filter(|c| wanted(c)).count())
+        .sum();
+    if total == 0 {
+        println!("No macro tests to run.");
+    } else {
+        println!("Running {} macro tests...\n", total);
+    }
+
+    let parser = SqlParser::from_dialect_name(&project.config.dialect.to_string())
+        .context("Invalid SQL dialect")?;
+    let template_ctx = common::build_template_context(&project, global.database.as_deref(), false);
+    let catalog = common::build_node_catalog(&project);
+    let env = MacroTestEnv {
+        vars: &project.config.vars,
+        macro_paths: &macro_paths,
+        template_ctx: &template_ctx,
+        catalog: &catalog,
+        parser: &parser,
+    };
+
+    let (mut passed, mut failed) = (0, 0);
+    'files: for fixture in &fixtures {
+        let file_name = fixture
+            .path
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        for case in fixture.tests.iter().filter(|c| wanted(c)) {
+            match run_case(&env, case).await {
+                Ok(()) => {
+                    passed += 1;
+                    println!("  \u{2713} {}::{}", file_name, case.name);
+                }
+                Err(e) => {
+                    failed += 1;
+                    println!("  \u{2717} {}::{} - {:#}", file_name, case.name, e);
+                    if args.fail_fast {
+                        break 'files;
+                    }
+                }
+            }
+        }
+    }
+
+    // Custom test macros are meant to be covered by fixtures too
+    let tested: HashSet<&str> = fixtures
+        .iter()
+        .flat_map(|f| f.tests.iter().map(|c| c.macro_name.as_str()))
+        .collect();
+    let untested: Vec<String> = ff_jinja::discover_custom_test_macros(&macro_paths)?
+        .into_iter()
+        .filter(|m| !tested.contains(m.macro_name.as_str()))
+        .map(|m| m.macro_name)
+        .collect();
+    if !untested.is_empty() && args.macros.is_none() {
+        println!();
+        println!(
+            "Custom test macros without fixtures: {}",
+            untested.join(", ")
+        );
+    }
+
+    if total > 0 {
+        println!();
+        println!("Passed: {}, Failed: {}", passed, failed);
+    }
+    if failed > 0 {
+        return Err(ExitCode(2).into());
+    }
+    Ok(())
+}
+
+/// Render one case and check it against its expectations.
+async fn run_case(env: &MacroTestEnv<'_>, case: &MacroTestCase) -> Result<()> {
+    let mut vars = env.vars.clone();
+    vars.extend(case.vars.clone());
+    let mut jinja = JinjaEnvironment::with_context(&vars, env.macro_paths, env.template_ctx);
+    jinja.set_catalog(Arc::clone(env.catalog));
+    let rendered = ff_jinja::render_macro_call(&jinja, case)?;
+
+    if let Some(expected) = &case.expected_sql {
+        let (want, got) = (
+            normalize_sql(env.parser, expected),
+            normalize_sql(env.parser, &rendered),
+        );
+        if want != got {
+            anyhow::bail!(
+                "rendered SQL differs\n    expected: {}\n    actual:   {}",
+                want,
+                got
+            );
+        }
+    }
+
+    if let Some(expected_rows) = &case.expected_rows {
+        let db = DuckDbBackend::in_memory().context("Failed to open in-memory DuckDB")?;
+        for (table, rows) in &case.given {
+            db.execute_batch(&given_table_sql(table, rows)?)
+                .await
+                .with_context(|| format!("Failed to create given table '{}'", table))?;
+        }
+        let mut actual = db
+            .query_rows(&rendered, MAX_RESULT_ROWS)
+            .await
+            .with_context(|| format!("Failed to run rendered SQL: {}", rendered))?;
+        let mut expected: Vec<Vec<String>> = expected_rows
+            .iter()
+            .map(|row| row.iter().map(cell_string).collect())
+            .collect();
+        actual.sort();
+        expected.sort();
+        if actual != expected {
+            anyhow::bail!(
+                "result rows differ\n    expected: {:?}\n    actual:   {:?}",
+                expected,
+                actual
+            );
+        }
+    }
+    Ok(())
+}
+
+/// Canonical form of a SQL statement or expression, so comparisons ignore
+/// formatting. Fragments that do not parse either way compare by their
+/// whitespace-collapsed text.
+fn normalize_sql(parser: &SqlParser, sql: &str) -> String {
+    if let Ok(statements) = parser.parse(sql) {
+        if !statements.is_empty() {
+            return statements
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(";\n");
+        }
+    }
+    if let Ok(statement) = parser.parse_single(&format!("SELECT {}", sql)) {
+        return statement.to_string();
+    }
+    sql.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// `CREATE TABLE ... AS VALUES` for a `given` table; columns are the row
+/// keys in first-seen order, with NULL where a row omits one.
+fn given_table_sql(table: &str, rows: &[serde_yaml::Mapping]) -> Result<String> {
+    let mut columns: Vec<String> = Vec::new();
+    for key in rows.iter().flat_map(|row| row.keys()) {
+        let name = key
+            .as_str()
+            .with_context(|| format!("given table '{}' has a non-string column name", table))?;
+        if !columns.iter().any(|c| c == name) {
+            columns.push(name.to_string());
+        }
+    }
+    if columns.is_empty() {
+        anyhow::bail!("given table '{}' needs at least one row", table);
+    }
+
+    let values = rows
+        .iter()
+        .map(|row| {
+            let cells = columns
+                .iter()
+                .map(|c| sql_literal(row.get(c.as_str()).unwrap_or(&serde_yaml::Value::Null)))
+                .collect::<Result<Vec<_>>>()?;
+            Ok(format!("({})", cells.join(", ")))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let column_list: Vec<String> = columns.iter().map(|c| quote_ident(c)).collect();
+
+    let (schema, _) = split_qualified_name(table);
+    let create_schema = if table.contains('.') {
+        format!("CREATE SCHEMA IF NOT EXISTS {};\n", quote_ident(schema))
+    } else {
+        String::new()
+    };
+    Ok(format!(
+        "{}CREATE TABLE {} AS SELECT * FROM (VALUES {}) AS t({})",
+        create_schema,
+        quote_qualified(table),
+        values.join(", "),
+        column_list.join(", ")
+    ))
+}
+
+/// Render a scalar YAML value as a SQL literal.
+fn sql_literal(value: &serde_yaml::Value) -> Result<String> {
+    Ok(match value {
+        serde_yaml::Value::Null => "NULL".to_string(),
+        serde_yaml::Value::Bool(b) => b.to_string().to_uppercase(),
+        serde_yaml::Value::Number(n) => n.to_string(),
+        serde_yaml::Value::String(s) => format!("'{}'", s.replace('\'', "''")),
+        other => anyhow::bail!("given values must be scalars, got {:?}", other),
+    })
+}
+
+/// Render an expected cell the way result rows are read back from DuckDB.
+fn cell_string(value: &serde_yaml::Value) -> String {
+    match value {
+        serde_yaml::Value::Null => "null".to_string(),
+        serde_yaml::Value::Bool(b) => b.to_string(),
+        serde_yaml::Value::Number(n) => n.to_string(),
+        serde_yaml::Value::String(s) => s.clone(),
+        other => serde_yaml::to_string(other)
+            .unwrap_or_default()
+            .trim()
+            .to_string(),
+    }
+}
+
+#[cfg(test)]
+#[path = "test_macros_test.rs"]
+mod tests;
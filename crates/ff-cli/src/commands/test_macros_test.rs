@@ -0,0 +1,90 @@
+use super::*;
+use crate::commands::test_support::{global, write, write_config};
+use std::path::Path;
+use tempfile::tempdir;
+
+fn write_project(root: &Path, fixture: &str) {
+    write_config(root, "test_project", "");
+    write(
+        root,
+        "macros/checks.sql",
+        r#"{% macro cents_to_dollars(col) %}{{ col }} / 100.0{% endmacro %}
+{% macro test_positive_values(model, column) %}
+select {{ column }} from {{ model }} where {{ column }} <= 0
+{% endmacro %}"#,
+    );
+    write(root, "macros/checks.test.yml", fixture);
+}
+
+fn args() -> TestMacrosArgs {
+    TestMacrosArgs {
+        macros: None,
+        fail_fast: false,
+    }
+}
+
+#[tokio::test]
+async fn test_macro_fixtures_pass() {
+    let temp = tempdir().unwrap();
+    write_project(
+        temp.path(),
+        r#"
+tests:
+  - name: divides_by_100
+    macro: cents_to_dollars
+    args: ["amount"]
+    expected_sql: "amount   /   100.0"
+  - name: flags_non_positive
+    macro: test_positive_values
+    args: ["payments", "amount"]
+    expected_sql: |
+      SELECT amount
+      FROM payments
+      WHERE amount <= 0
+    given:
+      payments:
+        - { amount: 5 }
+        - { amount: -1 }
+        - { amount: 0 }
+    expected_rows:
+      - [-1]
+      - [0]
+"#,
+    );
+
+    execute(&args(), &global(temp.path())).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_macro_fixture_failure_exits_nonzero() {
+    let temp = tempdir().unwrap();
+    write_project(
+        temp.path(),
+        r#"
+tests:
+  - name: wrong_rows
+    macro: test_positive_values
+    args: ["payments", "amount"]
+    given:
+      payments:
+        - { amount: -1 }
+    expected_rows: []
+"#,
+    );
+
+    let err = execute(&args(), &global(temp.path())).await.unwrap_err();
+    assert!(err.downcast_ref::<ExitCode>().is_some_and(|c| c.0 == 2));
+}
+
+#[test]
+fn test_normalize_sql_ignores_formatting() {
+    let parser = SqlParser::from_dialect_name("duckdb").unwrap();
+    assert_eq!(
+        normalize_sql(&parser, "select  a,\n  b from t"),
+        normalize_sql(&parser, "SELECT a, b FROM t")
+    );
+    assert_eq!(
+        normalize_sql(&parser, "round(x / 100.0,2)"),
+        normalize_sql(&parser, "round(x / 100.0, 2)")
+    );
+}
@@ -0,0 +1,35 @@
+//! Project fixture helpers shared by the command unit tests
+
+use std::fs;
+use std::path::Path;
+
+use crate::cli::GlobalArgs;
+
+/// Write `content` to `path` under `root`, creating parent directories
+pub(crate) fn write(root: &Path, path: &str, content: &str) {
+    let path = root.join(path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
+
+/// Write the `featherflow.yml` of a project named `name` on an in-memory
+/// DuckDB, followed by any `extra` config lines
+pub(crate) fn write_config(root: &Path, name: &str, extra: &str) {
+    write(
+        root,
+        "featherflow.yml",
+        &format!(
+            "name: {name}\n{extra}dialect: duckdb\ndatabase:\n  default:\n    type: duckdb\n    path: ':memory:'\n"
+        ),
+    );
+}
+
+/// Global args for the project at `root`, without database introspection
+pub(crate) fn global(root: &Path) -> GlobalArgs {
+    GlobalArgs {
+        verbose: false,
+        project_dir: root.to_path_buf(),
+        database: None,
+        offline: true,
+    }
+}
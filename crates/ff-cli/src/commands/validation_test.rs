@@ -141,6 +141,7 @@ fn test_column_missing_description_produces_error() {
         data_type: "INTEGER".to_string(),
         description: None,
         description_ai_generated: None,
+        description_inherited_from: None,
         primary_key: false,
 
         tests: vec![],
@@ -163,6 +164,7 @@ fn test_column_empty_description_produces_error() {
         data_type: "INTEGER".to_string(),
         description: Some("".to_string()),
         description_ai_generated: None,
+        description_inherited_from: None,
         primary_key: false,
 
         tests: vec![],
@@ -183,6 +185,7 @@ fn test_column_with_description_passes() {
         data_type: "INTEGER".to_string(),
         description: Some("Unique identifier for the user".to_string()),
         description_ai_generated: None,
+        description_inherited_from: None,
         primary_key: false,
 
         tests: vec![],
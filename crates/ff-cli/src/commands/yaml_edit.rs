@@ -0,0 +1,600 @@
+//! Comment-preserving edits to the `columns:` list of node YAML files, and to
+//! the per-table `columns:` lists of source files.
+//!
+//! Round-tripping through `serde_yaml` would drop comments and blank lines,
+//! so edits here work on the raw lines: only the lines that actually change
+//! are touched, and everything else (key order, comments, descriptions,
+//! tests) is carried over verbatim. Also provides a small unified diff used
+//! to preview edits before they are written.
+
+use anyhow::{bail, Context, Result};
+
+/// Line-oriented editor for the top-level `columns:` sequence of a node YAML file,
+/// or the per-table `columns:` sequences of a source file.
+#[derive(Debug, Clone)]
+pub(crate) struct ColumnsEditor {
+    lines: Vec<String>,
+    trailing_newline: bool,
+}
+
+/// Lines of the mapping that owns a list: the whole document, or one
+/// entry of a top-level list such as a source's `tables:`.
+#[derive(Clone, Copy)]
+struct Region {
+    start: usize,
+    end: usize,
+    /// Indentation of the mapping's keys
+    indent: usize,
+}
+
+/// Location of a `key:` block holding a sequence of mappings.
+struct ListBlock {
+    /// Index of the `key:` line
+    header: usize,
+    /// Whether the header is the empty flow form `key: []`
+    empty_flow: bool,
+    /// Parsed entries in file order
+    items: Vec<ListItem>,
+}
+
+/// One `- name: ...` entry inside a list block.
+struct ListItem {
+    /// Index of the line holding the `-` marker
+    start: usize,
+    /// One past the last non-blank, non-comment line of the entry
+    end: usize,
+    /// Indentation of the `-` marker
+    dash_indent: usize,
+    /// Indentation of the entry's keys (`name`, `type`, ...)
+    key_indent: usize,
+    /// Unquoted `name:` value
+    name: String,
+}
+
+impl ColumnsEditor {
+    /// Wrap the raw YAML text for editing.
+    pub(crate) fn new(content: &str) -> Self {
+        Self {
+            lines: content.lines().map(String::from).collect(),
+            trailing_newline: content.ends_with('\n') || content.is_empty(),
+        }
+    }
+
+    /// Render the (possibly edited) YAML text.
+    pub(crate) fn render(&self) -> String {
+        let mut out = self.lines.join("\n");
+        if self.trailing_newline && !out.is_empty() {
+            out.push('\n');
+        }
+        out
+    }
+
+    /// Names of the declared columns, in file order.
+    pub(crate) fn column_names(&self) -> Result<Vec<String>> {
+        Ok(self
+            .columns_block(None)?
+            .1
+            .map(|block| block.items.into_iter().map(|item| item.name).collect())
+            .unwrap_or_default())
+    }
+
+    /// Replace the `type:` of a column. Returns `false` if the column is not declared.
+    pub(crate) fn set_type(&mut self, column: &str, data_type: &str) -> Result<bool> {
+        let Some(item) = self.find_item(column)? else {
+            return Ok(false);
+        };
+        let value = plain_or_quoted(data_type);
+        match self
+            .find_key(&item, "type")
+            .or_else(|| self.find_key(&item, "data_type"))
+        {
+            Some((line, key)) => self.replace_value(line, key, &value),
+            None => {
+                let name_line = self.find_key(&item, "name").map_or(item.start, |(l, _)| l);
+                self.lines.insert(
+                    name_line + 1,
+                    format!("{}type: {}", " ".repeat(item.key_indent), value),
+                );
+            }
+        }
+        Ok(true)
+    }
+
+    /// Set the `description:` of a column, replacing any existing value.
+    ///
+    /// Returns `false` if the column is not declared.
+    pub(crate) fn set_description(&mut self, column: &str, description: &str) -> Result<bool> {
+        let Some(item) = self.find_item(column)? else {
+            return Ok(false);
+        };
+        let value = double_quoted(description);
+        match self.find_key(&item, "description") {
+            Some((line, key)) => {
+                self.remove_block_scalar_body(line, item.key_indent, item.end);
+                self.replace_value(line, key, &value);
+            }
+            None => {
+                let anchor = self
+                    .find_key(&item, "type")
+                    .or_else(|| self.find_key(&item, "data_type"))
+                    .or_else(|| self.find_key(&item, "name"))
+                    .map_or(item.start, |(l, _)| l);
+                self.lines.insert(
+                    anchor + 1,
+                    format!("{}description: {}", " ".repeat(item.key_indent), value),
+                );
+            }
+        }
+        Ok(true)
+    }
+
+    /// Remove a column entry. Returns `false` if the column is not declared.
+    pub(crate) fn remove_column(&mut self, column: &str) -> Result<bool> {
+        let Some(item) = self.find_item(column)? else {
+            return Ok(false);
+        };
+        self.lines.drain(item.start..item.end);
+        Ok(true)
+    }
+
+    /// Append a column entry at the end of the `columns:` block, creating the
+    /// block if the file does not have one yet.
+    pub(crate) fn append_column(&mut self, column: &str, data_type: &str) -> Result<()> {
+        self.append_column_in(None, column, data_type)
+    }
+
+    /// Append a column entry to the `columns:` of the `index`-th entry of a
+    /// source file's `tables:`, creating the block if the table has none.
+    pub(crate) fn append_table_column(
+        &mut self,
+        index: usize,
+        column: &str,
+        data_type: &str,
+    ) -> Result<()> {
+        self.append_column_in(Some(index), column, data_type)
+    }
+
+    /// Append an entry with the given columns to a source file's `tables:`,
+    /// creating the block if the file does not have one yet.
+    pub(crate) fn append_table(&mut self, name: &str, columns: &[(String, String)]) -> Result<()> {
+        let column_offset = self.table_column_offset()?;
+        let (insert_at, dash_indent) = match self.scan_list("tables", self.document())? {
+            Some(block) => {
+                if block.empty_flow {
+                    self.lines[block.header] = "tables:".to_string();
+                }
+                match block.items.last() {
+                    Some(last) => (last.end, last.dash_indent),
+                    None => (block.header + 1, 2),
+                }
+            }
+            None => {
+                while self.lines.last().is_some_and(|l| l.trim().is_empty()) {
+                    self.lines.pop();
+                }
+                self.lines.push("tables:".to_string());
+                (self.lines.len(), 2)
+            }
+        };
+        let key_indent = dash_indent + 2;
+        let column_dash = key_indent + column_offset;
+        let mut entry = vec![
+            format!(
+                "{}- name: {}",
+                " ".repeat(dash_indent),
+                plain_or_quoted(name)
+            ),
+            format!("{}columns:", " ".repeat(key_indent)),
+        ];
+        for (column, data_type) in columns {
+            entry.extend(column_lines(column_dash, column, data_type));
+        }
+        self.lines.splice(insert_at..insert_at, entry);
+        Ok(())
+    }
+
+    fn append_column_in(
+        &mut self,
+        table: Option<usize>,
+        column: &str,
+        data_type: &str,
+    ) -> Result<()> {
+        let (region, block) = self.columns_block(table)?;
+        let (insert_at, dash_indent) = match block {
+            Some(block) => {
+                if block.empty_flow {
+                    self.lines[block.header] = format!("{}columns:", " ".repeat(region.indent));
+                }
+                match block.items.last() {
+                    Some(last) => (last.end, last.dash_indent),
+                    None => (block.header + 1, region.indent + 2),
+                }
+            }
+            None if table.is_some() => {
+                let dash_indent = region.indent + self.table_column_offset()?;
+                self.lines
+                    .insert(region.end, format!("{}columns:", " ".repeat(region.indent)));
+                (region.end + 1, dash_indent)
+            }
+            None => {
+                while self.lines.last().is_some_and(|l| l.trim().is_empty()) {
+                    self.lines.pop();
+                }
+                self.lines.push(String::new());
+                self.lines.push("columns:".to_string());
+                (self.lines.len(), 2)
+            }
+        };
+        self.lines.splice(
+            insert_at..insert_at,
+            column_lines(dash_indent, column, data_type),
+        );
+        Ok(())
+    }
+
+    fn find_item(&self, column: &str) -> Result<Option<ListItem>> {
+        Ok(self.columns_block(None)?.1.and_then(|block| {
+            block
+                .items
+                .into_iter()
+                .find(|item| item.name.eq_ignore_ascii_case(column))
+        }))
+    }
+
+    /// How far the `columns:` entries of `tables:` are indented past their
+    /// `columns:` key, following the first table that has any (default 2).
+    fn table_column_offset(&self) -> Result<usize> {
+        let Some(tables) = self.scan_list("tables", self.document())? else {
+            return Ok(2);
+        };
+        for table in &tables.items {
+            let region = Region {
+                start: table.start,
+                end: table.end,
+                indent: table.key_indent,
+            };
+            if let Some(first) = self
+                .scan_list("columns", region)?
+                .and_then(|columns| columns.items.into_iter().next())
+            {
+                return Ok(first.dash_indent - table.key_indent);
+            }
+        }
+        Ok(2)
+    }
+
+    /// The whole document as a region.
+    fn document(&self) -> Region {
+        Region {
+            start: 0,
+            end: self.lines.len(),
+            indent: 0,
+        }
+    }
+
+    /// Locate a `columns:` block: the document's own, or that of the
+    /// `index`-th entry of its `tables:`.
+    fn columns_block(&self, table: Option<usize>) -> Result<(Region, Option<ListBlock>)> {
+        let region = match table {
+            None => self.document(),
+            Some(index) => {
+                let item = self
+                    .scan_list("tables", self.document())?
+                    .and_then(|block| block.items.into_iter().nth(index))
+                    .with_context(|| format!("no entry {index} in `tables:`"))?;
+                Region {
+                    start: item.start,
+                    end: item.end,
+                    indent: item.key_indent,
+                }
+            }
+        };
+        Ok((region, self.scan_list("columns", region)?))
+    }
+
+    /// Locate the `key:` block of a region and its entries.
+    fn scan_list(&self, key: &str, region: Region) -> Result<Option<ListBlock>> {
+        let prefix = format!("{key}:");
+        let Some(header) = (region.start..region.end).find(|&idx| {
+            let line = &self.lines[idx];
+            indent_of(line) == region.indent && line[region.indent..].starts_with(&prefix)
+        }) else {
+            return Ok(None);
+        };
+        let inline = strip_comment(&self.lines[header][region.indent + prefix.len()..]).trim();
+        let empty_flow = match inline {
+            "" => false,
+            "[]" => true,
+            _ => {
+                bail!("inline (flow-style) `{key}:` lists cannot be edited; use one entry per line")
+            }
+        };
+
+        let mut items: Vec<ListItem> = Vec::new();
+        let mut dash_indent: Option<usize> = None;
+        for (idx, line) in self
+            .lines
+            .iter()
+            .enumerate()
+            .take(region.end)
+            .skip(header + 1)
+        {
+            let trimmed = line.trim_start();
+            if trimmed.is_empty() || trimmed.starts_with('#') {
+                continue;
+            }
+            let indent = indent_of(line);
+            let is_dash = trimmed == "-" || trimmed.starts_with("- ");
+            let unindented_dash =
+                is_dash && indent == region.indent && dash_indent.is_none_or(|d| d == indent);
+            if indent <= region.indent && !unindented_dash {
+                break;
+            }
+            if is_dash && dash_indent.is_none_or(|d| d == indent) {
+                dash_indent = Some(indent);
+                let after_dash = &line[indent + 1..];
+                let key_indent = indent + 1 + (after_dash.len() - after_dash.trim_start().len());
+                items.push(ListItem {
+                    start: idx,
+                    end: idx + 1,
+                    dash_indent: indent,
+                    key_indent,
+                    name: String::new(),
+                });
+            } else if let Some(item) = items.last_mut() {
+                item.end = idx + 1;
+            }
+        }
+
+        for item in &mut items {
+            if let Some((line, key)) = self.find_key(item, "name") {
+                item.name = unquote(strip_comment(&self.lines[line][key..])).to_string();
+            }
+        }
+
+        Ok(Some(ListBlock {
+            header,
+            empty_flow,
+            items,
+        }))
+    }
+
+    /// Find `key:` inside an entry. Returns the line index and the byte offset
+    /// just past the colon.
+    fn find_key(&self, item: &ListItem, key: &str) -> Option<(usize, usize)> {
+        let prefix = format!("{key}:");
+        (item.start..item.end).find_map(|idx| {
+            let line = &self.lines[idx];
+            let at_key_indent = indent_of(line) == item.key_indent
+                || (idx == item.start && line.len() > item.key_indent);
+            if !at_key_indent {
+                return None;
+            }
+            let rest = &line[item.key_indent..];
+            let matches = rest.starts_with(&prefix)
+                && rest[prefix.len()..]
+                    .chars()
+                    .next()
+                    .is_none_or(char::is_whitespace);
+            matches.then_some((idx, item.key_indent + prefix.len()))
+        })
+    }
+
+    /// Replace the scalar after a key, keeping any trailing `# comment`.
+    fn replace_value(&mut self, line: usize, value_start: usize, value: &str) {
+        let old = &self.lines[line];
+        let comment = comment_suffix(&old[value_start..]);
+        self.lines[line] = format!("{} {}{}", &old[..value_start], value, comment);
+    }
+
+    /// Drop the indented body of a `|` / `>` block scalar that starts on `line`.
+    fn remove_block_scalar_body(&mut self, line: usize, key_indent: usize, item_end: usize) {
+        let colon = self.lines[line][key_indent..]
+            .find(':')
+            .map_or(0, |p| key_indent + p + 1);
+        let indicator = strip_comment(&self.lines[line][colon..]).trim();
+        if !(indicator.starts_with('|') || indicator.starts_with('>')) {
+            return;
+        }
+        let body_end = (line + 1..item_end)
+            .find(|&idx| {
+                let l = &self.lines[idx];
+                !l.trim().is_empty() && indent_of(l) <= key_indent
+            })
+            .unwrap_or(item_end);
+        self.lines.drain(line + 1..body_end);
+    }
+}
+
+/// The `- name:` / `type:` lines of a column entry whose dash is at `dash_indent`.
+fn column_lines(dash_indent: usize, column: &str, data_type: &str) -> [String; 2] {
+    [
+        format!(
+            "{}- name: {}",
+            " ".repeat(dash_indent),
+            plain_or_quoted(column)
+        ),
+        format!(
+            "{}type: {}",
+            " ".repeat(dash_indent + 2),
+            plain_or_quoted(data_type)
+        ),
+    ]
+}
+
+/// Number of leading spaces.
+fn indent_of(line: &str) -> usize {
+    line.len() - line.trim_start_matches(' ').len()
+}
+
+/// Strip a trailing ` # comment` that is not inside quotes.
+fn strip_comment(value: &str) -> &str {
+    let cut = comment_start(value).unwrap_or(value.len());
+    &value[..cut]
+}
+
+/// The ` # comment` suffix of a value (including its leading whitespace), or `""`.
+fn comment_suffix(value: &str) -> &str {
+    match comment_start(value) {
+        Some(pos) => {
+            let ws = value[..pos].len() - value[..pos].trim_end().len();
+            &value[pos - ws..]
+        }
+        None => "",
+    }
+}
+
+fn comment_start(value: &str) -> Option<usize> {
+    let mut quote: Option<char> = None;
+    let mut prev_ws = true;
+    for (i, c) in value.char_indices() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => {}
+            None if c == '"' || c == '\'' => quote = Some(c),
+            None if c == '#' && prev_ws => return Some(i),
+            None => {}
+        }
+        prev_ws = c.is_whitespace();
+    }
+    None
+}
+
+fn unquote(value: &str) -> &str {
+    let v = value.trim();
+    let quoted = v.len() >= 2
+        && ((v.starts_with('"') && v.ends_with('"')) || (v.starts_with('\'') && v.ends_with('\'')));
+    if quoted {
+        &v[1..v.len() - 1]
+    } else {
+        v
+    }
+}
+
+/// Render a scalar plain when YAML would read it back as the same string, quoted otherwise.
+fn plain_or_quoted(value: &str) -> String {
+    const RESERVED: &[&str] = &["true", "false", "yes", "no", "on", "off", "null", "~"];
+    let plain_safe = value
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || " _(),[].".contains(c))
+        && !value.ends_with(' ')
+        && !RESERVED.contains(&value.to_ascii_lowercase().as_str());
+    if plain_safe {
+        value.to_string()
+    } else {
+        double_quoted(value)
+    }
+}
+
+fn double_quoted(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{escaped}\"")
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiffOp {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// Lines of context shown around each change in [`unified_diff`].
+const DIFF_CONTEXT: usize = 2;
+
+/// Render a unified diff between two texts, or an empty string if they are equal.
+pub(crate) fn unified_diff(label: &str, old: &str, new: &str) -> String {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+    let ops = diff_ops(&a, &b);
+
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for (idx, _) in ops
+        .iter()
+        .enumerate()
+        .filter(|(_, (op, _))| *op != DiffOp::Equal)
+    {
+        let lo = idx.saturating_sub(DIFF_CONTEXT);
+        let hi = (idx + DIFF_CONTEXT).min(ops.len() - 1);
+        match ranges.last_mut() {
+            Some((_, end)) if lo <= *end + 1 => *end = hi,
+            _ => ranges.push((lo, hi)),
+        }
+    }
+    if ranges.is_empty() {
+        return String::new();
+    }
+
+    let mut old_line = vec![0usize; ops.len() + 1];
+    let mut new_line = vec![0usize; ops.len() + 1];
+    for (k, (op, _)) in ops.iter().enumerate() {
+        old_line[k + 1] = old_line[k] + usize::from(*op != DiffOp::Insert);
+        new_line[k + 1] = new_line[k] + usize::from(*op != DiffOp::Delete);
+    }
+
+    let mut out = format!("--- {label}\n+++ {label}\n");
+    for (lo, hi) in ranges {
+        out.push_str(&format!(
+            "@@ -{},{} +{},{} @@\n",
+            old_line[lo] + 1,
+            old_line[hi + 1] - old_line[lo],
+            new_line[lo] + 1,
+            new_line[hi + 1] - new_line[lo]
+        ));
+        for (op, line) in &ops[lo..=hi] {
+            let marker = match op {
+                DiffOp::Equal => ' ',
+                DiffOp::Delete => '-',
+                DiffOp::Insert => '+',
+            };
+            out.push(marker);
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Line-level edit script via a longest-common-subsequence table.
+fn diff_ops<'a>(a: &[&'a str], b: &[&'a str]) -> Vec<(DiffOp, &'a str)> {
+    let (n, m) = (a.len(), b.len());
+    let mut lcs = vec![vec![0u32; m + 1]; n + 1];
+    for i in (0..n).rev() {
+        for j in (0..m).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut ops = Vec::with_capacity(n.max(m));
+    while i < n && j < m {
+        if a[i] == b[j] {
+            ops.push((DiffOp::Equal, a[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            ops.push((DiffOp::Delete, a[i]));
+            i += 1;
+        } else {
+            ops.push((DiffOp::Insert, b[j]));
+            j += 1;
+        }
+    }
+    ops.extend(a[i..].iter().map(|l| (DiffOp::Delete, *l)));
+    ops.extend(b[j..].iter().map(|l| (DiffOp::Insert, *l)));
+    ops
+}
+
+#[cfg(test)]
+#[path = "yaml_edit_test.rs"]
+mod tests;
@@ -0,0 +1,146 @@
+use super::*;
+
+const MODEL_YAML: &str = r#"version: 1
+description: "Orders fact"
+# owned by analytics
+columns:
+  - name: order_id
+    type: INTEGER # surrogate key
+    description: "Order identifier"
+    tests:
+      - unique
+
+  # kept for BI
+  - name: legacy_code
+    type: VARCHAR
+  - name: amount
+    type: DOUBLE
+tags:
+  - finance
+"#;
+
+#[test]
+fn test_column_names_in_file_order() {
+    let editor = ColumnsEditor::new(MODEL_YAML);
+    assert_eq!(
+        editor.column_names().unwrap(),
+        vec!["order_id", "legacy_code", "amount"]
+    );
+}
+
+#[test]
+fn test_untouched_content_roundtrips() {
+    let editor = ColumnsEditor::new(MODEL_YAML);
+    assert_eq!(editor.render(), MODEL_YAML);
+}
+
+#[test]
+fn test_set_type_keeps_trailing_comment() {
+    let mut editor = ColumnsEditor::new(MODEL_YAML);
+    assert!(editor.set_type("ORDER_ID", "BIGINT").unwrap());
+    assert!(editor.set_type("amount", "DECIMAL(10,2)").unwrap());
+    assert!(!editor.set_type("missing", "INTEGER").unwrap());
+
+    let out = editor.render();
+    assert!(out.contains("    type: BIGINT # surrogate key\n"));
+    assert!(out.contains("    type: DECIMAL(10,2)\n"));
+    assert!(out.contains("# owned by analytics"));
+}
+
+#[test]
+fn test_remove_column_keeps_neighbours() {
+    let mut editor = ColumnsEditor::new(MODEL_YAML);
+    assert!(editor.remove_column("legacy_code").unwrap());
+
+    let out = editor.render();
+    assert!(!out.contains("legacy_code"));
+    assert!(out.contains("      - unique\n"));
+    assert!(out.contains("  - name: amount\n    type: DOUBLE\ntags:\n"));
+    assert_eq!(editor.column_names().unwrap(), vec!["order_id", "amount"]);
+}
+
+#[test]
+fn test_append_column_after_last_entry() {
+    let mut editor = ColumnsEditor::new(MODEL_YAML);
+    editor.append_column("created_at", "TIMESTAMP").unwrap();
+
+    let out = editor.render();
+    assert!(out.contains(
+        "  - name: amount\n    type: DOUBLE\n  - name: created_at\n    type: TIMESTAMP\ntags:\n"
+    ));
+}
+
+#[test]
+fn test_append_column_creates_block() {
+    let mut editor = ColumnsEditor::new("version: 1\n");
+    editor.append_column("id", "INTEGER").unwrap();
+    editor.append_column("true", "VARCHAR").unwrap();
+    assert_eq!(
+        editor.render(),
+        "version: 1\n\ncolumns:\n  - name: id\n    type: INTEGER\n  - name: \"true\"\n    type: VARCHAR\n"
+    );
+}
+
+#[test]
+fn test_append_column_replaces_empty_flow_list() {
+    let mut editor = ColumnsEditor::new("version: 1\ncolumns: []\n");
+    editor.append_column("id", "INTEGER").unwrap();
+    assert_eq!(
+        editor.render(),
+        "version: 1\ncolumns:\n  - name: id\n    type: INTEGER\n"
+    );
+}
+
+#[test]
+fn test_unindented_sequence() {
+    let yaml = "version: 1\ncolumns:\n- name: id\n  type: INTEGER\n- name: name\n  type: VARCHAR\nkind: sql\n";
+    let mut editor = ColumnsEditor::new(yaml);
+    assert_eq!(editor.column_names().unwrap(), vec!["id", "name"]);
+    assert!(editor.set_type("name", "TEXT").unwrap());
+    editor.append_column("email", "VARCHAR").unwrap();
+    assert_eq!(
+        editor.render(),
+        "version: 1\ncolumns:\n- name: id\n  type: INTEGER\n- name: name\n  type: TEXT\n- name: email\n  type: VARCHAR\nkind: sql\n"
+    );
+}
+
+#[test]
+fn test_flow_style_columns_rejected() {
+    let editor = ColumnsEditor::new("columns: [{name: id}]\n");
+    assert!(editor.column_names().is_err());
+}
+
+#[test]
+fn test_set_description_inserts_and_replaces() {
+    let mut editor = ColumnsEditor::new(MODEL_YAML);
+    assert!(editor.set_description("amount", "Order total").unwrap());
+    assert!(editor
+        .set_description("order_id", "Primary \"key\"")
+        .unwrap());
+
+    let out = editor.render();
+    assert!(out.contains("    type: DOUBLE\n    description: \"Order total\"\n"));
+    assert!(out.contains("    description: \"Primary \\\"key\\\"\"\n"));
+}
+
+#[test]
+fn test_set_description_replaces_block_scalar() {
+    let yaml = "columns:\n  - name: id\n    description: |\n      line one\n      line two\n    type: INTEGER\n";
+    let mut editor = ColumnsEditor::new(yaml);
+    assert!(editor.set_description("id", "Short").unwrap());
+    assert_eq!(
+        editor.render(),
+        "columns:\n  - name: id\n    description: \"Short\"\n    type: INTEGER\n"
+    );
+}
+
+#[test]
+fn test_unified_diff() {
+    let old = "a\nb\nc\nd\ne\nf\ng\n";
+    let new = "a\nb\nc\nD\ne\nf\ng\nh\n";
+    assert_eq!(
+        unified_diff("x.yml", old, new),
+        "--- x.yml\n+++ x.yml\n@@ -2,6 +2,7 @@\n b\n c\n-d\n+D\n e\n f\n g\n+h\n"
+    );
+    assert_eq!(unified_diff("x.yml", old, old), "");
+}
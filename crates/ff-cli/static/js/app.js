@@ -11,11 +11,13 @@ let projectData = null;
 let searchIndex = null;
 let graph = null;
 let selectedModel = null;
+let macroDocs = null;
 
 // -- Init --
 document.addEventListener('DOMContentLoaded', async () => {
   initTheme();
   await loadProjectData();
+  await loadMacroDocs();
   initSidebar();
   initSearch();
   initGraph();
@@ -40,6 +42,16 @@ async function loadProjectData() {
   }
 }
 
+async function loadMacroDocs() {
+  try {
+    const resp = await fetch('/api/macros.json');
+    macroDocs = await resp.json();
+  } catch (err) {
+    console.error('Failed to load macro docs:', err);
+    macroDocs = { project: [], builtin: [] };
+  }
+}
+
 async function loadSearchIndex() {
   if (searchIndex) return searchIndex;
   try {
@@ -159,6 +171,24 @@ function initSidebar() {
       sourceList.appendChild(item);
     }
   }
+
+  // Project macros
+  if (macroDocs && macroDocs.project.length > 0) {
+    document.getElementById('macro-section').classList.remove('hidden');
+    const macroList = document.getElementById('macro-list');
+    macroList.innerHTML = '';
+
+    for (const macro of macroDocs.project) {
+      const item = document.createElement('div');
+      item.className = 'model-item';
+      const name = document.createElement('span');
+      name.className = 'name';
+      name.textContent = macro.name;
+      item.appendChild(name);
+      item.addEventListener('click', () => showMacroPanel(macro));
+      macroList.appendChild(item);
+    }
+  }
 }
 
 function getModelPrefix(name) {
@@ -321,6 +351,35 @@ function showDetailPanel(doc) {
   panel.classList.remove('hidden');
 }
 
+function showMacroPanel(macro) {
+  const panel = document.getElementById('detail-panel');
+  document.getElementById('detail-title').textContent = macro.name;
+
+  let html = `<p class="detail-description">${escapeHtml(macro.description)}</p>`;
+  html += `<div class="detail-section"><h3>Category</h3><span class="badge">${escapeHtml(macro.category)}</span></div>`;
+  if (macro.params.length) {
+    html += '<div class="detail-section"><h3>Parameters</h3>';
+    html += '<table class="columns-table"><thead><tr><th>Name</th><th>Type</th><th>Description</th></tr></thead><tbody>';
+    for (const p of macro.params) {
+      html += `<tr>
+        <td><code>${escapeHtml(p.name)}</code>${p.required ? '' : ' <span class="badge">optional</span>'}</td>
+        <td>${escapeHtml(p.param_type)}</td>
+        <td>${escapeHtml(p.description)}</td>
+      </tr>`;
+    }
+    html += '</tbody></table></div>';
+  }
+  if (macro.example) {
+    html += `<div class="detail-section"><h3>Example</h3><pre class="sql-block">${escapeHtml(macro.example)}</pre></div>`;
+  }
+  if (macro.example_output) {
+    html += `<div class="detail-section"><h3>Output</h3><pre class="sql-block">${highlightSQL(escapeHtml(macro.example_output))}</pre></div>`;
+  }
+
+  document.getElementById('detail-content').innerHTML = html;
+  panel.classList.remove('hidden');
+}
+
 function closeDetailPanel() {
   document.getElementById('detail-panel').classList.add('hidden');
   selectedModel = null;
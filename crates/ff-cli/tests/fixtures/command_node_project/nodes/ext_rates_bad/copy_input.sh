@@ -0,0 +1,6 @@
+#!/bin/sh
+# Copy the single upstream table through unchanged.
+# FF_INPUT_FILES is a JSON object: {"stg_rates": "/path/to/stg_rates.parquet"}
+set -eu
+input=$(printf '%s' "$FF_INPUT_FILES" | sed 's/^{"[^"]*":"\([^"]*\)"}$/\1/')
+cp "$input" "$FF_OUTPUT_FILE"
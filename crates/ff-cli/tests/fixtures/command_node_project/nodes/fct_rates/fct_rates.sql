@@ -0,0 +1,5 @@
+select
+    code
+    , rate
+    , 1 / rate as inverse_rate
+from ext_rates
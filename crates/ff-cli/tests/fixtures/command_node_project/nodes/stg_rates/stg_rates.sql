@@ -0,0 +1,7 @@
+select
+    'EUR' as code
+    , 1.08::double as rate
+union all
+select
+    'GBP'
+    , 1.27::double
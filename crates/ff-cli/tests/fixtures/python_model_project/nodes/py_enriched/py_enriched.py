@@ -8,18 +8,17 @@ import duckdb
 import json
 import os
 
-db_path = os.environ["FF_DATABASE_PATH"]
-input_tables = json.loads(os.environ["FF_INPUT_TABLES"])
-output_table = os.environ["FF_OUTPUT_TABLE"]
+input_files = json.loads(os.environ["FF_INPUT_FILES"])
+output_file = os.environ["FF_OUTPUT_FILE"]
 
-conn = duckdb.connect(db_path)
+conn = duckdb.connect()
 
-# Read from upstream table
-df = conn.execute(f"SELECT * FROM {input_tables[0]}").fetchdf()
+# Read the exported upstream table
+df = conn.execute("SELECT * FROM read_parquet(?)", [input_files["stg_source"]]).fetchdf()
 
 # Enrich: add a score column
 df["score"] = df["amount"] * 1.1
 
-# Write output table
-conn.execute(f"CREATE OR REPLACE TABLE {output_table} AS SELECT * FROM df")
+# Write the output file for Feather-Flow to load
+conn.execute(f"COPY (SELECT * FROM df) TO '{output_file}' (FORMAT parquet)")
 conn.close()
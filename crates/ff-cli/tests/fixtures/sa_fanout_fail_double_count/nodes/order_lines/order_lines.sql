@@ -0,0 +1,5 @@
+select
+    o.id as order_id
+    , i.qty
+from raw_orders o
+inner join raw_items i on o.id = i.order_id
@@ -0,0 +1,7 @@
+select
+    o.id as order_id
+    , sum(o.shipping) as shipping_total
+    , sum(i.qty) as total_qty
+from raw_orders o
+inner join raw_items i on o.id = i.order_id
+group by o.id
@@ -2,4 +2,6 @@ select
     cast(price as integer) as price_int
     ,
     cast(amount as integer) as amount_int
+    ,
+    cast(amount as decimal(6, 2)) as amount_narrow
 from raw_data
@@ -987,6 +987,115 @@ fn test_compile_strict_flag() {
     let _ = output.status;
 }
 
+#[test]
+fn test_compile_honours_diagnostics_baseline() {
+    let tmp = tempfile::tempdir().unwrap();
+    let fixture = tmp.path().join("sa_xmodel_fail_missing_from_sql");
+    copy_dir_recursive(
+        Path::new("tests/fixtures/sa_xmodel_fail_missing_from_sql"),
+        &fixture,
+    );
+    let fixture = fixture.to_str().unwrap();
+
+    let output = Command::new(ff_bin())
+        .args([
+            "dt",
+            "analyze",
+            "--project-dir",
+            fixture,
+            "--write-baseline",
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "Writing the baseline should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let output = Command::new(ff_bin())
+        .args(["dt", "compile", "--project-dir", fixture])
+        .output()
+        .unwrap();
+    let combined = format!(
+        "{}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        output.status.success(),
+        "Baselined schema mismatches should not fail compile: {}",
+        combined
+    );
+    assert!(
+        !combined.contains("missing from SQL output"),
+        "Baselined schema mismatches should not be reported: {}",
+        combined
+    );
+
+    let output = Command::new(ff_bin())
+        .args(["dt", "compile", "--project-dir", fixture, "--strict"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "--strict should only fail on mismatches outside the baseline: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+
+#[test]
+fn test_compile_star_reads_propagated_schema_of_undeclared_node() {
+    let tmp = tempfile::tempdir().unwrap();
+    let root = tmp.path();
+    let write = |path: &str, content: &str| {
+        let path = root.join(path);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    };
+    write(
+        "featherflow.yml",
+        "name: star_project\ndatabase:\n  default:\n    type: duckdb\n    path: ':memory:'\n",
+    );
+    // No declared columns: star() must fall back to the inferred schema
+    write(
+        "nodes/stg_orders/stg_orders.sql",
+        "SELECT 1 AS order_id, 'x' AS note",
+    );
+    write("nodes/stg_orders/stg_orders.yml", "version: 1\n");
+    write(
+        "nodes/orders/orders.sql",
+        "SELECT {{ star('stg_orders', except=['note']) }} FROM stg_orders",
+    );
+    write(
+        "nodes/orders/orders.yml",
+        "version: 1\ncolumns:\n  - name: order_id\n    type: INTEGER\n",
+    );
+    let output_dir = root.join("compiled");
+
+    let output = Command::new(ff_bin())
+        .args([
+            "dt",
+            "compile",
+            "--offline",
+            "--project-dir",
+            root.to_str().unwrap(),
+            "--output-dir",
+            output_dir.to_str().unwrap(),
+        ])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "Compile should succeed: {}{}",
+        String::from_utf8_lossy(&output.stdout),
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let compiled = std::fs::read_to_string(output_dir.join("orders/orders.sql")).unwrap();
+    assert!(compiled.contains("order_id"), "compiled: {}", compiled);
+    assert!(!compiled.contains("note"), "compiled: {}", compiled);
+}
+
 #[test]
 fn test_compile_with_output_dir() {
     let fixture = "tests/fixtures/dual_path_project";
@@ -1083,3 +1192,73 @@ fn test_sample_project_compile_dual_path() {
         stdout
     );
 }
+
+// ═══════════════════════════════════════════════════════════════════════
+// Section 15: Command nodes
+// ═══════════════════════════════════════════════════════════════════════
+
+/// Run `ff dt run -n <nodes>` on a private copy of `command_node_project`.
+fn run_command_project(nodes: &str) -> std::process::Output {
+    let tmp = tempfile::tempdir().unwrap();
+    let fixture = tmp.path().join("command_node_project");
+    copy_dir_recursive(Path::new("tests/fixtures/command_node_project"), &fixture);
+
+    Command::new(ff_bin())
+        .args([
+            "dt",
+            "run",
+            "--project-dir",
+            fixture.to_str().unwrap(),
+            "-n",
+            nodes,
+        ])
+        .output()
+        .unwrap_or_else(|e| panic!("Failed to run ff dt run -n {nodes}: {e}"))
+}
+
+#[test]
+fn test_command_node_project_loads() {
+    let project = ff_core::Project::load(Path::new("tests/fixtures/command_node_project")).unwrap();
+    let model = project.get_model("ext_rates").unwrap();
+    assert!(model.is_command());
+    assert!(model.raw_sql.is_empty());
+    assert!(model.depends_on.iter().any(|d| d.as_ref() == "stg_rates"));
+}
+
+#[test]
+fn test_command_node_runs_end_to_end() {
+    let output = run_command_project("+fct_rates");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "Running the command node and its downstream model should succeed.\nstdout: {}\nstderr: {}",
+        stdout,
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(
+        stdout.contains("ext_rates (command)"),
+        "The command node should be reported as run: {}",
+        stdout
+    );
+    assert!(
+        stdout.contains("fct_rates (table)"),
+        "The model reading the command output should run: {}",
+        stdout
+    );
+}
+
+#[test]
+fn test_command_node_output_violating_schema_fails() {
+    let output = run_command_project("+ext_rates_bad");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        !output.status.success(),
+        "A command output missing a declared column should fail the run: {}",
+        stdout
+    );
+    assert!(
+        stdout.contains("[CMD002]") && stdout.contains("fetched_at"),
+        "The failure should name the missing column: {}",
+        stdout
+    );
+}
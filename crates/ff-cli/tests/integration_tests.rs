@@ -1460,6 +1460,7 @@ fn test_load_deferred_manifest() {
             post_hook: vec![],
             wap: None,
             sql_checksum: None,
+            columns: vec![],
         },
     );
     // Save manifest
@@ -1514,6 +1515,7 @@ fn test_defer_dependency_resolution() {
             post_hook: vec![],
             wap: None,
             sql_checksum: None,
+            columns: vec![],
         },
     );
 
@@ -1536,6 +1538,7 @@ fn test_defer_dependency_resolution() {
             post_hook: vec![],
             wap: None,
             sql_checksum: None,
+            columns: vec![],
         },
     );
 
@@ -1586,6 +1589,7 @@ fn test_defer_missing_upstream_detection() {
             post_hook: vec![],
             wap: None,
             sql_checksum: None,
+            columns: vec![],
         },
     );
 
@@ -1628,6 +1632,7 @@ fn test_defer_transitive_dependencies() {
             post_hook: vec![],
             wap: None,
             sql_checksum: None,
+            columns: vec![],
         },
     );
 
@@ -1650,6 +1655,7 @@ fn test_defer_transitive_dependencies() {
             post_hook: vec![],
             wap: None,
             sql_checksum: None,
+            columns: vec![],
         },
     );
 
@@ -1710,6 +1716,7 @@ fn test_defer_slim_ci_scenario() {
                 post_hook: vec![],
                 wap: None,
                 sql_checksum: None,
+                columns: vec![],
             },
         );
     }
@@ -2120,6 +2127,7 @@ fn test_analysis_diagnostic_json_roundtrip() {
         column: Some("col1".to_string()),
         hint: Some("Fix it".to_string()),
         pass_name: "type_inference".into(),
+        span: None,
     };
 
     let json = serde_json::to_string(&diag).unwrap();
@@ -2203,7 +2211,13 @@ fn test_analysis_pass_names() {
     assert!(names.contains(&"plan_unused_columns"));
     assert!(names.contains(&"cross_model_consistency"));
     assert!(names.contains(&"description_drift"));
-    assert_eq!(names.len(), 6);
+    assert!(names.contains(&"sql_style"));
+    assert!(names.contains(&"plan_join_fanout"));
+    assert!(names.contains(&"plan_determinism"));
+    assert!(names.contains(&"plan_null_semantics"));
+    assert!(names.contains(&"plan_numeric_precision"));
+    assert!(names.contains(&"plan_predicates"));
+    assert_eq!(names.len(), 12);
 }
 
 // ── Phase 1: Type Inference (A002, A004, A005) ─────────────────────────
@@ -2268,8 +2282,8 @@ fn test_analysis_lossy_cast_a005() {
     let diags = run_single_pass(&pipeline, "plan_type_inference");
     let a005 = diagnostics_with_code(&diags, ff_analysis::DiagnosticCode::A005);
     assert!(
-        a005.len() >= 2,
-        "Expected at least 2 A005 (FLOAT->INT, DECIMAL->INT), got {}:\n{:#?}",
+        a005.len() >= 3,
+        "Expected at least 3 A005 (FLOAT->INT, DECIMAL->INT, DECIMAL narrowing), got {}:\n{:#?}",
         a005.len(),
         a005
     );
@@ -2447,6 +2461,32 @@ fn test_analysis_equi_join_no_join_diagnostics() {
     );
 }
 
+#[test]
+fn test_analysis_join_fanout_a035_a036() {
+    let pipeline = build_analysis_pipeline("tests/fixtures/sa_fanout_fail_double_count");
+    let diags = run_single_pass(&pipeline, "plan_join_fanout");
+
+    let a035 = diagnostics_with_code(&diags, ff_analysis::DiagnosticCode::A035);
+    assert_eq!(
+        a035.len(),
+        1,
+        "Expected A035 only for SUM over the order side, got: {:#?}",
+        diags
+    );
+    assert_eq!(a035[0].model, "order_totals");
+    assert_eq!(a035[0].column.as_deref(), Some("shipping_total"));
+
+    let a036 = diagnostics_with_code(&diags, ff_analysis::DiagnosticCode::A036);
+    assert_eq!(
+        a036.len(),
+        1,
+        "Expected A036 for order_lines, got: {:#?}",
+        diags
+    );
+    assert_eq!(a036[0].model, "order_lines");
+    assert!(diagnostics_with_code(&diags, ff_analysis::DiagnosticCode::A034).is_empty());
+}
+
 // ── Phase 5: Cross-Model Consistency (A040, A041) ───────────────────────
 
 #[test]
@@ -276,6 +276,174 @@ fn test_analyze_diagnostic_project_json() {
     );
 }
 
+#[test]
+fn test_analyze_baseline_hides_recorded_diagnostics() {
+    let tmp = tempfile::tempdir().unwrap();
+    let baseline = tmp.path().join("baseline.json");
+    let baseline = baseline.to_str().unwrap();
+
+    let output = Command::new(ff_bin())
+        .args([
+            "dt",
+            "analyze",
+            "--project-dir",
+            diagnostic_project_dir(),
+            "--baseline",
+            baseline,
+            "--write-baseline",
+        ])
+        .output()
+        .expect("Failed to run ff dt analyze --write-baseline");
+    assert!(
+        output.status.success(),
+        "Writing a baseline should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let recorded: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(baseline).unwrap()).unwrap();
+    assert!(
+        !recorded["entries"].as_array().unwrap().is_empty(),
+        "Baseline should record the diagnostic project's findings"
+    );
+
+    let output = Command::new(ff_bin())
+        .args([
+            "dt",
+            "analyze",
+            "--project-dir",
+            diagnostic_project_dir(),
+            "--baseline",
+            baseline,
+            "--output",
+            "json",
+        ])
+        .output()
+        .expect("Failed to run ff dt analyze --baseline");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "Baselined diagnostics should not fail the run: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let diagnostics: Vec<serde_json::Value> = serde_json::from_str(stdout.trim()).unwrap();
+    assert!(
+        diagnostics.is_empty(),
+        "Every diagnostic should be baselined, got: {:?}",
+        diagnostics
+    );
+}
+
+#[test]
+fn test_analyze_sarif_output() {
+    let output = Command::new(ff_bin())
+        .args([
+            "dt",
+            "analyze",
+            "--project-dir",
+            diagnostic_project_dir(),
+            "--output",
+            "sarif",
+        ])
+        .output()
+        .expect("Failed to run ff dt analyze --output sarif");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let log: serde_json::Value = serde_json::from_str(stdout.trim())
+        .unwrap_or_else(|e| panic!("SARIF output should be valid JSON: {}\n{}", e, stdout));
+
+    assert_eq!(log["version"], "2.1.0");
+    let run = &log["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "featherflow");
+    let results = run["results"].as_array().unwrap();
+    assert!(
+        !results.is_empty(),
+        "Diagnostic project should produce results"
+    );
+    for result in results {
+        assert!(result["ruleId"].is_string());
+        assert!(["error", "warning", "note"].contains(&result["level"].as_str().unwrap()));
+        assert!(result["partialFingerprints"]["featherflow/v1"].is_string());
+        let uri = result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"]
+            .as_str()
+            .unwrap();
+        assert!(
+            uri.ends_with(".sql"),
+            "uri should point at the node's SQL: {}",
+            uri
+        );
+    }
+}
+
+#[test]
+fn test_analyze_verify_engine() {
+    let output = Command::new(ff_bin())
+        .args([
+            "dt",
+            "analyze",
+            "--project-dir",
+            sample_project_dir(),
+            "--verify-engine",
+            "--output",
+            "json",
+        ])
+        .output()
+        .expect("Failed to run ff dt analyze --verify-engine");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "Analyze with --verify-engine should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let diagnostics: Vec<serde_json::Value> = serde_json::from_str(stdout.trim())
+        .unwrap_or_else(|e| panic!("JSON parse failed: {}\nraw: {}", e, stdout));
+    for d in diagnostics
+        .iter()
+        .filter(|d| d.get("code").and_then(|c| c.as_str()) == Some("A009"))
+    {
+        let message = d.get("message").and_then(|m| m.as_str()).unwrap_or("");
+        assert!(message.contains("DuckDB"), "unexpected A009: {:#?}", d);
+    }
+}
+
+#[test]
+fn test_analyze_verify_engine_honours_pass_filter() {
+    let output = Command::new(ff_bin())
+        .args([
+            "dt",
+            "analyze",
+            "--project-dir",
+            sample_project_dir(),
+            "--verify-engine",
+            "--pass",
+            "plan_type_inference",
+            "--output",
+            "json",
+        ])
+        .output()
+        .expect("Failed to run ff dt analyze --verify-engine --pass");
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "Analyze should succeed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    let diagnostics: Vec<serde_json::Value> = serde_json::from_str(stdout.trim())
+        .unwrap_or_else(|e| panic!("JSON parse failed: {}\nraw: {}", e, stdout));
+    let passes: Vec<&str> = diagnostics
+        .iter()
+        .filter_map(|d| d.get("pass_name").and_then(|p| p.as_str()))
+        .collect();
+    assert!(
+        passes
+            .iter()
+            .all(|p| ["plan_type_inference", "suppressions", "baseline"].contains(p)),
+        "only the selected pass should report: {:?}",
+        passes
+    );
+}
+
 // ── ff analyze (sample_project regression guard) ────────────────────────
 
 /// Regression guard: `ff analyze` on the main sample project must produce zero
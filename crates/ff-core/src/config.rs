@@ -19,6 +19,9 @@ const TEST_DIR: &str = "tests";
 /// Hardcoded output/target directory name.
 const DEFAULT_TARGET_DIR: &str = "target";
 
+/// Default diagnostics baseline file, relative to the project root.
+const DEFAULT_ANALYSIS_BASELINE: &str = "analysis-baseline.json";
+
 /// Hardcoded clean targets.
 const DEFAULT_CLEAN_DIRS: &[&str] = &[DEFAULT_TARGET_DIR];
 
@@ -92,6 +95,10 @@ pub struct Config {
     /// Named run groups — collections of nodes with preset run parameters
     #[serde(default)]
     pub run_groups: Option<HashMap<String, RunGroupConfig>>,
+
+    /// Other Feather-Flow projects whose macros, functions, and nodes this project uses
+    #[serde(default)]
+    pub packages: Vec<crate::package::PackageSpec>,
 }
 
 /// Database connection configuration.
@@ -466,6 +473,31 @@ impl Config {
             }
         }
 
+        let mut package_names = std::collections::HashSet::new();
+        for package in &self.packages {
+            package.validate()?;
+            if package.name == self.name || !package_names.insert(package.name.as_str()) {
+                return Err(CoreError::ConfigInvalid {
+                    message: format!(
+                        "package name '{}' is used more than once (or matches the project name)",
+                        package.name
+                    ),
+                });
+            }
+        }
+
+        for code in self.analysis.lint.rules.keys() {
+            if !VALID_LINT_CODES.contains(&code.as_str()) {
+                return Err(CoreError::ConfigInvalid {
+                    message: format!(
+                        "Unknown lint rule '{}' in analysis.lint.rules. Valid rules: {}",
+                        code,
+                        VALID_LINT_CODES.join(", ")
+                    ),
+                });
+            }
+        }
+
         Ok(())
     }
 
@@ -654,14 +686,73 @@ pub enum ConfigSeverity {
 pub struct AnalysisConfig {
     #[serde(default)]
     pub severity_overrides: HashMap<String, ConfigSeverity>,
+    /// SQL style lint rules (L-codes)
+    #[serde(default)]
+    pub lint: LintConfig,
+    /// Diagnostics baseline file, relative to the project root
+    /// (default: `analysis-baseline.json`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub baseline: Option<String>,
+}
+
+impl AnalysisConfig {
+    /// Absolute path of the diagnostics baseline file
+    pub fn baseline_path(&self, root: &Path) -> PathBuf {
+        root.join(
+            self.baseline
+                .as_deref()
+                .unwrap_or(DEFAULT_ANALYSIS_BASELINE),
+        )
+    }
+}
+
+/// SQL style lint configuration (`analysis.lint`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LintConfig {
+    /// Run the `sql_style` pass during analysis
+    #[serde(default)]
+    pub enabled: bool,
+    /// Tags that mark a model as a mart, where `SELECT *` is banned (L001)
+    #[serde(default = "default_mart_tags")]
+    pub mart_tags: Vec<String>,
+    /// Required name prefixes per layer (L006).
+    ///
+    /// A model belongs to a layer when it carries a tag or targets a schema
+    /// with the layer's name, e.g. `staging: [stg_]`.
+    #[serde(default)]
+    pub naming: HashMap<String, Vec<String>>,
+    /// Per-rule severity, replacing the rule's default (`off` disables the rule)
+    #[serde(default)]
+    pub rules: HashMap<String, ConfigSeverity>,
+}
+
+impl Default for LintConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            mart_tags: default_mart_tags(),
+            naming: HashMap::new(),
+            rules: HashMap::new(),
+        }
+    }
+}
+
+fn default_mart_tags() -> Vec<String> {
+    vec!["mart".to_string()]
 }
 
 /// Valid diagnostic codes that can be overridden in `analysis.severity_overrides`
-const VALID_DIAGNOSTIC_CODES: &[&str] = &[
-    "A002", "A003", "A004", "A005", "A010", "A011", "A012", "A020", "A030", "A032", "A033", "A040",
-    "A041", "SA01", "SA02",
+/// and in a node's `analysis` block
+pub(crate) const VALID_DIAGNOSTIC_CODES: &[&str] = &[
+    "A002", "A003", "A004", "A005", "A006", "A007", "A008", "A009", "A010", "A011", "A012", "A013",
+    "A014", "A015", "A016", "A020", "A030", "A032", "A033", "A034", "A035", "A036", "A040", "A041",
+    "A042", "A060", "A061", "A062", "A070", "A071", "A072", "A073", "A074", "A080", "A081", "A082",
+    "SA01", "SA02", "L001", "L002", "L003", "L004", "L005", "L006",
 ];
 
+/// Valid lint rule codes for `analysis.lint.rules`
+const VALID_LINT_CODES: &[&str] = &["L001", "L002", "L003", "L004", "L005", "L006"];
+
 /// Data classification governance settings
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct DataClassificationConfig {
@@ -680,6 +771,9 @@ pub struct DocumentationConfig {
     pub require_model_descriptions: bool,
     #[serde(default)]
     pub require_column_descriptions: bool,
+    /// Fill missing column descriptions from copied/renamed upstream columns at compile time
+    #[serde(default)]
+    pub inherit_column_descriptions: bool,
 }
 
 /// Where to place the query comment relative to the SQL.
@@ -417,6 +417,54 @@ analysis:
     assert_eq!(config.analysis.severity_overrides.len(), 15);
 }
 
+#[test]
+fn test_analysis_lint_defaults() {
+    let config: Config = serde_yaml::from_str("name: test").unwrap();
+    assert!(!config.analysis.lint.enabled);
+    assert_eq!(config.analysis.lint.mart_tags, vec!["mart".to_string()]);
+    assert!(config.analysis.lint.naming.is_empty());
+    assert!(config.analysis.lint.rules.is_empty());
+}
+
+#[test]
+fn test_analysis_lint_parsing() {
+    let yaml = r#"
+name: test_project
+analysis:
+  lint:
+    enabled: true
+    mart_tags: [marts, reporting]
+    naming:
+      staging: [stg_]
+      marts: [fct_, dim_]
+    rules:
+      L004: off
+      L005: error
+"#;
+    let config: Config = serde_yaml::from_str(yaml).unwrap();
+    assert!(config.validate().is_ok());
+    let lint = &config.analysis.lint;
+    assert!(lint.enabled);
+    assert_eq!(lint.mart_tags, vec!["marts", "reporting"]);
+    assert_eq!(lint.naming["marts"], vec!["fct_", "dim_"]);
+    assert_eq!(lint.rules["L004"], ConfigSeverity::Off);
+    assert_eq!(lint.rules["L005"], ConfigSeverity::Error);
+}
+
+#[test]
+fn test_analysis_lint_rejects_non_lint_code() {
+    let yaml = r#"
+name: test_project
+analysis:
+  lint:
+    rules:
+      A020: off
+"#;
+    let config: Config = serde_yaml::from_str(yaml).unwrap();
+    let err = config.validate().unwrap_err().to_string();
+    assert!(err.contains("Unknown lint rule 'A020'"));
+}
+
 #[test]
 fn test_unknown_fields_rejected() {
     let yaml = r#"
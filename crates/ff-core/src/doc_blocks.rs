@@ -0,0 +1,138 @@
+//! Reusable documentation blocks
+//!
+//! `{% docs name %}...{% enddocs %}` blocks in `.md` files under the node and
+//! macro directories hold descriptions shared by several nodes or columns. A
+//! description that contains `{{ doc('name') }}` has it replaced with the
+//! block's text when the project loads, so the manifest, the meta database,
+//! generated docs, and description checks all see the resolved text.
+
+use crate::error::{CoreError, CoreResult};
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+static DOCS_BLOCK_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?s)\{%-?\s*docs\s+(\w+)\s*-?%\}(.*?)\{%-?\s*enddocs\s*-?%\}")
+        .expect("valid regex literal")
+});
+
+static DOC_REF_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"\{\{-?\s*doc\(\s*(?:'(\w+)'|"(\w+)")\s*\)\s*-?\}\}"#)
+        .expect("valid regex literal")
+});
+
+/// A documentation block
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DocBlock {
+    /// Block text, trimmed
+    pub text: String,
+    /// File the block is defined in
+    pub path: PathBuf,
+}
+
+/// Doc block name -> block
+pub type DocBlocks = BTreeMap<String, DocBlock>;
+
+/// Collect doc blocks from every `.md` file under `dirs` (recursively) into
+/// `blocks`, rejecting a name defined twice.
+pub fn discover_doc_blocks(dirs: &[PathBuf], blocks: &mut DocBlocks) -> CoreResult<()> {
+    let mut files = Vec::new();
+    for dir in dirs {
+        collect_markdown_files(dir, &mut files)?;
+    }
+    files.sort();
+
+    for path in files {
+        let content = std::fs::read_to_string(&path).map_err(|e| CoreError::IoWithPath {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+        for captures in DOCS_BLOCK_RE.captures_iter(&content) {
+            let name = captures[1].to_string();
+            if let Some(existing) = blocks.get(&name) {
+                return Err(CoreError::DuplicateDocBlock {
+                    name,
+                    first: existing.path.display().to_string(),
+                    second: path.display().to_string(),
+                });
+            }
+            blocks.insert(
+                name,
+                DocBlock {
+                    text: captures[2].trim().to_string(),
+                    path: path.clone(),
+                },
+            );
+        }
+    }
+    Ok(())
+}
+
+fn collect_markdown_files(dir: &Path, files: &mut Vec<PathBuf>) -> CoreResult<()> {
+    if !dir.is_dir() {
+        return Ok(());
+    }
+    let entries = std::fs::read_dir(dir).map_err(|e| CoreError::IoWithPath {
+        path: dir.display().to_string(),
+        source: e,
+    })?;
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_markdown_files(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "md") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+/// Replace every `{{ doc('name') }}` in `text` with the block's text.
+///
+/// `location` names where the description was written, for the error when
+/// a block does not exist.
+pub fn resolve_doc_refs(text: &str, blocks: &DocBlocks, location: &str) -> CoreResult<String> {
+    if !text.contains("doc(") {
+        return Ok(text.to_string());
+    }
+    let mut missing = None;
+    let resolved = DOC_REF_RE.replace_all(text, |captures: &regex::Captures<'_>| {
+        let name = captures
+            .get(1)
+            .or_else(|| captures.get(2))
+            .map_or("", |m| m.as_str());
+        match blocks.get(name) {
+            Some(block) => block.text.clone(),
+            None => {
+                missing.get_or_insert_with(|| name.to_string());
+                String::new()
+            }
+        }
+    });
+    match missing {
+        Some(name) => Err(CoreError::DocBlockNotFound {
+            name,
+            location: location.to_string(),
+        }),
+        None => Ok(resolved.into_owned()),
+    }
+}
+
+/// Resolve an optional description in place.
+pub(crate) fn resolve_description(
+    description: &mut Option<String>,
+    blocks: &DocBlocks,
+    location: impl FnOnce() -> String,
+) -> CoreResult<()> {
+    if let Some(text) = description {
+        if text.contains("doc(") {
+            *text = resolve_doc_refs(text, blocks, &location())?;
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "doc_blocks_test.rs"]
+mod tests;
@@ -0,0 +1,65 @@
+use super::*;
+use tempfile::TempDir;
+
+fn blocks_from(files: &[(&str, &str)]) -> CoreResult<DocBlocks> {
+    let dir = TempDir::new().unwrap();
+    for (name, content) in files {
+        let path = dir.path().join(name);
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, content).unwrap();
+    }
+    let mut blocks = DocBlocks::new();
+    discover_doc_blocks(&[dir.path().to_path_buf()], &mut blocks)?;
+    Ok(blocks)
+}
+
+#[test]
+fn test_discover_and_resolve_doc_blocks() {
+    let blocks = blocks_from(&[(
+        "shared/docs.md",
+        "# Shared\n\n{% docs customer_id %}\nUnique identifier of a customer.\n{% enddocs %}\n\n\
+         {%- docs order_status -%}One of placed, shipped, returned.{%- enddocs -%}",
+    )])
+    .unwrap();
+    assert_eq!(blocks.len(), 2);
+
+    assert_eq!(
+        resolve_doc_refs("{{ doc('customer_id') }}", &blocks, "stg_customers.id").unwrap(),
+        "Unique identifier of a customer."
+    );
+    assert_eq!(
+        resolve_doc_refs(
+            r#"Current status. {{ doc("order_status") }}"#,
+            &blocks,
+            "stg_orders.status"
+        )
+        .unwrap(),
+        "Current status. One of placed, shipped, returned."
+    );
+    assert_eq!(
+        resolve_doc_refs("No references here", &blocks, "x").unwrap(),
+        "No references here"
+    );
+}
+
+#[test]
+fn test_unknown_doc_block_is_an_error() {
+    let blocks = DocBlocks::new();
+    let err = resolve_doc_refs("{{ doc('missing') }}", &blocks, "stg_orders.status").unwrap_err();
+    assert!(matches!(err, CoreError::DocBlockNotFound { .. }), "{}", err);
+    assert!(err.to_string().contains("stg_orders.status"), "{}", err);
+}
+
+#[test]
+fn test_duplicate_doc_block_is_an_error() {
+    let err = blocks_from(&[
+        ("a.md", "{% docs id %}A{% enddocs %}"),
+        ("b.md", "{% docs id %}B{% enddocs %}"),
+    ])
+    .unwrap_err();
+    assert!(
+        matches!(err, CoreError::DuplicateDocBlock { .. }),
+        "{}",
+        err
+    );
+}
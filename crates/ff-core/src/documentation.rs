@@ -0,0 +1,198 @@
+//! Column description inheritance through lineage
+//!
+//! When `documentation.inherit_column_descriptions` is enabled, a column that
+//! is copied or renamed from an upstream model or source, and has no
+//! description of its own, inherits the upstream description at compile time.
+//! Inherited descriptions are recorded on the column together with the
+//! `node.column` they were originally written on.
+
+use crate::Project;
+use std::collections::HashMap;
+
+/// A copy or rename lineage edge used for description inheritance.
+///
+/// Like [`crate::classification::ClassificationEdge`], this decouples the
+/// propagation logic from the SQL-level lineage types in `ff-sql`. Transform
+/// and inspect edges must be excluded before calling
+/// [`propagate_descriptions_topo`].
+#[derive(Debug, Clone)]
+pub struct DescriptionEdge {
+    /// Source model (or source table) name
+    pub source_model: String,
+    /// Source column name
+    pub source_column: String,
+    /// Target model name
+    pub target_model: String,
+    /// Target column name
+    pub target_column: String,
+}
+
+/// A description a column receives from upstream.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InheritedDescription {
+    /// The inherited description text
+    pub description: String,
+    /// Where the description was written, as `node.column`
+    pub origin: String,
+}
+
+/// Build a lookup of node → lowercase column name → description from model
+/// schemas and source tables. Only columns with a description are included.
+pub fn build_description_lookup(project: &Project) -> HashMap<String, HashMap<String, String>> {
+    let mut lookup: HashMap<String, HashMap<String, String>> = HashMap::new();
+
+    for (name, model) in &project.models {
+        let Some(schema) = &model.schema else {
+            continue;
+        };
+        let cols: HashMap<String, String> = schema
+            .columns
+            .iter()
+            .filter_map(|c| Some((c.name.to_lowercase(), c.description.clone()?)))
+            .collect();
+        if !cols.is_empty() {
+            lookup.insert(name.to_string(), cols);
+        }
+    }
+
+    for source_file in &project.sources {
+        for table in &source_file.tables {
+            let cols: HashMap<String, String> = table
+                .columns
+                .iter()
+                .filter_map(|c| Some((c.name.to_lowercase(), c.description.clone()?)))
+                .collect();
+            if !cols.is_empty() {
+                lookup.insert(table.name.clone(), cols);
+            }
+        }
+    }
+
+    lookup
+}
+
+/// Propagate column descriptions along copy/rename edges in topological order.
+///
+/// A column inherits only when it has no declared description and all of its
+/// documented upstream columns agree on the same text; conflicting upstream
+/// descriptions are left for the user to resolve. Chains are followed, so the
+/// recorded origin is always the column the description was written on.
+///
+/// Returns a map of `model → lowercase column → inherited description`.
+pub fn propagate_descriptions_topo(
+    topo_order: &[String],
+    edges: &[DescriptionEdge],
+    declared: &HashMap<String, HashMap<String, String>>,
+) -> HashMap<String, HashMap<String, InheritedDescription>> {
+    let mut incoming: HashMap<(&str, String), Vec<&DescriptionEdge>> = HashMap::new();
+    let mut model_columns: HashMap<&str, Vec<String>> = HashMap::new();
+    for edge in edges {
+        let column = edge.target_column.to_lowercase();
+        let cols = model_columns.entry(edge.target_model.as_str()).or_default();
+        if !cols.contains(&column) {
+            cols.push(column.clone());
+        }
+        incoming
+            .entry((edge.target_model.as_str(), column))
+            .or_default()
+            .push(edge);
+    }
+
+    let mut inherited: HashMap<String, HashMap<String, InheritedDescription>> = HashMap::new();
+
+    for model in topo_order {
+        let Some(columns) = model_columns.get(model.as_str()) else {
+            continue;
+        };
+
+        for column in columns {
+            let is_documented = declared
+                .get(model)
+                .is_some_and(|cols| cols.contains_key(column));
+            if is_documented {
+                continue;
+            }
+
+            let candidates: Vec<InheritedDescription> = incoming
+                .get(&(model.as_str(), column.clone()))
+                .into_iter()
+                .flatten()
+                .filter_map(|edge| upstream_description(edge, declared, &inherited))
+                .collect();
+            let Some(first) = candidates.first() else {
+                continue;
+            };
+            if candidates
+                .iter()
+                .any(|c| c.description != first.description)
+            {
+                continue;
+            }
+
+            let first = first.clone();
+            inherited
+                .entry(model.clone())
+                .or_default()
+                .insert(column.clone(), first);
+        }
+    }
+
+    inherited
+}
+
+/// The description available on the source side of an edge, declared or inherited.
+fn upstream_description(
+    edge: &DescriptionEdge,
+    declared: &HashMap<String, HashMap<String, String>>,
+    inherited: &HashMap<String, HashMap<String, InheritedDescription>>,
+) -> Option<InheritedDescription> {
+    let column = edge.source_column.to_lowercase();
+    if let Some(description) = declared
+        .get(&edge.source_model)
+        .and_then(|cols| cols.get(&column))
+    {
+        return Some(InheritedDescription {
+            description: description.clone(),
+            origin: format!("{}.{}", edge.source_model, edge.source_column),
+        });
+    }
+    inherited
+        .get(&edge.source_model)
+        .and_then(|cols| cols.get(&column))
+        .cloned()
+}
+
+/// Fill missing column descriptions in model schemas from propagated results.
+///
+/// Columns that already have a description are never overwritten. Each filled
+/// column records its origin in `description_inherited_from`. Returns the
+/// number of columns updated.
+pub fn apply_inherited_descriptions(
+    project: &mut Project,
+    inherited: &HashMap<String, HashMap<String, InheritedDescription>>,
+) -> usize {
+    let mut applied = 0;
+    for (name, model) in &mut project.models {
+        let Some(cols) = inherited.get(name.as_str()) else {
+            continue;
+        };
+        let Some(schema) = model.schema.as_mut() else {
+            continue;
+        };
+        for column in &mut schema.columns {
+            if column.description.is_some() {
+                continue;
+            }
+            if let Some(found) = cols.get(&column.name.to_lowercase()) {
+                column.description = Some(found.description.clone());
+                column.description_inherited_from = Some(found.origin.clone());
+                applied += 1;
+            }
+        }
+    }
+    applied
+}
+
+#[cfg(test)]
+#[path = "documentation_test.rs"]
+mod tests;
@@ -0,0 +1,80 @@
+use super::*;
+
+fn edge(source: &str, source_col: &str, target: &str, target_col: &str) -> DescriptionEdge {
+    DescriptionEdge {
+        source_model: source.into(),
+        source_column: source_col.into(),
+        target_model: target.into(),
+        target_column: target_col.into(),
+    }
+}
+
+fn declare(
+    declared: &mut HashMap<String, HashMap<String, String>>,
+    node: &str,
+    column: &str,
+    description: &str,
+) {
+    declared
+        .entry(node.into())
+        .or_default()
+        .insert(column.into(), description.into());
+}
+
+#[test]
+fn test_propagate_descriptions_follows_chain_to_origin() {
+    // raw_orders (source) -> stg_orders (copy) -> fct_orders (rename)
+    let edges = vec![
+        edge("raw_orders", "id", "stg_orders", "id"),
+        edge("stg_orders", "id", "fct_orders", "order_id"),
+    ];
+    let topo = vec!["stg_orders".into(), "fct_orders".into()];
+    let mut declared = HashMap::new();
+    declare(&mut declared, "raw_orders", "id", "Order identifier");
+
+    let inherited = propagate_descriptions_topo(&topo, &edges, &declared);
+
+    let expected = InheritedDescription {
+        description: "Order identifier".into(),
+        origin: "raw_orders.id".into(),
+    };
+    assert_eq!(inherited["stg_orders"]["id"], expected);
+    assert_eq!(inherited["fct_orders"]["order_id"], expected);
+}
+
+#[test]
+fn test_propagate_descriptions_keeps_declared() {
+    let edges = vec![edge("a", "id", "b", "id")];
+    let topo = vec!["a".into(), "b".into()];
+    let mut declared = HashMap::new();
+    declare(&mut declared, "a", "id", "Upstream");
+    declare(&mut declared, "b", "id", "Downstream");
+
+    let inherited = propagate_descriptions_topo(&topo, &edges, &declared);
+    assert!(inherited.is_empty());
+}
+
+#[test]
+fn test_propagate_descriptions_skips_conflicting_upstreams() {
+    // b.id is fed by two upstream columns with different descriptions
+    let edges = vec![edge("a", "id", "b", "id"), edge("c", "id", "b", "id")];
+    let topo = vec!["a".into(), "c".into(), "b".into()];
+    let mut declared = HashMap::new();
+    declare(&mut declared, "a", "id", "Customer id");
+    declare(&mut declared, "c", "id", "Account id");
+
+    let inherited = propagate_descriptions_topo(&topo, &edges, &declared);
+    assert!(inherited.is_empty());
+}
+
+#[test]
+fn test_propagate_descriptions_is_case_insensitive() {
+    let edges = vec![edge("a", "ID", "b", "Id")];
+    let topo = vec!["a".into(), "b".into()];
+    let mut declared = HashMap::new();
+    declare(&mut declared, "a", "id", "Identifier");
+
+    let inherited = propagate_descriptions_topo(&topo, &edges, &declared);
+    assert_eq!(inherited["b"]["id"].description, "Identifier");
+    assert_eq!(inherited["b"]["id"].origin, "a.ID");
+}
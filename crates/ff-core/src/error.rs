@@ -167,6 +167,14 @@ pub enum CoreError {
     #[error("[PY003] Python model '{model}' schema violation: {message}")]
     PythonSchemaViolation { model: String, message: String },
 
+    /// CMD001: Command model program failed to start or exited non-zero
+    #[error("[CMD001] Command model '{name}' failed: {message}")]
+    CommandExecutionError { name: String, message: String },
+
+    /// CMD002: Command model output doesn't match declared columns
+    #[error("[CMD002] Command model '{model}' schema violation: {message}")]
+    CommandSchemaViolation { model: String, message: String },
+
     /// N001: Node directory is missing a YAML configuration file
     #[error("[N001] Node directory '{directory}' is missing a required .yml configuration file")]
     NodeMissingYaml { directory: String },
@@ -190,6 +198,42 @@ pub enum CoreError {
     /// N005: Node directory name doesn't match the data file name
     #[error("[N005] Node directory mismatch: directory '{directory}' contains file '{file}' (must match)")]
     NodeDirectoryMismatch { directory: String, file: String },
+
+    /// PKG001: Package directory does not exist
+    #[error("[PKG001] Package '{name}' not found at {path} (tarball packages are extracted by `ff dt deps`)")]
+    PackageNotFound { name: String, path: String },
+
+    /// PKG002: Package version does not satisfy the declared requirement
+    #[error("[PKG002] Package '{name}' is version {found}, which does not satisfy '{required}'")]
+    PackageVersionMismatch {
+        name: String,
+        required: String,
+        found: String,
+    },
+
+    /// PKG003: A package resource has the same name as an existing one
+    #[error("[PKG003] {kind} '{name}' from package '{package}' collides with an existing {kind} of the same name")]
+    PackageCollision {
+        package: String,
+        kind: String,
+        name: String,
+    },
+
+    /// PKG004: featherflow.lock is missing or does not match the packages on disk
+    #[error("[PKG004] featherflow.lock is out of date: {message}. Run `ff dt deps`")]
+    LockfileOutOfDate { message: String },
+
+    /// DOC001: A description references a doc block that does not exist
+    #[error("[DOC001] Description of '{location}' references unknown doc block '{name}'")]
+    DocBlockNotFound { name: String, location: String },
+
+    /// DOC002: Two doc blocks share a name
+    #[error("[DOC002] Doc block '{name}' is defined in both {first} and {second}")]
+    DuplicateDocBlock {
+        name: String,
+        first: String,
+        second: String,
+    },
 }
 
 /// Result type alias for CoreError
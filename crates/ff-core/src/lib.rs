@@ -8,6 +8,8 @@ pub mod checksum;
 pub mod classification;
 pub mod config;
 pub mod dag;
+pub mod doc_blocks;
+pub mod documentation;
 pub mod error;
 pub mod function;
 pub mod function_name;
@@ -15,6 +17,7 @@ pub mod model;
 pub mod model_name;
 mod newtype_string;
 pub mod node;
+pub mod package;
 pub mod project;
 pub mod query_comment;
 pub mod reference_manifest;
@@ -39,9 +42,13 @@ pub use function::{
     FunctionReturn, FunctionReturnColumn, FunctionSignature, FunctionType,
 };
 pub use function_name::FunctionName;
-pub use model::{DataClassification, Model, ModelKind, ModelSchema, PythonConfig};
+pub use model::{
+    CommandConfig, CommandFormat, DataClassification, Model, ModelKind, ModelSchema, PythonConfig,
+    PythonExchange,
+};
 pub use model_name::ModelName;
 pub use node::NodeKind;
+pub use package::{Package, PackageSpec};
 pub use project::{Project, ProjectParts};
 pub use query_comment::{ModelCommentInput, QueryCommentContext, QueryCommentMetadata};
 pub use reference_manifest::{ReferenceManifest, ReferenceModelRef};
@@ -4,8 +4,8 @@ pub mod schema;
 pub mod testing;
 
 pub use schema::{
-    ColumnReference, DataClassification, ModelKind, ModelSchema, PythonConfig, SchemaColumnDef,
-    StringOrVec,
+    ColumnReference, CommandConfig, CommandFormat, DataClassification, DiagnosticIgnore, ModelKind,
+    ModelSchema, NodeAnalysisConfig, PythonConfig, PythonExchange, SchemaColumnDef, StringOrVec,
 };
 pub use testing::{
     parse_test_definition, SchemaTest, SingularTest, TestConfig, TestDefinition, TestParams,
@@ -225,6 +225,63 @@ impl Model {
         })
     }
 
+    /// Create a new command model from its YAML file
+    ///
+    /// Command nodes have no source file, so `raw_sql` stays empty and the
+    /// `command` block stands in for the source when computing checksums.
+    /// Dependencies come from `depends_on` as for Python models.
+    pub fn from_command_node(
+        path: PathBuf,
+        schema: ModelSchema,
+    ) -> Result<Self, crate::error::CoreError> {
+        let name = path
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .ok_or_else(|| crate::error::CoreError::ModelParseError {
+                name: path.display().to_string(),
+                message: "Cannot extract model name from path".to_string(),
+            })?
+            .to_string();
+
+        match &schema.command {
+            Some(command) if !command.program.trim().is_empty() => {}
+            _ => {
+                return Err(CoreError::ModelParseError {
+                    name,
+                    message: "command node requires a `command.program`".into(),
+                });
+            }
+        }
+
+        let depends_on: HashSet<ModelName> = schema
+            .depends_on
+            .iter()
+            .filter_map(|s| ModelName::try_new(s.clone()))
+            .collect();
+
+        let (base_name, version) = Self::parse_version(&name);
+
+        Ok(Self {
+            name: ModelName::new(name),
+            path,
+            raw_sql: String::new(),
+            compiled_sql: None,
+            config: ModelConfig {
+                // Command output is always loaded into a table
+                materialized: Some(Materialization::Table),
+                schema: schema.schema.clone(),
+                tags: schema.tags.clone(),
+                ..ModelConfig::default()
+            },
+            depends_on,
+            external_deps: HashSet::new(),
+            schema: Some(schema),
+            base_name,
+            version,
+            kind: ModelKind::Command,
+        })
+    }
+
     /// Create a new model from a file path, using already-read YAML schema content.
     ///
     /// Avoids re-reading and re-parsing the YAML file when the caller has already
@@ -434,9 +491,23 @@ impl Model {
         self.kind == ModelKind::Python
     }
 
-    /// Compute a SHA-256 checksum of the raw source content (SQL or Python).
+    /// Check if this is a command model (an external program)
+    pub fn is_command(&self) -> bool {
+        self.kind == ModelKind::Command
+    }
+
+    /// Compute a SHA-256 checksum of the raw source content (SQL or Python),
+    /// or of the `command` block of a command model.
     pub fn sql_checksum(&self) -> String {
-        crate::compute_checksum(&self.raw_sql)
+        let command = self
+            .schema
+            .as_ref()
+            .and_then(|s| s.command.as_ref())
+            .filter(|_| self.is_command());
+        match command.and_then(|c| serde_json::to_string(c).ok()) {
+            Some(definition) => crate::compute_checksum(&definition),
+            None => crate::compute_checksum(&self.raw_sql),
+        }
     }
 
     /// Get the owner for this model from schema metadata
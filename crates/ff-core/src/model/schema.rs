@@ -21,6 +21,8 @@ pub enum ModelKind {
     Seed,
     /// A Python model — directory contains a `.py` file, executed via `uv run`
     Python,
+    /// An external program that writes its output table to a file
+    Command,
 }
 
 impl PartialEq for ModelKind {
@@ -50,6 +52,7 @@ impl std::fmt::Display for ModelKind {
             ModelKind::Model | ModelKind::Sql => write!(f, "sql"),
             ModelKind::Seed => write!(f, "seed"),
             ModelKind::Python => write!(f, "python"),
+            ModelKind::Command => write!(f, "command"),
         }
     }
 }
@@ -64,6 +67,79 @@ pub struct PythonConfig {
     /// Python package dependencies (e.g., ["pandas>=2.0", "scikit-learn>=1.3"])
     #[serde(default)]
     pub dependencies: Vec<String>,
+
+    /// How the script receives its inputs and returns its output
+    #[serde(default)]
+    pub exchange: PythonExchange,
+}
+
+/// How a Python model exchanges data with Feather-Flow
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum PythonExchange {
+    /// The script opens the DuckDB file (`FF_DATABASE_PATH`) and reads and
+    /// writes tables itself
+    Database,
+    /// Inputs and output are Parquet files; Feather-Flow loads the output
+    #[default]
+    Parquet,
+    /// Inputs and output are Arrow IPC stream files; Feather-Flow loads the output
+    Arrow,
+}
+
+impl std::fmt::Display for PythonExchange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PythonExchange::Database => write!(f, "database"),
+            PythonExchange::Parquet => write!(f, "parquet"),
+            PythonExchange::Arrow => write!(f, "arrow"),
+        }
+    }
+}
+
+/// Configuration for `kind: command` nodes
+///
+/// The program runs with the node directory as its working directory and
+/// receives the same `FF_*` environment variables as a Python model using
+/// file exchange. It must write its output to `FF_OUTPUT_FILE`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CommandConfig {
+    /// Program to run; paths containing a `/` are resolved against the node
+    /// directory, bare names are looked up on `PATH`
+    pub program: String,
+
+    /// Arguments passed to the program
+    #[serde(default)]
+    pub args: Vec<String>,
+
+    /// Extra environment variables for the program
+    #[serde(default)]
+    pub env: std::collections::BTreeMap<String, String>,
+
+    /// File format of the inputs and the output
+    #[serde(default)]
+    pub format: CommandFormat,
+}
+
+/// File format a command node reads and writes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum CommandFormat {
+    /// Parquet files
+    #[default]
+    Parquet,
+    /// Arrow IPC stream files
+    Arrow,
+}
+
+impl std::fmt::Display for CommandFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CommandFormat::Parquet => write!(f, "parquet"),
+            CommandFormat::Arrow => write!(f, "arrow"),
+        }
+    }
 }
 
 /// Schema metadata for a single model (from 1:1 .yml file)
@@ -124,8 +200,8 @@ pub struct ModelSchema {
     #[serde(default)]
     pub materialized: Option<String>,
 
-    /// Explicit dependency list for Python models (kind: python only).
-    /// Since Python scripts cannot be parsed for SQL table references,
+    /// Explicit dependency list for Python and command models.
+    /// Since scripts and programs cannot be parsed for SQL table references,
     /// dependencies must be declared here.
     #[serde(default)]
     pub depends_on: Vec<String>,
@@ -134,6 +210,10 @@ pub struct ModelSchema {
     #[serde(default)]
     pub python: Option<PythonConfig>,
 
+    /// Program to run (kind: command only)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub command: Option<CommandConfig>,
+
     /// Override target schema for seed/model loading
     #[serde(default)]
     pub schema: Option<String>,
@@ -178,6 +258,103 @@ pub struct ModelSchema {
     /// Enable/disable this seed (kind: seed only, default: true)
     #[serde(default = "default_enabled")]
     pub enabled: bool,
+
+    /// Per-node static analysis suppressions and severity overrides
+    #[serde(default)]
+    pub analysis: NodeAnalysisConfig,
+}
+
+/// Per-node analysis settings (`analysis:` block in node YAML)
+///
+/// ```yaml
+/// analysis:
+///   ignore:
+///     - code: A020
+///       columns: [legacy_id]
+///       reason: "kept for BI"
+///   overrides:
+///     A010: error
+/// ```
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct NodeAnalysisConfig {
+    /// Diagnostics suppressed for this node
+    #[serde(default)]
+    pub ignore: Vec<DiagnosticIgnore>,
+
+    /// Severity overrides that apply to this node only
+    #[serde(default)]
+    pub overrides: std::collections::HashMap<String, crate::config::ConfigSeverity>,
+}
+
+impl NodeAnalysisConfig {
+    /// Reject diagnostic codes in `ignore` or `overrides` that do not exist.
+    fn validate(&self, path: &std::path::Path) -> Result<(), CoreError> {
+        let codes = self
+            .ignore
+            .iter()
+            .map(|entry| ("ignore", entry.code()))
+            .chain(
+                self.overrides
+                    .keys()
+                    .map(|code| ("overrides", code.as_str())),
+            );
+        for (field, code) in codes {
+            if !crate::config::VALID_DIAGNOSTIC_CODES.contains(&code.to_uppercase().as_str()) {
+                return Err(CoreError::ConfigInvalid {
+                    message: format!(
+                        "Unknown diagnostic code '{}' in analysis.{} of {}. Valid codes: {}",
+                        code,
+                        field,
+                        path.display(),
+                        crate::config::VALID_DIAGNOSTIC_CODES.join(", ")
+                    ),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A suppressed diagnostic: a bare code, or a code limited to columns with a reason.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum DiagnosticIgnore {
+    /// Suppress every diagnostic with this code (e.g. `A030`)
+    Code(String),
+    /// Suppress a code, optionally only for some columns
+    Detailed {
+        code: String,
+        #[serde(default)]
+        columns: Vec<String>,
+        #[serde(default)]
+        reason: Option<String>,
+    },
+}
+
+impl DiagnosticIgnore {
+    /// The suppressed diagnostic code
+    pub fn code(&self) -> &str {
+        match self {
+            DiagnosticIgnore::Code(code) | DiagnosticIgnore::Detailed { code, .. } => code,
+        }
+    }
+
+    /// Columns the suppression is limited to (empty = whole node)
+    pub fn columns(&self) -> &[String] {
+        match self {
+            DiagnosticIgnore::Code(_) => &[],
+            DiagnosticIgnore::Detailed { columns, .. } => columns,
+        }
+    }
+
+    /// Why the diagnostic is suppressed, if given
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            DiagnosticIgnore::Code(_) => None,
+            DiagnosticIgnore::Detailed { reason, .. } => reason.as_deref(),
+        }
+    }
 }
 
 fn default_delimiter() -> char {
@@ -257,6 +434,7 @@ impl Default for ModelSchema {
             materialized: None,
             depends_on: Vec::new(),
             python: None,
+            command: None,
             schema: None,
             unique_key: None,
             incremental_strategy: None,
@@ -268,6 +446,7 @@ impl Default for ModelSchema {
             column_types: std::collections::HashMap::new(),
             delimiter: default_delimiter(),
             enabled: default_enabled(),
+            analysis: NodeAnalysisConfig::default(),
         }
     }
 }
@@ -297,6 +476,7 @@ impl ModelSchema {
                 version: schema.version,
             });
         }
+        schema.analysis.validate(path)?;
         Ok(schema)
     }
 
@@ -370,6 +550,12 @@ pub struct SchemaColumnDef {
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub description_ai_generated: Option<bool>,
 
+    /// Upstream `node.column` the description was inherited from through lineage.
+    /// Set at compile time when `documentation.inherit_column_descriptions` is enabled;
+    /// never read from YAML.
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub description_inherited_from: Option<String>,
+
     /// Whether this column is a primary key
     #[serde(default)]
     pub primary_key: bool,
@@ -35,6 +35,49 @@ columns:
     assert_eq!(schema.get_owner(), Some("data-team".to_string()));
 }
 
+#[test]
+fn test_parse_node_analysis_block() {
+    let yaml = r#"
+version: 1
+analysis:
+  ignore:
+    - A030
+    - code: A020
+      columns: [legacy_id]
+      reason: "kept for BI"
+  overrides:
+    A010: error
+"#;
+    let schema: ModelSchema = serde_yaml::from_str(yaml).unwrap();
+    let ignore = &schema.analysis.ignore;
+    assert_eq!(ignore.len(), 2);
+    assert_eq!(ignore[0].code(), "A030");
+    assert!(ignore[0].columns().is_empty());
+    assert_eq!(ignore[0].reason(), None);
+    assert_eq!(ignore[1].code(), "A020");
+    assert_eq!(ignore[1].columns(), ["legacy_id".to_string()]);
+    assert_eq!(ignore[1].reason(), Some("kept for BI"));
+    assert_eq!(
+        schema.analysis.overrides["A010"],
+        crate::config::ConfigSeverity::Error
+    );
+}
+
+#[test]
+fn test_node_analysis_block_rejects_unknown_codes() {
+    let path = std::path::Path::new("nodes/m/m.yml");
+    let ignore = "version: 1\nanalysis:\n  ignore:\n    - A999\n";
+    let err = ModelSchema::load_from_str(ignore, path).unwrap_err();
+    assert!(err.to_string().contains("'A999' in analysis.ignore"));
+
+    let overrides = "version: 1\nanalysis:\n  overrides:\n    X010: error\n";
+    let err = ModelSchema::load_from_str(overrides, path).unwrap_err();
+    assert!(err.to_string().contains("'X010' in analysis.overrides"));
+
+    let lowercase = "version: 1\nanalysis:\n  ignore:\n    - a030\n";
+    assert!(ModelSchema::load_from_str(lowercase, path).is_ok());
+}
+
 #[test]
 fn test_parse_owner_metadata() {
     let yaml = r##"
@@ -534,6 +577,23 @@ python:
     assert_eq!(python_config.dependencies.len(), 2);
     assert_eq!(python_config.dependencies[0], "pandas>=2.0");
     assert_eq!(python_config.dependencies[1], "scikit-learn>=1.3");
+    assert_eq!(python_config.exchange, PythonExchange::Parquet);
+}
+
+#[test]
+fn test_parse_python_exchange() {
+    let yaml = r#"
+version: 1
+kind: python
+depends_on:
+  - stg_orders
+python:
+  exchange: arrow
+"#;
+    let schema: ModelSchema = serde_yaml::from_str(yaml).unwrap();
+    let python_config = schema.python.as_ref().unwrap();
+    assert_eq!(python_config.exchange, PythonExchange::Arrow);
+    assert_eq!(python_config.exchange.to_string(), "arrow");
 }
 
 #[test]
@@ -18,7 +18,8 @@ use serde::{Deserialize, Serialize};
 /// | `seed`       | `.csv`      | CSV seed data                        |
 /// | `source`     | *(none)*    | External data source definition      |
 /// | `function`   | `.sql`      | User-defined SQL function / macro    |
-/// | `python`     | `.py`       | Python transformation *(reserved)*   |
+/// | `python`     | `.py`       | Python transformation                |
+/// | `command`    | *(none)*    | External program producing a table   |
 ///
 /// Legacy values (`model`, `sources`, `functions`) are accepted during
 /// deserialization and normalised to their modern equivalents via
@@ -34,8 +35,10 @@ pub enum NodeKind {
     Source,
     /// User-defined SQL function / macro
     Function,
-    /// Python transformation (reserved for future use)
+    /// Python transformation executed via `uv run`
     Python,
+    /// External program that writes a table to a file
+    Command,
 
     /// Legacy alias for [`NodeKind::Sql`]
     Model,
@@ -73,7 +76,7 @@ impl NodeKind {
             NodeKind::Seed => Some("csv"),
             NodeKind::Function => Some("sql"),
             NodeKind::Python => Some("py"),
-            NodeKind::Source => None,
+            NodeKind::Source | NodeKind::Command => None,
             _ => None,
         }
     }
@@ -86,6 +89,7 @@ impl NodeKind {
             NodeKind::Source => "source",
             NodeKind::Function => "function",
             NodeKind::Python => "python model",
+            NodeKind::Command => "command model",
             _ => "unknown",
         }
     }
@@ -99,6 +103,7 @@ impl std::fmt::Display for NodeKind {
             NodeKind::Source => write!(f, "source"),
             NodeKind::Function => write!(f, "function"),
             NodeKind::Python => write!(f, "python"),
+            NodeKind::Command => write!(f, "command"),
             _ => write!(f, "unknown"),
         }
     }
@@ -31,6 +31,7 @@ fn expected_extensions() {
     assert_eq!(NodeKind::Source.expected_extension(), None);
     assert_eq!(NodeKind::Function.expected_extension(), Some("sql"));
     assert_eq!(NodeKind::Python.expected_extension(), Some("py"));
+    assert_eq!(NodeKind::Command.expected_extension(), None);
 }
 
 #[test]
@@ -46,6 +47,9 @@ fn deserialize_modern_kinds() {
 
     let probe: NodeKindProbe = serde_yaml::from_str("kind: function").unwrap();
     assert_eq!(probe.kind.unwrap(), NodeKind::Function);
+
+    let probe: NodeKindProbe = serde_yaml::from_str("kind: command").unwrap();
+    assert_eq!(probe.kind.unwrap(), NodeKind::Command);
 }
 
 #[test]
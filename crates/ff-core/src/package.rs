@@ -0,0 +1,357 @@
+//! Local project packages
+//!
+//! A package is another Feather-Flow project pulled in through the
+//! `packages:` section of `featherflow.yml`, either from a local directory
+//! (e.g. a vendored git checkout) or from a `.tar.gz` tarball that
+//! `ff dt deps` extracts into `ff_packages/<name>/`. Package nodes and
+//! functions merge into discovery; package macros are namespaced under the
+//! package name (`shared.cents_to_dollars(...)`).
+//!
+//! `ff dt deps` records each package's version and content checksum in
+//! `featherflow.lock`. Loading a project with packages requires the lockfile
+//! to match what is on disk, so a package cannot change underneath a project
+//! without an explicit `ff dt deps`.
+
+use crate::config::Config;
+use crate::error::{CoreError, CoreResult};
+use crate::sql_utils::is_valid_identifier;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::{Path, PathBuf};
+
+/// Directory, relative to the project root, that tarball packages extract into
+pub const PACKAGES_DIR: &str = "ff_packages";
+
+/// Lockfile name, relative to the project root
+pub const LOCKFILE_NAME: &str = "featherflow.lock";
+
+/// One entry of the `packages:` section in `featherflow.yml`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PackageSpec {
+    /// Package name; must match the package's own project name and is the
+    /// namespace its macros are called through
+    pub name: String,
+
+    /// Directory containing the package's `featherflow.yml`, relative to the project root
+    #[serde(default)]
+    pub path: Option<String>,
+
+    /// `.tar.gz` archive of the package, relative to the project root
+    #[serde(default)]
+    pub tarball: Option<String>,
+
+    /// Semver requirement on the package's `version` (e.g. `">=1.2, <2"`)
+    #[serde(default)]
+    pub version: Option<String>,
+}
+
+impl PackageSpec {
+    /// Validate a spec in isolation: name, exactly one source, and version syntax.
+    pub(crate) fn validate(&self) -> CoreResult<()> {
+        let invalid = |reason: String| CoreError::ConfigInvalid {
+            message: format!("package '{}': {}", self.name, reason),
+        };
+        if !is_valid_identifier(&self.name) {
+            return Err(invalid(
+                "name must be a valid identifier (letters, digits, underscores)".to_string(),
+            ));
+        }
+        if self.path.is_some() == self.tarball.is_some() {
+            return Err(invalid(
+                "exactly one of `path` or `tarball` is required".to_string(),
+            ));
+        }
+        if let Some(ref requirement) = self.version {
+            semver::VersionReq::parse(requirement)
+                .map_err(|e| invalid(format!("invalid version requirement: {}", e)))?;
+        }
+        Ok(())
+    }
+
+    /// Directory the package is loaded from.
+    pub fn install_dir(&self, root: &Path) -> PathBuf {
+        match &self.path {
+            Some(path) => root.join(path),
+            None => root.join(PACKAGES_DIR).join(&self.name),
+        }
+    }
+
+    /// Tarball path, for tarball packages.
+    pub fn tarball_path(&self, root: &Path) -> Option<PathBuf> {
+        self.tarball.as_ref().map(|t| root.join(t))
+    }
+
+    /// Source as recorded in the lockfile (`path:<dir>` or `tarball:<file>`).
+    pub fn source_label(&self) -> String {
+        match (&self.path, &self.tarball) {
+            (Some(path), _) => format!("path:{}", path),
+            (None, Some(tarball)) => format!("tarball:{}", tarball),
+            (None, None) => String::new(),
+        }
+    }
+}
+
+/// A package resolved on disk
+#[derive(Debug, Clone)]
+pub struct Package {
+    /// Package name (macro namespace)
+    pub name: String,
+    /// Package project root
+    pub root: PathBuf,
+    /// The package's own `featherflow.yml`
+    pub config: Config,
+}
+
+impl Package {
+    /// Load a package from its install directory and check its name and version.
+    pub fn load(root: &Path, spec: &PackageSpec) -> CoreResult<Self> {
+        let package_root = spec.install_dir(root);
+        if !package_root.is_dir() {
+            return Err(CoreError::PackageNotFound {
+                name: spec.name.clone(),
+                path: package_root.display().to_string(),
+            });
+        }
+
+        let config = Config::load_from_dir(&package_root)?;
+        if config.name != spec.name {
+            return Err(CoreError::ConfigInvalid {
+                message: format!(
+                    "package '{}': project at {} is named '{}'",
+                    spec.name,
+                    package_root.display(),
+                    config.name
+                ),
+            });
+        }
+        if !config.packages.is_empty() {
+            log::warn!(
+                "Package '{}' declares its own packages; nested packages are not loaded",
+                spec.name
+            );
+        }
+
+        if let Some(ref requirement) = spec.version {
+            let required =
+                semver::VersionReq::parse(requirement).map_err(|e| CoreError::ConfigInvalid {
+                    message: format!(
+                        "package '{}': invalid version requirement: {}",
+                        spec.name, e
+                    ),
+                })?;
+            let found =
+                semver::Version::parse(&config.version).map_err(|e| CoreError::ConfigInvalid {
+                    message: format!(
+                        "package '{}': version '{}' is not semver: {}",
+                        spec.name, config.version, e
+                    ),
+                })?;
+            if !required.matches(&found) {
+                return Err(CoreError::PackageVersionMismatch {
+                    name: spec.name.clone(),
+                    required: requirement.clone(),
+                    found: config.version.clone(),
+                });
+            }
+        }
+
+        Ok(Self {
+            name: spec.name.clone(),
+            root: package_root,
+            config,
+        })
+    }
+
+    /// Absolute macro directories of the package.
+    pub fn macro_paths(&self) -> Vec<PathBuf> {
+        self.config.macro_paths_absolute(&self.root)
+    }
+}
+
+/// `featherflow.lock` contents
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Lockfile {
+    /// Locked packages, in `featherflow.yml` order
+    pub packages: Vec<LockedPackage>,
+}
+
+/// One locked package
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct LockedPackage {
+    /// Package name
+    pub name: String,
+    /// `path:<dir>` or `tarball:<file>`
+    pub source: String,
+    /// Package version at lock time
+    pub version: String,
+    /// SHA-256 of the tarball, or of the directory contents for path packages
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+    /// SHA-256 of the extracted `ff_packages/<name>/` tree, for tarball packages
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub installed_checksum: Option<String>,
+}
+
+impl LockedPackage {
+    /// Build the lock entry for a loaded package.
+    pub fn new(root: &Path, spec: &PackageSpec, package: &Package) -> CoreResult<Self> {
+        let tree_checksum = dir_checksum(&package.root)?;
+        let (checksum, installed_checksum) = match spec.tarball_path(root) {
+            Some(path) => (file_checksum(&path)?, Some(tree_checksum)),
+            None => (tree_checksum, None),
+        };
+        Ok(Self {
+            name: spec.name.clone(),
+            source: spec.source_label(),
+            version: package.config.version.clone(),
+            checksum: Some(checksum),
+            installed_checksum,
+        })
+    }
+}
+
+impl Lockfile {
+    /// Load `featherflow.lock` from the project root, if present.
+    pub fn load(root: &Path) -> CoreResult<Option<Self>> {
+        let path = root.join(LOCKFILE_NAME);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = std::fs::read_to_string(&path).map_err(|e| CoreError::IoWithPath {
+            path: path.display().to_string(),
+            source: e,
+        })?;
+        let lockfile =
+            serde_yaml::from_str(&content).map_err(|e| CoreError::LockfileOutOfDate {
+                message: format!("cannot parse {}: {}", LOCKFILE_NAME, e),
+            })?;
+        Ok(Some(lockfile))
+    }
+
+    /// Write `featherflow.lock` to the project root.
+    pub fn save(&self, root: &Path) -> CoreResult<()> {
+        let path = root.join(LOCKFILE_NAME);
+        let content = serde_yaml::to_string(self)?;
+        std::fs::write(&path, content).map_err(|e| CoreError::IoWithPath {
+            path: path.display().to_string(),
+            source: e,
+        })
+    }
+}
+
+/// SHA-256 of a file's bytes, hex encoded.
+pub fn file_checksum(path: &Path) -> CoreResult<String> {
+    let bytes = std::fs::read(path).map_err(|e| CoreError::IoWithPath {
+        path: path.display().to_string(),
+        source: e,
+    })?;
+    Ok(format!("{:x}", Sha256::digest(&bytes)))
+}
+
+/// SHA-256 over a directory's files, hex encoded.
+///
+/// Covers each file's path relative to `dir` and its bytes, in sorted order.
+/// Hidden entries (`.git`, ...) and `target/` build output are skipped.
+pub fn dir_checksum(dir: &Path) -> CoreResult<String> {
+    fn collect(dir: &Path, files: &mut Vec<PathBuf>) -> CoreResult<()> {
+        let entries = std::fs::read_dir(dir).map_err(|e| CoreError::IoWithPath {
+            path: dir.display().to_string(),
+            source: e,
+        })?;
+        for entry in entries {
+            let entry = entry.map_err(|e| CoreError::IoWithPath {
+                path: dir.display().to_string(),
+                source: e,
+            })?;
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            if name.starts_with('.') || name == "target" {
+                continue;
+            }
+            let path = entry.path();
+            if path.is_dir() {
+                collect(&path, files)?;
+            } else {
+                files.push(path);
+            }
+        }
+        Ok(())
+    }
+
+    let mut files = Vec::new();
+    collect(dir, &mut files)?;
+    files.sort();
+
+    let mut hasher = Sha256::new();
+    for path in &files {
+        let relative = path.strip_prefix(dir).unwrap_or(path);
+        hasher.update(relative.to_string_lossy().replace('\\', "/").as_bytes());
+        hasher.update([0]);
+        hasher.update(file_checksum(path)?.as_bytes());
+        hasher.update([0]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+/// Load every package declared in `config`, checking each against the lockfile.
+pub fn resolve_packages(root: &Path, config: &Config) -> CoreResult<Vec<Package>> {
+    if config.packages.is_empty() {
+        return Ok(Vec::new());
+    }
+    let lockfile = Lockfile::load(root)?.ok_or_else(|| CoreError::LockfileOutOfDate {
+        message: format!("{} not found", LOCKFILE_NAME),
+    })?;
+
+    let mut packages = Vec::with_capacity(config.packages.len());
+    for spec in &config.packages {
+        let package = Package::load(root, spec)?;
+        let expected = LockedPackage::new(root, spec, &package)?;
+        match lockfile.packages.iter().find(|l| l.name == spec.name) {
+            Some(locked) if *locked == expected => {}
+            Some(locked)
+                if locked.checksum == expected.checksum
+                    && locked.installed_checksum.is_some()
+                    && locked.installed_checksum != expected.installed_checksum =>
+            {
+                return Err(CoreError::LockfileOutOfDate {
+                    message: format!(
+                        "files extracted for package '{}' under {}/ changed since it was locked",
+                        spec.name, PACKAGES_DIR
+                    ),
+                });
+            }
+            Some(locked)
+                if locked.source == expected.source && locked.version == expected.version =>
+            {
+                return Err(CoreError::LockfileOutOfDate {
+                    message: format!(
+                        "contents of package '{}' changed since it was locked",
+                        spec.name
+                    ),
+                });
+            }
+            Some(locked) => {
+                return Err(CoreError::LockfileOutOfDate {
+                    message: format!(
+                        "package '{}' is locked at {} {} but {} {} is on disk",
+                        spec.name, locked.source, locked.version, expected.source, expected.version
+                    ),
+                });
+            }
+            None => {
+                return Err(CoreError::LockfileOutOfDate {
+                    message: format!("package '{}' is not locked", spec.name),
+                });
+            }
+        }
+        packages.push(package);
+    }
+    Ok(packages)
+}
+
+#[cfg(test)]
+#[path = "package_test.rs"]
+mod tests;
@@ -0,0 +1,180 @@
+use super::*;
+use tempfile::TempDir;
+
+fn spec(name: &str, path: Option<&str>, tarball: Option<&str>) -> PackageSpec {
+    PackageSpec {
+        name: name.to_string(),
+        path: path.map(str::to_string),
+        tarball: tarball.map(str::to_string),
+        version: None,
+    }
+}
+
+/// Project root with a `shared` package at `packages/shared`.
+fn project_with_package(version: &str) -> TempDir {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(
+        dir.path().join("featherflow.yml"),
+        "name: app\npackages:\n  - name: shared\n    path: packages/shared\n",
+    )
+    .unwrap();
+    let package = dir.path().join("packages/shared");
+    std::fs::create_dir_all(&package).unwrap();
+    std::fs::write(
+        package.join("featherflow.yml"),
+        format!("name: shared\nversion: \"{}\"\n", version),
+    )
+    .unwrap();
+    dir
+}
+
+#[test]
+fn test_spec_requires_exactly_one_source() {
+    assert!(spec("shared", Some("p"), None).validate().is_ok());
+    assert!(spec("shared", None, Some("p.tar.gz")).validate().is_ok());
+    assert!(spec("shared", None, None).validate().is_err());
+    assert!(spec("shared", Some("p"), Some("p.tar.gz"))
+        .validate()
+        .is_err());
+}
+
+#[test]
+fn test_spec_rejects_bad_name_and_version() {
+    assert!(spec("my-pkg", Some("p"), None).validate().is_err());
+
+    let mut bad_version = spec("shared", Some("p"), None);
+    bad_version.version = Some("not a version".to_string());
+    assert!(bad_version.validate().is_err());
+}
+
+#[test]
+fn test_package_version_mismatch() {
+    let dir = project_with_package("2.0.0");
+    let mut spec = spec("shared", Some("packages/shared"), None);
+    spec.version = Some("^1.0".to_string());
+
+    let err = Package::load(dir.path(), &spec).unwrap_err();
+    assert!(
+        matches!(err, CoreError::PackageVersionMismatch { .. }),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn test_resolve_packages_requires_matching_lockfile() {
+    let dir = project_with_package("1.0.0");
+    let config = Config::load_from_dir(dir.path()).unwrap();
+
+    let err = resolve_packages(dir.path(), &config).unwrap_err();
+    assert!(
+        matches!(err, CoreError::LockfileOutOfDate { .. }),
+        "{}",
+        err
+    );
+
+    let package = Package::load(dir.path(), &config.packages[0]).unwrap();
+    let lockfile = Lockfile {
+        packages: vec![LockedPackage::new(dir.path(), &config.packages[0], &package).unwrap()],
+    };
+    lockfile.save(dir.path()).unwrap();
+    let packages = resolve_packages(dir.path(), &config).unwrap();
+    assert_eq!(packages.len(), 1);
+    assert_eq!(packages[0].name, "shared");
+
+    // Bumping the package version without re-locking is caught
+    std::fs::write(
+        dir.path().join("packages/shared/featherflow.yml"),
+        "name: shared\nversion: \"1.1.0\"\n",
+    )
+    .unwrap();
+    let err = resolve_packages(dir.path(), &config).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("locked at path:packages/shared 1.0.0"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn test_resolve_packages_detects_path_package_drift() {
+    let dir = project_with_package("1.0.0");
+    let config = Config::load_from_dir(dir.path()).unwrap();
+    let macros = dir.path().join("packages/shared/macros");
+    std::fs::create_dir_all(&macros).unwrap();
+    std::fs::write(
+        macros.join("money.sql"),
+        "{% macro cents() %}1{% endmacro %}",
+    )
+    .unwrap();
+
+    let package = Package::load(dir.path(), &config.packages[0]).unwrap();
+    let locked = LockedPackage::new(dir.path(), &config.packages[0], &package).unwrap();
+    assert!(locked.checksum.is_some());
+    Lockfile {
+        packages: vec![locked],
+    }
+    .save(dir.path())
+    .unwrap();
+    assert!(resolve_packages(dir.path(), &config).is_ok());
+
+    // Editing a macro without bumping the version is caught
+    std::fs::write(
+        macros.join("money.sql"),
+        "{% macro cents() %}2{% endmacro %}",
+    )
+    .unwrap();
+    let err = resolve_packages(dir.path(), &config).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("contents of package 'shared' changed since it was locked"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn test_resolve_packages_detects_extracted_tarball_drift() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(
+        dir.path().join("featherflow.yml"),
+        "name: app\npackages:\n  - name: shared\n    tarball: vendor/shared.tar.gz\n",
+    )
+    .unwrap();
+    std::fs::create_dir_all(dir.path().join("vendor")).unwrap();
+    std::fs::write(dir.path().join("vendor/shared.tar.gz"), b"archive bytes").unwrap();
+    let installed = dir.path().join(PACKAGES_DIR).join("shared");
+    std::fs::create_dir_all(&installed).unwrap();
+    std::fs::write(
+        installed.join("featherflow.yml"),
+        "name: shared\nversion: \"1.0.0\"\n",
+    )
+    .unwrap();
+
+    let config = Config::load_from_dir(dir.path()).unwrap();
+    let package = Package::load(dir.path(), &config.packages[0]).unwrap();
+    let locked = LockedPackage::new(dir.path(), &config.packages[0], &package).unwrap();
+    assert!(locked.installed_checksum.is_some());
+    Lockfile {
+        packages: vec![locked],
+    }
+    .save(dir.path())
+    .unwrap();
+    assert!(resolve_packages(dir.path(), &config).is_ok());
+
+    // Editing the extracted files leaves the tarball untouched but is caught
+    std::fs::create_dir_all(installed.join("macros")).unwrap();
+    std::fs::write(
+        installed.join("macros/money.sql"),
+        "{% macro cents() %}2{% endmacro %}",
+    )
+    .unwrap();
+    let err = resolve_packages(dir.path(), &config).unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("files extracted for package 'shared' under ff_packages/ changed"),
+        "{}",
+        err
+    );
+}
@@ -1,11 +1,13 @@
 //! Project discovery and model loading
 
 use crate::config::Config;
+use crate::doc_blocks::{discover_doc_blocks, resolve_description, DocBlocks};
 use crate::error::{CoreError, CoreResult};
 use crate::function::FunctionDef;
 use crate::model::{Model, ModelSchema, SchemaTest, SingularTest};
 use crate::model_name::ModelName;
 use crate::node::{NodeKind, NodeKindProbe};
+use crate::package::resolve_packages;
 use crate::seed::Seed;
 use crate::source::SourceFile;
 use std::collections::HashMap;
@@ -84,6 +86,93 @@ struct DiscoveredNodes {
     functions: Vec<FunctionDef>,
 }
 
+impl DiscoveredNodes {
+    /// Merge a package's nodes into the project's, rejecting any resource
+    /// whose name is already taken.
+    fn merge_package(&mut self, package: &str, other: DiscoveredNodes) -> CoreResult<()> {
+        let collision = |kind: &str, name: &str| CoreError::PackageCollision {
+            package: package.to_string(),
+            kind: kind.to_string(),
+            name: name.to_string(),
+        };
+
+        for (name, model) in other.models {
+            if self.models.contains_key(&name)
+                || self.seeds.iter().any(|s| s.name.as_str() == name.as_str())
+            {
+                return Err(collision("model", &name));
+            }
+            self.models.insert(name, model);
+        }
+        for seed in other.seeds {
+            if self.models.contains_key(seed.name.as_str())
+                || self.seeds.iter().any(|s| s.name == seed.name)
+            {
+                return Err(collision("seed", &seed.name));
+            }
+            self.seeds.push(seed);
+        }
+        for source in other.sources {
+            if self.sources.iter().any(|s| s.name == source.name) {
+                return Err(collision("source", &source.name));
+            }
+            self.sources.push(source);
+        }
+        for function in other.functions {
+            if self.functions.iter().any(|f| f.name == function.name) {
+                return Err(collision("function", &function.name));
+            }
+            self.functions.push(function);
+        }
+
+        self.seeds.sort_by(|a, b| a.name.cmp(&b.name));
+        Ok(())
+    }
+
+    /// Replace `{{ doc('name') }}` references in every node, column, and
+    /// function argument description.
+    fn resolve_doc_blocks(&mut self, blocks: &DocBlocks) -> CoreResult<()> {
+        for (name, model) in &mut self.models {
+            let Some(schema) = &mut model.schema else {
+                continue;
+            };
+            resolve_description(&mut schema.description, blocks, || name.to_string())?;
+            for column in &mut schema.columns {
+                resolve_description(&mut column.description, blocks, || {
+                    format!("{}.{}", name, column.name)
+                })?;
+            }
+        }
+        for seed in &mut self.seeds {
+            resolve_description(&mut seed.description, blocks, || seed.name.to_string())?;
+        }
+        for source in &mut self.sources {
+            resolve_description(&mut source.description, blocks, || source.name.to_string())?;
+            for table in &mut source.tables {
+                resolve_description(&mut table.description, blocks, || {
+                    format!("{}.{}", source.name, table.name)
+                })?;
+                for column in &mut table.columns {
+                    resolve_description(&mut column.description, blocks, || {
+                        format!("{}.{}.{}", source.name, table.name, column.name)
+                    })?;
+                }
+            }
+        }
+        for function in &mut self.functions {
+            resolve_description(&mut function.description, blocks, || {
+                function.name.to_string()
+            })?;
+            for arg in &mut function.args {
+                resolve_description(&mut arg.description, blocks, || {
+                    format!("{}({})", function.name, arg.name)
+                })?;
+            }
+        }
+        Ok(())
+    }
+}
+
 impl Project {
     /// Load a project from a directory
     pub fn load(path: &Path) -> CoreResult<Self> {
@@ -105,7 +194,20 @@ impl Project {
             log::warn!("'external_tables' is deprecated. Use source files (kind: source) instead.");
         }
 
-        let nodes = Self::discover_all_nodes(&root, &config)?;
+        let packages = resolve_packages(&root, &config)?;
+
+        let mut nodes = Self::discover_all_nodes(&root, &config)?;
+        let mut doc_blocks = DocBlocks::new();
+        discover_doc_blocks(&Self::doc_paths(&root, &config), &mut doc_blocks)?;
+        for package in &packages {
+            let package_nodes = Self::discover_all_nodes(&package.root, &package.config)?;
+            nodes.merge_package(&package.name, package_nodes)?;
+            discover_doc_blocks(
+                &Self::doc_paths(&package.root, &package.config),
+                &mut doc_blocks,
+            )?;
+        }
+        nodes.resolve_doc_blocks(&doc_blocks)?;
         let (models, seeds, sources, functions) =
             (nodes.models, nodes.seeds, nodes.sources, nodes.functions);
 
@@ -122,9 +224,17 @@ impl Project {
             singular_tests,
             sources,
             functions,
+            packages,
         }))
     }
 
+    /// Directories searched for `{% docs %}` blocks: nodes and macros.
+    fn doc_paths(root: &Path, config: &Config) -> Vec<std::path::PathBuf> {
+        let mut paths = config.node_paths_absolute(root);
+        paths.extend(config.macro_paths_absolute(root));
+        paths
+    }
+
     /// Discover all nodes from `node_paths` directories.
     ///
     /// Each direct child of a node path must be a directory containing a
@@ -256,6 +366,7 @@ impl Project {
             NodeKind::Python => {
                 Self::load_python_node(path, &dir_name, &content, &config_path, models)
             }
+            NodeKind::Command => Self::load_command_node(&dir_name, &content, &config_path, models),
             kind => Err(CoreError::NodeUnsupportedKind {
                 directory: dir_name,
                 kind: kind.to_string(),
@@ -327,6 +438,31 @@ impl Project {
         Ok(())
     }
 
+    /// Load a `kind: command` node as a [`Model`].
+    fn load_command_node(
+        dir_name: &str,
+        yaml_content: &str,
+        yaml_path: &Path,
+        models: &mut HashMap<ModelName, Model>,
+    ) -> CoreResult<()> {
+        let schema: ModelSchema =
+            serde_yaml::from_str(yaml_content).map_err(|e| CoreError::ModelParseError {
+                name: dir_name.to_string(),
+                message: e.to_string(),
+            })?;
+
+        let model = Model::from_command_node(yaml_path.to_path_buf(), schema)?;
+
+        if models.contains_key(model.name.as_str()) {
+            return Err(CoreError::DuplicateModel {
+                name: model.name.to_string(),
+            });
+        }
+
+        models.insert(model.name.clone(), model);
+        Ok(())
+    }
+
     /// Load a `kind: seed` node as a [`Seed`].
     fn load_seed_node(dir: &Path, dir_name: &str, seeds: &mut Vec<Seed>) -> CoreResult<()> {
         let csv_path = dir.join(format!("{}.csv", dir_name));
@@ -10,6 +10,7 @@ use crate::function::FunctionDef;
 use crate::function_name::FunctionName;
 use crate::model::{Model, SchemaTest, SingularTest};
 use crate::model_name::ModelName;
+use crate::package::Package;
 use crate::seed::Seed;
 use crate::source::SourceFile;
 use std::collections::HashMap;
@@ -37,6 +38,8 @@ pub struct ProjectParts {
     pub sources: Vec<SourceFile>,
     /// User-defined function definitions
     pub functions: Vec<FunctionDef>,
+    /// Packages from `featherflow.yml`, in declaration order
+    pub packages: Vec<Package>,
 }
 
 /// Represents a Featherflow project
@@ -66,6 +69,10 @@ pub struct Project {
     /// User-defined function definitions
     pub functions: Vec<FunctionDef>,
 
+    /// Packages from `featherflow.yml`; their nodes and functions are already
+    /// merged into the collections above
+    pub packages: Vec<Package>,
+
     /// Function lookup by name (O(1) access, index into `functions` vec)
     functions_by_name: HashMap<FunctionName, usize>,
 }
@@ -90,6 +97,7 @@ impl Project {
             singular_tests: parts.singular_tests,
             sources: parts.sources,
             functions: parts.functions,
+            packages: parts.packages,
             functions_by_name,
         }
     }
@@ -687,3 +687,124 @@ tables:
     assert_eq!(project.sources.len(), 1);
     assert_eq!(project.sources[0].name, "my_source");
 }
+
+#[test]
+fn test_discover_command_model() {
+    let dir = TempDir::new().unwrap();
+
+    std::fs::write(dir.path().join("featherflow.yml"), "name: test_project\n").unwrap();
+
+    std::fs::create_dir_all(dir.path().join("nodes/stg_source")).unwrap();
+    std::fs::write(
+        dir.path().join("nodes/stg_source/stg_source.sql"),
+        "SELECT 1 AS id",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("nodes/stg_source/stg_source.yml"),
+        "kind: sql\nversion: 1\ncolumns:\n  - name: id\n    type: INTEGER\n",
+    )
+    .unwrap();
+
+    // Command nodes have no data file, only YAML
+    std::fs::create_dir_all(dir.path().join("nodes/ext_rates")).unwrap();
+    std::fs::write(
+        dir.path().join("nodes/ext_rates/ext_rates.yml"),
+        r#"
+kind: command
+version: 1
+depends_on:
+  - stg_source
+command:
+  program: ./extract.sh
+  args: ["--currency", "EUR"]
+  env:
+    RATES_API: https://example.com
+  format: arrow
+columns:
+  - name: id
+    type: INTEGER
+  - name: rate
+    type: DOUBLE
+"#,
+    )
+    .unwrap();
+
+    let project = Project::load(dir.path()).unwrap();
+    assert_eq!(project.models.len(), 2);
+
+    let model = project.get_model("ext_rates").unwrap();
+    assert!(model.is_command());
+    assert!(!model.is_python());
+    assert_eq!(
+        model.config.materialized,
+        Some(crate::config::Materialization::Table)
+    );
+    assert!(model.depends_on.iter().any(|d| d.as_ref() == "stg_source"));
+
+    let command = model.schema.as_ref().unwrap().command.as_ref().unwrap();
+    assert_eq!(command.program, "./extract.sh");
+    assert_eq!(command.args, vec!["--currency", "EUR"]);
+    assert_eq!(command.env["RATES_API"], "https://example.com");
+    assert_eq!(command.format, crate::model::CommandFormat::Arrow);
+
+    assert!(model.raw_sql.is_empty());
+    let checksum = model.sql_checksum();
+    let mut changed = model.clone();
+    if let Some(command) = changed.schema.as_mut().and_then(|s| s.command.as_mut()) {
+        command.args.push("--verbose".to_string());
+    }
+    assert_ne!(changed.sql_checksum(), checksum);
+}
+
+#[test]
+fn test_command_node_without_program_rejected() {
+    let dir = TempDir::new().unwrap();
+
+    std::fs::write(dir.path().join("featherflow.yml"), "name: test_project\n").unwrap();
+    std::fs::create_dir_all(dir.path().join("nodes/ext_rates")).unwrap();
+    std::fs::write(
+        dir.path().join("nodes/ext_rates/ext_rates.yml"),
+        "kind: command\nversion: 1\ncolumns:\n  - name: id\n    type: INTEGER\n",
+    )
+    .unwrap();
+
+    let err = Project::load(dir.path()).unwrap_err();
+    assert!(
+        matches!(err, CoreError::ModelParseError { ref name, .. } if name == "ext_rates"),
+        "Expected ModelParseError, got: {:?}",
+        err
+    );
+}
+
+#[test]
+fn test_doc_blocks_resolve_into_descriptions() {
+    let dir = setup_test_project();
+    std::fs::write(
+        dir.path().join("nodes/shared_docs.md"),
+        "{% docs order_id %}Unique identifier of an order.{% enddocs %}\n\
+         {% docs staged_orders %}Orders, staged.{% enddocs %}",
+    )
+    .unwrap();
+    std::fs::write(
+        dir.path().join("nodes/stg_orders/stg_orders.yml"),
+        r#"
+kind: sql
+version: 1
+description: "{{ doc('staged_orders') }}"
+columns:
+  - name: order_id
+    type: INTEGER
+    description: "{{ doc('order_id') }}"
+"#,
+    )
+    .unwrap();
+
+    let project = Project::load(dir.path()).unwrap();
+    let schema = project.models["stg_orders"].schema.as_ref().unwrap();
+    assert_eq!(schema.description.as_deref(), Some("Orders, staged."));
+    assert_eq!(
+        schema.columns[0].description.as_deref(),
+        Some("Unique identifier of an order.")
+    );
+}
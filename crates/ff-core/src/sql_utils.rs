@@ -62,6 +62,24 @@ pub fn escape_sql_string(value: &str) -> String {
     value.replace('\'', "''")
 }
 
+/// Whether `name` is a plain identifier: an ASCII letter or underscore
+/// followed by ASCII letters, digits, or underscores.
+///
+/// # Examples
+/// ```
+/// use ff_core::sql_utils::is_valid_identifier;
+/// assert!(is_valid_identifier("stg_orders"));
+/// assert!(!is_valid_identifier("1st"));
+/// assert!(!is_valid_identifier("my-package"));
+/// ```
+pub fn is_valid_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
 #[cfg(test)]
 #[path = "sql_utils_test.rs"]
 mod tests;
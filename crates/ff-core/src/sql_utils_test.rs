@@ -74,3 +74,13 @@ fn test_split_qualified_name_multiple_dots() {
         ("catalog.schema", "table")
     );
 }
+
+#[test]
+fn test_is_valid_identifier() {
+    assert!(is_valid_identifier("orders"));
+    assert!(is_valid_identifier("_private2"));
+    assert!(!is_valid_identifier(""));
+    assert!(!is_valid_identifier("2fast"));
+    assert!(!is_valid_identifier("my-package"));
+    assert!(!is_valid_identifier("schema.table"));
+}
@@ -0,0 +1,85 @@
+//! Dump the bundled DuckDB's function catalog for the static analyzer
+//!
+//! Writes the TSV snapshot that `ff-analysis` compiles into its DuckDB
+//! function stubs. Regenerate it after every DuckDB upgrade:
+//!
+//! ```text
+//! cargo run -p ff-db --example dump_duckdb_functions > crates/ff-analysis/data/duckdb_functions.tsv
+//! ```
+//!
+//! (`make duckdb-functions` does the same.)
+
+use duckdb::Connection;
+
+/// Built-in scalar, aggregate and macro overloads with SQL-callable names.
+///
+/// Operators (`+`, `~~`, `->>`) and internal helpers (`__internal_*`) are
+/// skipped. Macro parameters are untyped, so they are reported as `ANY`.
+const CATALOG_SQL: &str = "\
+SELECT
+    function_name,
+    function_type,
+    array_to_string(list_transform(parameter_types, t -> coalesce(t, 'ANY')), '|'),
+    coalesce(varargs, ''),
+    coalesce(return_type, ''),
+    coalesce(stability, 'CONSISTENT')
+FROM duckdb_functions()
+WHERE database_name = 'system'
+  AND schema_name = 'main'
+  AND function_type IN ('scalar', 'aggregate', 'macro')
+  AND regexp_full_match(function_name, '[a-z][a-z0-9_]*')
+ORDER BY
+    function_name,
+    function_type,
+    len(parameter_types),
+    array_to_string(list_transform(parameter_types, t -> coalesce(t, 'ANY')), '|'),
+    return_type";
+
+/// Window functions are not listed by `duckdb_functions()`, so their
+/// signatures are maintained here.
+const WINDOW_FUNCTIONS: &[(&str, &str, &str)] = &[
+    ("cume_dist", "", "DOUBLE"),
+    ("dense_rank", "", "BIGINT"),
+    ("fill", "ANY", "ANY"),
+    ("first_value", "ANY", "ANY"),
+    ("lag", "ANY", "ANY"),
+    ("lag", "ANY|BIGINT", "ANY"),
+    ("lag", "ANY|BIGINT|ANY", "ANY"),
+    ("last_value", "ANY", "ANY"),
+    ("lead", "ANY", "ANY"),
+    ("lead", "ANY|BIGINT", "ANY"),
+    ("lead", "ANY|BIGINT|ANY", "ANY"),
+    ("nth_value", "ANY|BIGINT", "ANY"),
+    ("ntile", "BIGINT", "BIGINT"),
+    ("percent_rank", "", "DOUBLE"),
+    ("rank", "", "BIGINT"),
+    ("rank_dense", "", "BIGINT"),
+    ("row_number", "", "BIGINT"),
+];
+
+fn main() -> duckdb::Result<()> {
+    let conn = Connection::open_in_memory()?;
+    let version: String = conn.query_row("SELECT version()", [], |row| row.get(0))?;
+
+    println!("# DuckDB function catalog snapshot, produced by `make duckdb-functions`.");
+    println!("# Do not edit by hand; regenerate after upgrading DuckDB.");
+    println!("# duckdb_version\t{version}");
+    println!("function_name\tfunction_type\tparameter_types\tvarargs\treturn_type\tstability");
+
+    let mut stmt = conn.prepare(CATALOG_SQL)?;
+    let mut rows = stmt.query([])?;
+    while let Some(row) = rows.next()? {
+        let fields: Vec<String> = (0..6)
+            .map(|idx| {
+                row.get::<_, Option<String>>(idx)
+                    .map(Option::unwrap_or_default)
+            })
+            .collect::<duckdb::Result<_>>()?;
+        println!("{}", fields.join("\t"));
+    }
+
+    for (name, params, ret) in WINDOW_FUNCTIONS {
+        println!("{name}\twindow\t{params}\t\t{ret}\tCONSISTENT");
+    }
+    Ok(())
+}
@@ -2,8 +2,8 @@
 
 use crate::error::{DbError, DbResult};
 use crate::traits::{
-    CsvLoadOptions, DatabaseCore, DatabaseCsv, DatabaseFunction, DatabaseIncremental,
-    DatabaseSchema,
+    CsvLoadOptions, DatabaseCore, DatabaseCsv, DatabaseExchange, DatabaseFunction,
+    DatabaseIncremental, DatabaseSchema, ExchangeFormat,
 };
 use async_trait::async_trait;
 use duckdb::Connection;
@@ -413,8 +413,9 @@ impl DuckDbBackend {
         relation_exists_on(&conn, name)
     }
 
-    /// Shared implementation for `query_sample_rows` and `query_rows`
-    fn query_rows_raw(&self, sql: &str, limit: usize) -> DbResult<Vec<Vec<String>>> {
+    /// Shared implementation for `query_sample_rows`, `query_rows`, and
+    /// `query_rows_with_columns`
+    fn query_rows_raw(&self, sql: &str, limit: usize) -> DbResult<(Vec<String>, Vec<Vec<String>>)> {
         let conn = self.lock_conn()?;
         let limited_sql = format!("SELECT * FROM ({}) AS subq LIMIT {}", sql, limit);
 
@@ -426,7 +427,10 @@ impl DuckDbBackend {
             })?;
         let mut rows = Vec::new();
         let mut result_rows = stmt.query([])?;
-        let column_count = result_rows.as_ref().map_or(0, |r| r.column_count());
+        let columns = result_rows
+            .as_ref()
+            .map_or_else(Vec::new, |r| r.column_names());
+        let column_count = columns.len();
 
         while let Some(row) = result_rows.next()? {
             let values: Vec<String> = (0..column_count)
@@ -435,7 +439,21 @@ impl DuckDbBackend {
             rows.push(values);
         }
 
-        Ok(rows)
+        Ok((columns, rows))
+    }
+
+    /// Load the `arrow` community extension, which reads and writes Arrow IPC.
+    ///
+    /// The extension is never installed implicitly, since that downloads code
+    /// from the community repository during a run.
+    fn load_arrow_extension(&self) -> DbResult<()> {
+        self.execute_batch_sync("LOAD arrow").map_err(|e| {
+            DbError::ExecutionError(format!(
+                "Arrow IPC exchange requires DuckDB's `arrow` community extension. \
+                 Install it once with `INSTALL arrow FROM community`, or use Parquet exchange ({})",
+                e
+            ))
+        })
     }
 }
 
@@ -458,11 +476,19 @@ impl DatabaseCore for DuckDbBackend {
     }
 
     async fn query_sample_rows(&self, sql: &str, limit: usize) -> DbResult<Vec<String>> {
-        let raw = self.query_rows_raw(sql, limit)?;
+        let (_, raw) = self.query_rows_raw(sql, limit)?;
         Ok(raw.into_iter().map(|row| row.join(", ")).collect())
     }
 
     async fn query_rows(&self, sql: &str, limit: usize) -> DbResult<Vec<Vec<String>>> {
+        Ok(self.query_rows_raw(sql, limit)?.1)
+    }
+
+    async fn query_rows_with_columns(
+        &self,
+        sql: &str,
+        limit: usize,
+    ) -> DbResult<(Vec<String>, Vec<Vec<String>>)> {
         self.query_rows_raw(sql, limit)
     }
 
@@ -549,11 +575,34 @@ impl DatabaseSchema for DuckDbBackend {
         Ok(())
     }
 
+    async fn set_search_path(&self, schemas: &[String]) -> DbResult<()> {
+        let sql = format!(
+            "SET search_path = '{}'",
+            escape_sql_string(&schemas.join(","))
+        );
+        self.execute_sync(&sql)?;
+        Ok(())
+    }
+
     async fn get_table_schema(&self, table: &str) -> DbResult<Vec<(String, String)>> {
         let conn = self.lock_conn()?;
         table_columns(&conn, table)
     }
 
+    async fn list_tables(&self, schema: &str) -> DbResult<Vec<String>> {
+        let conn = self.lock_conn()?;
+        let sql = "SELECT table_name FROM information_schema.tables \
+                   WHERE table_schema = ? ORDER BY table_name";
+        let mut stmt = conn.prepare(sql)?;
+        let mut names = Vec::new();
+        let mut rows = stmt.query(duckdb::params![schema])?;
+        while let Some(row) = rows.next()? {
+            let name: String = row.get(0)?;
+            names.push(name);
+        }
+        Ok(names)
+    }
+
     async fn describe_query(&self, sql: &str) -> DbResult<Vec<(String, String)>> {
         let conn = self.lock_conn()?;
         let describe_sql = format!("DESCRIBE SELECT * FROM ({}) AS subq", sql);
@@ -571,6 +620,25 @@ impl DatabaseSchema for DuckDbBackend {
         Ok(columns)
     }
 
+    async fn describe_query_columns(&self, sql: &str) -> DbResult<Vec<(String, String, bool)>> {
+        let conn = self.lock_conn()?;
+        let describe_sql = format!("DESCRIBE SELECT * FROM ({}) AS subq", sql);
+
+        let mut stmt = conn.prepare(&describe_sql)?;
+        let mut columns: Vec<(String, String, bool)> = Vec::new();
+        let mut rows = stmt.query([])?;
+
+        while let Some(row) = rows.next()? {
+            let column_name: String = row.get(0)?;
+            let column_type: String = row.get(1)?;
+            let null: Option<String> = row.get(2)?;
+            let nullable = null.as_deref() != Some("NO");
+            columns.push((column_name, column_type, nullable));
+        }
+
+        Ok(columns)
+    }
+
     async fn add_columns(&self, table: &str, columns: &[(String, String)]) -> DbResult<()> {
         let quoted_table = quote_qualified(table);
         for (name, col_type) in columns {
@@ -777,6 +845,38 @@ impl DatabaseFunction for DuckDbBackend {
     }
 }
 
+#[async_trait]
+impl DatabaseExchange for DuckDbBackend {
+    async fn export_query(&self, select: &str, path: &str, format: ExchangeFormat) -> DbResult<()> {
+        let copy_format = match format {
+            ExchangeFormat::Parquet => "parquet",
+            ExchangeFormat::ArrowIpc => {
+                self.load_arrow_extension()?;
+                "arrows"
+            }
+        };
+        let sql = format!(
+            "COPY ({}) TO '{}' (FORMAT {})",
+            select,
+            escape_sql_string(path),
+            copy_format
+        );
+        self.execute_sync(&sql)?;
+        Ok(())
+    }
+
+    async fn scan_file(&self, path: &str, format: ExchangeFormat) -> DbResult<String> {
+        let reader = match format {
+            ExchangeFormat::Parquet => "read_parquet",
+            ExchangeFormat::ArrowIpc => {
+                self.load_arrow_extension()?;
+                "read_arrow"
+            }
+        };
+        Ok(format!("{}('{}')", reader, escape_sql_string(path)))
+    }
+}
+
 /// Build a SQL join condition from a list of key columns with table alias prefixes.
 ///
 /// Produces clauses like `left.key1 = right.key1 AND left.key2 = right.key2`.
@@ -259,6 +259,56 @@ async fn test_describe_query() {
     assert_eq!(schema[2].0, "value");
 }
 
+#[tokio::test]
+async fn test_query_rows_with_columns() {
+    let db = DuckDbBackend::in_memory().unwrap();
+
+    let (columns, rows) = db
+        .query_rows_with_columns("SELECT 1 AS id, 'a' AS name UNION ALL SELECT 2, 'b'", 10)
+        .await
+        .unwrap();
+
+    assert_eq!(columns, vec!["id", "name"]);
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].len(), 2);
+}
+
+#[tokio::test]
+async fn test_set_search_path() {
+    let db = DuckDbBackend::in_memory().unwrap();
+
+    db.execute_batch("CREATE SCHEMA staging; CREATE TABLE staging.orders AS SELECT 1 AS id")
+        .await
+        .unwrap();
+    db.set_search_path(&["staging".to_string(), "main".to_string()])
+        .await
+        .unwrap();
+
+    assert_eq!(db.query_count("SELECT * FROM orders").await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn test_describe_query_columns() {
+    let db = DuckDbBackend::in_memory().unwrap();
+
+    db.execute_batch("CREATE TABLE described (id INTEGER NOT NULL, name VARCHAR)")
+        .await
+        .unwrap();
+
+    let columns = db
+        .describe_query_columns("SELECT id, name, CAST(id AS BIGINT) AS wide FROM described")
+        .await
+        .unwrap();
+
+    assert_eq!(columns.len(), 3);
+    assert_eq!(columns[0].0, "id");
+    assert_eq!(columns[0].1, "INTEGER");
+    assert_eq!(columns[1].1, "VARCHAR");
+    assert!(columns[1].2);
+    assert_eq!(columns[2].0, "wide");
+    assert_eq!(columns[2].1, "BIGINT");
+}
+
 #[tokio::test]
 async fn test_add_columns() {
     let db = DuckDbBackend::in_memory().unwrap();
@@ -284,3 +334,96 @@ async fn test_add_columns() {
     assert_eq!(schema[1].0, "name");
     assert_eq!(schema[2].0, "created_at");
 }
+
+#[tokio::test]
+async fn test_list_tables() {
+    let db = DuckDbBackend::in_memory().unwrap();
+
+    db.execute_batch(
+        "CREATE SCHEMA raw; \
+         CREATE TABLE raw.orders (id INT); \
+         CREATE TABLE raw.customers (id INT); \
+         CREATE VIEW raw.recent_orders AS SELECT * FROM raw.orders; \
+         CREATE TABLE main.unrelated (id INT)",
+    )
+    .await
+    .unwrap();
+
+    let tables = db.list_tables("raw").await.unwrap();
+    assert_eq!(tables, vec!["customers", "orders", "recent_orders"]);
+
+    let empty = db.list_tables("missing_schema").await.unwrap();
+    assert!(empty.is_empty());
+}
+
+#[tokio::test]
+async fn test_parquet_exchange_round_trip() {
+    let db = DuckDbBackend::in_memory().unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("orders.parquet");
+    let path = path.to_str().unwrap();
+
+    db.export_query(
+        "SELECT 1 AS id, 'a' AS name UNION ALL SELECT 2, 'b'",
+        path,
+        ExchangeFormat::Parquet,
+    )
+    .await
+    .unwrap();
+
+    let scan = db.scan_file(path, ExchangeFormat::Parquet).await.unwrap();
+    db.create_table_as("orders_copy", &format!("SELECT * FROM {}", scan), false)
+        .await
+        .unwrap();
+    assert_eq!(
+        db.query_count("SELECT * FROM orders_copy").await.unwrap(),
+        2
+    );
+    let schema = db.get_table_schema("orders_copy").await.unwrap();
+    assert_eq!(schema[0].0, "id");
+    assert_eq!(schema[1].0, "name");
+}
+
+#[tokio::test]
+async fn test_arrow_exchange_round_trip() {
+    let db = DuckDbBackend::in_memory().unwrap();
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("orders.arrows");
+    let path = path.to_str().unwrap();
+    let select = "SELECT 1 AS id, 'a' AS name UNION ALL SELECT 2, 'b'";
+
+    // The extension is never installed implicitly; without network access
+    // the export must fail with an actionable error instead
+    if db
+        .execute_batch("INSTALL arrow FROM community")
+        .await
+        .is_err()
+    {
+        let err = db
+            .export_query(select, path, ExchangeFormat::ArrowIpc)
+            .await
+            .unwrap_err();
+        assert!(
+            err.to_string().contains("INSTALL arrow FROM community"),
+            "unexpected error: {}",
+            err
+        );
+        return;
+    }
+
+    db.export_query(select, path, ExchangeFormat::ArrowIpc)
+        .await
+        .unwrap();
+
+    let scan = db.scan_file(path, ExchangeFormat::ArrowIpc).await.unwrap();
+    db.create_table_as("orders_copy", &format!("SELECT * FROM {}", scan), false)
+        .await
+        .unwrap();
+    assert_eq!(
+        db.query_count("SELECT * FROM orders_copy").await.unwrap(),
+        2
+    );
+    let schema = db.get_table_schema("orders_copy").await.unwrap();
+    assert_eq!(schema[0].0, "id");
+    assert_eq!(schema[1].0, "name");
+}
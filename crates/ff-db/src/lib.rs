@@ -11,6 +11,6 @@ pub mod traits;
 pub use duckdb::DuckDbBackend;
 pub use error::DbError;
 pub use traits::{
-    CsvLoadOptions, Database, DatabaseCore, DatabaseCsv, DatabaseFunction, DatabaseIncremental,
-    DatabaseSchema,
+    CsvLoadOptions, Database, DatabaseCore, DatabaseCsv, DatabaseExchange, DatabaseFunction,
+    DatabaseIncremental, DatabaseSchema, ExchangeFormat,
 };
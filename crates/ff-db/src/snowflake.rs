@@ -3,8 +3,8 @@
 
 use crate::error::{DbError, DbResult};
 use crate::traits::{
-    CsvLoadOptions, DatabaseCore, DatabaseCsv, DatabaseFunction, DatabaseIncremental,
-    DatabaseSchema,
+    CsvLoadOptions, DatabaseCore, DatabaseCsv, DatabaseExchange, DatabaseFunction,
+    DatabaseIncremental, DatabaseSchema, ExchangeFormat,
 };
 use async_trait::async_trait;
 
@@ -54,6 +54,14 @@ impl DatabaseCore for SnowflakeBackend {
         Err(not_impl("query_rows"))
     }
 
+    async fn query_rows_with_columns(
+        &self,
+        _sql: &str,
+        _limit: usize,
+    ) -> DbResult<(Vec<String>, Vec<Vec<String>>)> {
+        Err(not_impl("query_rows_with_columns"))
+    }
+
     async fn query_one(&self, _sql: &str) -> DbResult<Option<String>> {
         Err(not_impl("query_one"))
     }
@@ -81,14 +89,26 @@ impl DatabaseSchema for SnowflakeBackend {
         Err(not_impl("create_schema_if_not_exists"))
     }
 
+    async fn set_search_path(&self, _schemas: &[String]) -> DbResult<()> {
+        Err(not_impl("set_search_path"))
+    }
+
     async fn get_table_schema(&self, _table: &str) -> DbResult<Vec<(String, String)>> {
         Err(not_impl("get_table_schema"))
     }
 
+    async fn list_tables(&self, _schema: &str) -> DbResult<Vec<String>> {
+        Err(not_impl("list_tables"))
+    }
+
     async fn describe_query(&self, _sql: &str) -> DbResult<Vec<(String, String)>> {
         Err(not_impl("describe_query"))
     }
 
+    async fn describe_query_columns(&self, _sql: &str) -> DbResult<Vec<(String, String, bool)>> {
+        Err(not_impl("describe_query_columns"))
+    }
+
     async fn add_columns(&self, _table: &str, _columns: &[(String, String)]) -> DbResult<()> {
         Err(not_impl("add_columns"))
     }
@@ -153,3 +173,19 @@ impl DatabaseFunction for SnowflakeBackend {
         Err(not_impl("list_user_functions"))
     }
 }
+
+#[async_trait]
+impl DatabaseExchange for SnowflakeBackend {
+    async fn export_query(
+        &self,
+        _select: &str,
+        _path: &str,
+        _format: ExchangeFormat,
+    ) -> DbResult<()> {
+        Err(not_impl("export_query"))
+    }
+
+    async fn scan_file(&self, _path: &str, _format: ExchangeFormat) -> DbResult<String> {
+        Err(not_impl("scan_file"))
+    }
+}
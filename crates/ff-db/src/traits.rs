@@ -5,6 +5,7 @@
 //! - [`DatabaseSchema`]: DDL operations (create table/view, drop, alter)
 //! - [`DatabaseCsv`]: CSV loading and schema inference
 //! - [`DatabaseIncremental`]: Merge/delete-insert for incremental models
+//! - [`DatabaseExchange`]: Exporting queries to and reading tables from files
 //!
 //! The [`Database`] super-trait combines all of them. Consumers that need
 //! all capabilities use `Arc<dyn Database>`.
@@ -123,6 +124,15 @@ pub trait DatabaseCore: Send + Sync {
     /// individual column values (e.g. for diff comparisons).
     async fn query_rows(&self, sql: &str, limit: usize) -> DbResult<Vec<Vec<String>>>;
 
+    /// Query and return the result's column names alongside its rows
+    ///
+    /// Both come from the same execution, so the query runs only once.
+    async fn query_rows_with_columns(
+        &self,
+        sql: &str,
+        limit: usize,
+    ) -> DbResult<(Vec<String>, Vec<Vec<String>>)>;
+
     /// Query and return a single string value from the first row, first column
     async fn query_one(&self, sql: &str) -> DbResult<Option<String>>;
 
@@ -145,12 +155,24 @@ pub trait DatabaseSchema: Send + Sync {
     /// Create a schema if it does not exist
     async fn create_schema_if_not_exists(&self, schema: &str) -> DbResult<()>;
 
+    /// Resolve unqualified names against `schemas`, in order, for the rest of the session
+    async fn set_search_path(&self, schemas: &[String]) -> DbResult<()>;
+
     /// Get the schema (column names and types) for a table
     async fn get_table_schema(&self, table: &str) -> DbResult<Vec<(String, String)>>;
 
+    /// List the names of all tables and views in a schema, sorted by name
+    async fn list_tables(&self, schema: &str) -> DbResult<Vec<String>>;
+
     /// Get the schema for a SELECT query without executing it
     async fn describe_query(&self, sql: &str) -> DbResult<Vec<(String, String)>>;
 
+    /// Get the schema for a SELECT query, including whether each column is nullable
+    ///
+    /// Returns `(name, type, nullable)` triples. Engines that do not track
+    /// nullability through queries report every column as nullable.
+    async fn describe_query_columns(&self, sql: &str) -> DbResult<Vec<(String, String, bool)>>;
+
     /// Add columns to an existing table
     async fn add_columns(&self, table: &str, columns: &[(String, String)]) -> DbResult<()>;
 }
@@ -173,6 +195,35 @@ pub trait DatabaseCsv: Send + Sync {
     async fn infer_csv_schema(&self, path: &str) -> DbResult<Vec<(String, String)>>;
 }
 
+/// File format for exchanging tables with external processes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExchangeFormat {
+    /// Apache Parquet
+    Parquet,
+    /// Arrow IPC stream format
+    ArrowIpc,
+}
+
+impl ExchangeFormat {
+    /// Conventional file extension, without the dot
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExchangeFormat::Parquet => "parquet",
+            ExchangeFormat::ArrowIpc => "arrows",
+        }
+    }
+}
+
+/// Table exchange through files, for processes that cannot share a connection.
+#[async_trait]
+pub trait DatabaseExchange: Send + Sync {
+    /// Write the result of `select` to a file at `path`
+    async fn export_query(&self, select: &str, path: &str, format: ExchangeFormat) -> DbResult<()>;
+
+    /// Table expression reading the file at `path`, for use in a FROM clause
+    async fn scan_file(&self, path: &str, format: ExchangeFormat) -> DbResult<String>;
+}
+
 /// Merge and delete-insert operations for incremental models.
 #[async_trait]
 pub trait DatabaseIncremental: Send + Sync {
@@ -226,12 +277,22 @@ pub trait DatabaseFunction: Send + Sync {
 ///
 /// - [`DuckDbBackend`](crate::DuckDbBackend) - Primary implementation using DuckDB
 pub trait Database:
-    DatabaseCore + DatabaseSchema + DatabaseCsv + DatabaseIncremental + DatabaseFunction
+    DatabaseCore
+    + DatabaseSchema
+    + DatabaseCsv
+    + DatabaseIncremental
+    + DatabaseFunction
+    + DatabaseExchange
 {
 }
 
 /// Blanket implementation: any type that implements all sub-traits also implements Database.
 impl<T> Database for T where
-    T: DatabaseCore + DatabaseSchema + DatabaseCsv + DatabaseIncremental + DatabaseFunction
+    T: DatabaseCore
+        + DatabaseSchema
+        + DatabaseCsv
+        + DatabaseIncremental
+        + DatabaseFunction
+        + DatabaseExchange
 {
 }
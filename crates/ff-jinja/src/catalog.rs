@@ -0,0 +1,272 @@
+//! Schema-aware functions: columns_of(), star(), and union_nodes().
+//!
+//! These read a [`LazyNodeCatalog`]: the project's declared node schemas —
+//! the same catalog static analysis starts from and checks inferred schemas
+//! against (SA01/SA02) — plus, for nodes that declare no columns, schemas
+//! inferred on first use. They never touch the database and work with
+//! `--offline`.
+
+use crate::introspection::RelationColumn;
+use ff_core::sql_utils::quote_ident;
+use minijinja::value::{Kwargs, Value};
+use minijinja::{Error, ErrorKind};
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, OnceLock};
+
+/// Node name -> declared columns, in declaration order
+pub type NodeCatalog = BTreeMap<String, Vec<RelationColumn>>;
+
+/// Infers the schemas of undeclared nodes, given the declared ones
+type CatalogInference = Box<dyn Fn(&NodeCatalog) -> NodeCatalog + Send + Sync>;
+
+/// Node schemas read by `columns_of()`, `star()`, and `union_nodes()`.
+///
+/// Declared schemas answer lookups directly. The first lookup of a node
+/// that declares no columns runs the inference, once; its result answers
+/// that lookup and every later one. The inference must not read this
+/// catalog.
+#[derive(Default)]
+pub struct LazyNodeCatalog {
+    declared: NodeCatalog,
+    infer: Option<CatalogInference>,
+    inferred: OnceLock<NodeCatalog>,
+}
+
+impl LazyNodeCatalog {
+    /// Catalog of declared schemas only.
+    pub fn new(declared: NodeCatalog) -> Self {
+        Self {
+            declared,
+            infer: None,
+            inferred: OnceLock::new(),
+        }
+    }
+
+    /// Catalog whose undeclared nodes are filled in by `infer`.
+    pub fn with_inference(
+        declared: NodeCatalog,
+        infer: impl Fn(&NodeCatalog) -> NodeCatalog + Send + Sync + 'static,
+    ) -> Self {
+        Self {
+            declared,
+            infer: Some(Box::new(infer)),
+            inferred: OnceLock::new(),
+        }
+    }
+
+    /// A node's columns, declared or inferred.
+    fn get(&self, node: &str) -> Option<&Vec<RelationColumn>> {
+        match self.declared.get(node) {
+            Some(columns) if !columns.is_empty() => Some(columns),
+            declared => {
+                let Some(infer) = &self.infer else {
+                    return declared;
+                };
+                self.inferred
+                    .get_or_init(|| infer(&self.declared))
+                    .get(node)
+                    .or(declared)
+            }
+        }
+    }
+}
+
+fn catalog_error(function: &str, message: String) -> Error {
+    Error::new(
+        ErrorKind::InvalidOperation,
+        format!("{}() failed: {}", function, message),
+    )
+}
+
+/// Look up a node's columns, failing for unknown nodes and nodes without columns.
+fn node_columns<'c>(
+    catalog: &'c LazyNodeCatalog,
+    function: &str,
+    node: &str,
+) -> Result<&'c [RelationColumn], Error> {
+    match catalog.get(node) {
+        Some(columns) if !columns.is_empty() => Ok(columns.as_slice()),
+        Some(_) => Err(catalog_error(
+            function,
+            format!("node '{}' declares no columns", node),
+        )),
+        None => Err(catalog_error(function, format!("unknown node '{}'", node))),
+    }
+}
+
+/// Render a column name, quoting it only when it is not a plain identifier.
+fn sql_ident(name: &str) -> String {
+    let mut chars = name.chars();
+    let plain = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_lowercase() || c == '_')
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if plain {
+        name.to_string()
+    } else {
+        quote_ident(name)
+    }
+}
+
+/// Normalize a declared type for comparison (`varchar` == `VARCHAR`).
+fn normalize_type(data_type: &str) -> String {
+    data_type
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_uppercase()
+}
+
+/// Create the `columns_of()` function.
+///
+/// Usage in templates:
+/// ```jinja
+/// {% for col in columns_of("stg_orders") if col.data_type == "DECIMAL(10,2)" %}
+///   SUM({{ col.name }}) AS total_{{ col.name }},
+/// {% endfor %}
+/// ```
+pub(crate) fn make_columns_of_fn(
+    catalog: Arc<LazyNodeCatalog>,
+) -> impl Fn(&str) -> Result<Value, Error> + Send + Sync + Clone + 'static {
+    move |node: &str| {
+        let columns = node_columns(&catalog, "columns_of", node)?;
+        Ok(Value::from_serialize(columns))
+    }
+}
+
+/// Create the `star()` function.
+///
+/// Expands to a node's columns, minus any in `except`, each optionally
+/// aliased with `prefix`:
+/// ```jinja
+/// SELECT {{ star("stg_customers", except=["ssn"], prefix="customer_") }}
+/// FROM stg_customers
+/// ```
+pub(crate) fn make_star_fn(
+    catalog: Arc<LazyNodeCatalog>,
+) -> impl Fn(&str, Kwargs) -> Result<String, Error> + Send + Sync + Clone + 'static {
+    move |node: &str, kwargs: Kwargs| {
+        let except: Vec<String> = kwargs
+            .get::<Option<Vec<String>>>("except")?
+            .unwrap_or_default();
+        let prefix: Option<String> = kwargs.get("prefix")?;
+        kwargs.assert_all_used()?;
+
+        let columns = node_columns(&catalog, "star", node)?;
+        for name in &except {
+            if !columns.iter().any(|c| c.name.eq_ignore_ascii_case(name)) {
+                return Err(catalog_error(
+                    "star",
+                    format!("'{}' in except is not a column of '{}'", name, node),
+                ));
+            }
+        }
+
+        let selected: Vec<String> = columns
+            .iter()
+            .filter(|c| !except.iter().any(|e| e.eq_ignore_ascii_case(&c.name)))
+            .map(|c| match &prefix {
+                Some(prefix) => format!(
+                    "{} AS {}",
+                    sql_ident(&c.name),
+                    sql_ident(&format!("{}{}", prefix, c.name))
+                ),
+                None => sql_ident(&c.name),
+            })
+            .collect();
+        if selected.is_empty() {
+            return Err(catalog_error(
+                "star",
+                format!("except removes every column of '{}'", node),
+            ));
+        }
+        Ok(selected.join(", "))
+    }
+}
+
+/// Create the `union_nodes()` function.
+///
+/// Emits a `UNION ALL` of the nodes with columns aligned by name. A node
+/// missing a column selects a typed NULL in its place; a column declared
+/// with different types in two nodes fails the render.
+/// ```jinja
+/// {{ union_nodes(["orders_eu", "orders_us"]) }}
+/// ```
+pub(crate) fn make_union_nodes_fn(
+    catalog: Arc<LazyNodeCatalog>,
+) -> impl Fn(Vec<String>) -> Result<String, Error> + Send + Sync + Clone + 'static {
+    move |nodes: Vec<String>| {
+        if nodes.len() < 2 {
+            return Err(catalog_error(
+                "union_nodes",
+                "requires at least two nodes".to_string(),
+            ));
+        }
+
+        // Union of all columns in first-seen order, keyed case-insensitively,
+        // with the first declared type and the node that declared it.
+        let mut order: Vec<String> = Vec::new();
+        let mut unified: HashMap<String, (&RelationColumn, &str)> = HashMap::new();
+        let mut per_node: Vec<HashMap<String, &RelationColumn>> = Vec::with_capacity(nodes.len());
+        for node in &nodes {
+            let columns = node_columns(&catalog, "union_nodes", node)?;
+            let mut by_name = HashMap::with_capacity(columns.len());
+            for column in columns {
+                let key = column.name.to_lowercase();
+                match unified.get_mut(&key) {
+                    None => {
+                        order.push(key.clone());
+                        unified.insert(key.clone(), (column, node.as_str()));
+                    }
+                    Some((first, first_node)) => {
+                        let (a, b) = (
+                            normalize_type(&first.data_type),
+                            normalize_type(&column.data_type),
+                        );
+                        if a.is_empty() {
+                            *first = column;
+                            *first_node = node.as_str();
+                        } else if !b.is_empty() && a != b {
+                            return Err(catalog_error(
+                                "union_nodes",
+                                format!(
+                                    "column '{}' is {} in '{}' but {} in '{}'",
+                                    column.name, a, first_node, b, node
+                                ),
+                            ));
+                        }
+                    }
+                }
+                by_name.insert(key, column);
+            }
+            per_node.push(by_name);
+        }
+
+        let selects: Vec<String> = nodes
+            .iter()
+            .zip(&per_node)
+            .map(|(node, by_name)| {
+                let list: Vec<String> = order
+                    .iter()
+                    .map(|key| {
+                        let (column, _) = unified[key];
+                        let alias = sql_ident(&column.name);
+                        match by_name.get(key) {
+                            Some(own) => sql_ident(&own.name),
+                            None if column.data_type.trim().is_empty() => {
+                                format!("NULL AS {}", alias)
+                            }
+                            None => format!("CAST(NULL AS {}) AS {}", column.data_type, alias),
+                        }
+                    })
+                    .collect();
+                format!("SELECT {} FROM {}", list.join(", "), node)
+            })
+            .collect();
+        Ok(selects.join("\nUNION ALL\n"))
+    }
+}
+
+#[cfg(test)]
+#[path = "catalog_test.rs"]
+mod tests;
@@ -0,0 +1,133 @@
+use super::*;
+use crate::JinjaEnvironment;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+fn column(name: &str, data_type: &str) -> RelationColumn {
+    RelationColumn {
+        name: name.to_string(),
+        data_type: data_type.to_string(),
+    }
+}
+
+fn catalog_env() -> JinjaEnvironment<'static> {
+    let mut catalog = NodeCatalog::new();
+    catalog.insert(
+        "stg_customers".to_string(),
+        vec![
+            column("customer_id", "INTEGER"),
+            column("name", "VARCHAR"),
+            column("ssn", "VARCHAR"),
+        ],
+    );
+    catalog.insert(
+        "orders_eu".to_string(),
+        vec![
+            column("order_id", "INTEGER"),
+            column("vat", "DECIMAL(10,2)"),
+        ],
+    );
+    catalog.insert(
+        "orders_us".to_string(),
+        vec![column("order_id", "integer"), column("state", "VARCHAR")],
+    );
+    catalog.insert(
+        "orders_bad".to_string(),
+        vec![column("order_id", "VARCHAR")],
+    );
+    let mut env = JinjaEnvironment::new(&HashMap::new());
+    env.set_catalog(Arc::new(LazyNodeCatalog::new(catalog)));
+    env
+}
+
+#[test]
+fn test_columns_of() {
+    let rendered = catalog_env()
+        .render(
+            "{% for c in columns_of('stg_customers') %}{{ c.name }}:{{ c.data_type }} {% endfor %}",
+        )
+        .unwrap();
+    assert_eq!(rendered, "customer_id:INTEGER name:VARCHAR ssn:VARCHAR ");
+}
+
+#[test]
+fn test_columns_of_unknown_node() {
+    let err = catalog_env()
+        .render("{{ columns_of('nope') }}")
+        .unwrap_err();
+    assert!(err.to_string().contains("unknown node 'nope'"), "{}", err);
+}
+
+#[test]
+fn test_star_except_and_prefix() {
+    let env = catalog_env();
+    assert_eq!(
+        env.render("{{ star('stg_customers', except=['SSN']) }}")
+            .unwrap(),
+        "customer_id, name"
+    );
+    assert_eq!(
+        env.render("{{ star('stg_customers', except=['ssn'], prefix='c_') }}")
+            .unwrap(),
+        "customer_id AS c_customer_id, name AS c_name"
+    );
+}
+
+#[test]
+fn test_star_rejects_unknown_except() {
+    let err = catalog_env()
+        .render("{{ star('stg_customers', except=['email']) }}")
+        .unwrap_err();
+    assert!(err.to_string().contains("'email' in except"), "{}", err);
+}
+
+#[test]
+fn test_union_nodes_aligns_with_null_casts() {
+    let rendered = catalog_env()
+        .render("{{ union_nodes(['orders_eu', 'orders_us']) }}")
+        .unwrap();
+    assert_eq!(
+        rendered,
+        "SELECT order_id, vat, CAST(NULL AS VARCHAR) AS state FROM orders_eu\n\
+         UNION ALL\n\
+         SELECT order_id, CAST(NULL AS DECIMAL(10,2)) AS vat, state FROM orders_us"
+    );
+}
+
+#[test]
+fn test_union_nodes_type_conflict() {
+    let err = catalog_env()
+        .render("{{ union_nodes(['orders_eu', 'orders_bad']) }}")
+        .unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("column 'order_id' is INTEGER in 'orders_eu' but VARCHAR in 'orders_bad'"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn test_inference_runs_once_on_first_undeclared_lookup() {
+    let mut declared = NodeCatalog::new();
+    declared.insert("stg_orders".to_string(), vec![column("id", "INTEGER")]);
+    declared.insert("fct_orders".to_string(), Vec::new());
+
+    let runs = Arc::new(AtomicUsize::new(0));
+    let counter = Arc::clone(&runs);
+    let catalog = LazyNodeCatalog::with_inference(declared, move |declared| {
+        counter.fetch_add(1, Ordering::SeqCst);
+        assert!(declared.contains_key("stg_orders"));
+        let mut inferred = NodeCatalog::new();
+        inferred.insert("fct_orders".to_string(), vec![column("total", "DOUBLE")]);
+        inferred
+    });
+    let mut env = JinjaEnvironment::new(&HashMap::new());
+    env.set_catalog(Arc::new(catalog));
+
+    assert_eq!(env.render("{{ star('stg_orders') }}").unwrap(), "id");
+    assert_eq!(runs.load(Ordering::SeqCst), 0);
+
+    assert_eq!(env.render("{{ star('fct_orders') }}").unwrap(), "total");
+    assert_eq!(env.render("{{ star('fct_orders') }}").unwrap(), "total");
+    assert_eq!(runs.load(Ordering::SeqCst), 1);
+}
@@ -4,6 +4,7 @@
 //! that are injected into Jinja templates as globals or render context.
 
 use serde::Serialize;
+use std::path::PathBuf;
 
 /// Static context for a CLI invocation, set once on the environment.
 ///
@@ -23,6 +24,8 @@ pub struct TemplateContext {
     pub run_started_at: String,
     /// Feather-Flow version from Cargo.toml
     pub ff_version: String,
+    /// Packages whose macros are callable as `<package>.<macro>(...)`
+    pub packages: Vec<MacroPackage>,
 }
 
 impl TemplateContext {
@@ -35,8 +38,15 @@ impl TemplateContext {
             run_id: uuid::Uuid::new_v4().to_string(),
             run_started_at: chrono::Utc::now().to_rfc3339(),
             ff_version: env!("CARGO_PKG_VERSION").to_string(),
+            packages: Vec::new(),
         }
     }
+
+    /// Set the packages whose macros are namespaced under their names.
+    pub fn with_packages(mut self, packages: Vec<MacroPackage>) -> Self {
+        self.packages = packages;
+        self
+    }
 }
 
 impl Default for TemplateContext {
@@ -45,6 +55,15 @@ impl Default for TemplateContext {
     }
 }
 
+/// A named set of macro directories, imported into templates as a namespace
+#[derive(Debug, Clone)]
+pub struct MacroPackage {
+    /// Namespace name (`{{ name.macro() }}`)
+    pub name: String,
+    /// Directories holding the package's macro `.sql` files
+    pub macro_paths: Vec<PathBuf>,
+}
+
 /// Target configuration exposed to templates as `{{ target.name }}`, etc.
 #[derive(Debug, Clone, Default, Serialize)]
 pub struct TargetContext {
@@ -189,7 +189,7 @@ pub fn generate_custom_test_sql(
 }
 
 /// Convert a JSON value to Jinja syntax
-fn json_value_to_jinja(value: &serde_json::Value) -> String {
+pub(crate) fn json_value_to_jinja(value: &serde_json::Value) -> String {
     match value {
         serde_json::Value::Null => "none".to_string(),
         serde_json::Value::Bool(b) => {
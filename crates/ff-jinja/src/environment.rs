@@ -6,13 +6,18 @@ use crate::builtins::{
     make_limit_zero_fn, make_not_null_fn, make_percent_of_fn, make_round_money_fn,
     make_safe_divide_fn, make_slugify_fn, make_split_part_fn, make_surrogate_key_fn,
 };
-use crate::context::{ModelContext, TemplateContext};
+use crate::catalog::{make_columns_of_fn, make_star_fn, make_union_nodes_fn, LazyNodeCatalog};
+use crate::context::{MacroPackage, ModelContext, TemplateContext};
 use crate::error::{JinjaError, JinjaResult};
 use crate::functions::{
     make_env_fn, make_error_fn, make_from_json_fn, make_is_exists_fn, make_is_incremental_fn,
     make_log_fn, make_this_fn, make_to_json_fn, make_var_fn, make_warn_fn, yaml_to_json,
     DeprecationCapture, IncrementalState, WarningCapture,
 };
+use crate::introspection::{
+    make_get_columns_fn, make_get_relation_fn, make_offline_fn, make_run_query_fn, Introspector,
+    INTROSPECTION_FUNCTIONS,
+};
 use minijinja::{Environment, Value};
 use regex::Regex;
 use serde::Serialize;
@@ -37,6 +42,9 @@ pub struct JinjaEnvironment<'a> {
     /// Auto-generated `{% from "file.sql" import macro1, macro2 %}` lines
     /// prepended to every template so user macros are globally available.
     macro_preamble: String,
+    /// Existing project macro directories, kept to rebuild the loader in
+    /// [`Self::set_packages`]
+    macro_paths: Vec<PathBuf>,
 }
 
 impl std::fmt::Debug for JinjaEnvironment<'_> {
@@ -62,7 +70,7 @@ impl<'a> JinjaEnvironment<'a> {
         vars: &HashMap<String, serde_yaml::Value>,
         macro_paths: &[PathBuf],
         template_context: Option<&TemplateContext>,
-    ) -> (Environment<'a>, WarningCapture, String) {
+    ) -> (Environment<'a>, WarningCapture, String, Vec<PathBuf>) {
         let mut env = Environment::new();
         let warning_capture: WarningCapture = Arc::new(Mutex::new(Vec::new()));
 
@@ -84,6 +92,16 @@ impl<'a> JinjaEnvironment<'a> {
 
         register_builtins(&mut env);
 
+        // Introspection fails until a caller with a database connection
+        // supplies an introspector via set_introspector().
+        for function in INTROSPECTION_FUNCTIONS {
+            env.add_function(function, make_offline_fn(function));
+        }
+
+        // The catalog is empty, so the schema-aware functions fail for
+        // every node until a caller supplies one via set_catalog().
+        register_catalog_fns(&mut env, Arc::default());
+
         // Default is_exists() and is_incremental() to false. These will be
         // overridden by with_is_exists() or with_incremental_context() when
         // compiling/running incremental models.
@@ -109,12 +127,12 @@ impl<'a> JinjaEnvironment<'a> {
             .filter(|p| p.exists() && p.is_dir())
             .cloned()
             .collect();
-        let macro_preamble = build_macro_preamble(&valid_paths);
-        if !valid_paths.is_empty() {
-            env.set_loader(move |name: &str| load_macro_from_paths(name, &valid_paths));
-        }
+        let packages = template_context
+            .map(|ctx| ctx.packages.clone())
+            .unwrap_or_default();
+        let macro_preamble = install_macro_loader(&mut env, &valid_paths, packages);
 
-        (env, warning_capture, macro_preamble)
+        (env, warning_capture, macro_preamble, valid_paths)
     }
 
     /// Create a new Jinja environment with variables and macro paths
@@ -122,11 +140,13 @@ impl<'a> JinjaEnvironment<'a> {
     /// This enables loading macros using `{% from "file.sql" import macro_name %}` syntax.
     /// Macro files are loaded from the specified directories.
     pub fn with_macros(vars: &HashMap<String, serde_yaml::Value>, macro_paths: &[PathBuf]) -> Self {
-        let (env, warning_capture, macro_preamble) = Self::init_common(vars, macro_paths, None);
+        let (env, warning_capture, macro_preamble, macro_paths) =
+            Self::init_common(vars, macro_paths, None);
         Self {
             env,
             warning_capture,
             macro_preamble,
+            macro_paths,
         }
     }
 
@@ -139,12 +159,13 @@ impl<'a> JinjaEnvironment<'a> {
         macro_paths: &[PathBuf],
         context: &TemplateContext,
     ) -> Self {
-        let (env, warning_capture, macro_preamble) =
+        let (env, warning_capture, macro_preamble, macro_paths) =
             Self::init_common(vars, macro_paths, Some(context));
         Self {
             env,
             warning_capture,
             macro_preamble,
+            macro_paths,
         }
     }
 
@@ -197,6 +218,31 @@ impl<'a> JinjaEnvironment<'a> {
     pub fn get_captured_warnings(&self) -> Vec<String> {
         self.read_warnings().clone()
     }
+
+    /// Back `run_query()`, `get_columns()`, and `get_relation()` with a
+    /// database.
+    ///
+    /// Without an introspector these functions fail the render.
+    pub fn set_introspector(&mut self, introspector: Arc<dyn Introspector>) {
+        self.env
+            .add_function("run_query", make_run_query_fn(introspector.clone()));
+        self.env
+            .add_function("get_columns", make_get_columns_fn(introspector.clone()));
+        self.env
+            .add_function("get_relation", make_get_relation_fn(introspector));
+    }
+
+    /// Make package macros callable as `<package>.<macro>(...)`, for
+    /// environments built without a template context.
+    pub fn set_packages(&mut self, packages: Vec<MacroPackage>) {
+        self.macro_preamble = install_macro_loader(&mut self.env, &self.macro_paths, packages);
+    }
+
+    /// Replace the node catalog read by `columns_of()`, `star()`, and
+    /// `union_nodes()`.
+    pub fn set_catalog(&mut self, catalog: Arc<LazyNodeCatalog>) {
+        register_catalog_fns(&mut self.env, catalog);
+    }
 }
 
 impl JinjaEnvironment<'_> {
@@ -210,7 +256,8 @@ impl JinjaEnvironment<'_> {
         incremental_state: IncrementalState,
         qualified_name: &str,
     ) -> Self {
-        let (mut env, warning_capture, macro_preamble) = Self::init_common(vars, macro_paths, None);
+        let (mut env, warning_capture, macro_preamble, macro_paths) =
+            Self::init_common(vars, macro_paths, None);
 
         let is_exists_fn = make_is_exists_fn(incremental_state.clone());
         env.add_function("is_exists", is_exists_fn);
@@ -226,6 +273,7 @@ impl JinjaEnvironment<'_> {
             env,
             warning_capture,
             macro_preamble,
+            macro_paths,
         }
     }
 
@@ -241,7 +289,7 @@ impl JinjaEnvironment<'_> {
         template_context: Option<&TemplateContext>,
         is_exists_value: bool,
     ) -> Self {
-        let (mut env, warning_capture, macro_preamble) =
+        let (mut env, warning_capture, macro_preamble, macro_paths) =
             Self::init_common(vars, macro_paths, template_context);
 
         let val = is_exists_value;
@@ -262,6 +310,7 @@ impl JinjaEnvironment<'_> {
             env,
             warning_capture,
             macro_preamble,
+            macro_paths,
         }
     }
 }
@@ -272,6 +321,70 @@ impl Default for JinjaEnvironment<'_> {
     }
 }
 
+/// Template name of a package's macro namespace, after `<package>::`
+const PACKAGE_NAMESPACE: &str = "__namespace__";
+
+/// Set a loader that resolves project macro files by name and package macro
+/// files as `<package>::<file>`, and return the matching preamble.
+///
+/// Each package gets a synthetic `<package>::__namespace__` template holding
+/// all of its macro files, imported as `<package>`, so macros call each
+/// other inside a package without imports and are namespaced outside it.
+fn install_macro_loader(
+    env: &mut Environment<'_>,
+    paths: &[PathBuf],
+    packages: Vec<MacroPackage>,
+) -> String {
+    let mut preamble = build_macro_preamble(paths);
+    for package in &packages {
+        preamble.push_str(&format!(
+            "{{%- import \"{}::{}\" as {} -%}}",
+            package.name, PACKAGE_NAMESPACE, package.name
+        ));
+    }
+
+    if !paths.is_empty() || !packages.is_empty() {
+        let paths = paths.to_vec();
+        env.set_loader(move |name: &str| match name.split_once("::") {
+            Some((package_name, file)) => {
+                let Some(package) = packages.iter().find(|p| p.name == package_name) else {
+                    return Ok(None);
+                };
+                if file == PACKAGE_NAMESPACE {
+                    Ok(Some(package_namespace_source(package)))
+                } else {
+                    load_macro_from_paths(file, &package.macro_paths)
+                }
+            }
+            None => load_macro_from_paths(name, &paths),
+        });
+    }
+    preamble
+}
+
+/// Concatenate every `.sql` file in a package's macro directories, sorted by name.
+fn package_namespace_source(package: &MacroPackage) -> String {
+    let mut files: Vec<PathBuf> = package
+        .macro_paths
+        .iter()
+        .filter_map(|dir| std::fs::read_dir(dir).ok())
+        .flat_map(|entries| entries.flatten().map(|e| e.path()))
+        .filter(|path| path.extension().and_then(|e| e.to_str()) == Some("sql"))
+        .collect();
+    files.sort();
+    files
+        .iter()
+        .filter_map(|path| match std::fs::read_to_string(path) {
+            Ok(contents) => Some(contents),
+            Err(e) => {
+                log::warn!("Failed to read macro file {}: {}", path.display(), e);
+                None
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
 fn load_macro_from_paths(
     name: &str,
     paths: &[PathBuf],
@@ -348,6 +461,13 @@ fn collect_macros_from_entry(
     }
 }
 
+/// Register `columns_of()`, `star()`, and `union_nodes()` over `catalog`
+fn register_catalog_fns(env: &mut Environment<'_>, catalog: Arc<LazyNodeCatalog>) {
+    env.add_function("columns_of", make_columns_of_fn(Arc::clone(&catalog)));
+    env.add_function("star", make_star_fn(Arc::clone(&catalog)));
+    env.add_function("union_nodes", make_union_nodes_fn(catalog));
+}
+
 /// Register all built-in macros with the Jinja environment
 fn register_builtins(env: &mut Environment<'_>) {
     env.add_function("date_spine", make_date_spine_fn());
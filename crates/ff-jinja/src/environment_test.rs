@@ -183,6 +183,36 @@ fn test_macros_with_vars() {
     assert!(result.contains("created_at BETWEEN '2024-01-01' AND '2024-12-31'"));
 }
 
+#[test]
+fn test_package_macros_are_namespaced() {
+    use std::fs;
+    use tempfile::TempDir;
+
+    let temp = TempDir::new().unwrap();
+    let package_dir = temp.path().join("shared_macros");
+    fs::create_dir(&package_dir).unwrap();
+    fs::write(
+        package_dir.join("money.sql"),
+        r#"{% macro cents(col) %}{{ col }} / 100{% endmacro %}"#,
+    )
+    .unwrap();
+    fs::write(
+        package_dir.join("rounding.sql"),
+        r#"{% macro dollars(col) %}ROUND({{ cents(col) }}, 2){% endmacro %}"#,
+    )
+    .unwrap();
+
+    let mut env = JinjaEnvironment::default();
+    env.set_packages(vec![MacroPackage {
+        name: "shared".to_string(),
+        macro_paths: vec![package_dir],
+    }]);
+
+    let result = env.render("SELECT {{ shared.dollars('amount') }}").unwrap();
+    assert_eq!(result, "SELECT ROUND(amount / 100, 2)");
+    assert!(env.render("{{ dollars('amount') }}").is_err());
+}
+
 // ===== Built-in Macro Integration Tests =====
 
 #[test]
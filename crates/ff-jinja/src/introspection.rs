@@ -0,0 +1,145 @@
+//! Compile-time database introspection: run_query(), get_columns(), and
+//! get_relation().
+//!
+//! The functions delegate to an [`Introspector`] supplied by the caller via
+//! [`crate::JinjaEnvironment::set_introspector`]. Environments without one
+//! register stubs that fail the render, so a compile that must stay
+//! hermetic (`--offline`, or a command with no database) never silently
+//! produces SQL from missing data.
+
+use minijinja::value::Value;
+use minijinja::{Error, ErrorKind};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Result of a `run_query()` call
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueryResult {
+    /// Column names in select order
+    pub columns: Vec<String>,
+    /// Rows, each with one rendered value per column
+    pub rows: Vec<Vec<String>>,
+}
+
+/// A column returned by `get_columns()`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelationColumn {
+    /// Column name
+    pub name: String,
+    /// Database type (e.g. `INTEGER`, `VARCHAR`)
+    pub data_type: String,
+}
+
+/// A relation returned by `get_relation()`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Relation {
+    /// Schema the relation lives in
+    pub schema: String,
+    /// Relation name
+    pub name: String,
+    /// Quoted `schema.name`, ready to splice into SQL
+    pub quoted: String,
+}
+
+/// Database access for compile-time introspection.
+///
+/// Errors are returned as messages and surface as Jinja render errors.
+pub trait Introspector: Send + Sync {
+    /// Run a query and return its rows.
+    fn run_query(&self, sql: &str) -> Result<QueryResult, String>;
+
+    /// List the columns of a relation, or an error if it does not exist.
+    fn get_columns(&self, relation: &str) -> Result<Vec<RelationColumn>, String>;
+
+    /// Look up a relation, returning `None` if it does not exist.
+    fn get_relation(&self, name: &str) -> Result<Option<Relation>, String>;
+}
+
+/// Names of the introspection functions, in registration order
+pub(crate) const INTROSPECTION_FUNCTIONS: [&str; 3] = ["run_query", "get_columns", "get_relation"];
+
+fn introspection_error(function: &str, message: String) -> Error {
+    Error::new(
+        ErrorKind::InvalidOperation,
+        format!("{}() failed: {}", function, message),
+    )
+}
+
+/// Create a stub that fails because no database is available.
+pub(crate) fn make_offline_fn(
+    function: &'static str,
+) -> impl Fn(&[Value]) -> Result<Value, Error> + Send + Sync + Clone + 'static {
+    move |_args: &[Value]| {
+        Err(Error::new(
+            ErrorKind::InvalidOperation,
+            format!(
+                "{}() needs a database connection, which is unavailable here \
+                 (--offline, or a command that does not connect)",
+                function
+            ),
+        ))
+    }
+}
+
+/// Create the `run_query()` function.
+///
+/// Usage in templates:
+/// ```jinja
+/// {% set statuses = run_query("SELECT DISTINCT status FROM raw_orders") %}
+/// {% for row in statuses.rows %}
+///   SUM(CASE WHEN status = '{{ row[0] }}' THEN amount END) AS {{ row[0] }}_amount,
+/// {% endfor %}
+/// ```
+pub(crate) fn make_run_query_fn(
+    introspector: Arc<dyn Introspector>,
+) -> impl Fn(&str) -> Result<Value, Error> + Send + Sync + Clone + 'static {
+    move |sql: &str| {
+        let result = introspector
+            .run_query(sql)
+            .map_err(|e| introspection_error("run_query", e))?;
+        Ok(Value::from_serialize(&result))
+    }
+}
+
+/// Create the `get_columns()` function.
+///
+/// Usage in templates:
+/// ```jinja
+/// SELECT
+/// {%- for col in get_columns("raw_customers") if col.name != "ssn" %}
+///   {{ col.name }}{{ "," if not loop.last }}
+/// {%- endfor %}
+/// FROM raw_customers
+/// ```
+pub(crate) fn make_get_columns_fn(
+    introspector: Arc<dyn Introspector>,
+) -> impl Fn(&str) -> Result<Value, Error> + Send + Sync + Clone + 'static {
+    move |relation: &str| {
+        let columns = introspector
+            .get_columns(relation)
+            .map_err(|e| introspection_error("get_columns", e))?;
+        Ok(Value::from_serialize(&columns))
+    }
+}
+
+/// Create the `get_relation()` function.
+///
+/// Returns `none` when the relation does not exist, so templates can branch:
+/// ```jinja
+/// {% set prior = get_relation("legacy_orders") %}
+/// {% if prior %}UNION ALL SELECT * FROM {{ prior.quoted }}{% endif %}
+/// ```
+pub(crate) fn make_get_relation_fn(
+    introspector: Arc<dyn Introspector>,
+) -> impl Fn(&str) -> Result<Value, Error> + Send + Sync + Clone + 'static {
+    move |name: &str| {
+        let relation = introspector
+            .get_relation(name)
+            .map_err(|e| introspection_error("get_relation", e))?;
+        Ok(relation.map_or(Value::from(()), |r| Value::from_serialize(&r)))
+    }
+}
+
+#[cfg(test)]
+#[path = "introspection_test.rs"]
+mod tests;
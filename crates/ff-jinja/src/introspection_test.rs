@@ -0,0 +1,88 @@
+use super::*;
+use crate::JinjaEnvironment;
+
+struct FakeIntrospector;
+
+impl Introspector for FakeIntrospector {
+    fn run_query(&self, _sql: &str) -> Result<QueryResult, String> {
+        Ok(QueryResult {
+            columns: vec!["status".to_string()],
+            rows: vec![vec!["open".to_string()], vec!["closed".to_string()]],
+        })
+    }
+
+    fn get_columns(&self, relation: &str) -> Result<Vec<RelationColumn>, String> {
+        if relation != "raw_customers" {
+            return Err(format!("relation '{}' does not exist", relation));
+        }
+        Ok(vec![
+            RelationColumn {
+                name: "id".to_string(),
+                data_type: "INTEGER".to_string(),
+            },
+            RelationColumn {
+                name: "ssn".to_string(),
+                data_type: "VARCHAR".to_string(),
+            },
+        ])
+    }
+
+    fn get_relation(&self, name: &str) -> Result<Option<Relation>, String> {
+        Ok((name == "raw_customers").then(|| Relation {
+            schema: "raw".to_string(),
+            name: name.to_string(),
+            quoted: "\"raw\".\"raw_customers\"".to_string(),
+        }))
+    }
+}
+
+fn online_env() -> JinjaEnvironment<'static> {
+    let mut env = JinjaEnvironment::default();
+    env.set_introspector(Arc::new(FakeIntrospector));
+    env
+}
+
+#[test]
+fn test_run_query_rows() {
+    let rendered = online_env()
+        .render(
+            "{% for row in run_query('SELECT DISTINCT status FROM o').rows %}{{ row[0] }};{% endfor %}",
+        )
+        .unwrap();
+    assert_eq!(rendered, "open;closed;");
+}
+
+#[test]
+fn test_get_columns_filters_in_template() {
+    let rendered = online_env()
+        .render("{% for c in get_columns('raw_customers') if c.name != 'ssn' %}{{ c.name }} {{ c.data_type }}{% endfor %}")
+        .unwrap();
+    assert_eq!(rendered, "id INTEGER");
+}
+
+#[test]
+fn test_get_columns_error_fails_render() {
+    let err = online_env()
+        .render("{{ get_columns('missing') }}")
+        .unwrap_err();
+    assert!(err.to_string().contains("does not exist"), "{}", err);
+}
+
+#[test]
+fn test_get_relation_none_when_missing() {
+    let rendered = online_env()
+        .render("{% set r = get_relation('raw_customers') %}{{ r.quoted }}|{{ get_relation('nope') is none }}")
+        .unwrap();
+    assert_eq!(rendered, "\"raw\".\"raw_customers\"|true");
+}
+
+#[test]
+fn test_offline_functions_fail_loudly() {
+    let env = JinjaEnvironment::default();
+    for function in INTROSPECTION_FUNCTIONS {
+        let err = env
+            .render(&format!("{{{{ {}('x') }}}}", function))
+            .unwrap_err();
+        assert!(err.to_string().contains("--offline"), "{}", err);
+    }
+}
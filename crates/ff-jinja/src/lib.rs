@@ -8,22 +8,49 @@
 //! Context variables (`project_name`, `target`, `run_id`, `run_started_at`,
 //! `ff_version`, `executing`, `model`) are available when constructed with
 //! [`JinjaEnvironment::with_context`].
+//!
+//! `run_query()`, `get_columns()`, and `get_relation()` read from the
+//! database at compile time through an [`Introspector`] supplied with
+//! [`JinjaEnvironment::set_introspector`]; without one they fail the render.
+//!
+//! `columns_of()`, `star()`, and `union_nodes()` expand from node schemas
+//! in a [`LazyNodeCatalog`] supplied with [`JinjaEnvironment::set_catalog`],
+//! with no database access.
+//!
+//! [`discover_macro_docs`] loads user macro documentation from
+//! `macros/*.yml`. [`discover_macro_tests`] loads `*.test.yml` macro unit
+//! test fixtures and [`render_macro_call`] renders one case's macro call.
+//!
+//! [`SourceMap`] maps positions in rendered SQL back to the template, so
+//! diagnostics can point at the model's `.sql` file.
 
 pub(crate) mod builtins;
+pub(crate) mod catalog;
 pub(crate) mod context;
 pub(crate) mod custom_tests;
 pub(crate) mod environment;
 pub(crate) mod error;
 pub(crate) mod functions;
+pub(crate) mod introspection;
+pub(crate) mod macro_docs;
+pub(crate) mod macro_tests;
+pub(crate) mod source_map;
 
 pub use builtins::{
     get_builtin_macros, get_macro_by_name, get_macro_categories, get_macros_by_category,
     MacroMetadata, MacroParam,
 };
-pub use context::{ModelContext, TargetContext, TemplateContext};
+pub use catalog::{LazyNodeCatalog, NodeCatalog};
+pub use context::{MacroPackage, ModelContext, TargetContext, TemplateContext};
 pub use custom_tests::{
     discover_custom_test_macros, generate_custom_test_sql, CustomTestMacro, CustomTestRegistry,
 };
 pub use environment::JinjaEnvironment;
 pub use error::JinjaError;
 pub use functions::IncrementalState;
+pub use introspection::{Introspector, QueryResult, Relation, RelationColumn};
+pub use macro_docs::{discover_macro_docs, MacroDoc, MacroDocParam, USER_MACRO_CATEGORY};
+pub use macro_tests::{
+    discover_macro_tests, render_macro_call, MacroTestCase, MacroTestFile, MACRO_TEST_SUFFIX,
+};
+pub use source_map::SourceMap;
@@ -0,0 +1,163 @@
+//! Documentation for user macros
+//!
+//! `macros/*.yml` files (other than `*.test.yml` fixtures) describe the
+//! project's macros with the same fields as built-in [`MacroMetadata`]:
+//!
+//! ```yaml
+//! macros:
+//!   - name: cents_to_dollars
+//!     category: finance
+//!     description: Convert an integer amount in cents to dollars.
+//!     params:
+//!       - name: column
+//!         param_type: string
+//!         description: Column holding cents
+//!     example: "{{ cents_to_dollars('amount') }}"
+//!     example_output: "amount / 100.0"
+//! ```
+//!
+//! [`MacroDoc`] is the owned form both kinds of macro are documented through.
+
+use crate::builtins::MacroMetadata;
+use crate::error::{JinjaError, JinjaResult};
+use crate::macro_tests::MACRO_TEST_SUFFIX;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Category of user macros that don't declare one
+pub const USER_MACRO_CATEGORY: &str = "project";
+
+/// Documentation of one macro, built-in or user-defined
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MacroDoc {
+    /// Macro name as used in templates
+    pub name: String,
+    /// Category the macro is listed under
+    #[serde(default = "default_category")]
+    pub category: String,
+    /// Brief description of what the macro does
+    pub description: String,
+    /// Parameters accepted by the macro
+    #[serde(default)]
+    pub params: Vec<MacroDocParam>,
+    /// Example usage in a template
+    #[serde(default)]
+    pub example: String,
+    /// Expected output from the example
+    #[serde(default)]
+    pub example_output: String,
+    /// File the documentation was loaded from; empty for built-ins
+    #[serde(default, skip_deserializing, skip_serializing_if = "String::is_empty")]
+    pub source_file: String,
+}
+
+/// Parameter of a documented macro
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MacroDocParam {
+    /// Parameter name
+    pub name: String,
+    /// Parameter type (string, integer, array, ...)
+    #[serde(default = "default_param_type", alias = "type")]
+    pub param_type: String,
+    /// Whether this parameter is required
+    #[serde(default = "default_required")]
+    pub required: bool,
+    /// Description of the parameter
+    #[serde(default)]
+    pub description: String,
+}
+
+fn default_category() -> String {
+    USER_MACRO_CATEGORY.to_string()
+}
+
+fn default_param_type() -> String {
+    "any".to_string()
+}
+
+fn default_required() -> bool {
+    true
+}
+
+impl From<&MacroMetadata> for MacroDoc {
+    fn from(builtin: &MacroMetadata) -> Self {
+        Self {
+            name: builtin.name.to_string(),
+            category: builtin.category.to_string(),
+            description: builtin.description.to_string(),
+            params: builtin
+                .params
+                .iter()
+                .map(|p| MacroDocParam {
+                    name: p.name.to_string(),
+                    param_type: p.param_type.to_string(),
+                    required: p.required,
+                    description: p.description.to_string(),
+                })
+                .collect(),
+            example: builtin.example.to_string(),
+            example_output: builtin.example_output.to_string(),
+            source_file: String::new(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MacroDocFile {
+    macros: Vec<MacroDoc>,
+}
+
+/// Load user macro documentation from the `.yml`/`.yaml` files in the macro
+/// directories (non-recursive, like macro discovery), sorted by name.
+pub fn discover_macro_docs(macro_paths: &[impl AsRef<Path>]) -> JinjaResult<Vec<MacroDoc>> {
+    let mut docs = Vec::new();
+    for dir in macro_paths {
+        let dir = dir.as_ref();
+        if !dir.is_dir() {
+            continue;
+        }
+        let entries = fs::read_dir(dir).map_err(|e| {
+            JinjaError::Internal(format!(
+                "failed to read macro directory {}: {}",
+                dir.display(),
+                e
+            ))
+        })?;
+        for path in entries.flatten().map(|e| e.path()) {
+            let Some(file_name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            let is_yaml = file_name.ends_with(".yml") || file_name.ends_with(".yaml");
+            if !path.is_file() || !is_yaml || file_name.ends_with(MACRO_TEST_SUFFIX) {
+                continue;
+            }
+            let content = fs::read_to_string(&path).map_err(|e| {
+                JinjaError::Internal(format!("failed to read {}: {}", path.display(), e))
+            })?;
+            let file: MacroDocFile = serde_yaml::from_str(&content).map_err(|e| {
+                JinjaError::Internal(format!("failed to parse {}: {}", path.display(), e))
+            })?;
+            docs.extend(file.macros.into_iter().map(|mut doc| {
+                doc.source_file = path.display().to_string();
+                doc
+            }));
+        }
+    }
+
+    docs.sort_by(|a, b| a.name.cmp(&b.name));
+    if let Some(pair) = docs.windows(2).find(|pair| pair[0].name == pair[1].name) {
+        return Err(JinjaError::Internal(format!(
+            "macro '{}' is documented in both {} and {}",
+            pair[0].name, pair[0].source_file, pair[1].source_file
+        )));
+    }
+    Ok(docs)
+}
+
+#[cfg(test)]
+#[path = "macro_docs_test.rs"]
+mod tests;
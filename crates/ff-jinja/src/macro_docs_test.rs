@@ -0,0 +1,61 @@
+use super::*;
+use tempfile::TempDir;
+
+#[test]
+fn test_discover_macro_docs() {
+    let temp = TempDir::new().unwrap();
+    fs::write(
+        temp.path().join("money.yml"),
+        r#"
+macros:
+  - name: cents_to_dollars
+    category: finance
+    description: Convert cents to dollars.
+    params:
+      - name: column
+        type: string
+        description: Column holding cents
+      - name: precision
+        param_type: integer
+        required: false
+    example: "{{ cents_to_dollars('amount') }}"
+    example_output: "amount / 100.0"
+  - name: currency_label
+    description: Currency label from vars.
+"#,
+    )
+    .unwrap();
+    // Test fixtures share the directory but are not documentation
+    fs::write(temp.path().join("money.test.yml"), "tests: []\n").unwrap();
+
+    let docs = discover_macro_docs(&[temp.path()]).unwrap();
+    assert_eq!(docs.len(), 2);
+    let cents = &docs[0];
+    assert_eq!(cents.name, "cents_to_dollars");
+    assert_eq!(cents.category, "finance");
+    assert_eq!(cents.params[0].param_type, "string");
+    assert!(cents.params[0].required);
+    assert!(!cents.params[1].required);
+    assert!(cents.source_file.ends_with("money.yml"));
+    assert_eq!(docs[1].category, USER_MACRO_CATEGORY);
+}
+
+#[test]
+fn test_macro_documented_twice_is_an_error() {
+    let temp = TempDir::new().unwrap();
+    let doc = "macros:\n  - name: dup\n    description: Duplicate.\n";
+    fs::write(temp.path().join("a.yml"), doc).unwrap();
+    fs::write(temp.path().join("b.yml"), doc).unwrap();
+
+    let err = discover_macro_docs(&[temp.path()]).unwrap_err();
+    assert!(err.to_string().contains("documented in both"), "{}", err);
+}
+
+#[test]
+fn test_builtin_metadata_converts_to_macro_doc() {
+    let builtin = crate::get_macro_by_name("date_trunc").unwrap();
+    let doc = MacroDoc::from(builtin);
+    assert_eq!(doc.name, "date_trunc");
+    assert_eq!(doc.params.len(), builtin.params.len());
+    assert!(doc.source_file.is_empty());
+}
@@ -0,0 +1,182 @@
+//! Macro unit test fixtures
+//!
+//! A `<name>.test.yml` file next to the macro files holds test cases for
+//! the macros in that directory. Each case calls one macro with literal
+//! arguments and vars and states what it should render, and optionally the
+//! rows the rendered query should return against `given` input tables:
+//!
+//! ```yaml
+//! tests:
+//!   - name: rounds_to_cents
+//!     macro: cents_to_dollars
+//!     args: ["amount"]
+//!     kwargs: { precision: 2 }
+//!     expected_sql: "ROUND(amount / 100.0, 2)"
+//!   - name: flags_negative_amounts
+//!     macro: test_positive_values
+//!     args: ["payments", "amount"]
+//!     given:
+//!       payments:
+//!         - { amount: 5 }
+//!         - { amount: -1 }
+//!     expected_rows:
+//!       - [-1]
+//! ```
+
+use crate::custom_tests::json_value_to_jinja;
+use crate::environment::JinjaEnvironment;
+use crate::error::{JinjaError, JinjaResult};
+use serde::Deserialize;
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// File name suffix of macro test fixtures
+pub const MACRO_TEST_SUFFIX: &str = ".test.yml";
+
+/// One fixture file and its test cases
+#[derive(Debug, Clone)]
+pub struct MacroTestFile {
+    /// Fixture file path
+    pub path: PathBuf,
+    /// Test cases, in file order
+    pub tests: Vec<MacroTestCase>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct MacroTestFileContent {
+    tests: Vec<MacroTestCase>,
+}
+
+/// A single macro test case
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MacroTestCase {
+    /// Test name, unique within its file
+    pub name: String,
+
+    /// Macro to call; `<package>.<macro>` for package macros
+    #[serde(rename = "macro")]
+    pub macro_name: String,
+
+    /// Positional arguments
+    #[serde(default)]
+    pub args: Vec<serde_json::Value>,
+
+    /// Keyword arguments
+    #[serde(default)]
+    pub kwargs: BTreeMap<String, serde_json::Value>,
+
+    /// Vars visible to `var()` while rendering, over the project's vars
+    #[serde(default)]
+    pub vars: HashMap<String, serde_yaml::Value>,
+
+    /// Expected rendered SQL, compared after normalizing formatting
+    #[serde(default)]
+    pub expected_sql: Option<String>,
+
+    /// Input tables created before running the rendered query: table name -> rows
+    #[serde(default)]
+    pub given: BTreeMap<String, Vec<serde_yaml::Mapping>>,
+
+    /// Rows the rendered query must return, in any order
+    #[serde(default)]
+    pub expected_rows: Option<Vec<Vec<serde_yaml::Value>>>,
+}
+
+impl MacroTestCase {
+    fn validate(&self, path: &Path) -> JinjaResult<()> {
+        let invalid = |reason: &str| {
+            JinjaError::Internal(format!(
+                "{}: test '{}' {}",
+                path.display(),
+                self.name,
+                reason
+            ))
+        };
+        let is_identifier = |s: &str| {
+            let mut chars = s.chars();
+            chars
+                .next()
+                .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+                && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        };
+        if !self.macro_name.split('.').all(is_identifier) {
+            return Err(invalid("has an invalid macro name"));
+        }
+        if let Some(key) = self.kwargs.keys().find(|k| !is_identifier(k)) {
+            return Err(invalid(&format!("has an invalid kwarg '{}'", key)));
+        }
+        if self.expected_sql.is_none() && self.expected_rows.is_none() {
+            return Err(invalid("needs `expected_sql`, `expected_rows`, or both"));
+        }
+        if !self.given.is_empty() && self.expected_rows.is_none() {
+            return Err(invalid("has `given` tables but no `expected_rows`"));
+        }
+        Ok(())
+    }
+}
+
+/// Discover macro test fixtures in the given macro directories (non-recursive,
+/// like macro discovery), sorted by path.
+pub fn discover_macro_tests(macro_paths: &[impl AsRef<Path>]) -> JinjaResult<Vec<MacroTestFile>> {
+    let mut paths = Vec::new();
+    for dir in macro_paths {
+        let dir = dir.as_ref();
+        if !dir.is_dir() {
+            continue;
+        }
+        let entries = fs::read_dir(dir).map_err(|e| {
+            JinjaError::Internal(format!(
+                "failed to read macro directory {}: {}",
+                dir.display(),
+                e
+            ))
+        })?;
+        paths.extend(entries.flatten().map(|e| e.path()).filter(|p| {
+            p.is_file()
+                && p.file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.ends_with(MACRO_TEST_SUFFIX))
+        }));
+    }
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let content = fs::read_to_string(&path).map_err(|e| {
+                JinjaError::Internal(format!("failed to read {}: {}", path.display(), e))
+            })?;
+            let parsed: MacroTestFileContent = serde_yaml::from_str(&content).map_err(|e| {
+                JinjaError::Internal(format!("failed to parse {}: {}", path.display(), e))
+            })?;
+            for case in &parsed.tests {
+                case.validate(&path)?;
+            }
+            Ok(MacroTestFile {
+                path,
+                tests: parsed.tests,
+            })
+        })
+        .collect()
+}
+
+/// Render a test case's macro call, trimmed of surrounding whitespace.
+///
+/// `env` should be built with the case's vars so `var()` sees them.
+pub fn render_macro_call(env: &JinjaEnvironment<'_>, case: &MacroTestCase) -> JinjaResult<String> {
+    let mut arguments: Vec<String> = case.args.iter().map(json_value_to_jinja).collect();
+    arguments.extend(
+        case.kwargs
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, json_value_to_jinja(v))),
+    );
+    let template = format!("{{{{ {}({}) }}}}", case.macro_name, arguments.join(", "));
+    Ok(env.render(&template)?.trim().to_string())
+}
+
+#[cfg(test)]
+#[path = "macro_tests_test.rs"]
+mod tests;
@@ -0,0 +1,74 @@
+use super::*;
+use tempfile::TempDir;
+
+fn macros_dir(fixture: &str) -> TempDir {
+    let temp = TempDir::new().unwrap();
+    fs::write(
+        temp.path().join("money.sql"),
+        r#"{% macro cents_to_dollars(col, precision=2) %}ROUND({{ col }} / 100.0, {{ precision }}){% endmacro %}
+{% macro currency_label() %}'{{ var("currency", "usd") }}'{% endmacro %}"#,
+    )
+    .unwrap();
+    fs::write(temp.path().join("money.test.yml"), fixture).unwrap();
+    temp
+}
+
+#[test]
+fn test_discover_and_render_macro_tests() {
+    let temp = macros_dir(
+        r#"
+tests:
+  - name: default_precision
+    macro: cents_to_dollars
+    args: ["amount"]
+    expected_sql: "ROUND(amount / 100.0, 2)"
+  - name: custom_precision
+    macro: cents_to_dollars
+    args: ["amount"]
+    kwargs: { precision: 4 }
+    expected_sql: "ROUND(amount / 100.0, 4)"
+  - name: label_from_vars
+    macro: currency_label
+    vars: { currency: eur }
+    expected_sql: "'eur'"
+"#,
+    );
+    let files = discover_macro_tests(&[temp.path()]).unwrap();
+    assert_eq!(files.len(), 1);
+    assert_eq!(files[0].tests.len(), 3);
+
+    let paths = vec![temp.path().to_path_buf()];
+    for case in &files[0].tests {
+        let env = JinjaEnvironment::with_macros(&case.vars, &paths);
+        let rendered = render_macro_call(&env, case).unwrap();
+        assert_eq!(Some(rendered.as_str()), case.expected_sql.as_deref());
+    }
+}
+
+#[test]
+fn test_macro_test_requires_an_expectation() {
+    let temp = macros_dir(
+        r#"
+tests:
+  - name: no_expectation
+    macro: cents_to_dollars
+    args: ["amount"]
+"#,
+    );
+    let err = discover_macro_tests(&[temp.path()]).unwrap_err();
+    assert!(err.to_string().contains("needs `expected_sql`"), "{}", err);
+}
+
+#[test]
+fn test_macro_test_rejects_injected_macro_name() {
+    let temp = macros_dir(
+        r#"
+tests:
+  - name: injected
+    macro: "x() }}{{ env('HOME')"
+    expected_sql: ""
+"#,
+    );
+    let err = discover_macro_tests(&[temp.path()]).unwrap_err();
+    assert!(err.to_string().contains("invalid macro name"), "{}", err);
+}
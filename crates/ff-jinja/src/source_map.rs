@@ -0,0 +1,360 @@
+//! Map positions in rendered SQL back to the Jinja template
+//!
+//! minijinja does not report where rendered output came from, so the map is
+//! rebuilt after rendering by aligning lines. Each template line becomes a
+//! pattern whose literal text must appear, in order, on a rendered line and
+//! whose tags (`{{ }}`, `{% %}`, `{# #}`) match anything. The highest-scoring
+//! in-order alignment decides which template line each rendered line came
+//! from. A run of unaligned rendered lines facing a run of unaligned template
+//! lines of the same length is paired up line by line (this covers SQL that
+//! was rewritten after rendering, such as qualified table names). Remaining
+//! rendered lines (macro expansions, repeated loop bodies) are attributed to
+//! the closest aligned line above them.
+
+/// Line and column mapping from rendered SQL to its template.
+///
+/// Positions are 1-based; columns count characters.
+#[derive(Debug, Clone)]
+pub struct SourceMap {
+    lines: Vec<LineOrigin>,
+}
+
+/// The template line a rendered line came from
+#[derive(Debug, Clone)]
+struct LineOrigin {
+    /// Template line (1-based)
+    line: usize,
+    /// Literal text shared with the template line, empty when not aligned
+    pieces: Vec<Piece>,
+    /// Template column (0-based) for positions outside every piece
+    fallback: usize,
+}
+
+/// A run of literal template text found on a rendered line
+#[derive(Debug, Clone, Copy)]
+struct Piece {
+    rendered: usize,
+    template: usize,
+    len: usize,
+}
+
+/// A template line split into literal text and tags
+struct Pattern {
+    /// The whole line, trailing whitespace removed
+    text: Vec<char>,
+    segments: Vec<Segment>,
+    /// Whether the line holds literal text other than whitespace
+    has_text: bool,
+    /// Whether the line holds only statement/comment tags and whitespace
+    control_only: bool,
+    /// Column (0-based) of the first tag, else of the first non-blank character
+    anchor: usize,
+}
+
+enum Segment {
+    Literal { start: usize, text: Vec<char> },
+    Tag,
+}
+
+impl SourceMap {
+    /// Build the map for `rendered`, the output of rendering `template`.
+    pub fn new(template: &str, rendered: &str) -> Self {
+        let patterns = parse_patterns(template);
+        let rendered: Vec<Vec<char>> = rendered
+            .lines()
+            .map(|line| line.trim_end().chars().collect())
+            .collect();
+        let mut aligned = align(&patterns, &rendered);
+        let paired = pair_gaps(&mut aligned, patterns.len());
+
+        let mut lines = Vec::with_capacity(rendered.len());
+        let mut previous: Option<usize> = None;
+        for (r, line) in rendered.iter().enumerate() {
+            let origin = match aligned[r] {
+                Some(t) => {
+                    previous = Some(t);
+                    let pieces = if paired[r] {
+                        common_affixes(&patterns[t].text, line)
+                    } else {
+                        match_pattern(&patterns[t], line).unwrap_or_default()
+                    };
+                    LineOrigin {
+                        line: t + 1,
+                        pieces,
+                        fallback: patterns[t].anchor,
+                    }
+                }
+                None => {
+                    let t = previous
+                        .or_else(|| aligned[r..].iter().flatten().next().copied())
+                        .unwrap_or(0);
+                    LineOrigin {
+                        line: t + 1,
+                        pieces: Vec::new(),
+                        fallback: patterns.get(t).map_or(0, |p| p.anchor),
+                    }
+                }
+            };
+            lines.push(origin);
+        }
+        Self { lines }
+    }
+
+    /// Map a rendered `(line, column)` to the template `(line, column)`.
+    ///
+    /// Columns inside literal template text map exactly; columns inside text
+    /// produced by a tag map to the start of that tag.
+    pub fn locate(&self, line: usize, column: usize) -> (usize, usize) {
+        let Some(origin) = self.lines.get(line.saturating_sub(1)).or(self.lines.last()) else {
+            return (line.max(1), column.max(1));
+        };
+        let col = column.saturating_sub(1);
+        let mapped = origin
+            .pieces
+            .iter()
+            .rev()
+            .find(|p| p.rendered <= col)
+            .map(|p| {
+                if col < p.rendered + p.len {
+                    p.template + (col - p.rendered)
+                } else {
+                    p.template + p.len
+                }
+            })
+            .unwrap_or(origin.fallback);
+        (origin.line, mapped + 1)
+    }
+}
+
+/// Split the template into one pattern per line, tracking tags that span lines
+fn parse_patterns(template: &str) -> Vec<Pattern> {
+    let mut open_tag: Option<&'static str> = None;
+    template
+        .lines()
+        .map(|line| {
+            let chars: Vec<char> = line.trim_end().chars().collect();
+            let mut segments = Vec::new();
+            let mut anchor = None;
+            let mut only_control = true;
+            let mut literal_start = 0;
+            let mut i = 0;
+            if let Some(close) = open_tag {
+                segments.push(Segment::Tag);
+                anchor = Some(0);
+                only_control = close != "}}";
+            }
+            while i < chars.len() {
+                if let Some(close) = open_tag {
+                    if starts_with(&chars, i, close) {
+                        open_tag = None;
+                        i += 2;
+                        literal_start = i;
+                    } else {
+                        i += 1;
+                    }
+                    continue;
+                }
+                let close = match (chars[i], chars.get(i + 1)) {
+                    ('{', Some('{')) => "}}",
+                    ('{', Some('%')) => "%}",
+                    ('{', Some('#')) => "#}",
+                    _ => {
+                        i += 1;
+                        continue;
+                    }
+                };
+                push_literal(&mut segments, &chars[literal_start..i], literal_start);
+                segments.push(Segment::Tag);
+                anchor.get_or_insert(i);
+                only_control &= close != "}}";
+                open_tag = Some(close);
+                i += 2;
+            }
+            if open_tag.is_none() {
+                push_literal(&mut segments, &chars[literal_start..], literal_start);
+            }
+
+            let has_text = segments.iter().any(|s| match s {
+                Segment::Literal { text, .. } => text.iter().any(|c| !c.is_whitespace()),
+                Segment::Tag => false,
+            });
+            let has_tag = segments.iter().any(|s| matches!(s, Segment::Tag));
+            Pattern {
+                control_only: has_tag && only_control && !has_text,
+                has_text,
+                anchor: anchor
+                    .or_else(|| chars.iter().position(|c| !c.is_whitespace()))
+                    .unwrap_or(0),
+                segments,
+                text: chars,
+            }
+        })
+        .collect()
+}
+
+fn push_literal(segments: &mut Vec<Segment>, text: &[char], start: usize) {
+    if !text.is_empty() {
+        segments.push(Segment::Literal {
+            start,
+            text: text.to_vec(),
+        });
+    }
+}
+
+fn starts_with(chars: &[char], at: usize, needle: &str) -> bool {
+    needle
+        .chars()
+        .enumerate()
+        .all(|(k, c)| chars.get(at + k) == Some(&c))
+}
+
+/// Match a rendered line against a template pattern, returning the literal
+/// pieces' positions on the rendered line.
+fn match_pattern(pattern: &Pattern, line: &[char]) -> Option<Vec<Piece>> {
+    if pattern.control_only || pattern.segments.is_empty() {
+        return line.iter().all(|c| c.is_whitespace()).then(Vec::new);
+    }
+    let mut pieces = Vec::new();
+    let mut pos = 0;
+    let last = pattern.segments.len() - 1;
+    for (idx, segment) in pattern.segments.iter().enumerate() {
+        let Segment::Literal { start, text } = segment else {
+            continue;
+        };
+        let after_tag = idx > 0;
+        let found = if !after_tag {
+            line.starts_with(text).then_some(0)
+        } else if idx == last {
+            let at = line.len().checked_sub(text.len())?;
+            (at >= pos && line[at..] == text[..]).then_some(at)
+        } else {
+            find(&line[pos..], text).map(|at| pos + at)
+        }?;
+        pieces.push(Piece {
+            rendered: found,
+            template: *start,
+            len: text.len(),
+        });
+        pos = found + text.len();
+    }
+    if matches!(pattern.segments.last(), Some(Segment::Literal { .. })) && pos != line.len() {
+        return None;
+    }
+    Some(pieces)
+}
+
+fn find(haystack: &[char], needle: &[char]) -> Option<usize> {
+    if needle.is_empty() {
+        return Some(0);
+    }
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+/// Pair equally long runs of unaligned rendered and template lines.
+///
+/// Returns which rendered lines were paired this way.
+fn pair_gaps(aligned: &mut [Option<usize>], template_lines: usize) -> Vec<bool> {
+    let mut paired = vec![false; aligned.len()];
+    let mut anchors: Vec<(usize, usize)> = aligned
+        .iter()
+        .enumerate()
+        .filter_map(|(r, t)| t.map(|t| (r, t)))
+        .collect();
+    anchors.push((aligned.len(), template_lines));
+
+    let (mut r0, mut t0) = (0, 0);
+    for (r1, t1) in anchors {
+        if r1 - r0 == t1 - t0 {
+            for k in 0..r1 - r0 {
+                aligned[r0 + k] = Some(t0 + k);
+                paired[r0 + k] = true;
+            }
+        }
+        (r0, t0) = (r1 + 1, t1 + 1);
+    }
+    paired
+}
+
+/// Pieces for the text a template line and a rewritten rendered line share
+/// at their start and end
+fn common_affixes(template: &[char], line: &[char]) -> Vec<Piece> {
+    let prefix = template
+        .iter()
+        .zip(line)
+        .take_while(|(a, b)| a == b)
+        .count();
+    let max_suffix = template.len().min(line.len()) - prefix;
+    let suffix = template
+        .iter()
+        .rev()
+        .zip(line.iter().rev())
+        .take(max_suffix)
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let mut pieces = Vec::new();
+    if prefix > 0 {
+        pieces.push(Piece {
+            rendered: 0,
+            template: 0,
+            len: prefix,
+        });
+    }
+    if suffix > 0 {
+        pieces.push(Piece {
+            rendered: line.len() - suffix,
+            template: template.len() - suffix,
+            len: suffix,
+        });
+    }
+    pieces
+}
+
+/// Highest-scoring in-order alignment of rendered lines to template lines
+///
+/// Lines with literal text score 2, lines that are blank or only tags score 1,
+/// so concrete SQL wins over placeholders when both could match.
+fn align(patterns: &[Pattern], rendered: &[Vec<char>]) -> Vec<Option<usize>> {
+    let (n, m) = (patterns.len(), rendered.len());
+    let score = |t: usize, r: usize| -> u32 {
+        match match_pattern(&patterns[t], &rendered[r]) {
+            Some(_) if patterns[t].has_text => 2,
+            Some(_) => 1,
+            None => 0,
+        }
+    };
+
+    // best[t][r]: best score aligning patterns[t..] with rendered[r..]
+    let mut best = vec![vec![0u32; m + 1]; n + 1];
+    let mut matched = vec![vec![0u32; m]; n];
+    for t in (0..n).rev() {
+        for r in (0..m).rev() {
+            matched[t][r] = score(t, r);
+            let take = if matched[t][r] > 0 {
+                matched[t][r] + best[t + 1][r + 1]
+            } else {
+                0
+            };
+            best[t][r] = take.max(best[t + 1][r]).max(best[t][r + 1]);
+        }
+    }
+
+    let mut aligned = vec![None; m];
+    let (mut t, mut r) = (0, 0);
+    while t < n && r < m {
+        if matched[t][r] > 0 && best[t][r] == matched[t][r] + best[t + 1][r + 1] {
+            aligned[r] = Some(t);
+            t += 1;
+            r += 1;
+        } else if best[t][r] == best[t + 1][r] {
+            t += 1;
+        } else {
+            r += 1;
+        }
+    }
+    aligned
+}
+
+#[cfg(test)]
+#[path = "source_map_test.rs"]
+mod tests;
@@ -0,0 +1,69 @@
+use super::*;
+
+#[test]
+fn test_plain_sql_maps_to_itself() {
+    let sql = "SELECT id,\n    name\nFROM users";
+    let map = SourceMap::new(sql, sql);
+    assert_eq!(map.locate(1, 8), (1, 8));
+    assert_eq!(map.locate(2, 5), (2, 5));
+    assert_eq!(map.locate(3, 6), (3, 6));
+}
+
+#[test]
+fn test_block_tags_shift_lines() {
+    let template = "{{ config(materialized='table') }}\n\nSELECT\n    id,\n{% if var('with_name') %}\n    name,\n{% endif %}\n    amount\nFROM {{ ref('orders') }}";
+    let rendered = "\n\nSELECT\n    id,\n\n    name,\n\n    amount\nFROM main.orders";
+    let map = SourceMap::new(template, rendered);
+    assert_eq!(map.locate(6, 5), (6, 5));
+    assert_eq!(map.locate(8, 5), (8, 5));
+    assert_eq!(map.locate(9, 1), (9, 1));
+}
+
+#[test]
+fn test_removed_lines_and_tag_output_columns() {
+    let template = "{% set cols = ['a', 'b'] %}\nSELECT {{ cols | join(', ') }}, total\nFROM {{ ref('t') }}\nWHERE total > {{ var('min') }}";
+    let rendered = "SELECT a, b, total\nFROM main.t\nWHERE total > 10";
+    let map = SourceMap::new(template, rendered);
+    // `total` on the select line sits after the tag's output
+    assert_eq!(map.locate(1, 14), (2, 33));
+    // Inside the tag's output maps to the start of the tag
+    assert_eq!(map.locate(1, 8), (2, 8));
+    assert_eq!(map.locate(2, 8), (3, 6));
+    assert_eq!(map.locate(3, 7), (4, 7));
+}
+
+#[test]
+fn test_macro_expansion_maps_to_call_line() {
+    let template = "SELECT\n    {{ audit_columns() }},\n    id\nFROM src";
+    let rendered = "SELECT\n    created_at,\n    updated_at,\n    id\nFROM src";
+    let map = SourceMap::new(template, rendered);
+    assert_eq!(map.locate(2, 5), (2, 5));
+    assert_eq!(map.locate(3, 5), (2, 5));
+    assert_eq!(map.locate(4, 5), (3, 5));
+    assert_eq!(map.locate(5, 1), (4, 1));
+}
+
+#[test]
+fn test_rewritten_lines_pair_with_template_lines() {
+    let template = "SELECT id\nFROM stg_orders o\nWHERE amount > 1";
+    let rendered = "SELECT id\nFROM main.stg_orders o\nWHERE amount > 1";
+    let map = SourceMap::new(template, rendered);
+    assert_eq!(map.locate(2, 1), (2, 1));
+    assert_eq!(map.locate(2, 11), (2, 6));
+    assert_eq!(map.locate(3, 7), (3, 7));
+}
+
+#[test]
+fn test_multiline_tags() {
+    let template = "{{\n    config(\n        materialized='view'\n    )\n}}\nSELECT 1 AS one";
+    let rendered = "\nSELECT 1 AS one";
+    let map = SourceMap::new(template, rendered);
+    assert_eq!(map.locate(2, 13), (6, 13));
+}
+
+#[test]
+fn test_out_of_range_lines_clamp_to_last_line() {
+    let map = SourceMap::new("SELECT 1", "SELECT 1");
+    assert_eq!(map.locate(7, 1), (1, 1));
+    assert_eq!(SourceMap::new("", "").locate(3, 2), (3, 2));
+}
@@ -119,6 +119,7 @@ impl MetaDb {
             "DELETE FROM ff_meta.rule_violations WHERE run_id IN (SELECT run_id FROM ff_meta.compilation_runs WHERE project_id = ?)",
             "DELETE FROM ff_meta.diagnostics WHERE run_id IN (SELECT run_id FROM ff_meta.compilation_runs WHERE project_id = ?)",
             "DELETE FROM ff_meta.schema_mismatches WHERE run_id IN (SELECT run_id FROM ff_meta.compilation_runs WHERE project_id = ?)",
+            "DELETE FROM ff_meta.introspection_cache WHERE run_id IN (SELECT run_id FROM ff_meta.compilation_runs WHERE project_id = ?)",
             "DELETE FROM ff_meta.compilation_runs WHERE project_id = ?",
             "DELETE FROM ff_meta.project_hooks WHERE project_id = ?",
             "DELETE FROM ff_meta.project_vars WHERE project_id = ?",
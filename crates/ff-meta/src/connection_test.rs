@@ -112,6 +112,7 @@ fn all_tables_exist() {
         "model_run_state",
         "model_run_input_checksums",
         "model_run_config",
+        "introspection_cache",
     ];
 
     for table in &expected_tables {
@@ -26,4 +26,16 @@ pub static MIGRATIONS: &[Migration] = &[
         version: 3,
         sql: include_str!("v003_description_ai_generated.sql"),
     },
+    Migration {
+        version: 4,
+        sql: include_str!("v004_description_inherited_from.sql"),
+    },
+    Migration {
+        version: 5,
+        sql: include_str!("v005_schema_inferred_by.sql"),
+    },
+    Migration {
+        version: 6,
+        sql: include_str!("v006_introspection_cache.sql"),
+    },
 ];
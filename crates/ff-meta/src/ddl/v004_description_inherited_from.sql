@@ -0,0 +1,30 @@
+-- ============================================================
+-- v004: Add description_inherited_from for lineage inheritance
+-- ============================================================
+-- Records the upstream `node.column` a model column's description was
+-- inherited from when `documentation.inherit_column_descriptions` is
+-- enabled. NULL means the description (if any) was declared in YAML.
+
+ALTER TABLE ff_meta.model_columns
+    ADD COLUMN description_inherited_from VARCHAR;
+
+-- Recreate v_columns to include description_inherited_from
+DROP VIEW IF EXISTS ff_meta.v_columns;
+CREATE VIEW ff_meta.v_columns AS
+SELECT
+    mc.column_id,
+    m.name AS model_name,
+    mc.name AS column_name,
+    mc.declared_type,
+    mc.inferred_type,
+    mc.nullability_declared,
+    mc.nullability_inferred,
+    mc.description,
+    mc.description_ai_generated,
+    mc.description_inherited_from,
+    mc.is_primary_key,
+    mc.classification,
+    mc.effective_classification,
+    mc.ordinal_position
+FROM ff_meta.model_columns mc
+JOIN ff_meta.models m ON mc.model_id = m.model_id;
@@ -0,0 +1,12 @@
+-- ============================================================
+-- v005: Add schema_inferred_by for engine-inferred models
+-- ============================================================
+-- Records how static analysis inferred a model's output schema:
+--   'datafusion' = planned by DataFusion
+--   'engine'     = DataFusion could not plan the model, so DuckDB
+--                  described it over empty stub tables
+--   NULL         = not analyzed
+-- Engine-inferred models skip the plan-based diagnostic passes.
+
+ALTER TABLE ff_meta.models
+    ADD COLUMN schema_inferred_by VARCHAR;
@@ -0,0 +1,19 @@
+-- ============================================================
+-- v006: Cache compile-time introspection results
+-- ============================================================
+-- Templates can call run_query(), get_columns(), and get_relation()
+-- while compiling. Each distinct call is answered from the database
+-- once per compile and recorded here against that compile's run, so
+-- the data a compiled model was generated from can be inspected later.
+--   function_name = run_query | get_columns | get_relation
+--   argument      = the SQL or relation name passed in
+--   result_json   = JSON of the value returned to the template
+
+CREATE TABLE ff_meta.introspection_cache (
+    run_id        INTEGER NOT NULL REFERENCES ff_meta.compilation_runs(run_id),
+    function_name VARCHAR NOT NULL
+        CHECK (function_name IN ('run_query', 'get_columns', 'get_relation')),
+    argument      VARCHAR NOT NULL,
+    result_json   VARCHAR NOT NULL,
+    PRIMARY KEY (run_id, function_name, argument)
+);
@@ -90,6 +90,28 @@ pub struct ManifestModel {
     /// SHA-256 checksum of the raw SQL content (for change detection)
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub sql_checksum: Option<String>,
+
+    /// Column definitions from the model's YAML schema
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub columns: Vec<ManifestModelColumn>,
+}
+
+/// A column in a manifest model
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestModelColumn {
+    /// Column name
+    pub name: String,
+
+    /// Declared column data type
+    pub data_type: String,
+
+    /// Column description (declared or inherited)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Upstream `node.column` the description was inherited from
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_inherited_from: Option<String>,
 }
 
 /// A source entry in the manifest
@@ -184,6 +206,15 @@ impl Manifest {
         }
     }
 
+    fn model_column_to_manifest(col: &ff_core::model::SchemaColumnDef) -> ManifestModelColumn {
+        ManifestModelColumn {
+            name: col.name.clone(),
+            data_type: col.data_type.clone(),
+            description: col.description.clone(),
+            description_inherited_from: col.description_inherited_from.clone(),
+        }
+    }
+
     fn test_definition_name(t: &ff_core::model::TestDefinition) -> String {
         match t {
             ff_core::model::TestDefinition::Simple(name) => name.clone(),
@@ -299,6 +330,16 @@ impl Manifest {
             post_hook: model.config.post_hook.clone(),
             wap,
             sql_checksum: Some(model.sql_checksum()),
+            columns: model
+                .schema
+                .as_ref()
+                .map(|s| {
+                    s.columns
+                        .iter()
+                        .map(Self::model_column_to_manifest)
+                        .collect()
+                })
+                .unwrap_or_default(),
         };
 
         self.models.insert(model.name.clone(), manifest_model);
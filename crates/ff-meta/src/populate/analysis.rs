@@ -42,14 +42,44 @@ pub struct SchemaMismatch {
     pub inferred_value: Option<String>,
 }
 
+/// How static analysis inferred a model's output schema.
+pub struct SchemaInference {
+    pub model_id: i64,
+    /// `"datafusion"` when planned, `"engine"` when described by the database
+    pub inferred_by: String,
+}
+
 /// Update model_columns with inferred types and nullability from static analysis.
+///
+/// Columns the model's YAML does not declare are appended after the declared ones.
 pub fn populate_inferred_schemas(conn: &Connection, columns: &[InferredColumn]) -> MetaResult<()> {
     for col in columns {
+        let updated = conn
+            .execute(
+                "UPDATE ff_meta.model_columns SET inferred_type = ?, nullability_inferred = ? WHERE model_id = ? AND lower(name) = lower(?)",
+                duckdb::params![col.inferred_type, col.nullability_inferred, col.model_id, col.column_name],
+            )
+            .populate_context("update inferred schema")?;
+        if updated == 0 {
+            conn.execute(
+                "INSERT INTO ff_meta.model_columns (model_id, name, inferred_type, nullability_inferred, ordinal_position)
+                 SELECT ?, ?, ?, ?, COALESCE(MAX(ordinal_position), 0) + 1 FROM ff_meta.model_columns WHERE model_id = ?",
+                duckdb::params![col.model_id, col.column_name, col.inferred_type, col.nullability_inferred, col.model_id],
+            )
+            .populate_context("insert inferred column")?;
+        }
+    }
+    Ok(())
+}
+
+/// Update models with how their schema was inferred during static analysis.
+pub fn populate_schema_inference(conn: &Connection, entries: &[SchemaInference]) -> MetaResult<()> {
+    for entry in entries {
         conn.execute(
-            "UPDATE ff_meta.model_columns SET inferred_type = ?, nullability_inferred = ? WHERE model_id = ? AND name = ?",
-            duckdb::params![col.inferred_type, col.nullability_inferred, col.model_id, col.column_name],
+            "UPDATE ff_meta.models SET schema_inferred_by = ? WHERE model_id = ?",
+            duckdb::params![entry.inferred_by, entry.model_id],
         )
-        .populate_context("update inferred schema")?;
+        .populate_context("update schema_inferred_by")?;
     }
     Ok(())
 }
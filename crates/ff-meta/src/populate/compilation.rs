@@ -65,3 +65,29 @@ pub fn clear_model_dependencies(conn: &Connection, model_id: i64) -> MetaResult<
     .populate_context("delete model_external_dependencies")?;
     Ok(())
 }
+
+/// A compile-time introspection call and the result returned to the template.
+pub struct IntrospectionEntry {
+    /// `run_query`, `get_columns`, or `get_relation`
+    pub function_name: String,
+    /// SQL or relation name passed to the function
+    pub argument: String,
+    /// JSON of the returned value
+    pub result_json: String,
+}
+
+/// Record the introspection calls a compile answered from the database.
+pub fn populate_introspection_cache(
+    conn: &Connection,
+    run_id: i64,
+    entries: &[IntrospectionEntry],
+) -> MetaResult<()> {
+    for entry in entries {
+        conn.execute(
+            "INSERT INTO ff_meta.introspection_cache (run_id, function_name, argument, result_json) VALUES (?, ?, ?, ?)",
+            duckdb::params![run_id, entry.function_name, entry.argument, entry.result_json],
+        )
+        .populate_context("insert introspection_cache")?;
+    }
+    Ok(())
+}
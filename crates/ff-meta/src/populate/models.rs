@@ -216,8 +216,8 @@ fn insert_single_column(
     });
 
     conn.execute(
-        "INSERT INTO ff_meta.model_columns (model_id, name, declared_type, nullability_declared, description, description_ai_generated, is_primary_key, classification, ordinal_position)
-         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?)",
+        "INSERT INTO ff_meta.model_columns (model_id, name, declared_type, nullability_declared, description, description_ai_generated, description_inherited_from, is_primary_key, classification, ordinal_position)
+         VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
         duckdb::params![
             model_id,
             col.name,
@@ -225,6 +225,7 @@ fn insert_single_column(
             nullability,
             col.description,
             col.description_ai_generated,
+            col.description_inherited_from,
             col.primary_key,
             classification,
             (ordinal + 1) as i32,
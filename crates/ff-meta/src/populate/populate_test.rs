@@ -1,4 +1,4 @@
-use super::analysis::{Diagnostic, InferredColumn, LineageEdge, SchemaMismatch};
+use super::analysis::{Diagnostic, InferredColumn, LineageEdge, SchemaInference, SchemaMismatch};
 use super::execution::{ConfigSnapshot, InputChecksum, ModelRunRecord, ModelRunStatus};
 #[cfg(test)]
 use crate::MetaDb;
@@ -49,6 +49,7 @@ fn test_model(name: &str) -> Model {
                 data_type: "INTEGER".to_string(),
                 description: Some("Primary key".to_string()),
                 description_ai_generated: None,
+                description_inherited_from: None,
                 primary_key: true,
                 tests: vec![],
                 references: None,
@@ -410,6 +411,7 @@ fn populate_model_columns_with_constraints_and_references() {
                 data_type: "INTEGER".to_string(),
                 description: None,
                 description_ai_generated: None,
+                description_inherited_from: None,
                 primary_key: true,
                 tests: vec![],
                 references: None,
@@ -420,6 +422,7 @@ fn populate_model_columns_with_constraints_and_references() {
                 data_type: "INTEGER".to_string(),
                 description: Some("FK to customers".to_string()),
                 description_ai_generated: None,
+                description_inherited_from: None,
                 primary_key: false,
                 tests: vec![],
                 references: Some(ColumnReference {
@@ -433,6 +436,7 @@ fn populate_model_columns_with_constraints_and_references() {
                 data_type: "VARCHAR".to_string(),
                 description: None,
                 description_ai_generated: None,
+                description_inherited_from: None,
                 primary_key: false,
                 tests: vec![],
                 references: None,
@@ -842,6 +846,39 @@ fn populate_empty_project() {
     .unwrap();
 }
 
+#[test]
+fn populate_model_columns_records_inherited_description() {
+    let meta = open_meta();
+    let config = test_config();
+
+    meta.transaction(|conn| {
+        let project_id =
+            super::project::populate_project(conn, &config, &PathBuf::from("/tmp/project"))?;
+
+        let mut model = test_model("stg_orders");
+        let column = &mut model.schema.as_mut().unwrap().columns[0];
+        column.description = Some("Order identifier".to_string());
+        column.description_inherited_from = Some("raw_orders.id".to_string());
+
+        let mut models = HashMap::new();
+        models.insert(ModelName::new("stg_orders"), model);
+        super::models::populate_models(conn, project_id, &models, &config)?;
+
+        let (description, origin): (String, String) = conn
+            .query_row(
+                "SELECT description, description_inherited_from FROM ff_meta.v_columns WHERE model_name = 'stg_orders' AND column_name = 'id'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!(description, "Order identifier");
+        assert_eq!(origin, "raw_orders.id");
+
+        Ok(())
+    })
+    .unwrap();
+}
+
 #[test]
 fn populate_model_no_schema() {
     let meta = open_meta();
@@ -916,6 +953,7 @@ fn populate_project_load_orchestrator() {
         singular_tests: vec![],
         sources: vec![test_source()],
         functions: vec![test_function()],
+        packages: vec![],
     });
 
     meta.transaction(|conn| {
@@ -1244,6 +1282,41 @@ fn populate_inferred_schemas_updates_columns() {
     .unwrap();
 }
 
+#[test]
+fn populate_schema_inference_marks_models() {
+    let meta = open_meta();
+    let config = test_config();
+
+    meta.transaction(|conn| {
+        let project_id =
+            super::project::populate_project(conn, &config, &PathBuf::from("/tmp/project"))?;
+        let mut models = HashMap::new();
+        models.insert(ModelName::new("orders"), test_model("orders"));
+        models.insert(ModelName::new("pivoted"), test_model("pivoted"));
+        let id_map = super::models::populate_models(conn, project_id, &models, &config)?;
+
+        let entries = vec![SchemaInference {
+            model_id: id_map["pivoted"],
+            inferred_by: "engine".to_string(),
+        }];
+        super::analysis::populate_schema_inference(conn, &entries)?;
+
+        let inferred_by = |model_id: i64| -> Option<String> {
+            conn.query_row(
+                "SELECT schema_inferred_by FROM ff_meta.models WHERE model_id = ?",
+                duckdb::params![model_id],
+                |row| row.get(0),
+            )
+            .unwrap()
+        };
+        assert_eq!(inferred_by(id_map["pivoted"]).as_deref(), Some("engine"));
+        assert_eq!(inferred_by(id_map["orders"]), None);
+
+        Ok(())
+    })
+    .unwrap();
+}
+
 #[test]
 fn populate_column_lineage_edges() {
     let meta = open_meta();
@@ -1780,6 +1853,41 @@ fn begin_population_creates_run_and_clears_data() {
     .unwrap();
 }
 
+#[test]
+fn populate_introspection_cache_records_calls() {
+    let meta = open_meta();
+    let config = test_config();
+
+    meta.transaction(|conn| {
+        let project_id =
+            super::project::populate_project(conn, &config, &PathBuf::from("/tmp/project"))?;
+        let run_id = super::lifecycle::begin_population(conn, project_id, "compile", None)?;
+
+        super::compilation::populate_introspection_cache(
+            conn,
+            run_id,
+            &[super::compilation::IntrospectionEntry {
+                function_name: "get_columns".to_string(),
+                argument: "raw_orders".to_string(),
+                result_json: r#"[{"name":"id","data_type":"INTEGER"}]"#.to_string(),
+            }],
+        )?;
+
+        let (function_name, result_json): (String, String) = conn
+            .query_row(
+                "SELECT function_name, result_json FROM ff_meta.introspection_cache WHERE run_id = ?",
+                duckdb::params![run_id],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!(function_name, "get_columns");
+        assert!(result_json.contains("INTEGER"));
+
+        Ok(())
+    })
+    .unwrap();
+}
+
 #[test]
 fn complete_population_updates_status() {
     let meta = open_meta();
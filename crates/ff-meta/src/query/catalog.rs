@@ -0,0 +1,43 @@
+//! Catalog queries: the output columns of a project's models.
+
+use crate::error::{MetaError, MetaResult};
+use duckdb::Connection;
+use std::collections::HashMap;
+
+/// Output columns of every model in a project, keyed by model name.
+///
+/// Columns come in ordinal order, each with the type static analysis inferred
+/// or, where it inferred none, the type its YAML declares.
+pub fn model_column_types(
+    conn: &Connection,
+    project_name: &str,
+) -> MetaResult<HashMap<String, Vec<(String, String)>>> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT m.name, c.name, COALESCE(c.inferred_type, c.declared_type, '')
+             FROM ff_meta.model_columns c
+             JOIN ff_meta.models m ON m.model_id = c.model_id
+             JOIN ff_meta.projects p ON p.project_id = m.project_id
+             WHERE p.name = ?
+             ORDER BY m.name, c.ordinal_position",
+        )
+        .map_err(|e| MetaError::QueryError(format!("prepare model_column_types: {e}")))?;
+
+    let rows: Vec<(String, String, String)> = stmt
+        .query_map(duckdb::params![project_name], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+        })
+        .map_err(|e| MetaError::QueryError(format!("query model_column_types: {e}")))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| MetaError::QueryError(format!("collect model_column_types: {e}")))?;
+
+    let mut types: HashMap<String, Vec<(String, String)>> = HashMap::new();
+    for (model, column, data_type) in rows {
+        types.entry(model).or_default().push((column, data_type));
+    }
+    Ok(types)
+}
+
+#[cfg(test)]
+#[path = "catalog_test.rs"]
+mod tests;
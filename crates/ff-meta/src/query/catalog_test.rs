@@ -0,0 +1,69 @@
+use super::*;
+use crate::populate::analysis::{populate_inferred_schemas, InferredColumn};
+use crate::MetaDb;
+
+/// A meta DB with project `test` and model `orders`, returning the model id
+fn setup(meta: &MetaDb) -> i64 {
+    let conn = meta.conn();
+    conn.execute(
+        "INSERT INTO ff_meta.projects (name, root_path, db_path) VALUES ('test', '/tmp', '/tmp/dev.duckdb')",
+        [],
+    )
+    .unwrap();
+    conn.execute(
+        "INSERT INTO ff_meta.models (project_id, name, source_path, materialization, raw_sql)
+         SELECT project_id, 'orders', 'nodes/orders/orders.sql', 'table', 'SELECT 1' FROM ff_meta.projects",
+        [],
+    )
+    .unwrap();
+    conn.query_row("SELECT model_id FROM ff_meta.models", [], |r| r.get(0))
+        .unwrap()
+}
+
+#[test]
+fn model_column_types_prefers_inferred_types() {
+    let meta = MetaDb::open_memory().unwrap();
+    let model_id = setup(&meta);
+    let conn = meta.conn();
+    conn.execute(
+        "INSERT INTO ff_meta.model_columns (model_id, name, declared_type, ordinal_position)
+         VALUES (?, 'id', 'INTEGER', 1), (?, 'status', 'VARCHAR', 2)",
+        duckdb::params![model_id, model_id],
+    )
+    .unwrap();
+
+    let inferred = [
+        InferredColumn {
+            model_id,
+            column_name: "id".to_string(),
+            inferred_type: Some("BIGINT".to_string()),
+            nullability_inferred: None,
+        },
+        InferredColumn {
+            model_id,
+            column_name: "amount".to_string(),
+            inferred_type: Some("DECIMAL(10,2)".to_string()),
+            nullability_inferred: Some("nullable".to_string()),
+        },
+    ];
+    populate_inferred_schemas(conn, &inferred).unwrap();
+
+    let types = model_column_types(conn, "test").unwrap();
+    assert_eq!(
+        types["orders"],
+        vec![
+            ("id".to_string(), "BIGINT".to_string()),
+            ("status".to_string(), "VARCHAR".to_string()),
+            ("amount".to_string(), "DECIMAL(10,2)".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn model_column_types_of_unknown_project_is_empty() {
+    let meta = MetaDb::open_memory().unwrap();
+    setup(&meta);
+
+    let types = model_column_types(meta.conn(), "other").unwrap();
+    assert!(types.is_empty());
+}
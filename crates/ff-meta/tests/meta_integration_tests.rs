@@ -65,6 +65,7 @@ fn make_model(name: &str) -> Model {
                     data_type: "INTEGER".to_string(),
                     description: Some("Primary key".to_string()),
                     description_ai_generated: None,
+                    description_inherited_from: None,
                     primary_key: true,
                     tests: vec![TestDefinition::Simple("not_null".to_string())],
                     references: None,
@@ -75,6 +76,7 @@ fn make_model(name: &str) -> Model {
                     data_type: "VARCHAR".to_string(),
                     description: Some("Entity name".to_string()),
                     description_ai_generated: None,
+                    description_inherited_from: None,
                     primary_key: false,
                     tests: vec![],
                     references: None,
@@ -190,6 +192,7 @@ fn build_test_project() -> Project {
         sources: vec![make_source()],
         seeds: vec![make_seed("raw_customers"), make_seed("raw_orders")],
         functions: vec![make_function("safe_divide")],
+        packages: vec![],
         tests: vec![],
         singular_tests: vec![],
     })
@@ -2,13 +2,14 @@
 //!
 //! This crate provides SQL parsing using sqlparser-rs with dialect support,
 //! table dependency extraction, column-level lineage via AST visitor,
-//! and ephemeral model inlining.
+//! source locations of output columns, and ephemeral model inlining.
 
 pub mod dialect;
 pub mod error;
 pub mod extractor;
 pub mod inline;
 pub mod lineage;
+pub mod location;
 pub mod parser;
 pub mod qualify;
 pub mod suggestions;
@@ -28,6 +29,7 @@ pub use lineage::{
     extract_column_lineage, ColumnLineage, ColumnRef, DescriptionStatus, ExprType, LineageEdge,
     LineageKind, ModelLineage, ProjectLineage,
 };
+pub use location::locate_column;
 pub use parser::SqlParser;
 pub use qualify::{qualify_statements, qualify_table_references};
 pub use sqlparser::ast::Statement;
@@ -0,0 +1,85 @@
+//! Source locations of named SQL constructs
+//!
+//! Diagnostics name the output column they are about; this module finds that
+//! column in the SQL text using the spans sqlparser records on the AST.
+
+use std::ops::ControlFlow;
+
+use sqlparser::ast::{
+    visit_expressions, Expr, Ident, Query, Select, SelectItem, SetExpr, Spanned, Statement,
+};
+use sqlparser::tokenizer::Span;
+
+/// Find where `column` is produced or referenced in `stmt`.
+///
+/// Prefers the item of the outermost SELECT that outputs the column (by alias
+/// or bare column name), then falls back to the first reference to a column of
+/// that name anywhere in the statement. Names are compared case-insensitively.
+/// Returns `None` when the column does not appear in the SQL.
+pub fn locate_column(stmt: &Statement, column: &str) -> Option<Span> {
+    output_column_span(stmt, column).or_else(|| first_reference_span(stmt, column))
+}
+
+/// Span of the outermost SELECT item named `column`
+fn output_column_span(stmt: &Statement, column: &str) -> Option<Span> {
+    let Statement::Query(query) = stmt else {
+        return None;
+    };
+    let select = outermost_select(query)?;
+    select
+        .projection
+        .iter()
+        .find_map(|item| match item {
+            SelectItem::ExprWithAlias { alias, .. } if alias.value.eq_ignore_ascii_case(column) => {
+                Some(item.span())
+            }
+            SelectItem::UnnamedExpr(expr)
+                if column_ident(expr).is_some_and(|i| i.value.eq_ignore_ascii_case(column)) =>
+            {
+                Some(expr.span())
+            }
+            _ => None,
+        })
+        .filter(|span| *span != Span::empty())
+}
+
+/// The SELECT whose projection defines a query's output columns
+///
+/// For set operations the left-most branch names the columns.
+fn outermost_select(query: &Query) -> Option<&Select> {
+    let mut body = query.body.as_ref();
+    loop {
+        match body {
+            SetExpr::Select(select) => return Some(select),
+            SetExpr::Query(inner) => body = inner.body.as_ref(),
+            SetExpr::SetOperation { left, .. } => body = left.as_ref(),
+            _ => return None,
+        }
+    }
+}
+
+/// Span of the first column reference named `column`
+fn first_reference_span(stmt: &Statement, column: &str) -> Option<Span> {
+    let mut found = None;
+    let _ = visit_expressions(stmt, |expr| match column_ident(expr) {
+        Some(ident) if ident.value.eq_ignore_ascii_case(column) && ident.span != Span::empty() => {
+            found = Some(ident.span);
+            ControlFlow::Break(())
+        }
+        _ => ControlFlow::<()>::Continue(()),
+    });
+    found
+}
+
+/// The column identifier of a bare or qualified column reference
+fn column_ident(expr: &Expr) -> Option<&Ident> {
+    match expr {
+        Expr::Identifier(ident) => Some(ident),
+        Expr::CompoundIdentifier(parts) => parts.last(),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[path = "location_test.rs"]
+mod tests;
@@ -0,0 +1,44 @@
+use super::*;
+use crate::SqlParser;
+
+fn locate(sql: &str, column: &str) -> Option<(u64, u64, u64, u64)> {
+    let stmt = SqlParser::duckdb().parse_single(sql).unwrap();
+    locate_column(&stmt, column).map(|span| {
+        (
+            span.start.line,
+            span.start.column,
+            span.end.line,
+            span.end.column,
+        )
+    })
+}
+
+#[test]
+fn test_locates_aliased_output_column() {
+    let sql = "SELECT\n    id,\n    amount * 2 AS doubled\nFROM orders";
+    assert_eq!(locate(sql, "doubled"), Some((3, 5, 3, 26)));
+}
+
+#[test]
+fn test_locates_bare_and_qualified_columns() {
+    let sql = "SELECT o.id, status FROM orders o";
+    assert_eq!(locate(sql, "ID"), Some((1, 8, 1, 12)));
+    assert_eq!(locate(sql, "status"), Some((1, 14, 1, 20)));
+}
+
+#[test]
+fn test_outer_select_wins_over_ctes() {
+    let sql = "WITH base AS (SELECT id AS total FROM t)\nSELECT sum(id) AS total FROM base";
+    assert_eq!(locate(sql, "total").map(|s| s.0), Some(2));
+}
+
+#[test]
+fn test_falls_back_to_first_reference() {
+    let sql = "SELECT id FROM orders\nWHERE customer_id IS NOT NULL";
+    assert_eq!(locate(sql, "customer_id"), Some((2, 7, 2, 18)));
+}
+
+#[test]
+fn test_unknown_column_is_not_located() {
+    assert_eq!(locate("SELECT id FROM orders", "missing"), None);
+}